@@ -0,0 +1,360 @@
+#[cfg(feature = "current")]
+use crate::Current;
+#[cfg(feature = "voltage")]
+use crate::Voltage;
+use core::fmt;
+
+/// The ratio of the peak to the RMS value of a sine wave (√2), scaled by 10¹⁸.
+const SQRT_2_E18: u128 = 1_414_213_562_373_095_049;
+
+/// The scale of the conversion factors, so that 10¹⁸ is a factor of one.
+const RMS_E18: u128 = 1_000_000_000_000_000_000;
+
+/// How the magnitude of an AC value is measured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AcMeasure {
+    /// The largest instantaneous value, measured from zero.
+    Peak,
+    /// The root mean square value, which delivers the same power as a DC value.
+    Rms,
+    /// The difference between the largest positive and negative instantaneous values.
+    PeakToPeak,
+}
+
+impl AcMeasure {
+    /// Returns the abbreviation used when displaying an AC value, such as `"RMS"`.
+    pub const fn abbreviation(&self) -> &'static str {
+        match self {
+            AcMeasure::Peak => "pk",
+            AcMeasure::Rms => "RMS",
+            AcMeasure::PeakToPeak => "p-p",
+        }
+    }
+
+    /// The value of a sine wave with an RMS value of one, scaled by 10¹⁸.
+    const fn factor(&self) -> u128 {
+        match self {
+            AcMeasure::Peak => SQRT_2_E18,
+            AcMeasure::Rms => RMS_E18,
+            AcMeasure::PeakToPeak => SQRT_2_E18 * 2,
+        }
+    }
+
+    /// Converts a magnitude measured this way to the given measure, rounded to the nearest
+    /// whole number.
+    const fn convert(&self, magnitude: u128, to: AcMeasure) -> u128 {
+        let (numerator, denominator) = (to.factor(), self.factor());
+
+        (magnitude * numerator + denominator / 2) / denominator
+    }
+}
+
+impl fmt::Display for AcMeasure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.abbreviation())
+    }
+}
+
+/// An AC voltage, tagged with whether it is a peak, RMS or peak-to-peak value.
+///
+/// The voltage is kept exactly as it was created, and only converted when another measure is
+/// requested, so converting back and forth does not accumulate rounding errors. Conversions assume
+/// a sine wave and are rounded to the nearest whole microvolt (μV).
+///
+/// Only the magnitude of the voltage is kept, so a negative voltage is treated as positive.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let mains = AcVoltage::from_rms(230.volts());
+///
+/// assert_eq!(mains.peak(), 325_269_119.micro_volts());
+/// assert_eq!(mains.peak_to_peak(), 650_538_239.micro_volts());
+/// assert_eq!(mains.rms(), 230.volts());
+/// ```
+#[cfg(feature = "voltage")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AcVoltage {
+    voltage: Voltage,
+    measure: AcMeasure,
+}
+
+#[cfg(feature = "voltage")]
+impl AcVoltage {
+    /// Creates a new `AcVoltage` from a voltage measured in the given way.
+    ///
+    /// Panics if the voltage is the minimum value, as its magnitude cannot be represented.
+    pub const fn new(voltage: Voltage, measure: AcMeasure) -> Self {
+        Self {
+            voltage: voltage.abs(),
+            measure,
+        }
+    }
+
+    /// Creates a new `AcVoltage` from a peak voltage.
+    pub const fn from_peak(voltage: Voltage) -> Self {
+        Self::new(voltage, AcMeasure::Peak)
+    }
+
+    /// Creates a new `AcVoltage` from an RMS voltage.
+    pub const fn from_rms(voltage: Voltage) -> Self {
+        Self::new(voltage, AcMeasure::Rms)
+    }
+
+    /// Creates a new `AcVoltage` from a peak-to-peak voltage.
+    pub const fn from_peak_to_peak(voltage: Voltage) -> Self {
+        Self::new(voltage, AcMeasure::PeakToPeak)
+    }
+
+    /// Returns the voltage as it was created, without any conversion.
+    pub const fn voltage(&self) -> Voltage {
+        self.voltage
+    }
+
+    /// Returns how the voltage was measured.
+    pub const fn measure(&self) -> AcMeasure {
+        self.measure
+    }
+
+    /// Converts the voltage to the given measure, returning `None` if the result would overflow.
+    pub fn checked_to(&self, measure: AcMeasure) -> Option<Voltage> {
+        let magnitude = self
+            .measure
+            .convert(self.voltage.micro_volts() as u128, measure);
+
+        if magnitude > i64::MAX as u128 {
+            None
+        } else {
+            Some(Voltage::from_micro_volts(magnitude as i64))
+        }
+    }
+
+    /// Converts the voltage to the given measure.
+    ///
+    /// Panics if the result would overflow.
+    pub fn to(&self, measure: AcMeasure) -> Voltage {
+        match self.checked_to(measure) {
+            Some(voltage) => voltage,
+            None => panic!("Overflow when converting AC voltage"),
+        }
+    }
+
+    /// Returns the peak voltage.
+    ///
+    /// Panics if the result would overflow.
+    pub fn peak(&self) -> Voltage {
+        self.to(AcMeasure::Peak)
+    }
+
+    /// Returns the RMS voltage.
+    pub fn rms(&self) -> Voltage {
+        self.to(AcMeasure::Rms)
+    }
+
+    /// Returns the peak-to-peak voltage.
+    ///
+    /// Panics if the result would overflow.
+    pub fn peak_to_peak(&self) -> Voltage {
+        self.to(AcMeasure::PeakToPeak)
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl fmt::Display for AcVoltage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.voltage, f)?;
+        write!(f, " {}", self.measure)
+    }
+}
+
+/// An AC current, tagged with whether it is a peak, RMS or peak-to-peak value.
+///
+/// The current is kept exactly as it was created, and only converted when another measure is
+/// requested, so converting back and forth does not accumulate rounding errors. Conversions assume
+/// a sine wave and are rounded to the nearest whole microamp (μA).
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let current = AcCurrent::from_peak(1.amps());
+///
+/// assert_eq!(current.rms(), 707_107.micro_amps());
+/// assert_eq!(current.peak_to_peak(), 2.amps());
+/// ```
+#[cfg(feature = "current")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AcCurrent {
+    current: Current,
+    measure: AcMeasure,
+}
+
+#[cfg(feature = "current")]
+impl AcCurrent {
+    /// Creates a new `AcCurrent` from a current measured in the given way.
+    pub const fn new(current: Current, measure: AcMeasure) -> Self {
+        Self { current, measure }
+    }
+
+    /// Creates a new `AcCurrent` from a peak current.
+    pub const fn from_peak(current: Current) -> Self {
+        Self::new(current, AcMeasure::Peak)
+    }
+
+    /// Creates a new `AcCurrent` from an RMS current.
+    pub const fn from_rms(current: Current) -> Self {
+        Self::new(current, AcMeasure::Rms)
+    }
+
+    /// Creates a new `AcCurrent` from a peak-to-peak current.
+    pub const fn from_peak_to_peak(current: Current) -> Self {
+        Self::new(current, AcMeasure::PeakToPeak)
+    }
+
+    /// Returns the current as it was created, without any conversion.
+    pub const fn current(&self) -> Current {
+        self.current
+    }
+
+    /// Returns how the current was measured.
+    pub const fn measure(&self) -> AcMeasure {
+        self.measure
+    }
+
+    /// Converts the current to the given measure, returning `None` if the result would overflow.
+    pub fn checked_to(&self, measure: AcMeasure) -> Option<Current> {
+        let magnitude = self
+            .measure
+            .convert(self.current.micro_amps() as u128, measure);
+
+        if magnitude > u64::MAX as u128 {
+            None
+        } else {
+            Some(Current::from_micro_amps(magnitude as u64))
+        }
+    }
+
+    /// Converts the current to the given measure.
+    ///
+    /// Panics if the result would overflow.
+    pub fn to(&self, measure: AcMeasure) -> Current {
+        match self.checked_to(measure) {
+            Some(current) => current,
+            None => panic!("Overflow when converting AC current"),
+        }
+    }
+
+    /// Returns the peak current.
+    ///
+    /// Panics if the result would overflow.
+    pub fn peak(&self) -> Current {
+        self.to(AcMeasure::Peak)
+    }
+
+    /// Returns the RMS current.
+    pub fn rms(&self) -> Current {
+        self.to(AcMeasure::Rms)
+    }
+
+    /// Returns the peak-to-peak current.
+    ///
+    /// Panics if the result would overflow.
+    pub fn peak_to_peak(&self) -> Current {
+        self.to(AcMeasure::PeakToPeak)
+    }
+}
+
+#[cfg(feature = "current")]
+impl fmt::Display for AcCurrent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.current, f)?;
+        write!(f, " {}", self.measure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(1_000_000, AcMeasure::Rms, AcMeasure::Peak, 1_414_214; "RMS to peak")]
+    #[test_case(1_000_000, AcMeasure::Rms, AcMeasure::PeakToPeak, 2_828_427; "RMS to peak-to-peak")]
+    #[test_case(1_000_000, AcMeasure::Peak, AcMeasure::Rms, 707_107; "peak to RMS")]
+    #[test_case(1_000_000, AcMeasure::Peak, AcMeasure::PeakToPeak, 2_000_000; "peak to peak-to-peak")]
+    #[test_case(3, AcMeasure::PeakToPeak, AcMeasure::Peak, 2; "peak-to-peak to peak rounds to nearest")]
+    #[test_case(1_234, AcMeasure::Rms, AcMeasure::Rms, 1_234; "same measure is unchanged")]
+    fn test_convert(magnitude: u128, from: AcMeasure, to: AcMeasure, expected: u128) {
+        assert_eq!(from.convert(magnitude, to), expected);
+    }
+
+    #[test_case(AcMeasure::Peak, "pk"; "peak")]
+    #[test_case(AcMeasure::Rms, "RMS"; "RMS")]
+    #[test_case(AcMeasure::PeakToPeak, "p-p"; "peak-to-peak")]
+    fn test_measure_abbreviation(measure: AcMeasure, expected: &str) {
+        assert_eq!(measure.abbreviation(), expected);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(AcVoltage::from_rms(Voltage::from_micro_volts(230_000_000)), 325_269_119, 230_000_000, 650_538_239; "mains RMS")]
+    #[test_case(AcVoltage::from_peak(Voltage::from_micro_volts(-5_000_000)), 5_000_000, 3_535_534, 10_000_000; "negative peak")]
+    #[test_case(AcVoltage::from_peak_to_peak(Voltage::from_micro_volts(3_300_000)), 1_650_000, 1_166_726, 3_300_000; "peak-to-peak")]
+    fn test_ac_voltage(voltage: AcVoltage, peak: i64, rms: i64, peak_to_peak: i64) {
+        assert_eq!(voltage.peak().micro_volts(), peak);
+        assert_eq!(voltage.rms().micro_volts(), rms);
+        assert_eq!(voltage.peak_to_peak().micro_volts(), peak_to_peak);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    fn test_ac_voltage_keeps_original() {
+        let voltage = AcVoltage::from_rms(Voltage::from_micro_volts(1_000_001));
+
+        assert_eq!(voltage.voltage(), Voltage::from_micro_volts(1_000_001));
+        assert_eq!(voltage.measure(), AcMeasure::Rms);
+        assert_eq!(voltage.rms(), voltage.voltage());
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    fn test_ac_voltage_overflow() {
+        let voltage = AcVoltage::from_rms(Voltage::from_micro_volts(i64::MAX));
+
+        assert_eq!(voltage.checked_to(AcMeasure::Peak), None);
+        assert!(voltage.checked_to(AcMeasure::Rms).is_some());
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    #[should_panic]
+    fn test_ac_voltage_overflow_panics() {
+        AcVoltage::from_rms(Voltage::from_micro_volts(i64::MAX)).peak();
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    fn test_ac_voltage_display() {
+        use alloc::format;
+
+        let voltage = AcVoltage::from_rms(Voltage::from_micro_volts(230_000_000));
+        assert_eq!(format!("{}", voltage), "230.00 V RMS");
+    }
+
+    #[cfg(feature = "current")]
+    #[test_case(AcCurrent::from_peak(Current::from_micro_amps(1_000_000)), 1_000_000, 707_107, 2_000_000; "peak")]
+    #[test_case(AcCurrent::from_rms(Current::from_micro_amps(0)), 0, 0, 0; "zero")]
+    #[test_case(AcCurrent::from_peak_to_peak(Current::from_micro_amps(u64::MAX)), u64::MAX / 2 + 1, 6_521_908_912_666_391_105, u64::MAX; "maximum")]
+    fn test_ac_current(current: AcCurrent, peak: u64, rms: u64, peak_to_peak: u64) {
+        assert_eq!(current.peak().micro_amps(), peak);
+        assert_eq!(current.rms().micro_amps(), rms);
+        assert_eq!(current.peak_to_peak().micro_amps(), peak_to_peak);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_ac_current_overflow() {
+        let current = AcCurrent::from_peak(Current::from_micro_amps(u64::MAX));
+
+        assert_eq!(current.checked_to(AcMeasure::PeakToPeak), None);
+        assert_eq!(current.measure(), AcMeasure::Peak);
+        assert_eq!(current.current(), Current::from_micro_amps(u64::MAX));
+    }
+}
@@ -0,0 +1,445 @@
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::{isqrt, try_scale_f64_to_u64};
+use crate::parse::{parse_si_unsigned, ParseError};
+#[cfg(all(feature = "voltage", feature = "current"))]
+use crate::{AcCurrent, AcVoltage};
+use crate::{Power, PowerFactor, ReactivePower, UnitError};
+use core::{cmp, fmt, hash, iter, ops, str};
+
+/// Represents an apparent power value, stored as whole microvolt-amperes (μVA) as a 64-bit value.
+/// This value can only be positive.
+///
+/// **Reminder:** `1000 μVA = 1 mVA, 1000 mVA = 1 VA, 1000 VA = 1 kVA`
+///
+/// This is an immutable type. Any math operators return a new `ApparentPower` value.
+///
+/// The apparent power of an AC load is the product of its RMS voltage and current. It is made up of
+/// the real `Power` delivered to the load and the `ReactivePower` exchanged with it, which form the
+/// sides of the power triangle (`S² = P² + Q²`).
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let real = 80.watts();
+/// let reactive = ReactivePower::from_micro_vars(60_000_000); // 60 VAR
+///
+/// let apparent = ApparentPower::from_real_and_reactive(real, reactive).unwrap();
+/// assert_eq!(apparent, ApparentPower::from_micro_volt_amps(100_000_000));
+///
+/// let pf = apparent.power_factor(real).unwrap();
+/// assert_eq!(pf.ratio(), 0.8);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApparentPower {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_volt_amps"))]
+    raw: u64,
+}
+
+impl ApparentPower {
+    /// Creates a new `ApparentPower` from a number of whole microvolt-amperes (μVA).
+    #[inline]
+    pub const fn from_micro_volt_amps(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `ApparentPower` from a number of fractional millivolt-amperes (mVA).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_volt_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_micro_volt_amps)
+    }
+
+    /// Creates a new `ApparentPower` from a number of fractional volt-amperes (VA).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_volt_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_micro_volt_amps)
+    }
+
+    /// Creates a new `ApparentPower` from a number of fractional kilovolt-amperes (kVA).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_volt_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000f64).map(Self::from_micro_volt_amps)
+    }
+
+    /// Calculates the apparent power from the real and reactive power of a load
+    /// (`S = √(P² + Q²)`).
+    ///
+    /// Will be rounded down to the nearest whole microvolt-ampere (μVA).
+    /// Returns `None` if the result would overflow.
+    pub fn from_real_and_reactive(real: Power, reactive: ReactivePower) -> Option<Self> {
+        let real = real.micro_watts() as u128;
+        let reactive = reactive.micro_vars().unsigned_abs() as u128;
+
+        let sum_of_squares = (real * real).checked_add(reactive * reactive)?;
+
+        u64::try_from(isqrt(sum_of_squares))
+            .ok()
+            .map(Self::from_micro_volt_amps)
+    }
+
+    /// Calculates the apparent power of an AC load from its voltage and current, which are
+    /// converted to RMS values first (`S = Vrms × Irms`).
+    ///
+    /// Will be rounded down to the nearest whole microvolt-ampere (μVA).
+    /// Returns `None` if the result would overflow.
+    #[cfg(all(feature = "voltage", feature = "current"))]
+    pub fn from_ac(voltage: AcVoltage, current: AcCurrent) -> Option<Self> {
+        let voltage = voltage.rms().micro_volts().unsigned_abs() as u128;
+        let current = current.rms().micro_amps() as u128;
+
+        u64::try_from(voltage * current / 1_000_000)
+            .ok()
+            .map(Self::from_micro_volt_amps)
+    }
+
+    /// Returns the apparent power value in whole microvolt-amperes (μVA).
+    #[inline]
+    pub const fn micro_volt_amps(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the apparent power value in fractional millivolt-amperes (mVA).
+    #[inline]
+    pub fn milli_volt_amps(&self) -> f64 {
+        self.raw as f64 / 1_000_f64
+    }
+
+    /// Returns the apparent power value in fractional volt-amperes (VA).
+    #[inline]
+    pub fn volt_amps(&self) -> f64 {
+        self.raw as f64 / 1_000_000_f64
+    }
+
+    /// Returns the apparent power value in fractional kilovolt-amperes (kVA).
+    #[inline]
+    pub fn kilo_volt_amps(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_f64
+    }
+
+    /// Calculates the real power delivered to the load, given its reactive power
+    /// (`P = √(S² - Q²)`).
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW).
+    /// Returns `None` if the reactive power is greater than the apparent power.
+    pub fn real_power(&self, reactive: ReactivePower) -> Option<Power> {
+        Self::remaining_side(self.raw, reactive.micro_vars().unsigned_abs())
+            .map(Power::from_micro_watts)
+    }
+
+    /// Calculates the magnitude of the reactive power exchanged with the load, given its real
+    /// power (`Q = √(S² - P²)`).
+    ///
+    /// The power triangle does not say whether the load is inductive or capacitive, so the
+    /// result is always positive (inductive). Negate it for a capacitive load.
+    ///
+    /// Will be rounded down to the nearest whole microvolt-ampere reactive (μVAR).
+    /// Returns `None` if the real power is greater than the apparent power.
+    pub fn reactive_power(&self, real: Power) -> Option<ReactivePower> {
+        let reactive = Self::remaining_side(self.raw, real.micro_watts())?;
+
+        i64::try_from(reactive)
+            .ok()
+            .map(ReactivePower::from_micro_vars)
+    }
+
+    /// Calculates the power factor of the load, given its real power (`PF = P ÷ S`).
+    ///
+    /// Will be rounded down to the nearest whole millionth.
+    /// Returns `None` if the apparent power is zero or the real power is greater than the
+    /// apparent power.
+    pub fn power_factor(&self, real: Power) -> Option<PowerFactor> {
+        if self.raw == 0 || real.micro_watts() > self.raw {
+            return None;
+        }
+
+        let millionths = real.micro_watts() as u128 * 1_000_000 / self.raw as u128;
+        PowerFactor::from_millionths(millionths as u32)
+    }
+
+    /// Returns whether the apparent power value is zero volt-amperes (0VA).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns an `ApparentPower` value of zero volt-amperes (0VA).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_micro_volt_amps(0)
+    }
+
+    /// Adds two `ApparentPower` values together, returning `None` if the result would overflow.
+    ///
+    /// This is only meaningful for loads with the same power factor.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_micro_volt_amps)
+    }
+
+    /// Subtracts one `ApparentPower` value from another, returning `None` if the result would
+    /// underflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_micro_volt_amps)
+    }
+
+    /// Returns the unit symbol used when displaying the apparent power value, such as `"kVA"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "μVA"),
+            1_000..=999_999 => (1_000, "mVA"),
+            1_000_000..=999_999_999 => (1_000_000, "VA"),
+            _ => (1_000_000_000, "kVA"),
+        }
+    }
+
+    /// Calculates the remaining side of a right triangle from its hypotenuse and one side,
+    /// returning `None` if the side is longer than the hypotenuse.
+    fn remaining_side(hypotenuse: u64, side: u64) -> Option<u64> {
+        let (hypotenuse, side) = (hypotenuse as u128, side as u128);
+        let difference = (hypotenuse * hypotenuse).checked_sub(side * side)?;
+
+        Some(isqrt(difference) as u64)
+    }
+}
+
+approx::impl_approx_eq!(ApparentPower, unsigned);
+compare::impl_const_compare!(ApparentPower, unsigned);
+
+impl PartialEq for ApparentPower {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for ApparentPower {}
+
+impl PartialOrd for ApparentPower {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ApparentPower {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for ApparentPower {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for ApparentPower {
+    type Output = Self;
+
+    /// Adds two `ApparentPower` values together, returning a new `ApparentPower` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("Overflow when adding apparent power values")
+    }
+}
+
+impl ops::Sub for ApparentPower {
+    type Output = Self;
+
+    /// Subtracts the `ApparentPower` value from another, returning a new `ApparentPower` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .expect("Overflow when subtracting apparent power values")
+    }
+}
+
+impl iter::Sum for ApparentPower {
+    /// Sums the `ApparentPower` values, returning zero (0VA) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a ApparentPower> for ApparentPower {
+    /// Sums the `ApparentPower` values, returning zero (0VA) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl fmt::Display for ApparentPower {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let (scale, unit) = self.display_scale();
+        let value = (self.raw as f64) / (scale as f64);
+
+        display::write_padded(f, format_args!("{value:.precision$} {unit}"))
+    }
+}
+
+impl str::FromStr for ApparentPower {
+    type Err = ParseError;
+
+    /// Parses an `ApparentPower` value from a string with an optional SI prefix and unit symbol,
+    /// such as `250mVA`, `1.5 kVA` or `5`.
+    ///
+    /// Will be rounded down to the nearest whole microvolt-ampere (μVA).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["VA"], 6).map(Self::from_micro_volt_amps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(80_000_000, 60_000_000, Some(100_000_000); "3-4-5 triangle")]
+    #[test_case(80_000_000, -60_000_000, Some(100_000_000); "capacitive load")]
+    #[test_case(5_000, 0, Some(5_000); "purely resistive")]
+    #[test_case(1, 1, Some(1); "rounds down")]
+    #[test_case(u64::MAX, 0, Some(u64::MAX); "maximum real power")]
+    #[test_case(u64::MAX, i64::MAX, None; "overflow")]
+    fn test_from_real_and_reactive(real: u64, reactive: i64, expected: Option<u64>) {
+        let apparent = ApparentPower::from_real_and_reactive(
+            Power::from_micro_watts(real),
+            ReactivePower::from_micro_vars(reactive),
+        );
+
+        assert_eq!(apparent.map(|s| s.micro_volt_amps()), expected);
+    }
+
+    #[test_case(100_000_000, 80_000_000, Some(60_000_000); "3-4-5 triangle")]
+    #[test_case(100_000_000, 100_000_000, Some(0); "purely resistive")]
+    #[test_case(100_000_000, 100_000_001, None; "real power too large")]
+    fn test_reactive_power(apparent: u64, real: u64, expected: Option<i64>) {
+        let apparent = ApparentPower::from_micro_volt_amps(apparent);
+        let reactive = apparent.reactive_power(Power::from_micro_watts(real));
+
+        assert_eq!(reactive.map(|q| q.micro_vars()), expected);
+    }
+
+    #[test_case(100_000_000, -60_000_000, Some(80_000_000); "capacitive load")]
+    #[test_case(100_000_000, 100_000_000, Some(0); "purely reactive")]
+    #[test_case(1_000, 1_001, None; "reactive power too large")]
+    fn test_real_power(apparent: u64, reactive: i64, expected: Option<u64>) {
+        let apparent = ApparentPower::from_micro_volt_amps(apparent);
+        let real = apparent.real_power(ReactivePower::from_micro_vars(reactive));
+
+        assert_eq!(real.map(|p| p.micro_watts()), expected);
+    }
+
+    #[test_case(100_000_000, 80_000_000, Some(800_000); "lagging load")]
+    #[test_case(3, 1, Some(333_333); "rounds down")]
+    #[test_case(0, 0, None; "zero apparent power")]
+    #[test_case(1_000, 1_001, None; "real power too large")]
+    fn test_power_factor(apparent: u64, real: u64, expected: Option<u32>) {
+        let apparent = ApparentPower::from_micro_volt_amps(apparent);
+        let pf = apparent.power_factor(Power::from_micro_watts(real));
+
+        assert_eq!(pf.map(|pf| pf.millionths()), expected);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current"))]
+    #[test]
+    fn test_from_ac() {
+        use crate::{Current, Voltage};
+
+        let voltage = AcVoltage::from_rms(Voltage::from_micro_volts(230_000_000));
+        let current = AcCurrent::from_peak(Current::from_micro_amps(1_414_214));
+
+        let apparent = ApparentPower::from_ac(voltage, current).unwrap();
+        assert_eq!(apparent.micro_volt_amps(), 230_000_000);
+
+        let overflow = AcVoltage::from_rms(Voltage::from_micro_volts(i64::MAX));
+        let current = AcCurrent::from_rms(Current::from_micro_amps(3_000_000));
+        assert_eq!(ApparentPower::from_ac(overflow, current), None);
+    }
+
+    #[test_case(1.5, Ok(1_500_000); "volt-amps")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    fn test_try_from_volt_amps(value: f64, expected: Result<u64, UnitError>) {
+        assert_eq!(
+            ApparentPower::try_from_volt_amps(value).map(|s| s.micro_volt_amps()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let apparent = ApparentPower::from_micro_volt_amps(2_500_000_000);
+
+        assert_eq!(apparent.milli_volt_amps(), 2_500_000.0);
+        assert_eq!(apparent.volt_amps(), 2_500.0);
+        assert_eq!(apparent.kilo_volt_amps(), 2.5);
+        assert_eq!(ApparentPower::try_from_kilo_volt_amps(2.5), Ok(apparent));
+        assert_eq!(
+            ApparentPower::try_from_milli_volt_amps(2_500_000.0),
+            Ok(apparent)
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = ApparentPower::from_micro_volt_amps(3_000);
+        let b = ApparentPower::from_micro_volt_amps(1_000);
+
+        assert_eq!(a + b, ApparentPower::from_micro_volt_amps(4_000));
+        assert_eq!(a - b, ApparentPower::from_micro_volt_amps(2_000));
+        assert_eq!(b.checked_sub(a), None);
+        assert_eq!(
+            [a, b].iter().sum::<ApparentPower>(),
+            ApparentPower::from_micro_volt_amps(4_000)
+        );
+    }
+
+    #[test_case(999, "999.00 μVA"; "microvolt-amps")]
+    #[test_case(1_500_000, "1.50 VA"; "volt-amps")]
+    #[test_case(2_500_000_000, "2.50 kVA"; "kilovolt-amps")]
+    fn test_display(micro_volt_amps: u64, expected: &str) {
+        let apparent = ApparentPower::from_micro_volt_amps(micro_volt_amps);
+        assert_eq!(format!("{}", apparent), expected);
+    }
+
+    #[test_case("1.5kVA", Ok(1_500_000_000); "kilovolt-amps")]
+    #[test_case("250 mVA", Ok(250_000); "millivolt-amps with space")]
+    #[test_case("5", Ok(5_000_000); "no unit")]
+    #[test_case("", Err(ParseError::Empty); "empty")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        assert_eq!(
+            s.parse::<ApparentPower>().map(|p| p.micro_volt_amps()),
+            expected
+        );
+    }
+}
@@ -0,0 +1,32 @@
+/// Implements the `approx_eq` and `approx_eq_rel` methods for a unit type with a `raw` field.
+macro_rules! impl_approx_eq {
+    ($type:ident, unsigned) => {
+        $crate::approx::impl_approx_eq!($type, |raw: u64| raw);
+    };
+    ($type:ident, signed) => {
+        $crate::approx::impl_approx_eq!($type, |raw: i64| raw.unsigned_abs());
+    };
+    ($type:ident, |$raw:ident: $int:ty| $magnitude:expr) => {
+        impl $type {
+            /// Returns `true` if the values differ by no more than the absolute tolerance.
+            pub fn approx_eq(&self, other: Self, tolerance: Self) -> bool {
+                let magnitude = |$raw: $int| -> u64 { $magnitude };
+                self.raw.abs_diff(other.raw) <= magnitude(tolerance.raw)
+            }
+
+            /// Returns `true` if the values differ by no more than a percentage of the larger
+            /// magnitude, such as `1.0` for 1%.
+            ///
+            /// Equal values are always approximately equal, but otherwise this returns `false` if
+            /// the percentage is negative or NaN.
+            pub fn approx_eq_rel(&self, other: Self, percent: f64) -> bool {
+                let magnitude = |$raw: $int| -> u64 { $magnitude };
+                let largest = magnitude(self.raw).max(magnitude(other.raw));
+
+                self.raw.abs_diff(other.raw) as f64 <= largest as f64 * percent / 100f64
+            }
+        }
+    };
+}
+
+pub(crate) use impl_approx_eq;
@@ -0,0 +1,276 @@
+//! American Wire Gauge (AWG) sizes for solid copper wire.
+//!
+//! Each `Gauge` gives the cross-sectional area of the wire, its resistance at 20°C and an
+//! estimate of the current it can carry. Gauges from 4/0 (0000) to 40 AWG are supported, with the
+//! aught sizes numbered downward from zero, so 1/0 is `0`, 2/0 is `-1`, 3/0 is `-2` and 4/0 is
+//! `-3`.
+//!
+//! ```rust
+//! use ohms::awg::Gauge;
+//! use ohms::prelude::*;
+//!
+//! let wire = Gauge::new(22).unwrap();
+//!
+//! assert_eq!(wire.square_micro_meters(), 325_534); // 0.326mm²
+//! assert_eq!(wire.resistance_per_meter(), 53.milli_ohms());
+//! assert_eq!(wire.micro_ohms_per_meter(), 52_962);
+//! assert_eq!(wire.resistance(2_500).unwrap(), 132.milli_ohms()); // 2.5m
+//! assert_eq!(wire.ampacity(), 7.amps());
+//! ```
+use crate::materials::COPPER;
+use crate::resistivity::conductor_resistance;
+use crate::{display, Current, CurrentDensity, Resistance, RoundingMode};
+use core::fmt;
+
+/// The smallest (thickest) supported gauge number, 4/0 AWG.
+pub const MIN_GAUGE: i8 = -3;
+
+/// The largest (thinnest) supported gauge number, 40 AWG.
+pub const MAX_GAUGE: i8 = 40;
+
+/// The cross-sectional area in square micrometers (μm²) and the ampacity in microamps (μA) of
+/// each gauge, starting from 4/0 AWG.
+const TABLE: [(u64, u64); 44] = [
+    (107_219_303, 380_000_000), // 4/0
+    (85_028_773, 328_000_000),  // 3/0
+    (67_430_882, 283_000_000),  // 2/0
+    (53_475_121, 245_000_000),  // 1/0
+    (42_407_699, 211_000_000),  // 1
+    (33_630_834, 181_000_000),  // 2
+    (26_670_464, 158_000_000),  // 3
+    (21_150_639, 135_000_000),  // 4
+    (16_773_220, 118_000_000),  // 5
+    (13_301_768, 101_000_000),  // 6
+    (10_548_782, 89_000_000),   // 7
+    (8_365_564, 73_000_000),    // 8
+    (6_634_194, 64_000_000),    // 9
+    (5_261_155, 55_000_000),    // 10
+    (4_172_286, 47_000_000),    // 11
+    (3_308_773, 41_000_000),    // 12
+    (2_623_976, 35_000_000),    // 13
+    (2_080_908, 32_000_000),    // 14
+    (1_650_235, 28_000_000),    // 15
+    (1_308_696, 22_000_000),    // 16
+    (1_037_843, 19_000_000),    // 17
+    (823_047, 16_000_000),      // 18
+    (652_706, 14_000_000),      // 19
+    (517_619, 11_000_000),      // 20
+    (410_491, 9_000_000),       // 21
+    (325_534, 7_000_000),       // 22
+    (258_160, 4_700_000),       // 23
+    (204_730, 3_500_000),       // 24
+    (162_359, 2_700_000),       // 25
+    (128_756, 2_200_000),       // 26
+    (102_108, 1_700_000),       // 27
+    (80_976, 1_400_000),        // 28
+    (64_217, 1_200_000),        // 29
+    (50_926, 860_000),          // 30
+    (40_386, 700_000),          // 31
+    (32_028, 530_000),          // 32
+    (25_399, 430_000),          // 33
+    (20_142, 330_000),          // 34
+    (15_974, 270_000),          // 35
+    (12_668, 210_000),          // 36
+    (10_046, 170_000),          // 37
+    (7_967, 130_000),           // 38
+    (6_318, 110_000),           // 39
+    (5_010, 90_000),            // 40
+];
+
+/// An American Wire Gauge (AWG) size of solid copper wire.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gauge {
+    number: i8,
+}
+
+impl Gauge {
+    /// Creates a new `Gauge` from its number, where 1/0 to 4/0 are `0` to `-3`.
+    ///
+    /// Returns `None` if the gauge is thicker than 4/0 or thinner than 40 AWG.
+    #[inline]
+    pub const fn new(number: i8) -> Option<Self> {
+        if number < MIN_GAUGE || number > MAX_GAUGE {
+            return None;
+        }
+
+        Some(Self { number })
+    }
+
+    /// Returns the gauge number, where 1/0 to 4/0 are `0` to `-3`.
+    #[inline]
+    pub const fn number(&self) -> i8 {
+        self.number
+    }
+
+    /// Returns the cross-sectional area of the wire in whole square micrometers (μm²).
+    #[inline]
+    pub const fn square_micro_meters(&self) -> u64 {
+        self.entry().0
+    }
+
+    /// Returns the cross-sectional area of the wire in fractional square millimeters (mm²).
+    #[inline]
+    pub fn square_milli_meters(&self) -> f64 {
+        self.square_micro_meters() as f64 / 1_000_000f64
+    }
+
+    /// Returns the resistance of one meter of the wire at 20°C.
+    ///
+    /// Will be rounded to the nearest whole milliohm (mΩ), so the thickest gauges lose most of
+    /// their precision. Use `micro_ohms_per_meter`, or `resistance` with the actual length, for
+    /// those.
+    #[inline]
+    pub fn resistance_per_meter(&self) -> Resistance {
+        self.resistance(1_000)
+            .expect("Resistance of one meter of wire cannot overflow")
+    }
+
+    /// Returns the resistance of one meter of the wire at 20°C in whole microohms (μΩ).
+    ///
+    /// Will be rounded to the nearest whole microohm (μΩ).
+    #[inline]
+    pub fn micro_ohms_per_meter(&self) -> u64 {
+        // pΩ·m × 1,000,000 ÷ μm² is in microohms per meter
+        let micro_ohms = RoundingMode::Nearest.div_u128(
+            COPPER.resistivity().pico_ohm_meters() as u128 * 1_000_000,
+            self.square_micro_meters() as u128,
+        );
+
+        micro_ohms as u64
+    }
+
+    /// Returns the resistance of a length of the wire in whole millimeters (mm) at 20°C.
+    ///
+    /// Will be rounded to the nearest whole milliohm (mΩ).
+    /// Returns `None` if the resistance would overflow.
+    #[inline]
+    pub fn resistance(&self, length_mm: u64) -> Option<Resistance> {
+        conductor_resistance(
+            COPPER.resistivity(),
+            length_mm,
+            self.square_micro_meters(),
+            RoundingMode::Nearest,
+        )
+    }
+
+    /// Returns an estimate of the current the wire can carry as a single conductor in chassis
+    /// wiring.
+    ///
+    /// This is a rule of thumb only; bundled or enclosed wires and insulation ratings allow much
+    /// less current, so check the applicable standard for power distribution.
+    #[inline]
+    pub const fn ampacity(&self) -> Current {
+        Current::from_micro_amps(self.entry().1)
+    }
+
+    /// Calculates the current density in the wire when carrying a current.
+    ///
+    /// Will be rounded down to the nearest whole microamp per square millimeter (μA/mm²).
+    /// Returns `None` if the result would overflow.
+    #[inline]
+    pub fn current_density(&self, current: Current) -> Option<CurrentDensity> {
+        CurrentDensity::from_current(current, self.square_micro_meters())
+    }
+
+    /// Returns the thinnest gauge whose ampacity estimate is at least the given current.
+    ///
+    /// Returns `None` if even 4/0 AWG cannot carry the current.
+    pub fn for_current(current: Current) -> Option<Self> {
+        (MIN_GAUGE..=MAX_GAUGE)
+            .rev()
+            .filter_map(Self::new)
+            .find(|gauge| gauge.ampacity() >= current)
+    }
+
+    const fn entry(&self) -> (u64, u64) {
+        TABLE[(self.number - MIN_GAUGE) as usize]
+    }
+}
+
+impl fmt::Display for Gauge {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.number <= 0 {
+            display::write_padded(f, format_args!("{}/0 AWG", 1 - self.number))
+        } else {
+            display::write_padded(f, format_args!("{} AWG", self.number))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(-4, None; "thicker than 4/0")]
+    #[test_case(-3, Some(-3); "4/0")]
+    #[test_case(40, Some(40); "40")]
+    #[test_case(41, None; "thinner than 40")]
+    fn test_new(number: i8, expected: Option<i8>) {
+        assert_eq!(Gauge::new(number).map(|g| g.number()), expected);
+    }
+
+    #[test]
+    fn test_table_ordered() {
+        assert!(TABLE
+            .windows(2)
+            .all(|pair| pair[0].0 > pair[1].0 && pair[0].1 > pair[1].1));
+    }
+
+    #[test_case(-3, 107_219_303, 161, 380_000_000; "4/0")]
+    #[test_case(10, 5_261_155, 3_277, 55_000_000; "10")]
+    #[test_case(22, 325_534, 52_962, 7_000_000; "22")]
+    #[test_case(24, 204_730, 84_213, 3_500_000; "24")]
+    #[test_case(40, 5_010, 3_441_317, 90_000; "40")]
+    fn test_gauge(number: i8, square_micro_meters: u64, micro_ohms_per_meter: u64, ampacity: u64) {
+        let gauge = Gauge::new(number).unwrap();
+
+        assert_eq!(gauge.square_micro_meters(), square_micro_meters);
+        assert_eq!(gauge.micro_ohms_per_meter(), micro_ohms_per_meter);
+        assert_eq!(
+            gauge.resistance_per_meter().milli_ohms(),
+            (micro_ohms_per_meter + 500) / 1_000
+        );
+        assert_eq!(gauge.ampacity().micro_amps(), ampacity);
+    }
+
+    #[test_case(-3, 10_000, Some(2); "4/0 over 10m")]
+    #[test_case(18, 2_000, Some(42); "18 over 2m")]
+    #[test_case(40, u64::MAX, None; "overflow")]
+    fn test_resistance(number: i8, length_mm: u64, expected: Option<u64>) {
+        let resistance = Gauge::new(number).unwrap().resistance(length_mm);
+        assert_eq!(resistance.map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test_case(10, 10_000_000, Some(1_900_723); "10 AWG at 10A")]
+    #[test_case(40, 90_000, Some(17_964_071); "40 AWG at its ampacity")]
+    #[test_case(40, u64::MAX, None; "overflow")]
+    fn test_current_density(number: i8, micro_amps: u64, expected: Option<u64>) {
+        let density = Gauge::new(number)
+            .unwrap()
+            .current_density(Current::from_micro_amps(micro_amps));
+        assert_eq!(
+            density.map(|d| d.micro_amps_per_square_milli_meter()),
+            expected
+        );
+    }
+
+    #[test_case(0, Some(40); "no current")]
+    #[test_case(5_000_000, Some(22); "5A")]
+    #[test_case(7_000_000, Some(22); "exactly 7A")]
+    #[test_case(380_000_000, Some(-3); "4/0")]
+    #[test_case(380_000_001, None; "too much current")]
+    fn test_for_current(micro_amps: u64, expected: Option<i8>) {
+        let gauge = Gauge::for_current(Current::from_micro_amps(micro_amps));
+        assert_eq!(gauge.map(|g| g.number()), expected);
+    }
+
+    #[test_case(-3, "4/0 AWG"; "4/0")]
+    #[test_case(0, "1/0 AWG"; "1/0")]
+    #[test_case(22, "22 AWG"; "22")]
+    fn test_display(number: i8, expected: &str) {
+        let gauge = Gauge::new(number).unwrap();
+        assert_eq!(format!("{gauge}"), expected);
+    }
+}
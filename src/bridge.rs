@@ -0,0 +1,200 @@
+use crate::Resistance;
+#[cfg(feature = "voltage")]
+use crate::Voltage;
+
+/// Calculates the unknown fourth arm of a balanced Wheatstone bridge given the three known arms.
+///
+/// At balance `R4 = R3 × R2 / R1`, which is independent of the excitation voltage.
+///
+/// Will be rounded down to the nearest whole milliohm (mΩ).
+/// Panics if `r1` is zero.
+///
+/// ```rust
+/// use ohms::prelude::*;
+/// use ohms::wheatstone_balance_resistor;
+///
+/// let r4 = wheatstone_balance_resistor(1.kilo_ohms(), 2.kilo_ohms(), 500.ohms());
+/// assert_eq!(r4.ohms(), 1000.0);
+/// ```
+pub fn wheatstone_balance_resistor(r1: Resistance, r2: Resistance, r3: Resistance) -> Resistance {
+    if r1.is_zero() {
+        panic!("Resistance R1 cannot be zero, bridge cannot be balanced");
+    }
+
+    let milli_ohms = (r3.milli_ohms() as u128)
+        .checked_mul(r2.milli_ohms() as u128)
+        .expect("Resistance would overflow")
+        / r1.milli_ohms() as u128;
+
+    Resistance::from_milli_ohms(u64::try_from(milli_ohms).expect("Resistance would overflow"))
+}
+
+/// Calculates the sensitivity of a quarter Wheatstone bridge at its operating point, in volts
+/// per ohm (V/Ω).
+///
+/// This assumes all four arms are at their nominal resistance, so that a small change in one arm
+/// `ΔR` produces an output voltage of approximately `V_exc × ΔR / 4R`.
+/// Panics if the nominal resistance is zero.
+#[cfg(feature = "voltage")]
+pub fn wheatstone_sensitivity(v_exc: Voltage, r_nominal: Resistance) -> f64 {
+    if r_nominal.is_zero() {
+        panic!("Nominal resistance cannot be zero");
+    }
+
+    v_exc.volts() / (4f64 * r_nominal.ohms())
+}
+
+/// Transforms a delta (Π) network into its equivalent wye (T) network.
+///
+/// The delta resistors are given as `[R_ab, R_bc, R_ca]`, each between a pair of terminals, and
+/// the wye resistors are returned as `[R_a, R_b, R_c]`, each between a terminal and the common
+/// node. For example `R_a = R_ab × R_ca / (R_ab + R_bc + R_ca)`.
+///
+/// Will be rounded down to the nearest whole milliohm (mΩ).
+/// If all three resistors are zero, the wye resistors are also zero.
+///
+/// ```rust
+/// use ohms::delta_to_wye;
+/// use ohms::prelude::*;
+///
+/// let [r_a, r_b, r_c] = delta_to_wye([30.ohms(), 30.ohms(), 30.ohms()]);
+/// assert_eq!(r_a, 10.ohms());
+/// ```
+pub fn delta_to_wye(delta: [Resistance; 3]) -> [Resistance; 3] {
+    let [r_ab, r_bc, r_ca] = delta.map(|r| r.milli_ohms() as u128);
+    let sum = r_ab + r_bc + r_ca;
+
+    if sum == 0 {
+        return [Resistance::zero(); 3];
+    }
+
+    // Each product divided by the sum is never larger than either resistor in the product
+    [r_ab * r_ca, r_ab * r_bc, r_bc * r_ca]
+        .map(|product| Resistance::from_milli_ohms((product / sum) as u64))
+}
+
+/// Transforms a wye (T) network into its equivalent delta (Π) network.
+///
+/// The wye resistors are given as `[R_a, R_b, R_c]`, each between a terminal and the common
+/// node, and the delta resistors are returned as `[R_ab, R_bc, R_ca]`, each between a pair of
+/// terminals. For example `R_ab = (R_a·R_b + R_b·R_c + R_c·R_a) / R_c`.
+///
+/// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+///
+/// Will be rounded down to the nearest whole milliohm (mΩ).
+/// Panics if any of the resistors are zero, or the resistance would overflow.
+///
+/// ```rust
+/// use ohms::prelude::*;
+/// use ohms::wye_to_delta;
+///
+/// let [r_ab, r_bc, r_ca] = wye_to_delta([10.ohms(), 10.ohms(), 10.ohms()]);
+/// assert_eq!(r_ab, 30.ohms());
+/// ```
+pub fn wye_to_delta(wye: [Resistance; 3]) -> [Resistance; 3] {
+    if wye.iter().any(Resistance::is_zero) {
+        panic!("Resistance cannot be zero, infinite delta resistance would result");
+    }
+
+    let [r_a, r_b, r_c] = wye.map(|r| r.milli_ohms() as u128);
+
+    let sum_of_products = (r_a * r_b)
+        .checked_add(r_b * r_c)
+        .and_then(|sum| sum.checked_add(r_c * r_a))
+        .expect("Resistance would overflow");
+
+    [r_c, r_a, r_b].map(|opposite| {
+        let milli_ohms = sum_of_products / opposite;
+        Resistance::from_milli_ohms(u64::try_from(milli_ohms).expect("Resistance would overflow"))
+    })
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(1_000_000, 1_000_000, 350_000, 350_000; "equal ratio arms equal R3")]
+    #[test_case(1_000_000, 2_000_000, 500_000, 1_000_000; "1kΩ, 2kΩ, 500Ω equals 1kΩ")]
+    #[test_case(3_000, 1_000, 1_000, 333; "rounds down to nearest milliohm")]
+    fn test_wheatstone_balance_resistor(
+        r1_milli_ohms: u64,
+        r2_milli_ohms: u64,
+        r3_milli_ohms: u64,
+        expected_milli_ohms: u64,
+    ) {
+        let r4 = wheatstone_balance_resistor(
+            Resistance::from_milli_ohms(r1_milli_ohms),
+            Resistance::from_milli_ohms(r2_milli_ohms),
+            Resistance::from_milli_ohms(r3_milli_ohms),
+        );
+
+        assert_eq!(r4.milli_ohms(), expected_milli_ohms);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wheatstone_balance_resistor_zero_r1_panics() {
+        let r = Resistance::from_milli_ohms(1_000);
+        wheatstone_balance_resistor(Resistance::zero(), r, r);
+    }
+
+    #[test_case([30_000, 30_000, 30_000], [10_000, 10_000, 10_000]; "balanced delta equals one third")]
+    #[test_case([10_000, 20_000, 30_000], [5_000, 3_333, 10_000]; "unbalanced delta")]
+    #[test_case([0, 0, 10_000], [0, 0, 0]; "two shorted arms")]
+    #[test_case([0, 0, 0], [0, 0, 0]; "all zero")]
+    #[test_case([u64::MAX, u64::MAX, u64::MAX], [u64::MAX / 3; 3]; "maximum does not overflow")]
+    fn test_delta_to_wye(delta: [u64; 3], expected: [u64; 3]) {
+        let wye = delta_to_wye(delta.map(Resistance::from_milli_ohms));
+        assert_eq!(wye.map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test_case([10_000, 10_000, 10_000], [30_000, 30_000, 30_000]; "balanced wye equals three times")]
+    #[test_case([5_000, 10_000, 20_000], [17_500, 70_000, 35_000]; "unbalanced wye")]
+    fn test_wye_to_delta(wye: [u64; 3], expected: [u64; 3]) {
+        let delta = wye_to_delta(wye.map(Resistance::from_milli_ohms));
+        assert_eq!(delta.map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test]
+    fn test_wye_to_delta_round_trip() {
+        let delta = [
+            Resistance::from_milli_ohms(100_000),
+            Resistance::from_milli_ohms(220_000),
+            Resistance::from_milli_ohms(470_000),
+        ];
+
+        // Rounding the wye resistors to whole milliohms loses a little precision
+        let round_trip = wye_to_delta(delta_to_wye(delta));
+        for (actual, expected) in round_trip.iter().zip(delta) {
+            let expected = expected.milli_ohms();
+            assert!(actual.milli_ohms().abs_diff(expected) <= expected / 10_000);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wye_to_delta_zero_panics() {
+        let r = Resistance::from_milli_ohms(1_000);
+        wye_to_delta([r, Resistance::zero(), r]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wye_to_delta_overflow_panics() {
+        wye_to_delta([Resistance::from_milli_ohms(u64::MAX); 3]);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(5_000_000, 350_000, 0.003_571_428_571; "5V, 350Ω strain gauge")]
+    #[test_case(10_000_000, 1_000_000, 0.002_5; "10V, 1kΩ")]
+    fn test_wheatstone_sensitivity(micro_volts: i64, milli_ohms: u64, expected: f64) {
+        let sensitivity = wheatstone_sensitivity(
+            Voltage::from_micro_volts(micro_volts),
+            Resistance::from_milli_ohms(milli_ohms),
+        );
+
+        assert!((sensitivity - expected).abs() < 1e-9);
+    }
+}
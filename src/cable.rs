@@ -0,0 +1,141 @@
+//! Voltage drop across the wiring between a supply and its load.
+//!
+//! Current flows out to the load and back again, so the drop is across the round-trip resistance
+//! of both conductors, which is twice the resistance of a single run of the cable.
+//!
+//! ```rust
+//! use ohms::awg::Gauge;
+//! use ohms::cable;
+//! use ohms::prelude::*;
+//!
+//! // 2A over 5m of 22 AWG from a 12V supply
+//! let gauge = Gauge::new(22).unwrap();
+//! let drop = cable::awg_voltage_drop(gauge, 5_000, 2.amps(), 12.volts()).unwrap();
+//!
+//! assert_eq!(drop.voltage(), 1_060.milli_volts());
+//! assert_eq!(drop.percent().milli_percent(), 8_833); // 8.833% of the supply
+//! ```
+use crate::awg::Gauge;
+use crate::law::CheckedMul;
+use crate::{Current, Percent, Resistance, Voltage};
+
+/// The voltage drop across a cable and its share of the supply voltage.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CableDrop {
+    voltage: Voltage,
+    percent: Percent,
+}
+
+impl CableDrop {
+    /// Calculates the voltage drop for a load current and round-trip resistance, as a share of
+    /// the supply voltage.
+    ///
+    /// The percentage is rounded to the nearest whole milli-percent (m%).
+    /// Returns `None` if the supply voltage is not positive or the result would overflow.
+    pub fn new(load: Current, round_trip_resistance: Resistance, supply: Voltage) -> Option<Self> {
+        if !supply.is_positive() {
+            return None;
+        }
+
+        let voltage = voltage_drop(load, round_trip_resistance)?;
+        let percent = Percent::from_ratio(
+            voltage.micro_volts().unsigned_abs(),
+            supply.micro_volts().unsigned_abs(),
+        )?;
+
+        Some(Self { voltage, percent })
+    }
+
+    /// Returns the voltage dropped across the cable.
+    #[inline]
+    pub const fn voltage(&self) -> Voltage {
+        self.voltage
+    }
+
+    /// Returns the voltage drop as a percentage of the supply voltage.
+    #[inline]
+    pub const fn percent(&self) -> Percent {
+        self.percent
+    }
+}
+
+/// Calculates the voltage dropped across the round-trip resistance of a cable at a load current
+/// (`V = I × R`).
+///
+/// Returns `None` if the result would overflow.
+#[inline]
+pub fn voltage_drop(load: Current, round_trip_resistance: Resistance) -> Option<Voltage> {
+    load.checked_mul(round_trip_resistance)
+}
+
+/// Calculates the voltage drop across a two-conductor copper cable of an AWG size, given the
+/// one-way length of the cable in whole millimeters (mm), at 20°C.
+///
+/// Returns `None` if the supply voltage is not positive or the result would overflow.
+pub fn awg_voltage_drop(
+    gauge: Gauge,
+    length_mm: u64,
+    load: Current,
+    supply: Voltage,
+) -> Option<CableDrop> {
+    let round_trip_resistance = gauge.resistance(length_mm.checked_mul(2)?)?;
+    CableDrop::new(load, round_trip_resistance, supply)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2_000_000, 100, Some(200_000); "2A through 100mΩ")]
+    #[test_case(0, 100, Some(0); "no load")]
+    #[test_case(u64::MAX, u64::MAX, None; "overflow")]
+    fn test_voltage_drop(micro_amps: u64, milli_ohms: u64, expected: Option<i64>) {
+        let drop = voltage_drop(
+            Current::from_micro_amps(micro_amps),
+            Resistance::from_milli_ohms(milli_ohms),
+        );
+        assert_eq!(drop.map(|v| v.micro_volts()), expected);
+    }
+
+    #[test_case(2_000_000, 100, 5_000_000, Some((200_000, 4_000)); "4% of 5V")]
+    #[test_case(1_000_000, 24, 12_000_000, Some((24_000, 200)); "0.2% of 12V")]
+    #[test_case(2_000_000, 100, 0, None; "zero supply")]
+    #[test_case(2_000_000, 100, -5_000_000, None; "negative supply")]
+    fn test_cable_drop(
+        micro_amps: u64,
+        milli_ohms: u64,
+        supply: i64,
+        expected: Option<(i64, u32)>,
+    ) {
+        let drop = CableDrop::new(
+            Current::from_micro_amps(micro_amps),
+            Resistance::from_milli_ohms(milli_ohms),
+            Voltage::from_micro_volts(supply),
+        );
+
+        let drop = drop.map(|d| (d.voltage().micro_volts(), d.percent().milli_percent()));
+        assert_eq!(drop, expected);
+    }
+
+    #[test_case(22, 5_000, 2_000_000, 12_000_000, Some((1_060_000, 8_833)); "22 AWG over 5m")]
+    #[test_case(10, 10_000, 30_000_000, 24_000_000, Some((1_980_000, 8_250)); "10 AWG over 10m")]
+    #[test_case(22, u64::MAX, 1_000_000, 12_000_000, None; "length overflow")]
+    fn test_awg_voltage_drop(
+        number: i8,
+        length_mm: u64,
+        micro_amps: u64,
+        supply: i64,
+        expected: Option<(i64, u32)>,
+    ) {
+        let drop = awg_voltage_drop(
+            Gauge::new(number).unwrap(),
+            length_mm,
+            Current::from_micro_amps(micro_amps),
+            Voltage::from_micro_volts(supply),
+        );
+
+        let drop = drop.map(|d| (d.voltage().micro_volts(), d.percent().milli_percent()));
+        assert_eq!(drop, expected);
+    }
+}
@@ -0,0 +1,397 @@
+use crate::scalar::Scalar;
+use crate::Ppm;
+
+/// A linear calibration that corrects a raw reading with a gain and an offset
+/// (`corrected = raw × gain + offset`).
+///
+/// All of the math is done with integers, so no floating-point code is pulled in. The gain is
+/// kept as an exact ratio when calibrating from two reference points, rather than being rounded.
+///
+/// This can be used with any of the unit types, such as `Voltage`, `Current` or `Temperature`.
+///
+/// ```rust
+/// # #[cfg(feature = "voltage")] {
+/// use ohms::prelude::*;
+///
+/// // The sensor reads 12mV at 0V and 4.98V at 5V
+/// let cal = LinearCal::from_two_points(
+///     (12.milli_volts(), Voltage::zero()),
+///     (4_980.milli_volts(), 5.volts()),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(cal.apply(2_496.milli_volts()), 2.5.volts());
+/// assert_eq!(cal.unapply(2.5.volts()), 2_496.milli_volts());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LinearCal<T> {
+    raw_point: T,
+    corrected_point: T,
+    numerator: i128,
+    denominator: i128,
+}
+
+impl<T: Scalar> LinearCal<T> {
+    /// Creates a new `LinearCal` from a gain, where `Ppm::new(1_000_000)` is a gain of one, and an
+    /// offset that is added after the gain is applied.
+    ///
+    /// Panics if the gain is zero, as the calibration could not be reversed.
+    pub fn new(gain: Ppm, offset: T) -> Self {
+        if gain.ppm() == 0 {
+            panic!("Calibration gain cannot be zero");
+        }
+
+        Self {
+            raw_point: T::ZERO,
+            corrected_point: offset,
+            numerator: gain.ppm() as i128,
+            denominator: 1_000_000,
+        }
+    }
+
+    /// Creates a new `LinearCal` from two reference points, each a pair of the raw reading and
+    /// the value it should be corrected to.
+    ///
+    /// Returns `None` if the raw readings or the corrected values are equal, as the gain would be
+    /// infinite or zero.
+    pub fn from_two_points(first: (T, T), second: (T, T)) -> Option<Self> {
+        let numerator = second.1.to_raw() - first.1.to_raw();
+        let denominator = second.0.to_raw() - first.0.to_raw();
+
+        if numerator == 0 || denominator == 0 {
+            return None;
+        }
+
+        Some(Self {
+            raw_point: first.0,
+            corrected_point: first.1,
+            numerator,
+            denominator,
+        })
+    }
+
+    /// Corrects a raw reading, returning `None` if the result would overflow.
+    ///
+    /// Will be rounded toward zero to the nearest whole base unit of `T`.
+    pub fn checked_apply(&self, raw: T) -> Option<T> {
+        interpolate(
+            raw,
+            (self.raw_point, self.corrected_point),
+            self.numerator,
+            self.denominator,
+        )
+    }
+
+    /// Reverses the correction, returning the raw reading that would result in the corrected
+    /// value, or `None` if the result would overflow.
+    ///
+    /// Will be rounded toward zero to the nearest whole base unit of `T`.
+    pub fn checked_unapply(&self, corrected: T) -> Option<T> {
+        interpolate(
+            corrected,
+            (self.corrected_point, self.raw_point),
+            self.denominator,
+            self.numerator,
+        )
+    }
+
+    /// Corrects a raw reading.
+    ///
+    /// Will be rounded toward zero to the nearest whole base unit of `T`.
+    /// Panics if the result would overflow.
+    pub fn apply(&self, raw: T) -> T {
+        self.checked_apply(raw)
+            .expect("Calibrated value would overflow")
+    }
+
+    /// Reverses the correction, returning the raw reading that would result in the corrected value.
+    ///
+    /// Will be rounded toward zero to the nearest whole base unit of `T`.
+    /// Panics if the result would overflow.
+    pub fn unapply(&self, corrected: T) -> T {
+        self.checked_unapply(corrected)
+            .expect("Calibrated value would overflow")
+    }
+}
+
+/// A calibration table that corrects a raw reading by linearly interpolating between
+/// `(raw, corrected)` points, for sensors with a non-linear response such as a thermistor divider.
+///
+/// The points must be sorted by their raw reading, which must be strictly increasing. All of the
+/// math is done with integers, and the table can borrow a `const` or `static` slice so it does not
+/// need to be copied into RAM.
+///
+/// ```rust
+/// # #[cfg(feature = "voltage")] {
+/// use ohms::prelude::*;
+///
+/// const POINTS: &[(Voltage, Voltage)] = &[
+///     (Voltage::from_micro_volts(0), Voltage::from_micro_volts(0)),
+///     (Voltage::from_micro_volts(1_000_000), Voltage::from_micro_volts(2_000_000)),
+///     (Voltage::from_micro_volts(2_000_000), Voltage::from_micro_volts(5_000_000)),
+/// ];
+///
+/// let table = CalTable::new(POINTS).unwrap();
+///
+/// assert_eq!(table.apply(1.5.volts()), 3.5.volts());
+/// assert_eq!(table.apply(3.volts()), 5.volts()); // Clamped to the last point
+/// assert_eq!(table.checked_apply(3.volts()), None);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct CalTable<'a, T> {
+    points: &'a [(T, T)],
+}
+
+impl<'a, T: Scalar> CalTable<'a, T> {
+    /// Creates a new `CalTable` from a slice of `(raw, corrected)` points.
+    ///
+    /// Returns `None` if there are fewer than two points, or the raw readings are not strictly
+    /// increasing.
+    pub fn new(points: &'a [(T, T)]) -> Option<Self> {
+        if points.len() < 2 {
+            return None;
+        }
+
+        if points
+            .windows(2)
+            .any(|pair| pair[0].0.to_raw() >= pair[1].0.to_raw())
+        {
+            return None;
+        }
+
+        Some(Self { points })
+    }
+
+    /// Returns the `(raw, corrected)` points of the table.
+    pub fn points(&self) -> &'a [(T, T)] {
+        self.points
+    }
+
+    /// Corrects a raw reading, returning `None` if it is outside of the range of the table or the
+    /// result would overflow.
+    ///
+    /// Will be rounded toward zero to the nearest whole base unit of `T`.
+    pub fn checked_apply(&self, raw: T) -> Option<T> {
+        let value = raw.to_raw();
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+
+        if value < first.0.to_raw() || value > last.0.to_raw() {
+            return None;
+        }
+
+        // Index of the first point above the reading, which is always at least one
+        let upper = self
+            .points
+            .partition_point(|point| point.0.to_raw() <= value);
+        if upper == self.points.len() {
+            return Some(last.1);
+        }
+
+        let (low, high) = (self.points[upper - 1], self.points[upper]);
+        interpolate(
+            raw,
+            low,
+            high.1.to_raw() - low.1.to_raw(),
+            high.0.to_raw() - low.0.to_raw(),
+        )
+    }
+
+    /// Corrects a raw reading, clamping readings outside of the range of the table to the first
+    /// or last point.
+    ///
+    /// Will be rounded toward zero to the nearest whole base unit of `T`.
+    /// Panics if the result would overflow.
+    pub fn apply(&self, raw: T) -> T {
+        let value = raw.to_raw();
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+
+        if value <= first.0.to_raw() {
+            first.1
+        } else if value >= last.0.to_raw() {
+            last.1
+        } else {
+            self.checked_apply(raw)
+                .expect("Calibrated value would overflow")
+        }
+    }
+}
+
+/// Maps `value` along the line through `point` with a slope of `numerator ÷ denominator`.
+fn interpolate<T: Scalar>(
+    value: T,
+    point: (T, T),
+    numerator: i128,
+    denominator: i128,
+) -> Option<T> {
+    let delta = value.to_raw().checked_sub(point.0.to_raw())?;
+    let scaled = delta.checked_mul(numerator)? / denominator;
+
+    T::from_raw(point.1.to_raw().checked_add(scaled)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "voltage")]
+    use crate::Voltage;
+    use crate::{Energy, Frequency, Temperature};
+    use test_case::test_case;
+
+    #[test_case(1_000_000, 0, 1_234, 1_234; "identity")]
+    #[test_case(1_002_000, -500, 1_000_000, 1_001_500; "gain and offset")]
+    #[test_case(-1_000_000, 0, 1_000, -1_000; "negative gain")]
+    fn test_new(gain_ppm: i32, offset: i64, raw: i64, expected: i64) {
+        let cal = LinearCal::new(
+            Ppm::new(gain_ppm),
+            Temperature::from_milli_degrees_celsius(offset),
+        );
+        let raw = Temperature::from_milli_degrees_celsius(raw);
+
+        assert_eq!(cal.apply(raw).milli_degrees_celsius(), expected);
+        assert_eq!(cal.unapply(cal.apply(raw)), raw);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_new_zero_gain_panics() {
+        LinearCal::new(Ppm::new(0), Temperature::zero());
+    }
+
+    #[test]
+    fn test_from_two_points() {
+        // Reads 0.5°C high at 0°C and 1.5°C high at 100°C
+        let cal = LinearCal::from_two_points(
+            (
+                Temperature::from_milli_degrees_celsius(500),
+                Temperature::from_milli_degrees_celsius(0),
+            ),
+            (
+                Temperature::from_milli_degrees_celsius(101_500),
+                Temperature::from_milli_degrees_celsius(100_000),
+            ),
+        )
+        .unwrap();
+
+        let raw = Temperature::from_milli_degrees_celsius(51_000);
+        let corrected = Temperature::from_milli_degrees_celsius(50_000);
+
+        assert_eq!(cal.apply(raw), corrected);
+        assert_eq!(cal.unapply(corrected), raw);
+    }
+
+    #[test_case((0, 10), (0, 20); "equal raw readings")]
+    #[test_case((0, 10), (5, 10); "equal corrected values")]
+    fn test_from_two_points_degenerate(first: (u64, u64), second: (u64, u64)) {
+        let point = |(raw, corrected): (u64, u64)| {
+            (
+                Frequency::from_milli_hertz(raw),
+                Frequency::from_milli_hertz(corrected),
+            )
+        };
+
+        assert_eq!(
+            LinearCal::from_two_points(point(first), point(second)),
+            None
+        );
+    }
+
+    #[test]
+    fn test_checked_apply_out_of_range() {
+        let cal = LinearCal::new(Ppm::new(1_000_000), Energy::from_nano_joules(100));
+
+        assert_eq!(cal.checked_apply(Energy::from_nano_joules(u64::MAX)), None);
+        assert_eq!(cal.checked_unapply(Energy::from_nano_joules(50)), None);
+    }
+
+    const FREQUENCY_POINTS: &[(Frequency, Frequency)] = &[
+        (
+            Frequency::from_milli_hertz(100),
+            Frequency::from_milli_hertz(1_000),
+        ),
+        (
+            Frequency::from_milli_hertz(200),
+            Frequency::from_milli_hertz(3_000),
+        ),
+        (
+            Frequency::from_milli_hertz(400),
+            Frequency::from_milli_hertz(3_500),
+        ),
+    ];
+
+    #[test_case(100, Some(1_000); "first point")]
+    #[test_case(150, Some(2_000); "first segment")]
+    #[test_case(200, Some(3_000); "middle point")]
+    #[test_case(300, Some(3_250); "second segment")]
+    #[test_case(400, Some(3_500); "last point")]
+    #[test_case(99, None; "below range")]
+    #[test_case(401, None; "above range")]
+    fn test_cal_table_checked_apply(raw: u64, expected: Option<u64>) {
+        let table = CalTable::new(FREQUENCY_POINTS).unwrap();
+
+        assert_eq!(
+            table
+                .checked_apply(Frequency::from_milli_hertz(raw))
+                .map(|f| f.milli_hertz()),
+            expected
+        );
+    }
+
+    #[test_case(0, 1_000; "clamped below range")]
+    #[test_case(250, 3_125; "interpolated")]
+    #[test_case(u64::MAX, 3_500; "clamped above range")]
+    fn test_cal_table_apply(raw: u64, expected: u64) {
+        let table = CalTable::new(FREQUENCY_POINTS).unwrap();
+
+        assert_eq!(
+            table.apply(Frequency::from_milli_hertz(raw)).milli_hertz(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_cal_table_decreasing_output() {
+        // A thermistor reads a lower temperature as the measured value rises
+        let points = [
+            (
+                Temperature::from_milli_degrees_celsius(-1_000),
+                Temperature::from_milli_degrees_celsius(80_000),
+            ),
+            (
+                Temperature::from_milli_degrees_celsius(1_000),
+                Temperature::from_milli_degrees_celsius(-20_000),
+            ),
+        ];
+        let table = CalTable::new(&points).unwrap();
+
+        assert_eq!(
+            table.apply(Temperature::zero()),
+            Temperature::from_milli_degrees_celsius(30_000)
+        );
+    }
+
+    #[test_case([]; "empty")]
+    #[test_case([(1, 1)]; "single point")]
+    #[test_case([(1, 1), (1, 2)]; "duplicate raw reading")]
+    #[test_case([(2, 1), (1, 2)]; "unsorted")]
+    fn test_cal_table_invalid<const N: usize>(points: [(u64, u64); N]) {
+        let points = points.map(|(raw, corrected)| {
+            (
+                Frequency::from_milli_hertz(raw),
+                Frequency::from_milli_hertz(corrected),
+            )
+        });
+
+        assert_eq!(CalTable::new(&points), None);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    fn test_rounds_toward_zero() {
+        let cal = LinearCal::new(Ppm::new(500_000), Voltage::zero());
+
+        assert_eq!(cal.apply(Voltage::from_micro_volts(3)).micro_volts(), 1);
+        assert_eq!(cal.apply(Voltage::from_micro_volts(-3)).micro_volts(), -1);
+    }
+}
@@ -0,0 +1,769 @@
+use crate::approx;
+use crate::compare;
+#[cfg(feature = "resistance")]
+use crate::math::f64_to_i64;
+use crate::math::{f64_to_u64, try_scale_f64_to_u64};
+use crate::parse::{parse_si_unsigned, ParseError};
+use crate::Frequency;
+use crate::UnitError;
+#[cfg(feature = "voltage")]
+use crate::{Energy, Voltage};
+#[cfg(feature = "resistance")]
+use crate::{Impedance, Resistance};
+use core::f64::consts::PI;
+use core::{cmp, hash, ops, str};
+
+/// Represents a capacitance value, stored as whole picofarads (pF) as a 64-bit value.
+/// This value can only be positive.
+///
+/// **Reminder:** `1000 pF = 1 nF, 1000 nF = 1 μF, 1000 μF = 1 mF, 1000 mF = 1 F`
+///
+/// This is an immutable type. Any math operators return a new `Capacitance` value.
+///
+/// # Creating a Capacitance value
+/// You can create a `Capacitance` value using the `from_pico_farads` method, or using one of the
+/// extension methods on integer and floating-point types:
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let c1 = Capacitance::from_pico_farads(470); // 470pF
+///
+/// // More ergonomic:
+/// let c2 = 100.nano_farads(); // 100nF
+/// let c3 = 4.7.micro_farads(); // 4.7μF
+/// ```
+///
+/// # Comparing Capacitance values
+/// You can compare two `Capacitance` values using the `==`, `!=`, `<`, `>`, `<=` and `>=` operators.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let c1 = 220.nano_farads(); // 220nF
+/// let c2 = 1.micro_farads(); // 1μF
+///
+/// if c1 > c2 {
+///     println!("{} is greater than {}", c1, c2);
+/// } else {
+///     println!("{} is less than or equal to {}", c1, c2);
+/// }
+/// ```
+///
+/// # Combining Capacitance values
+/// You can use the `+` and `-` operators to add and subtract `Capacitance` values from each other.
+/// The result is a new `Capacitance` value, rounded down to the nearest whole picofarad (pF).
+///
+/// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let c1 = 100.nano_farads(); // 100nF
+/// let c2 = 2.2.micro_farads(); // 2.2μF
+///
+/// let sum = c1 + c2; // 2.3μF
+/// let diff = c2 - 330.nano_farads(); // 1.87μF
+/// ```
+///
+/// # Scaling Capacitance values
+/// You can use the `*` and `/` operators to scale `Capacitance` values by an integer or floating-point value.
+/// The result is a new `Capacitance` value, rounded down to the nearest whole picofarad (pF).
+///
+/// If the result of operation would overflow or underflow, the operation will panic.
+///
+/// If the result of the operation would be infinite or NaN, the operation will panic.
+///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let c1 = 47.nano_farads(); // 47nF
+/// let c2 = c1 * 3; // 141nF
+///
+/// let c3 = 10.micro_farads(); // 10μF
+/// let c4 = c3 / 2.5; // 4μF
+/// ```
+///
+/// # Converting to other denominations
+/// You can use the `pico_farads`, `nano_farads`, `micro_farads`, `milli_farads`, and `farads`
+/// methods to convert a `Capacitance` value to a numeric value in the specified denomination.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let c1 = 0.1.micro_farads(); // 0.1μF
+///
+/// println!("{} μF is {} nF", c1.micro_farads(), c1.nano_farads());
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capacitance {
+    #[cfg_attr(feature = "serde", serde(rename = "pico_farads"))]
+    raw: u64,
+}
+
+impl Capacitance {
+    /// Creates a new `Capacitance` from a number of whole picofarads (pF).
+    ///
+    /// It is recommended to use the `pico_farads`, `nano_farads`, `micro_farads`, `milli_farads`,
+    /// and `farads` extension methods on integer and floating-point types instead.
+    #[inline]
+    pub const fn from_pico_farads(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Capacitance` from a number of fractional picofarads (pF).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_pico_farads(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_pico_farads)
+    }
+
+    /// Creates a new `Capacitance` from a number of fractional nanofarads (nF).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_nano_farads(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_pico_farads)
+    }
+
+    /// Creates a new `Capacitance` from a number of fractional microfarads (μF).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_farads(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_pico_farads)
+    }
+
+    /// Creates a new `Capacitance` from a number of fractional millifarads (mF).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_farads(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000f64).map(Self::from_pico_farads)
+    }
+
+    /// Creates a new `Capacitance` from a number of fractional farads (F).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_farads(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000_000f64).map(Self::from_pico_farads)
+    }
+
+    /// Returns the capacitance value in whole picofarads (pF).
+    #[inline]
+    pub const fn pico_farads(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the capacitance value in fractional nanofarads (nF).
+    #[inline]
+    pub fn nano_farads(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the capacitance value in fractional microfarads (μF).
+    #[inline]
+    pub fn micro_farads(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Returns the capacitance value in fractional millifarads (mF).
+    #[inline]
+    pub fn milli_farads(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000f64
+    }
+
+    /// Returns the capacitance value in fractional farads (F).
+    #[inline]
+    pub fn farads(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_000f64
+    }
+
+    /// Returns whether the capacitance value is zero farads (0F).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `Capacitance` value of zero farads (0F).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_pico_farads(0)
+    }
+
+    /// Adds two `Capacitance` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_pico_farads)
+    }
+
+    /// Subtracts one `Capacitance` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_pico_farads)
+    }
+
+    /// Multiplies the `Capacitance` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_pico_farads)
+    }
+
+    /// Divides the `Capacitance` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_pico_farads)
+    }
+
+    /// Multiplies the `Capacitance` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole picofarad (pF).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_pico_farads)
+    }
+
+    /// Divides the `Capacitance` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole picofarad (pF).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_pico_farads)
+    }
+
+    /// Calculates the capacitive reactance at a given frequency (`Xc = 1 / 2πfC`).
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    /// Panics if the capacitance or frequency is zero, as an infinite reactance would result.
+    #[cfg(feature = "resistance")]
+    pub fn reactance(&self, frequency: Frequency) -> Resistance {
+        if self.is_zero() || frequency.is_zero() {
+            panic!("Capacitance and frequency cannot be zero, infinite reactance would result");
+        }
+
+        let milli_ohms = (*self * frequency) * 1_000f64;
+        Resistance::from_milli_ohms(f64_to_u64(milli_ohms).expect("Reactance would overflow"))
+    }
+
+    /// Calculates the capacitive reactance at a given frequency (`Xc = 1 / 2πfC`).
+    ///
+    /// The result is a purely reactive `Impedance` with a negative reactive part, as the current
+    /// through a capacitor leads the voltage across it.
+    ///
+    /// Will be rounded toward zero to the nearest whole milliohm (mΩ).
+    /// Panics if the capacitance or frequency is zero, as an infinite reactance would result.
+    #[cfg(feature = "resistance")]
+    pub fn reactance_at(&self, frequency: Frequency) -> Impedance {
+        if self.is_zero() || frequency.is_zero() {
+            panic!("Capacitance and frequency cannot be zero, infinite reactance would result");
+        }
+
+        let milli_ohms = 1e18f64 / (2f64 * PI * frequency.milli_hertz() as f64 * self.raw as f64);
+        let reactance = f64_to_i64(milli_ohms).expect("Reactance would overflow");
+
+        Impedance::from_milli_ohms(0, -reactance)
+    }
+
+    /// Calculates the energy stored in the capacitance when charged to a given voltage (`E = ½CV²`).
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    #[cfg(feature = "voltage")]
+    pub fn energy_at(&self, voltage: Voltage) -> Energy {
+        let micro_volts = voltage.micro_volts().unsigned_abs() as u128;
+
+        let nano_joules = (micro_volts * micro_volts)
+            .checked_mul(self.raw as u128)
+            .expect("Energy would overflow")
+            / 2_000_000_000_000_000u128;
+
+        Energy::from_nano_joules(u64::try_from(nano_joules).expect("Energy would overflow"))
+    }
+
+    /// Returns the unit symbol used when displaying the capacitance value, such as `"nF"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "pF"),
+            1_000..=999_999 => (1_000, "nF"),
+            1_000_000..=999_999_999 => (1_000_000, "μF"),
+            1_000_000_000..=999_999_999_999 => (1_000_000_000, "mF"),
+            _ => (1_000_000_000_000, "F"),
+        }
+    }
+}
+
+approx::impl_approx_eq!(Capacitance, unsigned);
+compare::impl_const_compare!(Capacitance, unsigned);
+
+impl PartialEq for Capacitance {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Capacitance {}
+
+impl PartialOrd for Capacitance {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Capacitance {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for Capacitance {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for Capacitance {
+    type Output = Self;
+
+    /// Adds two `Capacitance` values together, returning a new `Capacitance` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_pico_farads)
+            .expect("Overflow when adding capacitance values")
+    }
+}
+
+impl ops::Sub for Capacitance {
+    type Output = Self;
+
+    /// Subtracts one `Capacitance` value from another, returning a new `Capacitance` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_pico_farads)
+            .expect("Overflow when subtracting capacitance values")
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i:ty) => {
+        impl ops::Mul<$i> for Capacitance {
+            type Output = Self;
+
+            /// Multiplies a `Capacitance` value by an integer value, returning a new `Capacitance` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn mul(self, scale_factor: $i) -> Self {
+                if scale_factor < 0 {
+                    panic!("Cannot multiply capacitance value by negative value")
+                }
+                self.raw
+                    .checked_mul(scale_factor as u64)
+                    .map(Self::from_pico_farads)
+                    .expect("Overflow when multiplying capacitance value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for Capacitance {
+    type Output = Self;
+
+    /// Multiplies a `Capacitance` value by a floating-point value, returning a new `Capacitance` value.
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for Capacitance {
+    type Output = Self;
+
+    /// Multiplies a `Capacitance` value by a floating-point value, returning a new `Capacitance` value.
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply capacitance value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply capacitance value by NaN"),
+            _ if scale_factor.is_sign_negative() => {
+                panic!("Cannot multiply capacitance value by negative value")
+            }
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_pico_farads(result as u64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i:ty) => {
+        impl ops::Div<$i> for Capacitance {
+            type Output = Self;
+
+            /// Divides a `Capacitance` value by an integer value, returning a new `Capacitance` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide capacitance value by zero");
+                } else if divisor < 0 {
+                    panic!("Cannot divide capacitance value by negative value");
+                }
+                self.raw
+                    .checked_div(divisor as u64)
+                    .map(Self::from_pico_farads)
+                    .expect("Overflow when dividing capacitance value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for Capacitance {
+    type Output = Self;
+
+    /// Divides a `Capacitance` value by a floating-point value, returning a new `Capacitance` value.
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for Capacitance {
+    type Output = Self;
+
+    /// Divides a `Capacitance` value by a floating-point value, returning a new `Capacitance` value.
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide capacitance value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide capacitance value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide capacitance value by NaN"),
+            _ if divisor.is_sign_negative() => {
+                panic!("Cannot divide capacitance value by negative value")
+            }
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_pico_farads(result as u64)
+    }
+}
+
+impl ops::Mul<Frequency> for Capacitance {
+    type Output = f64;
+
+    /// Calculates the capacitive reactance in ohms (Ω) at a given frequency (`Xc = 1 / 2πfC`).
+    ///
+    /// Returns infinity if the capacitance or frequency is zero.
+    #[inline]
+    fn mul(self, frequency: Frequency) -> Self::Output {
+        1f64 / (2f64 * PI * frequency.hertz() * self.farads())
+    }
+}
+
+impl ops::Mul<Capacitance> for Frequency {
+    type Output = f64;
+
+    /// Calculates the capacitive reactance in ohms (Ω) at a given frequency (`Xc = 1 / 2πfC`).
+    ///
+    /// Returns infinity if the capacitance or frequency is zero.
+    #[inline]
+    fn mul(self, capacitance: Capacitance) -> Self::Output {
+        capacitance * self
+    }
+}
+
+/// Extension trait for simple short-hands for creating `Capacitance` values from integer values.
+pub trait FromInteger {
+    /// Creates a new `Capacitance` from a number of whole picofarads (pF).
+    fn pico_farads(self) -> Capacitance;
+
+    /// Creates a new `Capacitance` from a number of whole nanofarads (nF).
+    fn nano_farads(self) -> Capacitance;
+
+    /// Creates a new `Capacitance` from a number of whole microfarads (μF).
+    fn micro_farads(self) -> Capacitance;
+
+    /// Creates a new `Capacitance` from a number of whole millifarads (mF).
+    fn milli_farads(self) -> Capacitance;
+
+    /// Creates a new `Capacitance` from a number of whole farads (F).
+    fn farads(self) -> Capacitance;
+}
+
+crate::__define_unit_from_integer!(
+    Capacitance,
+    FromInteger,
+    from_pico_farads,
+    pico_farads,
+    [
+        nano_farads = 1_000,
+        micro_farads = 1_000_000,
+        milli_farads = 1_000_000_000,
+        farads = 1_000_000_000_000
+    ],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
+
+/// Extension trait for simple short-hands for creating `Capacitance` values from floating-point values.
+pub trait FromFloat {
+    /// Creates a new `Capacitance` from a number of fractional picofarads (pF).
+    ///
+    /// The fractional part is rounded down to the nearest whole picofarad (pF).
+    fn pico_farads(self) -> Capacitance;
+
+    /// Creates a new `Capacitance` from a number of fractional nanofarads (nF).
+    ///
+    /// The fractional part is rounded down to the nearest whole picofarad (pF).
+    fn nano_farads(self) -> Capacitance;
+
+    /// Creates a new `Capacitance` from a number of fractional microfarads (μF).
+    ///
+    /// The fractional part is rounded down to the nearest whole picofarad (pF).
+    fn micro_farads(self) -> Capacitance;
+
+    /// Creates a new `Capacitance` from a number of fractional millifarads (mF).
+    ///
+    /// The fractional part is rounded down to the nearest whole picofarad (pF).
+    fn milli_farads(self) -> Capacitance;
+
+    /// Creates a new `Capacitance` from a number of fractional farads (F).
+    ///
+    /// The fractional part is rounded down to the nearest whole picofarad (pF).
+    fn farads(self) -> Capacitance;
+}
+
+crate::__define_unit_from_float!(
+    Capacitance,
+    FromFloat,
+    from_pico_farads,
+    pico_farads,
+    [
+        nano_farads = 1_000,
+        micro_farads = 1_000_000,
+        milli_farads = 1_000_000_000,
+        farads = 1_000_000_000_000
+    ],
+    f32,
+    f64
+);
+
+crate::__define_unit_display!(Capacitance);
+
+impl str::FromStr for Capacitance {
+    type Err = ParseError;
+
+    /// Parses a `Capacitance` value from a string with an optional SI prefix and unit symbol, such as
+    /// `100nF`, `4.7 μF` or `22p`.
+    ///
+    /// Will be rounded down to the nearest whole picofarad (pF).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["F"], 12).map(Self::from_pico_farads)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(100.nano_farads(), 100_000; "100nF")]
+    #[test_case(4.7.micro_farads(), 4_700_000; "4.7μF")]
+    #[test_case(1.farads(), 1_000_000_000_000; "1F")]
+    #[test_case(2.2.milli_farads(), 2_200_000_000; "2.2mF")]
+    fn test_extension_methods(capacitance: Capacitance, expected_pico_farads: u64) {
+        assert_eq!(capacitance.pico_farads(), expected_pico_farads);
+    }
+
+    #[test_case(470, "470.00 pF"; "picofarads")]
+    #[test_case(100_000, "100.00 nF"; "nanofarads")]
+    #[test_case(4_700_000, "4.70 μF"; "microfarads")]
+    #[test_case(2_200_000_000, "2.20 mF"; "millifarads")]
+    #[test_case(1_500_000_000_000, "1.50 F"; "farads")]
+    fn test_display(pico_farads: u64, expected: &str) {
+        let capacitance = Capacitance::from_pico_farads(pico_farads);
+        assert_eq!(format!("{capacitance}"), expected);
+    }
+
+    #[test_case("100nF", Ok(100_000); "nano prefix")]
+    #[test_case("4.7 uF", Ok(4_700_000); "micro prefix with space")]
+    #[test_case("22p", Ok(22); "prefix without symbol")]
+    #[test_case("1F", Ok(1_000_000_000_000); "farads")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Capacitance>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Capacitance::from_pico_farads(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(100_000, 1_000_000, -1_591_549; "100nF at 1kHz")]
+    #[test_case(10_000_000, 50_000, -318_309; "10μF at 50Hz")]
+    #[test_case(1_000_000_000_000, 1_000_000_000, 0; "1F at 1MHz rounds toward zero")]
+    fn test_reactance_at(pico_farads: u64, milli_hertz: u64, expected_milli_ohms: i64) {
+        let capacitance = Capacitance::from_pico_farads(pico_farads);
+        let reactance = capacitance.reactance_at(Frequency::from_milli_hertz(milli_hertz));
+
+        assert_eq!(
+            reactance,
+            Impedance::from_milli_ohms(0, expected_milli_ohms)
+        );
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    #[should_panic]
+    fn test_reactance_at_zero_frequency_panics() {
+        let _ = 1.micro_farads().reactance_at(Frequency::zero());
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(100_000_000, 12_000_000, 7_200_000; "100μF at 12V equals 7.2mJ")]
+    #[test_case(1_000_000_000_000, -5_000_000, 12_500_000_000; "1F at -5V equals 12.5J")]
+    #[test_case(1_000, 1_000, 0; "1nF at 1mV rounds down to 0nJ")]
+    fn test_energy_at(pico_farads: u64, micro_volts: i64, expected_nano_joules: u64) {
+        let capacitance = Capacitance::from_pico_farads(pico_farads);
+        let energy = capacitance.energy_at(Voltage::from_micro_volts(micro_volts));
+
+        assert_eq!(energy.nano_joules(), expected_nano_joules);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    #[should_panic]
+    fn test_energy_at_overflow_panics() {
+        let capacitance = Capacitance::from_pico_farads(u64::MAX);
+        let _ = capacitance.energy_at(Voltage::from_micro_volts(i64::MAX));
+    }
+
+    #[test_case(Capacitance::try_from_nano_farads(1.5), Ok(Capacitance::from_pico_farads(1_500)); "valid value")]
+    #[test_case(Capacitance::try_from_nano_farads(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Capacitance::try_from_nano_farads(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Capacitance::try_from_nano_farads(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Capacitance::try_from_nano_farads(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(
+        result: Result<Capacitance, UnitError>,
+        expected: Result<Capacitance, UnitError>,
+    ) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Capacitance::from_pico_farads(4_700_000),
+            &[
+                Token::Struct {
+                    name: "Capacitance",
+                    len: 1,
+                },
+                Token::Str("pico_farads"),
+                Token::U64(4_700_000),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(100_000, 1_000_000, 1_591_549; "100nF at 1kHz")]
+    #[test_case(10_000_000, 50_000, 318_309; "10μF at 50Hz")]
+    fn test_reactance(pico_farads: u64, milli_hertz: u64, expected_milli_ohms: u64) {
+        let capacitance = Capacitance::from_pico_farads(pico_farads);
+        let reactance = capacitance.reactance(Frequency::from_milli_hertz(milli_hertz));
+
+        assert_eq!(reactance, Resistance::from_milli_ohms(expected_milli_ohms));
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    #[should_panic]
+    fn test_reactance_zero_frequency_panics() {
+        let _ = Capacitance::from_pico_farads(1_000_000).reactance(Frequency::zero());
+    }
+
+    #[test]
+    fn test_mul_frequency() {
+        let capacitance = Capacitance::from_pico_farads(100_000);
+        let frequency = Frequency::from_milli_hertz(1_000_000);
+
+        assert!((capacitance * frequency - 10_000f64 / (2f64 * PI)).abs() < 1e-9);
+        assert_eq!(frequency * capacitance, capacitance * frequency);
+        assert!((capacitance * Frequency::zero()).is_infinite());
+    }
+}
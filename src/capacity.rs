@@ -0,0 +1,350 @@
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::try_scale_f64_to_u64;
+use crate::parse::{parse_si_unsigned, ParseError};
+use crate::{Charge, UnitError};
+use core::{cmp, fmt, hash, ops, str};
+
+/// Represents a battery capacity value, stored as whole microamp-hours (μAh) as a 64-bit value.
+/// This value can only be positive.
+///
+/// **Reminder:** `1000 μAh = 1 mAh, 1000 mAh = 1 Ah`
+///
+/// This is an immutable type. Any math operators return a new `Capacity` value.
+///
+/// A `Capacity` holds the same quantity as a `Charge`, but in the units battery datasheets use.
+/// Use `from_charge` and `to_charge` to convert between the two.
+///
+/// # Estimating runtime
+/// Dividing a `Capacity` by a `Current` gives the runtime of a battery at a constant load, and
+/// multiplying it by a `Voltage` gives the energy it stores at its nominal voltage.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current"))] {
+/// use ohms::prelude::*;
+///
+/// let battery = Capacity::from_micro_amp_hours(2_000_000); // 2000mAh
+///
+/// let runtime = battery / 250.milli_amps();
+/// assert_eq!(runtime.as_secs(), 8 * 60 * 60); // 8h
+///
+/// let energy = battery * 3_700.milli_volts();
+/// assert_eq!(energy.watt_hours(), 7.4);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capacity {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_amp_hours"))]
+    raw: u64,
+}
+
+impl Capacity {
+    /// Creates a new `Capacity` from a number of whole microamp-hours (μAh).
+    #[inline]
+    pub const fn from_micro_amp_hours(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Capacity` from a number of fractional microamp-hours (μAh).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_amp_hours(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_micro_amp_hours)
+    }
+
+    /// Creates a new `Capacity` from a number of fractional milliamp-hours (mAh).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_amp_hours(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_micro_amp_hours)
+    }
+
+    /// Creates a new `Capacity` from a number of fractional amp-hours (Ah).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_amp_hours(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_micro_amp_hours)
+    }
+
+    /// Creates a new `Capacity` from a `Charge` value.
+    ///
+    /// Will be rounded down to the nearest whole microamp-hour (μAh).
+    #[inline]
+    pub const fn from_charge(charge: Charge) -> Self {
+        Self::from_micro_amp_hours(charge.micro_coulombs() / 3_600)
+    }
+
+    /// Returns the capacity value in whole microamp-hours (μAh).
+    #[inline]
+    pub const fn micro_amp_hours(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the capacity value in fractional milliamp-hours (mAh).
+    #[inline]
+    pub fn milli_amp_hours(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the capacity value in fractional amp-hours (Ah).
+    #[inline]
+    pub fn amp_hours(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Converts the capacity value to a `Charge` value, returning `None` if the result would
+    /// overflow.
+    #[inline]
+    pub fn to_charge(&self) -> Option<Charge> {
+        self.raw.checked_mul(3_600).map(Charge::from_micro_coulombs)
+    }
+
+    /// Returns whether the capacity value is zero amp-hours (0Ah).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `Capacity` value of zero amp-hours (0Ah).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_micro_amp_hours(0)
+    }
+
+    /// Adds two `Capacity` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_micro_amp_hours)
+    }
+
+    /// Subtracts one `Capacity` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_micro_amp_hours)
+    }
+
+    /// Multiplies the `Capacity` value by an integer value, returning `None` if the result would
+    /// overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_micro_amp_hours)
+    }
+
+    /// Divides the `Capacity` value by an integer value, returning `None` if the divisor is zero.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw
+            .checked_div(divisor)
+            .map(Self::from_micro_amp_hours)
+    }
+
+    /// Returns the unit symbol used when displaying the capacity value, such as `"mAh"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "μAh"),
+            1_000..=999_999 => (1_000, "mAh"),
+            _ => (1_000_000, "Ah"),
+        }
+    }
+}
+
+approx::impl_approx_eq!(Capacity, unsigned);
+compare::impl_const_compare!(Capacity, unsigned);
+
+impl PartialEq for Capacity {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Capacity {}
+
+impl PartialOrd for Capacity {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Capacity {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for Capacity {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for Capacity {
+    type Output = Self;
+
+    /// Adds two `Capacity` values together, returning a new `Capacity` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("Overflow when adding capacity values")
+    }
+}
+
+impl ops::Sub for Capacity {
+    type Output = Self;
+
+    /// Subtracts one `Capacity` value from another, returning a new `Capacity` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .expect("Overflow when subtracting capacity values")
+    }
+}
+
+impl From<Charge> for Capacity {
+    /// Converts a `Charge` value to a `Capacity` value, rounded down to the nearest whole
+    /// microamp-hour (μAh).
+    #[inline]
+    fn from(charge: Charge) -> Self {
+        Self::from_charge(charge)
+    }
+}
+
+impl fmt::Display for Capacity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let (scale, unit) = self.display_scale();
+        let value = (self.raw as f64) / (scale as f64);
+
+        display::write_padded(f, format_args!("{value:.precision$} {unit}"))
+    }
+}
+
+impl str::FromStr for Capacity {
+    type Err = ParseError;
+
+    /// Parses a `Capacity` value from a string with an optional SI prefix and unit symbol, such as
+    /// `2000mAh`, `2.5 Ah` or `500u`.
+    ///
+    /// Will be rounded down to the nearest whole microamp-hour (μAh).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["Ah"], 6).map(Self::from_micro_amp_hours)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(1_500.0, Ok(1_500_000); "milliamp-hours")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    #[test_case(1e20, Err(UnitError::Overflow); "overflow")]
+    fn test_try_from_milli_amp_hours(value: f64, expected: Result<u64, UnitError>) {
+        let capacity = Capacity::try_from_milli_amp_hours(value).map(|c| c.micro_amp_hours());
+        assert_eq!(capacity, expected);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let capacity = Capacity::try_from_amp_hours(2.5).unwrap();
+
+        assert_eq!(capacity.micro_amp_hours(), 2_500_000);
+        assert_eq!(capacity.milli_amp_hours(), 2_500.0);
+        assert_eq!(capacity.amp_hours(), 2.5);
+    }
+
+    #[test_case(1_000, Some(3_600_000); "1mAh equals 3.6C")]
+    #[test_case(u64::MAX, None; "overflow")]
+    fn test_to_charge(micro_amp_hours: u64, expected: Option<u64>) {
+        let charge = Capacity::from_micro_amp_hours(micro_amp_hours).to_charge();
+        assert_eq!(charge.map(|q| q.micro_coulombs()), expected);
+    }
+
+    #[test_case(3_600_000, 1_000; "3.6C equals 1mAh")]
+    #[test_case(3_599, 0; "rounds down")]
+    fn test_from_charge(micro_coulombs: u64, expected: u64) {
+        let charge = Charge::from_micro_coulombs(micro_coulombs);
+
+        assert_eq!(Capacity::from_charge(charge).micro_amp_hours(), expected);
+        assert_eq!(Capacity::from(charge).micro_amp_hours(), expected);
+    }
+
+    #[test_case(1_000, 500, Some(1_500), Some(500); "in range")]
+    #[test_case(u64::MAX, 1, None, Some(u64::MAX - 1); "add overflow")]
+    #[test_case(0, 1, Some(1), None; "sub underflow")]
+    fn test_checked_add_sub(lhs: u64, rhs: u64, sum: Option<u64>, difference: Option<u64>) {
+        let lhs = Capacity::from_micro_amp_hours(lhs);
+        let rhs = Capacity::from_micro_amp_hours(rhs);
+
+        assert_eq!(lhs.checked_add(rhs).map(|c| c.micro_amp_hours()), sum);
+        assert_eq!(
+            lhs.checked_sub(rhs).map(|c| c.micro_amp_hours()),
+            difference
+        );
+    }
+
+    #[test]
+    fn test_checked_mul_div_int() {
+        let capacity = Capacity::from_micro_amp_hours(1_000);
+
+        assert_eq!(
+            capacity.checked_mul_int(3),
+            Some(Capacity::from_micro_amp_hours(3_000))
+        );
+        assert_eq!(capacity.checked_mul_int(u64::MAX), None);
+        assert_eq!(
+            capacity.checked_div_int(4),
+            Some(Capacity::from_micro_amp_hours(250))
+        );
+        assert_eq!(capacity.checked_div_int(0), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sub_underflow_panics() {
+        let _ = Capacity::zero() - Capacity::from_micro_amp_hours(1);
+    }
+
+    #[test_case(500, "500.00 μAh"; "microamp-hours")]
+    #[test_case(2_000_000, "2.00 Ah"; "amp-hours")]
+    #[test_case(1_500, "1.50 mAh"; "milliamp-hours")]
+    fn test_display(micro_amp_hours: u64, expected: &str) {
+        let capacity = Capacity::from_micro_amp_hours(micro_amp_hours);
+        assert_eq!(format!("{capacity}"), expected);
+    }
+
+    #[test_case("2000mAh", Ok(2_000_000); "milliamp-hours")]
+    #[test_case("2.5 Ah", Ok(2_500_000); "amp-hours")]
+    #[test_case("-1Ah", Err(()); "negative")]
+    fn test_from_str(s: &str, expected: Result<u64, ()>) {
+        let capacity = s
+            .parse::<Capacity>()
+            .map(|c| c.micro_amp_hours())
+            .map_err(|_| ());
+        assert_eq!(capacity, expected);
+    }
+}
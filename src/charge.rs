@@ -0,0 +1,618 @@
+use crate::approx;
+use crate::compare;
+use crate::math::{f64_to_u64, try_scale_f64_to_u64};
+use crate::parse::{parse_si_unsigned, ParseError};
+use crate::UnitError;
+use core::{cmp, hash, ops, str};
+
+/// Represents a charge value, stored as whole microcoulombs (μC) as a 64-bit value.
+/// This value can only be positive.
+///
+/// **Reminder:** `1000 μC = 1 mC, 1000 mC = 1 C, 3.6 C = 1 mAh, 1000 mAh = 1 Ah`
+///
+/// This is an immutable type. Any math operators return a new `Charge` value.
+///
+/// # Creating a Charge value
+/// You can create a `Charge` value using the `from_micro_coulombs` method, or using one of the
+/// extension methods on integer and floating-point types:
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let q1 = Charge::from_micro_coulombs(1500); // 1.5mC
+///
+/// // More ergonomic:
+/// let q2 = 2_000.milli_amp_hours(); // 2000mAh
+/// let q3 = 2.5.amp_hours(); // 2.5Ah
+/// ```
+///
+/// # Comparing Charge values
+/// You can compare two `Charge` values using the `==`, `!=`, `<`, `>`, `<=` and `>=` operators.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let q1 = 500.milli_coulombs(); // 500mC
+/// let q2 = 1.coulombs(); // 1C
+///
+/// if q1 > q2 {
+///     println!("{} is greater than {}", q1, q2);
+/// } else {
+///     println!("{} is less than or equal to {}", q1, q2);
+/// }
+/// ```
+///
+/// # Combining Charge values
+/// You can use the `+` and `-` operators to add and subtract `Charge` values from each other.
+/// The result is a new `Charge` value, rounded down to the nearest whole microcoulomb (μC).
+///
+/// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let q1 = 1_200.milli_amp_hours(); // 1200mAh
+/// let q2 = 2.5.amp_hours(); // 2.5Ah
+///
+/// let sum = q1 + q2; // 3.7Ah
+/// let diff = q2 - 500.milli_amp_hours(); // 2Ah
+/// ```
+///
+/// # Scaling Charge values
+/// You can use the `*` and `/` operators to scale `Charge` values by an integer or floating-point value.
+/// The result is a new `Charge` value, rounded down to the nearest whole microcoulomb (μC).
+///
+/// If the result of operation would overflow or underflow, the operation will panic.
+///
+/// If the result of the operation would be infinite or NaN, the operation will panic.
+///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let q1 = 100.milli_amp_hours(); // 100mAh
+/// let q2 = q1 * 3; // 300mAh
+///
+/// let q3 = 3.amp_hours(); // 3Ah
+/// let q4 = q3 / 2.5; // 1.2Ah
+/// ```
+///
+/// # Converting to other denominations
+/// You can use the `micro_coulombs`, `milli_coulombs`, `coulombs`, `milli_amp_hours`, and
+/// `amp_hours` methods to convert a `Charge` value to a numeric value in the specified
+/// denomination.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let q1 = 7.2.coulombs(); // 7.2C
+///
+/// println!("{} C is {} mAh", q1.coulombs(), q1.milli_amp_hours());
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Charge {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_coulombs"))]
+    raw: u64,
+}
+
+impl Charge {
+    /// Creates a new `Charge` from a number of whole microcoulombs (μC).
+    ///
+    /// It is recommended to use the `micro_coulombs`, `milli_coulombs`, `coulombs`,
+    /// `milli_amp_hours`, and `amp_hours` extension methods on integer and floating-point types
+    /// instead.
+    #[inline]
+    pub const fn from_micro_coulombs(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Charge` from a number of fractional microcoulombs (μC).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_coulombs(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_micro_coulombs)
+    }
+
+    /// Creates a new `Charge` from a number of fractional millicoulombs (mC).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_coulombs(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_micro_coulombs)
+    }
+
+    /// Creates a new `Charge` from a number of fractional coulombs (C).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_coulombs(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_micro_coulombs)
+    }
+
+    /// Creates a new `Charge` from a number of fractional milliamp-hours (mAh).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_amp_hours(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 3_600_000f64).map(Self::from_micro_coulombs)
+    }
+
+    /// Creates a new `Charge` from a number of fractional amp-hours (Ah).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_amp_hours(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 3_600_000_000f64).map(Self::from_micro_coulombs)
+    }
+
+    /// Returns the charge value in whole microcoulombs (μC).
+    #[inline]
+    pub const fn micro_coulombs(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the charge value in fractional millicoulombs (mC).
+    #[inline]
+    pub fn milli_coulombs(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the charge value in fractional coulombs (C).
+    #[inline]
+    pub fn coulombs(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Returns the charge value in fractional milliamp-hours (mAh).
+    #[inline]
+    pub fn milli_amp_hours(&self) -> f64 {
+        self.raw as f64 / 3_600_000f64
+    }
+
+    /// Returns the charge value in fractional amp-hours (Ah).
+    #[inline]
+    pub fn amp_hours(&self) -> f64 {
+        self.raw as f64 / 3_600_000_000f64
+    }
+
+    /// Returns whether the charge value is zero coulombs (0C).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `Charge` value of zero coulombs (0C).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_micro_coulombs(0)
+    }
+
+    /// Adds two `Charge` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_micro_coulombs)
+    }
+
+    /// Subtracts one `Charge` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_micro_coulombs)
+    }
+
+    /// Multiplies the `Charge` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_micro_coulombs)
+    }
+
+    /// Divides the `Charge` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_micro_coulombs)
+    }
+
+    /// Multiplies the `Charge` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole microcoulomb (μC).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_micro_coulombs)
+    }
+
+    /// Divides the `Charge` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole microcoulomb (μC).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_micro_coulombs)
+    }
+
+    /// Returns the unit symbol used when displaying the charge value, such as `"mC"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "μC"),
+            1_000..=999_999 => (1_000, "mC"),
+            _ => (1_000_000, "C"),
+        }
+    }
+}
+
+approx::impl_approx_eq!(Charge, unsigned);
+compare::impl_const_compare!(Charge, unsigned);
+
+impl PartialEq for Charge {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Charge {}
+
+impl PartialOrd for Charge {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Charge {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for Charge {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for Charge {
+    type Output = Self;
+
+    /// Adds two `Charge` values together, returning a new `Charge` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_micro_coulombs)
+            .expect("Overflow when adding charge values")
+    }
+}
+
+impl ops::Sub for Charge {
+    type Output = Self;
+
+    /// Subtracts one `Charge` value from another, returning a new `Charge` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_micro_coulombs)
+            .expect("Overflow when subtracting charge values")
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i:ty) => {
+        impl ops::Mul<$i> for Charge {
+            type Output = Self;
+
+            /// Multiplies a `Charge` value by an integer value, returning a new `Charge` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn mul(self, scale_factor: $i) -> Self {
+                if scale_factor < 0 {
+                    panic!("Cannot multiply charge value by negative value")
+                }
+                self.raw
+                    .checked_mul(scale_factor as u64)
+                    .map(Self::from_micro_coulombs)
+                    .expect("Overflow when multiplying charge value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for Charge {
+    type Output = Self;
+
+    /// Multiplies a `Charge` value by a floating-point value, returning a new `Charge` value.
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for Charge {
+    type Output = Self;
+
+    /// Multiplies a `Charge` value by a floating-point value, returning a new `Charge` value.
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply charge value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply charge value by NaN"),
+            _ if scale_factor.is_sign_negative() => {
+                panic!("Cannot multiply charge value by negative value")
+            }
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_micro_coulombs(result as u64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i:ty) => {
+        impl ops::Div<$i> for Charge {
+            type Output = Self;
+
+            /// Divides a `Charge` value by an integer value, returning a new `Charge` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide charge value by zero");
+                } else if divisor < 0 {
+                    panic!("Cannot divide charge value by negative value");
+                }
+                self.raw
+                    .checked_div(divisor as u64)
+                    .map(Self::from_micro_coulombs)
+                    .expect("Overflow when dividing charge value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for Charge {
+    type Output = Self;
+
+    /// Divides a `Charge` value by a floating-point value, returning a new `Charge` value.
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for Charge {
+    type Output = Self;
+
+    /// Divides a `Charge` value by a floating-point value, returning a new `Charge` value.
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide charge value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide charge value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide charge value by NaN"),
+            _ if divisor.is_sign_negative() => {
+                panic!("Cannot divide charge value by negative value")
+            }
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_micro_coulombs(result as u64)
+    }
+}
+
+/// Extension trait for simple short-hands for creating `Charge` values from integer values.
+pub trait FromInteger {
+    /// Creates a new `Charge` from a number of whole microcoulombs (μC).
+    fn micro_coulombs(self) -> Charge;
+
+    /// Creates a new `Charge` from a number of whole millicoulombs (mC).
+    fn milli_coulombs(self) -> Charge;
+
+    /// Creates a new `Charge` from a number of whole coulombs (C).
+    fn coulombs(self) -> Charge;
+
+    /// Creates a new `Charge` from a number of whole milliamp-hours (mAh).
+    fn milli_amp_hours(self) -> Charge;
+
+    /// Creates a new `Charge` from a number of whole amp-hours (Ah).
+    fn amp_hours(self) -> Charge;
+}
+
+crate::__define_unit_from_integer!(
+    Charge,
+    FromInteger,
+    from_micro_coulombs,
+    micro_coulombs,
+    [
+        milli_coulombs = 1_000,
+        coulombs = 1_000_000,
+        milli_amp_hours = 3_600_000,
+        amp_hours = 3_600_000_000
+    ],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
+
+/// Extension trait for simple short-hands for creating `Charge` values from floating-point values.
+pub trait FromFloat {
+    /// Creates a new `Charge` from a number of fractional microcoulombs (μC).
+    ///
+    /// The fractional part is rounded down to the nearest whole microcoulomb (μC).
+    fn micro_coulombs(self) -> Charge;
+
+    /// Creates a new `Charge` from a number of fractional millicoulombs (mC).
+    ///
+    /// The fractional part is rounded down to the nearest whole microcoulomb (μC).
+    fn milli_coulombs(self) -> Charge;
+
+    /// Creates a new `Charge` from a number of fractional coulombs (C).
+    ///
+    /// The fractional part is rounded down to the nearest whole microcoulomb (μC).
+    fn coulombs(self) -> Charge;
+
+    /// Creates a new `Charge` from a number of fractional milliamp-hours (mAh).
+    ///
+    /// The fractional part is rounded down to the nearest whole microcoulomb (μC).
+    fn milli_amp_hours(self) -> Charge;
+
+    /// Creates a new `Charge` from a number of fractional amp-hours (Ah).
+    ///
+    /// The fractional part is rounded down to the nearest whole microcoulomb (μC).
+    fn amp_hours(self) -> Charge;
+}
+
+crate::__define_unit_from_float!(
+    Charge,
+    FromFloat,
+    from_micro_coulombs,
+    micro_coulombs,
+    [
+        milli_coulombs = 1_000,
+        coulombs = 1_000_000,
+        milli_amp_hours = 3_600_000,
+        amp_hours = 3_600_000_000
+    ],
+    f32,
+    f64
+);
+
+crate::__define_unit_display!(Charge);
+
+impl str::FromStr for Charge {
+    type Err = ParseError;
+
+    /// Parses a `Charge` value from a string with an optional SI prefix and unit symbol, such as
+    /// `1.5C`, `250 mC` or `10u`.
+    ///
+    /// Will be rounded down to the nearest whole microcoulomb (μC).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["C"], 6).map(Self::from_micro_coulombs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(1.milli_amp_hours(), 3_600_000; "1mAh equals 3.6C")]
+    #[test_case(1.amp_hours(), 3_600_000_000; "1Ah equals 3,600C")]
+    #[test_case(2.5.coulombs(), 2_500_000; "2.5C")]
+    #[test_case(250.milli_coulombs(), 250_000; "250mC")]
+    fn test_extension_methods(charge: Charge, expected_micro_coulombs: u64) {
+        assert_eq!(charge.micro_coulombs(), expected_micro_coulombs);
+    }
+
+    #[test_case(250, "250.00 μC"; "microcoulombs")]
+    #[test_case(1_500, "1.50 mC"; "millicoulombs")]
+    #[test_case(3_600_000_000, "3600.00 C"; "coulombs")]
+    fn test_display(micro_coulombs: u64, expected: &str) {
+        let charge = Charge::from_micro_coulombs(micro_coulombs);
+        assert_eq!(format!("{charge}"), expected);
+    }
+
+    #[test_case("1.5C", Ok(1_500_000); "coulombs")]
+    #[test_case("250 mC", Ok(250_000); "milli prefix with space")]
+    #[test_case("10u", Ok(10); "prefix without symbol")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Charge>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Charge::from_micro_coulombs(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(Charge::try_from_milli_coulombs(1.5), Ok(Charge::from_micro_coulombs(1_500)); "valid value")]
+    #[test_case(Charge::try_from_milli_coulombs(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Charge::try_from_milli_coulombs(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Charge::try_from_milli_coulombs(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Charge::try_from_milli_coulombs(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(result: Result<Charge, UnitError>, expected: Result<Charge, UnitError>) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Charge::from_micro_coulombs(3_600_000),
+            &[
+                Token::Struct {
+                    name: "Charge",
+                    len: 1,
+                },
+                Token::Str("micro_coulombs"),
+                Token::U64(3_600_000),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,462 @@
+//! Resistor color codes, as defined by IEC 60062.
+//!
+//! A color code is made up of two or three significant digit bands, a multiplier band, and
+//! optionally a tolerance band and a temperature coefficient band:
+//!
+//! | Bands | Layout                                                  |
+//! |-------|---------------------------------------------------------|
+//! | 3     | digit, digit, multiplier (±20% tolerance)               |
+//! | 4     | digit, digit, multiplier, tolerance                     |
+//! | 5     | digit, digit, digit, multiplier, tolerance              |
+//! | 6     | digit, digit, digit, multiplier, tolerance, temperature |
+//!
+//! ```rust
+//! use ohms::color_code::{self, ColorBand::*};
+//! use ohms::prelude::*;
+//!
+//! let code = color_code::decode(&[Yellow, Violet, Red, Gold]).unwrap();
+//! assert_eq!(code.resistance(), 4.7.kilo_ohms());
+//! assert_eq!(code.tolerance_ppm(), 50_000); // ±5%
+//!
+//! let bands = color_code::encode_4_band(4.7.kilo_ohms(), Gold).unwrap();
+//! assert_eq!(bands, [Yellow, Violet, Red, Gold]);
+//! ```
+use crate::{Resistance, Toleranced};
+use core::fmt;
+
+/// The tolerance of a resistor without a tolerance band, in parts per million (±20%).
+const NO_BAND_TOLERANCE_PPM: u32 = 200_000;
+
+/// A single band of a resistor color code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ColorBand {
+    Black,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Violet,
+    Grey,
+    White,
+    Gold,
+    Silver,
+}
+
+impl ColorBand {
+    /// Returns the band for a significant digit from 0 to 9, or `None` if the digit is larger.
+    pub const fn from_digit(digit: u8) -> Option<Self> {
+        match digit {
+            0 => Some(ColorBand::Black),
+            1 => Some(ColorBand::Brown),
+            2 => Some(ColorBand::Red),
+            3 => Some(ColorBand::Orange),
+            4 => Some(ColorBand::Yellow),
+            5 => Some(ColorBand::Green),
+            6 => Some(ColorBand::Blue),
+            7 => Some(ColorBand::Violet),
+            8 => Some(ColorBand::Grey),
+            9 => Some(ColorBand::White),
+            _ => None,
+        }
+    }
+
+    /// Returns the significant digit of the band, or `None` for gold and silver.
+    pub const fn digit(self) -> Option<u8> {
+        match self {
+            ColorBand::Black => Some(0),
+            ColorBand::Brown => Some(1),
+            ColorBand::Red => Some(2),
+            ColorBand::Orange => Some(3),
+            ColorBand::Yellow => Some(4),
+            ColorBand::Green => Some(5),
+            ColorBand::Blue => Some(6),
+            ColorBand::Violet => Some(7),
+            ColorBand::Grey => Some(8),
+            ColorBand::White => Some(9),
+            ColorBand::Gold | ColorBand::Silver => None,
+        }
+    }
+
+    /// Returns the power of ten of the band when used as a multiplier, from `-2` for silver
+    /// (×0.01) to `9` for white (×1,000,000,000).
+    pub const fn multiplier_exponent(self) -> i8 {
+        match self {
+            ColorBand::Gold => -1,
+            ColorBand::Silver => -2,
+            _ => match self.digit() {
+                Some(digit) => digit as i8,
+                None => 0,
+            },
+        }
+    }
+
+    /// Returns the tolerance of the band in parts per million (ppm), such as `10_000` for brown
+    /// (±1%), or `None` if the band is not a valid tolerance band.
+    pub const fn tolerance_ppm(self) -> Option<u32> {
+        match self {
+            ColorBand::Brown => Some(10_000),
+            ColorBand::Red => Some(20_000),
+            ColorBand::Orange => Some(500),
+            ColorBand::Yellow => Some(200),
+            ColorBand::Green => Some(5_000),
+            ColorBand::Blue => Some(2_500),
+            ColorBand::Violet => Some(1_000),
+            ColorBand::Grey => Some(100),
+            ColorBand::Gold => Some(50_000),
+            ColorBand::Silver => Some(100_000),
+            ColorBand::Black | ColorBand::White => None,
+        }
+    }
+
+    /// Returns the temperature coefficient of the band in parts per million per kelvin (ppm/K),
+    /// or `None` if the band is not a valid temperature coefficient band.
+    pub const fn temperature_coefficient_ppm(self) -> Option<u32> {
+        match self {
+            ColorBand::Black => Some(250),
+            ColorBand::Brown => Some(100),
+            ColorBand::Red => Some(50),
+            ColorBand::Orange => Some(15),
+            ColorBand::Yellow => Some(25),
+            ColorBand::Green => Some(20),
+            ColorBand::Blue => Some(10),
+            ColorBand::Violet => Some(5),
+            ColorBand::Grey => Some(1),
+            ColorBand::White | ColorBand::Gold | ColorBand::Silver => None,
+        }
+    }
+}
+
+impl fmt::Display for ColorBand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            ColorBand::Black => "black",
+            ColorBand::Brown => "brown",
+            ColorBand::Red => "red",
+            ColorBand::Orange => "orange",
+            ColorBand::Yellow => "yellow",
+            ColorBand::Green => "green",
+            ColorBand::Blue => "blue",
+            ColorBand::Violet => "violet",
+            ColorBand::Grey => "grey",
+            ColorBand::White => "white",
+            ColorBand::Gold => "gold",
+            ColorBand::Silver => "silver",
+        };
+
+        f.write_str(name)
+    }
+}
+
+/// Error returned when decoding or encoding a resistor color code fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorCodeError {
+    /// The number of bands was not 3, 4, 5 or 6.
+    InvalidBandCount,
+    /// A significant digit band was gold or silver.
+    InvalidDigit,
+    /// The tolerance band does not represent a tolerance.
+    InvalidTolerance,
+    /// The temperature coefficient band does not represent a temperature coefficient.
+    InvalidTemperatureCoefficient,
+    /// The resistance is too large to be represented by the available bands.
+    OutOfRange,
+}
+
+impl fmt::Display for ColorCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ColorCodeError::InvalidBandCount => "color code must have 3, 4, 5 or 6 bands",
+            ColorCodeError::InvalidDigit => "invalid significant digit band",
+            ColorCodeError::InvalidTolerance => "invalid tolerance band",
+            ColorCodeError::InvalidTemperatureCoefficient => "invalid temperature coefficient band",
+            ColorCodeError::OutOfRange => "resistance cannot be represented by color code",
+        };
+
+        f.write_str(message)
+    }
+}
+
+/// A decoded resistor color code.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ColorCode {
+    resistance: Resistance,
+    tolerance_ppm: u32,
+    temperature_coefficient_ppm: Option<u32>,
+}
+
+impl ColorCode {
+    /// Returns the nominal resistance.
+    #[inline]
+    pub const fn resistance(&self) -> Resistance {
+        self.resistance
+    }
+
+    /// Returns the tolerance in parts per million (ppm), such as `50_000` for ±5%.
+    #[inline]
+    pub const fn tolerance_ppm(&self) -> u32 {
+        self.tolerance_ppm
+    }
+
+    /// Returns the tolerance as a fractional percentage, such as `5.0` for ±5%.
+    #[inline]
+    pub fn tolerance_percent(&self) -> f64 {
+        self.tolerance_ppm as f64 / 10_000f64
+    }
+
+    /// Returns the temperature coefficient in parts per million per kelvin (ppm/K), if the color
+    /// code has a temperature coefficient band.
+    #[inline]
+    pub const fn temperature_coefficient_ppm(&self) -> Option<u32> {
+        self.temperature_coefficient_ppm
+    }
+
+    /// Returns the resistance with its worst-case bounds from the tolerance.
+    #[inline]
+    pub fn toleranced(&self) -> Toleranced<Resistance> {
+        Toleranced::from_ppm(self.resistance, self.tolerance_ppm)
+    }
+}
+
+/// Decodes a 3, 4, 5 or 6-band resistor color code, read from left to right.
+pub fn decode(bands: &[ColorBand]) -> Result<ColorCode, ColorCodeError> {
+    let (digit_count, tolerance, temperature_coefficient) = match bands.len() {
+        3 => (2, None, None),
+        4 => (2, Some(bands[3]), None),
+        5 => (3, Some(bands[4]), None),
+        6 => (3, Some(bands[4]), Some(bands[5])),
+        _ => return Err(ColorCodeError::InvalidBandCount),
+    };
+
+    let mut significand = 0u64;
+    for band in &bands[..digit_count] {
+        let digit = band.digit().ok_or(ColorCodeError::InvalidDigit)?;
+        significand = significand * 10 + digit as u64;
+    }
+
+    let multiplier = bands[digit_count];
+
+    // The multiplier is in ohms, so offset it by three for milliohms
+    let milli_ohms = significand * 10u64.pow((multiplier.multiplier_exponent() + 3) as u32);
+
+    let tolerance_ppm = match tolerance {
+        Some(band) => band
+            .tolerance_ppm()
+            .ok_or(ColorCodeError::InvalidTolerance)?,
+        None => NO_BAND_TOLERANCE_PPM,
+    };
+
+    let temperature_coefficient_ppm = match temperature_coefficient {
+        Some(band) => Some(
+            band.temperature_coefficient_ppm()
+                .ok_or(ColorCodeError::InvalidTemperatureCoefficient)?,
+        ),
+        None => None,
+    };
+
+    Ok(ColorCode {
+        resistance: Resistance::from_milli_ohms(milli_ohms),
+        tolerance_ppm,
+        temperature_coefficient_ppm,
+    })
+}
+
+/// Encodes a resistance into a 4-band color code with two significant digits.
+///
+/// The resistance is rounded to the nearest value that can be represented.
+pub fn encode_4_band(
+    resistance: Resistance,
+    tolerance: ColorBand,
+) -> Result<[ColorBand; 4], ColorCodeError> {
+    check_tolerance(tolerance)?;
+    let [d1, d2, multiplier] = encode_significand::<2, 3>(resistance)?;
+
+    Ok([d1, d2, multiplier, tolerance])
+}
+
+/// Encodes a resistance into a 5-band color code with three significant digits.
+///
+/// The resistance is rounded to the nearest value that can be represented.
+pub fn encode_5_band(
+    resistance: Resistance,
+    tolerance: ColorBand,
+) -> Result<[ColorBand; 5], ColorCodeError> {
+    check_tolerance(tolerance)?;
+    let [d1, d2, d3, multiplier] = encode_significand::<3, 4>(resistance)?;
+
+    Ok([d1, d2, d3, multiplier, tolerance])
+}
+
+/// Encodes a resistance into a 6-band color code with three significant digits and a temperature
+/// coefficient.
+///
+/// The resistance is rounded to the nearest value that can be represented.
+pub fn encode_6_band(
+    resistance: Resistance,
+    tolerance: ColorBand,
+    temperature_coefficient: ColorBand,
+) -> Result<[ColorBand; 6], ColorCodeError> {
+    check_tolerance(tolerance)?;
+    temperature_coefficient
+        .temperature_coefficient_ppm()
+        .ok_or(ColorCodeError::InvalidTemperatureCoefficient)?;
+    let [d1, d2, d3, multiplier] = encode_significand::<3, 4>(resistance)?;
+
+    Ok([d1, d2, d3, multiplier, tolerance, temperature_coefficient])
+}
+
+fn check_tolerance(tolerance: ColorBand) -> Result<(), ColorCodeError> {
+    tolerance
+        .tolerance_ppm()
+        .map(|_| ())
+        .ok_or(ColorCodeError::InvalidTolerance)
+}
+
+/// Encodes a resistance into `DIGITS` significant digit bands followed by a multiplier band,
+/// choosing the smallest multiplier that fits.
+fn encode_significand<const DIGITS: usize, const BANDS: usize>(
+    resistance: Resistance,
+) -> Result<[ColorBand; BANDS], ColorCodeError> {
+    let milli_ohms = resistance.milli_ohms() as u128;
+    let limit = 10u128.pow(DIGITS as u32);
+
+    for multiplier in [
+        ColorBand::Silver,
+        ColorBand::Gold,
+        ColorBand::Black,
+        ColorBand::Brown,
+        ColorBand::Red,
+        ColorBand::Orange,
+        ColorBand::Yellow,
+        ColorBand::Green,
+        ColorBand::Blue,
+        ColorBand::Violet,
+        ColorBand::Grey,
+        ColorBand::White,
+    ] {
+        let scale = 10u128.pow((multiplier.multiplier_exponent() + 3) as u32);
+        let mut significand = (milli_ohms + scale / 2) / scale;
+
+        if significand >= limit {
+            continue;
+        }
+
+        let mut bands = [ColorBand::Black; BANDS];
+        bands[DIGITS] = multiplier;
+
+        for band in bands[..DIGITS].iter_mut().rev() {
+            *band = ColorBand::from_digit((significand % 10) as u8).unwrap_or(ColorBand::Black);
+            significand /= 10;
+        }
+
+        return Ok(bands);
+    }
+
+    Err(ColorCodeError::OutOfRange)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::ColorBand::*;
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(&[Brown, Black, Red], 1_000_000, 200_000; "3 bands")]
+    #[test_case(&[Yellow, Violet, Red, Gold], 4_700_000, 50_000; "4 bands")]
+    #[test_case(&[Brown, Black, Black, Black, Brown], 100_000, 10_000; "5 bands")]
+    #[test_case(&[Red, Red, Black, Silver, Brown], 2_200, 10_000; "silver multiplier")]
+    #[test_case(&[Brown, Black, Gold, Gold], 1_000, 50_000; "gold multiplier")]
+    #[test_case(&[White, White, White], 99_000_000_000_000, 200_000; "maximum 3 bands")]
+    fn test_decode(bands: &[ColorBand], milli_ohms: u64, tolerance_ppm: u32) {
+        let code = decode(bands).unwrap();
+
+        assert_eq!(code.resistance().milli_ohms(), milli_ohms);
+        assert_eq!(code.tolerance_ppm(), tolerance_ppm);
+        assert_eq!(code.temperature_coefficient_ppm(), None);
+    }
+
+    #[test]
+    fn test_decode_6_bands() {
+        let code = decode(&[Orange, Orange, White, Black, Brown, Brown]).unwrap();
+
+        assert_eq!(code.resistance().milli_ohms(), 339_000);
+        assert_eq!(code.tolerance_percent(), 1.0);
+        assert_eq!(code.temperature_coefficient_ppm(), Some(100));
+    }
+
+    #[test]
+    fn test_toleranced() {
+        let r = decode(&[Yellow, Violet, Red, Gold]).unwrap().toleranced();
+
+        assert_eq!(r.min().milli_ohms(), 4_465_000);
+        assert_eq!(r.max().milli_ohms(), 4_935_000);
+    }
+
+    #[test_case(&[Brown, Black], ColorCodeError::InvalidBandCount; "too few bands")]
+    #[test_case(&[Brown; 7], ColorCodeError::InvalidBandCount; "too many bands")]
+    #[test_case(&[Gold, Black, Red, Gold], ColorCodeError::InvalidDigit; "gold digit")]
+    #[test_case(&[Brown, Black, Red, Black], ColorCodeError::InvalidTolerance; "black tolerance")]
+    #[test_case(&[Brown, Black, Black, Red, Brown, Gold], ColorCodeError::InvalidTemperatureCoefficient; "gold temperature coefficient")]
+    fn test_decode_error(bands: &[ColorBand], expected: ColorCodeError) {
+        assert_eq!(decode(bands), Err(expected));
+    }
+
+    #[test_case(4_700_000, [Yellow, Violet, Red, Gold]; "4.7kΩ")]
+    #[test_case(220_000, [Red, Red, Brown, Gold]; "220 ohms")]
+    #[test_case(10_000, [Brown, Black, Black, Gold]; "10 ohms")]
+    #[test_case(4_700, [Yellow, Violet, Gold, Gold]; "4.7 ohms")]
+    #[test_case(220, [Red, Red, Silver, Gold]; "0.22 ohms")]
+    #[test_case(4_749_999, [Yellow, Violet, Red, Gold]; "rounds down to nearest value")]
+    #[test_case(4_750_000, [Yellow, Grey, Red, Gold]; "rounds up to nearest value")]
+    #[test_case(9_960_000, [Brown, Black, Orange, Gold]; "rounding carries into multiplier")]
+    #[test_case(0, [Black, Black, Silver, Gold]; "zero")]
+    fn test_encode_4_band(milli_ohms: u64, expected: [ColorBand; 4]) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(encode_4_band(resistance, Gold), Ok(expected));
+    }
+
+    #[test_case(4_750_000, [Yellow, Violet, Green, Brown, Brown]; "4.75kΩ")]
+    #[test_case(100_000, [Brown, Black, Black, Black, Brown]; "100 ohms")]
+    #[test_case(1_000, [Brown, Black, Black, Silver, Brown]; "1 ohm")]
+    fn test_encode_5_band(milli_ohms: u64, expected: [ColorBand; 5]) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(encode_5_band(resistance, Brown), Ok(expected));
+    }
+
+    #[test]
+    fn test_encode_6_band() {
+        let bands = encode_6_band(Resistance::from_milli_ohms(339_000), Brown, Brown);
+        assert_eq!(bands, Ok([Orange, Orange, White, Black, Brown, Brown]));
+    }
+
+    #[test]
+    fn test_encode_errors() {
+        let resistance = Resistance::from_milli_ohms(1_000_000);
+
+        assert_eq!(
+            encode_4_band(resistance, White),
+            Err(ColorCodeError::InvalidTolerance)
+        );
+        assert_eq!(
+            encode_6_band(resistance, Brown, Gold),
+            Err(ColorCodeError::InvalidTemperatureCoefficient)
+        );
+        assert_eq!(
+            encode_4_band(Resistance::from_milli_ohms(u64::MAX), Gold),
+            Err(ColorCodeError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let bands = [Yellow, Violet, Red, Gold];
+        let code = decode(&bands).unwrap();
+        assert_eq!(encode_4_band(code.resistance(), Gold), Ok(bands));
+
+        let bands = [Brown, Black, Black, Black, Brown];
+        let code = decode(&bands).unwrap();
+        assert_eq!(encode_5_band(code.resistance(), Brown), Ok(bands));
+    }
+}
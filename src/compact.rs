@@ -0,0 +1,314 @@
+//! Compact 32-bit unit types for small microcontrollers.
+//!
+//! On targets such as AVR and MSP430, 64-bit arithmetic is slow and pulls in large library
+//! routines. These types store whole milli-units (or whole ohms) in a 32-bit integer instead, and
+//! only use integer math for their operations and formatting.
+//!
+//! They convert losslessly into the full unit types with `From`, and back with `TryFrom`, which
+//! rounds towards zero and fails with [`UnitError::Overflow`] if the value does not fit.
+//!
+//! ```rust
+//! # #[cfg(feature = "voltage")] {
+//! use ohms::compact::Voltage32;
+//! use ohms::prelude::*;
+//!
+//! let supply = Voltage32::from_milli_volts(3_300);
+//! assert_eq!(Voltage::from(supply), 3.3.volts());
+//!
+//! let reading = Voltage32::try_from(1_234_567.micro_volts()).unwrap();
+//! assert_eq!(reading.milli_volts(), 1_234);
+//! # }
+//! ```
+
+use crate::display;
+#[cfg(feature = "current")]
+use crate::Current;
+#[cfg(feature = "power")]
+use crate::Power;
+#[cfg(feature = "resistance")]
+use crate::Resistance;
+use crate::UnitError;
+#[cfg(feature = "voltage")]
+use crate::Voltage;
+use core::{fmt, ops};
+
+macro_rules! impl_compact {
+    (
+        $(#[$meta:meta])*
+        $name:ident($int:ty, $field:literal) => $wide:ident($wide_int:ty, $scale:expr);
+        $from:ident, $getter:ident, $wide_from:ident, $wide_getter:ident;
+        $symbol:literal, $description:literal
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        pub struct $name {
+            #[cfg_attr(feature = "serde", serde(rename = $field))]
+            raw: $int,
+        }
+
+        impl $name {
+            #[doc = concat!(
+                "Creates a new `", stringify!($name), "` from a number of whole ", $description, "."
+            )]
+            #[inline]
+            pub const fn $from(value: $int) -> Self {
+                Self { raw: value }
+            }
+
+            #[doc = concat!("Returns the value in whole ", $description, ".")]
+            #[inline]
+            pub const fn $getter(&self) -> $int {
+                self.raw
+            }
+
+            /// Returns whether the value is zero.
+            #[inline]
+            pub const fn is_zero(&self) -> bool {
+                self.raw == 0
+            }
+
+            #[doc = concat!("Returns a `", stringify!($name), "` value of zero.")]
+            #[inline]
+            pub const fn zero() -> Self {
+                Self::$from(0)
+            }
+
+            /// Adds another value, returning `None` if the result would overflow.
+            pub const fn checked_add(self, other: Self) -> Option<Self> {
+                match self.raw.checked_add(other.raw) {
+                    Some(raw) => Some(Self::$from(raw)),
+                    None => None,
+                }
+            }
+
+            /// Subtracts another value, returning `None` if the result would overflow.
+            pub const fn checked_sub(self, other: Self) -> Option<Self> {
+                match self.raw.checked_sub(other.raw) {
+                    Some(raw) => Some(Self::$from(raw)),
+                    None => None,
+                }
+            }
+        }
+
+        impl ops::Add for $name {
+            type Output = Self;
+
+            /// Panics if the result would overflow.
+            fn add(self, other: Self) -> Self::Output {
+                self.checked_add(other)
+                    .expect(concat!("Overflow when adding ", stringify!($name), " values"))
+            }
+        }
+
+        impl ops::Sub for $name {
+            type Output = Self;
+
+            /// Panics if the result would overflow.
+            fn sub(self, other: Self) -> Self::Output {
+                self.checked_sub(other)
+                    .expect(concat!("Overflow when subtracting ", stringify!($name), " values"))
+            }
+        }
+
+        impl From<$name> for $wide {
+            fn from(value: $name) -> Self {
+                $wide::$wide_from(<$wide_int>::from(value.raw) * $scale)
+            }
+        }
+
+        impl TryFrom<$wide> for $name {
+            type Error = UnitError;
+
+            /// Rounds towards zero to the nearest whole unit, failing if the result does not fit.
+            fn try_from(value: $wide) -> Result<Self, Self::Error> {
+                <$int>::try_from(value.$wide_getter() / $scale)
+                    .map(Self::$from)
+                    .map_err(|_| UnitError::Overflow)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                display::write_padded(f, format_args!(concat!("{} ", $symbol), self.raw))
+            }
+        }
+
+        #[cfg(feature = "defmt")]
+        impl defmt::Format for $name {
+            fn format(&self, f: defmt::Formatter) {
+                defmt::write!(f, "{} {=str}", self.raw, $symbol)
+            }
+        }
+
+        #[cfg(feature = "ufmt")]
+        impl ufmt::uDisplay for $name {
+            fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+            where
+                W: ufmt::uWrite + ?Sized,
+            {
+                ufmt::uwrite!(f, "{} {}", self.raw, $symbol)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "voltage")]
+impl_compact! {
+    /// Represents a voltage in whole millivolts (mV) stored in an `i32`, for a range of about
+    /// ±2.1MV.
+    Voltage32(i32, "milli_volts") => Voltage(i64, 1_000);
+    from_milli_volts, milli_volts, from_micro_volts, micro_volts;
+    "mV", "millivolts (mV)"
+}
+
+#[cfg(feature = "current")]
+impl_compact! {
+    /// Represents a current in whole milliamps (mA) stored in a `u32`, for a range of up to about
+    /// 4.29MA.
+    Current32(u32, "milli_amps") => Current(u64, 1_000);
+    from_milli_amps, milli_amps, from_micro_amps, micro_amps;
+    "mA", "milliamps (mA)"
+}
+
+#[cfg(feature = "resistance")]
+impl_compact! {
+    /// Represents a resistance in whole ohms (Ω) stored in a `u32`, for a range of up to about
+    /// 4.29GΩ.
+    ///
+    /// Whole ohms are used rather than milliohms, which would limit the range to about 4.29kΩ.
+    Resistance32(u32, "ohms") => Resistance(u64, 1_000);
+    from_ohms, ohms, from_milli_ohms, milli_ohms;
+    "Ω", "ohms (Ω)"
+}
+
+#[cfg(feature = "power")]
+impl_compact! {
+    /// Represents a power in whole milliwatts (mW) stored in a `u32`, for a range of up to about
+    /// 4.29MW.
+    Power32(u32, "milli_watts") => Power(u64, 1_000);
+    from_milli_watts, milli_watts, from_micro_watts, micro_watts;
+    "mW", "milliwatts (mW)"
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    #[cfg(all(feature = "voltage", feature = "resistance"))]
+    use alloc::format;
+    #[cfg(any(feature = "voltage", feature = "resistance"))]
+    use test_case::test_case;
+
+    #[cfg(feature = "voltage")]
+    #[test_case(3_300, 3_300_000; "positive")]
+    #[test_case(-12_000, -12_000_000; "negative")]
+    #[test_case(i32::MIN, i32::MIN as i64 * 1_000; "minimum")]
+    fn test_voltage_into_wide(milli_volts: i32, expected_micro_volts: i64) {
+        let voltage = Voltage::from(Voltage32::from_milli_volts(milli_volts));
+        assert_eq!(voltage.micro_volts(), expected_micro_volts);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(1_234_567, Ok(1_234); "rounds down")]
+    #[test_case(-1_234_567, Ok(-1_234); "rounds towards zero")]
+    #[test_case(i32::MAX as i64 * 1_000 + 999, Ok(i32::MAX); "maximum")]
+    #[test_case(i32::MAX as i64 * 1_000 + 1_000, Err(UnitError::Overflow); "too large")]
+    #[test_case(i32::MIN as i64 * 1_000 - 1_000, Err(UnitError::Overflow); "too small")]
+    fn test_voltage_try_from_wide(micro_volts: i64, expected: Result<i32, UnitError>) {
+        let voltage = Voltage32::try_from(Voltage::from_micro_volts(micro_volts));
+        assert_eq!(voltage.map(|v| v.milli_volts()), expected);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_current_round_trip() {
+        let current = Current32::from_milli_amps(u32::MAX);
+        let wide = Current::from(current);
+
+        assert_eq!(wide.micro_amps(), u32::MAX as u64 * 1_000);
+        assert_eq!(Current32::try_from(wide), Ok(current));
+        assert_eq!(
+            Current32::try_from(wide + Current::from_micro_amps(1_000)),
+            Err(UnitError::Overflow)
+        );
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(4_700_999, 4_700; "4.7kΩ rounds down")]
+    #[test_case(999, 0; "less than 1Ω")]
+    fn test_resistance_try_from_wide(milli_ohms: u64, expected_ohms: u32) {
+        let resistance = Resistance32::try_from(Resistance::from_milli_ohms(milli_ohms)).unwrap();
+        assert_eq!(resistance.ohms(), expected_ohms);
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    fn test_power_into_wide() {
+        let power = Power::from(Power32::from_milli_watts(250));
+        assert_eq!(power.micro_watts(), 250_000);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(1_000, 2_300, Some(3_300); "add")]
+    #[test_case(i32::MAX, 1, None; "overflow")]
+    fn test_checked_add(a: i32, b: i32, expected: Option<i32>) {
+        let sum = Voltage32::from_milli_volts(a).checked_add(Voltage32::from_milli_volts(b));
+        assert_eq!(sum.map(|v| v.milli_volts()), expected);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_add_and_sub() {
+        let a = Current32::from_milli_amps(500);
+        let b = Current32::from_milli_amps(200);
+
+        assert_eq!((a + b).milli_amps(), 700);
+        assert_eq!((a - b).milli_amps(), 300);
+        assert!(Current32::zero().is_zero());
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    #[should_panic]
+    fn test_sub_overflow_panics() {
+        let _ = Current32::zero() - Current32::from_milli_amps(1);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "resistance"))]
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            format!("{}", Voltage32::from_milli_volts(-3_300)),
+            "-3300 mV"
+        );
+        assert_eq!(format!("{:>8}", Resistance32::from_ohms(470)), "   470 Ω");
+    }
+
+    #[cfg(all(feature = "ufmt", feature = "resistance"))]
+    #[test]
+    fn test_udisplay() {
+        let mut output = alloc::string::String::new();
+        ufmt::uwrite!(output, "{}", Resistance32::from_ohms(4_700)).unwrap();
+        assert_eq!(output, "4700 Ω");
+    }
+
+    #[cfg(all(feature = "serde", feature = "voltage"))]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Voltage32::from_milli_volts(3_300),
+            &[
+                Token::Struct {
+                    name: "Voltage32",
+                    len: 1,
+                },
+                Token::Str("milli_volts"),
+                Token::I32(3_300),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,89 @@
+/// Implements the `const` comparison methods, including `abs_diff` for signed or unsigned values,
+/// for a unit type with a `raw` field.
+macro_rules! impl_const_compare {
+    ($type:ident, unsigned) => {
+        $crate::compare::impl_const_compare!($type);
+
+        impl $type {
+            /// Returns the magnitude of the difference between two values.
+            ///
+            /// Unlike subtraction, this never panics, whichever value is larger.
+            #[inline]
+            pub const fn abs_diff(self, other: Self) -> Self {
+                Self {
+                    raw: self.raw.abs_diff(other.raw),
+                }
+            }
+        }
+    };
+    ($type:ident, signed) => {
+        $crate::compare::impl_const_compare!($type);
+
+        impl $type {
+            /// Returns the magnitude of the difference between two values.
+            ///
+            /// Unlike subtraction, this never panics, and instead saturates at the maximum value if
+            /// the difference is too large to be represented.
+            #[inline]
+            pub const fn abs_diff(self, other: Self) -> Self {
+                let difference = self.raw.abs_diff(other.raw);
+
+                Self {
+                    raw: if difference > i64::MAX as u64 {
+                        i64::MAX
+                    } else {
+                        difference as i64
+                    },
+                }
+            }
+        }
+    };
+    ($type:ident) => {
+        impl $type {
+            /// Returns the smaller of two values.
+            ///
+            /// This is equivalent to `Ord::min`, but can be used in `const` contexts.
+            #[inline]
+            pub const fn min(self, other: Self) -> Self {
+                if other.raw < self.raw {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            /// Returns the larger of two values.
+            ///
+            /// This is equivalent to `Ord::max`, but can be used in `const` contexts.
+            #[inline]
+            pub const fn max(self, other: Self) -> Self {
+                if other.raw >= self.raw {
+                    other
+                } else {
+                    self
+                }
+            }
+
+            /// Restricts the value to be within the inclusive range from `min` to `max`.
+            ///
+            /// This is equivalent to `Ord::clamp`, but can be used in `const` contexts.
+            /// Panics if `min` is greater than `max`.
+            #[inline]
+            pub const fn clamp(self, min: Self, max: Self) -> Self {
+                if min.raw > max.raw {
+                    panic!("Minimum cannot be greater than maximum");
+                }
+
+                if self.raw < min.raw {
+                    min
+                } else if self.raw > max.raw {
+                    max
+                } else {
+                    self
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_const_compare;
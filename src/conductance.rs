@@ -0,0 +1,627 @@
+use crate::approx;
+use crate::compare;
+use crate::math::{f64_to_u64, try_scale_f64_to_u64};
+use crate::parse::{parse_si_unsigned, ParseError};
+#[cfg(feature = "resistance")]
+use crate::Resistance;
+use crate::UnitError;
+use core::{cmp, hash, ops, str};
+
+/// Represents a conductance value, stored as whole nanosiemens (nS) as a 64-bit value.
+/// This value can only be positive.
+///
+/// **Reminder:** `1000 nS = 1 μS, 1000 μS = 1 mS, 1000 mS = 1 S, 1 S = 1 / 1 Ω`
+///
+/// This is an immutable type. Any math operators return a new `Conductance` value.
+///
+/// # Creating a Conductance value
+/// You can create a `Conductance` value using the `from_nano_siemens` method, or using one of the
+/// extension methods on integer and floating-point types:
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let g1 = Conductance::from_nano_siemens(1500); // 1.5μS
+///
+/// // More ergonomic:
+/// let g2 = 10.milli_siemens(); // 10mS
+/// let g3 = 0.5.siemens(); // 0.5S
+/// ```
+///
+/// # Comparing Conductance values
+/// You can compare two `Conductance` values using the `==`, `!=`, `<`, `>`, `<=` and `>=` operators.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let g1 = 220.micro_siemens(); // 220μS
+/// let g2 = 1.milli_siemens(); // 1mS
+///
+/// if g1 > g2 {
+///     println!("{} is greater than {}", g1, g2);
+/// } else {
+///     println!("{} is less than or equal to {}", g1, g2);
+/// }
+/// ```
+///
+/// # Combining Conductance values
+/// You can use the `+` and `-` operators to add and subtract `Conductance` values from each other.
+/// The result is a new `Conductance` value, rounded down to the nearest whole nanosiemens (nS).
+///
+/// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let g1 = 1.milli_siemens(); // 1mS
+/// let g2 = 2.5.milli_siemens(); // 2.5mS
+///
+/// let sum = g1 + g2; // 3.5mS
+/// let diff = g2 - 500.micro_siemens(); // 2mS
+/// ```
+///
+/// # Scaling Conductance values
+/// You can use the `*` and `/` operators to scale `Conductance` values by an integer or floating-point value.
+/// The result is a new `Conductance` value, rounded down to the nearest whole nanosiemens (nS).
+///
+/// If the result of operation would overflow or underflow, the operation will panic.
+///
+/// If the result of the operation would be infinite or NaN, the operation will panic.
+///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let g1 = 10.micro_siemens(); // 10μS
+/// let g2 = g1 * 3; // 30μS
+///
+/// let g3 = 1.siemens(); // 1S
+/// let g4 = g3 / 2.5; // 0.4S
+/// ```
+///
+/// # Converting to other denominations
+/// You can use the `nano_siemens`, `micro_siemens`, `milli_siemens`, and `siemens` methods to
+/// convert a `Conductance` value to a numeric value in the specified denomination.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let g1 = 0.25.milli_siemens(); // 0.25mS
+///
+/// println!("{} mS is {} μS", g1.milli_siemens(), g1.micro_siemens());
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Conductance {
+    #[cfg_attr(feature = "serde", serde(rename = "nano_siemens"))]
+    raw: u64,
+}
+
+impl Conductance {
+    /// Creates a new `Conductance` from a number of whole nanosiemens (nS).
+    ///
+    /// It is recommended to use the `nano_siemens`, `micro_siemens`, `milli_siemens`, and `siemens`
+    /// extension methods on integer and floating-point types instead.
+    #[inline]
+    pub const fn from_nano_siemens(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Conductance` from a number of fractional nanosiemens (nS).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_nano_siemens(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_nano_siemens)
+    }
+
+    /// Creates a new `Conductance` from a number of fractional microsiemens (μS).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_siemens(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_nano_siemens)
+    }
+
+    /// Creates a new `Conductance` from a number of fractional millisiemens (mS).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_siemens(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_nano_siemens)
+    }
+
+    /// Creates a new `Conductance` from a number of fractional siemens (S).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_siemens(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000f64).map(Self::from_nano_siemens)
+    }
+
+    /// Returns the conductance value in whole nanosiemens (nS).
+    #[inline]
+    pub const fn nano_siemens(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the conductance value in fractional microsiemens (μS).
+    #[inline]
+    pub fn micro_siemens(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the conductance value in fractional millisiemens (mS).
+    #[inline]
+    pub fn milli_siemens(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Returns the conductance value in fractional siemens (S).
+    #[inline]
+    pub fn siemens(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000f64
+    }
+
+    /// Returns whether the conductance value is zero siemens (0S).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `Conductance` value of zero siemens (0S).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_nano_siemens(0)
+    }
+
+    /// Adds two `Conductance` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_nano_siemens)
+    }
+
+    /// Subtracts one `Conductance` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_nano_siemens)
+    }
+
+    /// Multiplies the `Conductance` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_nano_siemens)
+    }
+
+    /// Divides the `Conductance` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_nano_siemens)
+    }
+
+    /// Multiplies the `Conductance` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole nanosiemens (nS).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_nano_siemens)
+    }
+
+    /// Divides the `Conductance` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole nanosiemens (nS).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_nano_siemens)
+    }
+
+    /// Converts the conductance to its equivalent resistance (`R = 1 / G`).
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    /// Panics if the conductance is zero, as an infinite resistance would result.
+    #[cfg(feature = "resistance")]
+    pub fn to_resistance(&self) -> Resistance {
+        if self.is_zero() {
+            panic!("Conductance cannot be zero, infinite resistance would result");
+        }
+
+        Resistance::from_milli_ohms(1_000_000_000_000u64 / self.raw)
+    }
+
+    /// Returns the unit symbol used when displaying the conductance value, such as `"μS"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "nS"),
+            1_000..=999_999 => (1_000, "μS"),
+            1_000_000..=999_999_999 => (1_000_000, "mS"),
+            _ => (1_000_000_000, "S"),
+        }
+    }
+}
+
+approx::impl_approx_eq!(Conductance, unsigned);
+compare::impl_const_compare!(Conductance, unsigned);
+
+impl PartialEq for Conductance {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Conductance {}
+
+impl PartialOrd for Conductance {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Conductance {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for Conductance {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for Conductance {
+    type Output = Self;
+
+    /// Adds two `Conductance` values together, returning a new `Conductance` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_nano_siemens)
+            .expect("Overflow when adding conductance values")
+    }
+}
+
+impl ops::Sub for Conductance {
+    type Output = Self;
+
+    /// Subtracts one `Conductance` value from another, returning a new `Conductance` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_nano_siemens)
+            .expect("Overflow when subtracting conductance values")
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i:ty) => {
+        impl ops::Mul<$i> for Conductance {
+            type Output = Self;
+
+            /// Multiplies a `Conductance` value by an integer value, returning a new `Conductance` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn mul(self, scale_factor: $i) -> Self {
+                if scale_factor < 0 {
+                    panic!("Cannot multiply conductance value by negative value")
+                }
+                self.raw
+                    .checked_mul(scale_factor as u64)
+                    .map(Self::from_nano_siemens)
+                    .expect("Overflow when multiplying conductance value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for Conductance {
+    type Output = Self;
+
+    /// Multiplies a `Conductance` value by a floating-point value, returning a new `Conductance` value.
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for Conductance {
+    type Output = Self;
+
+    /// Multiplies a `Conductance` value by a floating-point value, returning a new `Conductance` value.
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply conductance value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply conductance value by NaN"),
+            _ if scale_factor.is_sign_negative() => {
+                panic!("Cannot multiply conductance value by negative value")
+            }
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_nano_siemens(result as u64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i:ty) => {
+        impl ops::Div<$i> for Conductance {
+            type Output = Self;
+
+            /// Divides a `Conductance` value by an integer value, returning a new `Conductance` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide conductance value by zero");
+                } else if divisor < 0 {
+                    panic!("Cannot divide conductance value by negative value");
+                }
+                self.raw
+                    .checked_div(divisor as u64)
+                    .map(Self::from_nano_siemens)
+                    .expect("Overflow when dividing conductance value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for Conductance {
+    type Output = Self;
+
+    /// Divides a `Conductance` value by a floating-point value, returning a new `Conductance` value.
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for Conductance {
+    type Output = Self;
+
+    /// Divides a `Conductance` value by a floating-point value, returning a new `Conductance` value.
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide conductance value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide conductance value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide conductance value by NaN"),
+            _ if divisor.is_sign_negative() => {
+                panic!("Cannot divide conductance value by negative value")
+            }
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_nano_siemens(result as u64)
+    }
+}
+
+/// Extension trait for simple short-hands for creating `Conductance` values from integer values.
+pub trait FromInteger {
+    /// Creates a new `Conductance` from a number of whole nanosiemens (nS).
+    fn nano_siemens(self) -> Conductance;
+
+    /// Creates a new `Conductance` from a number of whole microsiemens (μS).
+    fn micro_siemens(self) -> Conductance;
+
+    /// Creates a new `Conductance` from a number of whole millisiemens (mS).
+    fn milli_siemens(self) -> Conductance;
+
+    /// Creates a new `Conductance` from a number of whole siemens (S).
+    fn siemens(self) -> Conductance;
+}
+
+crate::__define_unit_from_integer!(
+    Conductance,
+    FromInteger,
+    from_nano_siemens,
+    nano_siemens,
+    [
+        micro_siemens = 1_000,
+        milli_siemens = 1_000_000,
+        siemens = 1_000_000_000
+    ],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
+
+/// Extension trait for simple short-hands for creating `Conductance` values from floating-point values.
+pub trait FromFloat {
+    /// Creates a new `Conductance` from a number of fractional nanosiemens (nS).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanosiemens (nS).
+    fn nano_siemens(self) -> Conductance;
+
+    /// Creates a new `Conductance` from a number of fractional microsiemens (μS).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanosiemens (nS).
+    fn micro_siemens(self) -> Conductance;
+
+    /// Creates a new `Conductance` from a number of fractional millisiemens (mS).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanosiemens (nS).
+    fn milli_siemens(self) -> Conductance;
+
+    /// Creates a new `Conductance` from a number of fractional siemens (S).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanosiemens (nS).
+    fn siemens(self) -> Conductance;
+}
+
+crate::__define_unit_from_float!(
+    Conductance,
+    FromFloat,
+    from_nano_siemens,
+    nano_siemens,
+    [
+        micro_siemens = 1_000,
+        milli_siemens = 1_000_000,
+        siemens = 1_000_000_000
+    ],
+    f32,
+    f64
+);
+
+crate::__define_unit_display!(Conductance);
+
+impl str::FromStr for Conductance {
+    type Err = ParseError;
+
+    /// Parses a `Conductance` value from a string with an optional SI prefix and unit symbol, such as
+    /// `10mS`, `2.5 μS` or `1`.
+    ///
+    /// Will be rounded down to the nearest whole nanosiemens (nS).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["S"], 9).map(Self::from_nano_siemens)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(10.micro_siemens(), 10_000; "10μS")]
+    #[test_case(2.5.milli_siemens(), 2_500_000; "2.5mS")]
+    #[test_case(1.siemens(), 1_000_000_000; "1S")]
+    fn test_extension_methods(conductance: Conductance, expected_nano_siemens: u64) {
+        assert_eq!(conductance.nano_siemens(), expected_nano_siemens);
+    }
+
+    #[test_case(470, "470.00 nS"; "nanosiemens")]
+    #[test_case(10_000, "10.00 μS"; "microsiemens")]
+    #[test_case(2_500_000, "2.50 mS"; "millisiemens")]
+    #[test_case(1_500_000_000, "1.50 S"; "siemens")]
+    fn test_display(nano_siemens: u64, expected: &str) {
+        let conductance = Conductance::from_nano_siemens(nano_siemens);
+        assert_eq!(format!("{conductance}"), expected);
+    }
+
+    #[test_case("10mS", Ok(10_000_000); "milli prefix")]
+    #[test_case("2.5 uS", Ok(2_500); "micro prefix with space")]
+    #[test_case("1", Ok(1_000_000_000); "siemens without symbol")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Conductance>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Conductance::from_nano_siemens(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(Conductance::try_from_micro_siemens(1.5), Ok(Conductance::from_nano_siemens(1_500)); "valid value")]
+    #[test_case(Conductance::try_from_micro_siemens(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Conductance::try_from_micro_siemens(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Conductance::try_from_micro_siemens(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Conductance::try_from_micro_siemens(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(
+        result: Result<Conductance, UnitError>,
+        expected: Result<Conductance, UnitError>,
+    ) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Conductance::from_nano_siemens(10_000),
+            &[
+                Token::Struct {
+                    name: "Conductance",
+                    len: 1,
+                },
+                Token::Str("nano_siemens"),
+                Token::U64(10_000),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(1_000_000, 1_000_000; "1mS equals 1kΩ")]
+    #[test_case(1_000_000_000, 1_000; "1S equals 1Ω")]
+    #[test_case(3_000_000, 333_333; "rounds down to nearest milliohm")]
+    fn test_to_resistance(nano_siemens: u64, expected_milli_ohms: u64) {
+        let conductance = Conductance::from_nano_siemens(nano_siemens);
+        assert_eq!(
+            conductance.to_resistance().milli_ohms(),
+            expected_milli_ohms
+        );
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    #[should_panic]
+    fn test_to_resistance_zero_conductance_panics() {
+        let _ = Conductance::zero().to_resistance();
+    }
+}
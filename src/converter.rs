@@ -0,0 +1,175 @@
+//! Ideal duty cycle calculations for switching (SMPS) converters.
+//!
+//! Each function takes the input and output voltages of a converter running in continuous
+//! conduction mode and returns the `DutyCycle` of its switch. The magnitudes of the voltages are
+//! used, so the negative output of an inverting buck-boost converter can be passed as-is.
+//!
+//! Converters with a freewheeling diode lose its forward voltage on every cycle, which the switch
+//! must make up for. Pass `Rectifier::Diode` with the forward voltage to compensate, or
+//! `Rectifier::Synchronous` for a converter with a second switch.
+//!
+//! ```rust
+//! use ohms::prelude::*;
+//! use ohms::converter::{self, Rectifier};
+//!
+//! let duty = converter::buck_duty_cycle(12.volts(), 5.volts(), Rectifier::Synchronous).unwrap();
+//! assert_eq!(duty.milli_percent(), 41_667); // 41.667%
+//!
+//! let diode = Rectifier::Diode(500.milli_volts());
+//! let duty = converter::buck_duty_cycle(12.volts(), 5.volts(), diode).unwrap();
+//! assert_eq!(duty.milli_percent(), 44_000); // 44%
+//! ```
+use crate::{DutyCycle, Voltage};
+
+/// The number of milli-percent in a duty cycle of 100%.
+const FULL_MILLI_PERCENT: u128 = 100_000;
+
+/// The rectifier used by a converter during the off time of its switch.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Rectifier {
+    /// A second switch with no voltage drop.
+    #[default]
+    Synchronous,
+    /// A freewheeling diode with the given forward voltage.
+    Diode(Voltage),
+}
+
+impl Rectifier {
+    /// Returns the magnitude of the forward voltage lost in the rectifier, in microvolts (μV).
+    fn drop(self) -> u128 {
+        match self {
+            Rectifier::Synchronous => 0,
+            Rectifier::Diode(forward) => magnitude(forward),
+        }
+    }
+}
+
+/// Calculates the duty cycle of a buck (step-down) converter (`D = Vout ÷ Vin`).
+///
+/// With a diode, the forward voltage is added to both sides (`D = (Vout + Vd) ÷ (Vin + Vd)`).
+/// Will be rounded to the nearest whole milli-percent (m%).
+/// Returns `None` if the input voltage is zero or the output is greater than the input.
+pub fn buck_duty_cycle(vin: Voltage, vout: Voltage, rectifier: Rectifier) -> Option<DutyCycle> {
+    let vin = magnitude(vin);
+    let vout = magnitude(vout);
+
+    if vin == 0 || vout > vin {
+        return None;
+    }
+
+    let drop = rectifier.drop();
+    ratio(vout + drop, vin + drop)
+}
+
+/// Calculates the duty cycle of a boost (step-up) converter (`D = 1 - Vin ÷ Vout`).
+///
+/// With a diode, the forward voltage is added to the output (`D = 1 - Vin ÷ (Vout + Vd)`).
+/// Will be rounded to the nearest whole milli-percent (m%).
+/// Returns `None` if the output voltage is zero or less than the input.
+pub fn boost_duty_cycle(vin: Voltage, vout: Voltage, rectifier: Rectifier) -> Option<DutyCycle> {
+    let vin = magnitude(vin);
+    let vout = magnitude(vout);
+
+    if vout == 0 || vout < vin {
+        return None;
+    }
+
+    let vout = vout + rectifier.drop();
+    ratio(vout - vin, vout)
+}
+
+/// Calculates the duty cycle of an inverting buck-boost converter
+/// (`D = Vout ÷ (Vin + Vout)`).
+///
+/// With a diode, the forward voltage is added to the output
+/// (`D = (Vout + Vd) ÷ (Vin + Vout + Vd)`).
+/// Will be rounded to the nearest whole milli-percent (m%).
+/// Returns `None` if both voltages are zero.
+pub fn buck_boost_duty_cycle(
+    vin: Voltage,
+    vout: Voltage,
+    rectifier: Rectifier,
+) -> Option<DutyCycle> {
+    let vin = magnitude(vin);
+    let vout = magnitude(vout) + rectifier.drop();
+
+    ratio(vout, vin + vout)
+}
+
+/// Returns the magnitude of a voltage in microvolts (μV).
+fn magnitude(voltage: Voltage) -> u128 {
+    voltage.micro_volts().unsigned_abs() as u128
+}
+
+/// Converts a ratio no greater than one to a duty cycle, rounded to the nearest whole
+/// milli-percent (m%). Returns `None` if the denominator is zero.
+fn ratio(numerator: u128, denominator: u128) -> Option<DutyCycle> {
+    if denominator == 0 {
+        return None;
+    }
+
+    let raw = (numerator * FULL_MILLI_PERCENT + denominator / 2) / denominator;
+
+    DutyCycle::from_milli_percent(raw as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    const DIODE: Rectifier = Rectifier::Diode(Voltage::from_micro_volts(500_000));
+
+    #[test_case(12_000_000, 5_000_000, Rectifier::Synchronous, Some(41_667); "12V to 5V")]
+    #[test_case(12_000_000, 5_000_000, DIODE, Some(44_000); "12V to 5V with diode")]
+    #[test_case(5_000_000, 5_000_000, Rectifier::Synchronous, Some(100_000); "equal")]
+    #[test_case(5_000_000, 3_300_000, Rectifier::Diode(Voltage::from_micro_volts(-300_000)), Some(67_925); "negative forward voltage uses magnitude")]
+    #[test_case(0, 0, Rectifier::Synchronous, None; "zero input")]
+    #[test_case(5_000_000, 12_000_000, Rectifier::Synchronous, None; "output above input")]
+    #[test_case(i64::MAX, i64::MAX, Rectifier::Diode(Voltage::from_micro_volts(i64::MAX)), Some(100_000); "maximum")]
+    fn test_buck_duty_cycle(vin: i64, vout: i64, rectifier: Rectifier, expected: Option<u32>) {
+        let duty = buck_duty_cycle(
+            Voltage::from_micro_volts(vin),
+            Voltage::from_micro_volts(vout),
+            rectifier,
+        );
+
+        assert_eq!(duty.map(|d| d.milli_percent()), expected);
+    }
+
+    #[test_case(5_000_000, 12_000_000, Rectifier::Synchronous, Some(58_333); "5V to 12V")]
+    #[test_case(5_000_000, 12_000_000, DIODE, Some(60_000); "5V to 12V with diode")]
+    #[test_case(3_300_000, 3_300_000, Rectifier::Synchronous, Some(0); "equal")]
+    #[test_case(0, 5_000_000, Rectifier::Synchronous, Some(100_000); "zero input")]
+    #[test_case(0, 0, Rectifier::Synchronous, None; "zero output")]
+    #[test_case(12_000_000, 5_000_000, Rectifier::Synchronous, None; "output below input")]
+    fn test_boost_duty_cycle(vin: i64, vout: i64, rectifier: Rectifier, expected: Option<u32>) {
+        let duty = boost_duty_cycle(
+            Voltage::from_micro_volts(vin),
+            Voltage::from_micro_volts(vout),
+            rectifier,
+        );
+
+        assert_eq!(duty.map(|d| d.milli_percent()), expected);
+    }
+
+    #[test_case(12_000_000, -12_000_000, Rectifier::Synchronous, Some(50_000); "inverting 12V")]
+    #[test_case(12_000_000, -5_000_000, Rectifier::Synchronous, Some(29_412); "step down")]
+    #[test_case(5_000_000, -12_000_000, Rectifier::Synchronous, Some(70_588); "step up")]
+    #[test_case(12_000_000, -12_000_000, DIODE, Some(51_020); "inverting 12V with diode")]
+    #[test_case(0, 0, Rectifier::Synchronous, None; "zero")]
+    fn test_buck_boost_duty_cycle(
+        vin: i64,
+        vout: i64,
+        rectifier: Rectifier,
+        expected: Option<u32>,
+    ) {
+        let duty = buck_boost_duty_cycle(
+            Voltage::from_micro_volts(vin),
+            Voltage::from_micro_volts(vout),
+            rectifier,
+        );
+
+        assert_eq!(duty.map(|d| d.milli_percent()), expected);
+    }
+}
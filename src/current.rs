@@ -1,5 +1,11 @@
-use crate::assert_positive_float;
-use core::{cmp, fmt, ops};
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::{f64_to_u64, try_round_f64_to_u64, try_scale_f64_to_u64};
+use crate::math::{isqrt, mean_square};
+use crate::parse::{parse_si_unsigned, ParseError};
+use crate::{RoundingMode, UnitError};
+use core::{cmp, fmt, hash, iter, ops, str};
 
 /// Represents a current value, stored as whole microamps (μA) as a 64-bit value.
 /// This value can only be positive.
@@ -43,6 +49,7 @@ use core::{cmp, fmt, ops};
 /// The result is a new `Current` value, rounded down to the nearest whole microamp (μA).
 ///
 /// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
 ///
 /// ```rust
 /// use ohms::prelude::*;
@@ -62,6 +69,9 @@ use core::{cmp, fmt, ops};
 ///
 /// If the result of the operation would be infinite or NaN, the operation will panic.
 ///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
 /// ```rust
 /// use ohms::prelude::*;
 ///
@@ -84,8 +94,10 @@ use core::{cmp, fmt, ops};
 /// println!("{:.3} A is {:.1} mA", c1.amps(), c1.milli_amps());
 /// ```
 ///
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Current {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_amps"))]
     raw: u64,
 }
 
@@ -99,6 +111,76 @@ impl Current {
         Self { raw: value }
     }
 
+    /// Creates a new `Current` from a number of whole nanoamps (nA).
+    ///
+    /// Returns `None` if the value is not a whole number of microamps (μA).
+    #[inline]
+    pub const fn from_nano_amps(value: u64) -> Option<Self> {
+        if value.is_multiple_of(1_000) {
+            Some(Self::from_micro_amps(value / 1_000))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `Current` from a number of nanoamps (nA).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative, not a whole number of
+    /// microamps (μA) or too large to be represented.
+    #[inline]
+    pub fn try_from_nano_amps(value: f64) -> Result<Self, UnitError> {
+        let micro_amps = try_scale_f64_to_u64(value / 1_000f64, 1f64)?;
+        if value % 1_000f64 != 0f64 {
+            return Err(UnitError::Inexact);
+        }
+
+        Ok(Self::from_micro_amps(micro_amps))
+    }
+
+    /// Creates a new `Current` from a number of fractional microamps (μA).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_micro_amps)
+    }
+
+    /// Creates a new `Current` from a number of fractional milliamps (mA).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_micro_amps)
+    }
+
+    /// Creates a new `Current` from a number of fractional amps (A).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_micro_amps)
+    }
+
+    /// Creates a new `Current` from a number of fractional units, rounding to the nearest whole
+    /// microamp (μA) in the given direction rather than truncating.
+    ///
+    /// Returns an error if the value is NaN, infinite, rounds to a negative number or is too large
+    /// to be represented.
+    #[inline]
+    pub fn try_from_rounded(
+        value: f64,
+        unit: CurrentUnit,
+        mode: RoundingMode,
+    ) -> Result<Self, UnitError> {
+        try_round_f64_to_u64(value, unit.scale() as f64, mode).map(Self::from_micro_amps)
+    }
+
+    /// Returns the current value in nanoamps (nA).
+    #[inline]
+    pub fn nano_amps(&self) -> f64 {
+        self.raw as f64 * 1_000_f64
+    }
+
     /// Returns the current value in whole microamps (μA).
     #[inline]
     pub const fn micro_amps(&self) -> u64 {
@@ -117,6 +199,20 @@ impl Current {
         self.raw as f64 / 1_000_000f64
     }
 
+    /// Returns the current value as whole milliamps (A) and the remaining microamps (μA),
+    /// without any floating-point math.
+    #[inline]
+    pub const fn to_milli_amps_parts(&self) -> (u64, u32) {
+        (self.raw / 1_000, (self.raw % 1_000) as u32)
+    }
+
+    /// Returns the current value as whole amps (A) and the remaining microamps (μA),
+    /// without any floating-point math.
+    #[inline]
+    pub const fn to_amps_parts(&self) -> (u64, u32) {
+        (self.raw / 1_000_000, (self.raw % 1_000_000) as u32)
+    }
+
     /// Returns whether the current value is zero amps (0A).
     #[inline]
     pub const fn is_zero(&self) -> bool {
@@ -128,8 +224,184 @@ impl Current {
     pub const fn zero() -> Self {
         Self::from_micro_amps(0)
     }
+
+    /// Adds two `Current` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_micro_amps)
+    }
+
+    /// Subtracts one `Current` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_micro_amps)
+    }
+
+    /// Multiplies the `Current` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_micro_amps)
+    }
+
+    /// Divides the `Current` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_micro_amps)
+    }
+
+    /// Multiplies the `Current` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole microamp (μA).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_micro_amps)
+    }
+
+    /// Divides the `Current` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole microamp (μA).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_micro_amps)
+    }
+
+    /// Multiplies the `Current` value by a floating-point value, rounding the result to the nearest
+    /// whole microamp (μA) in the given direction.
+    ///
+    /// Returns `None` if the factor is infinite, NaN, negative or the result would overflow.
+    #[inline]
+    pub fn checked_mul_f64_rounded(self, factor: f64, mode: RoundingMode) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(mode.round_f64(self.raw as f64 * factor)).map(Self::from_micro_amps)
+    }
+
+    /// Divides the `Current` value by a floating-point value, rounding the result to the nearest
+    /// whole microamp (μA) in the given direction.
+    ///
+    /// Returns `None` if the divisor is zero, infinite, NaN, negative or the result would overflow.
+    #[inline]
+    pub fn checked_div_f64_rounded(self, divisor: f64, mode: RoundingMode) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(mode.round_f64(self.raw as f64 / divisor)).map(Self::from_micro_amps)
+    }
+
+    /// Calculates the true RMS (root mean square) current of a set of samples.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Returns `None` if there are no samples.
+    pub fn rms(samples: &[Current]) -> Option<Current> {
+        let mean_square = mean_square(samples.iter().map(|sample| sample.raw))?;
+
+        Some(Self::from_micro_amps(isqrt(mean_square) as u64))
+    }
+
+    /// Calculates the true RMS (root mean square) current of a set of samples.
+    ///
+    /// This is equivalent to `Current::rms`.
+    #[inline]
+    pub fn rms_from_samples(samples: &[Current]) -> Option<Current> {
+        Self::rms(samples)
+    }
+
+    /// Calculates the form factor of a waveform given its RMS and average currents.
+    ///
+    /// This is the ratio of the RMS current to the average current, where a pure sine wave is
+    /// approximately `1.11`.
+    /// Panics if the average current is zero.
+    pub fn form_factor(rms: Current, avg: Current) -> f64 {
+        if avg.is_zero() {
+            panic!("Average current cannot be zero");
+        }
+
+        rms.raw as f64 / avg.raw as f64
+    }
+
+    /// Returns the unit symbol used when displaying the current value, such as `"mA"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "μA"),
+            1_000..=999_999 => (1_000, "mA"),
+            _ => (1_000_000, "A"),
+        }
+    }
+
+    /// Formats the current value into a byte buffer in the given unit, rounded to `precision`
+    /// decimal places, returning the written string, such as `"22.72 mA"`.
+    ///
+    /// No floating-point math or heap allocation is used, so this is suitable for rendering values
+    /// to displays on targets without a floating-point unit. Returns an error if the buffer is too
+    /// small to hold the formatted value.
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        unit: CurrentUnit,
+        precision: usize,
+    ) -> Result<&'a str, fmt::Error> {
+        display::format_into(buf, false, self.raw, unit.scale(), precision, unit.symbol())
+    }
+}
+
+/// The units a `Current` value can be formatted in, used by [`Current::format_into`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CurrentUnit {
+    /// Microamps (μA).
+    MicroAmps,
+    /// Milliamps (mA).
+    MilliAmps,
+    /// Amps (A).
+    Amps,
+}
+
+impl CurrentUnit {
+    /// Returns the number of base units in one of this unit.
+    pub(crate) const fn scale(self) -> u64 {
+        match self {
+            CurrentUnit::MicroAmps => 1,
+            CurrentUnit::MilliAmps => 1_000,
+            CurrentUnit::Amps => 1_000_000,
+        }
+    }
+
+    /// Returns the symbol of this unit, such as `"mA"`.
+    #[inline]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            CurrentUnit::MicroAmps => "μA",
+            CurrentUnit::MilliAmps => "mA",
+            CurrentUnit::Amps => "A",
+        }
+    }
 }
 
+approx::impl_approx_eq!(Current, unsigned);
+compare::impl_const_compare!(Current, unsigned);
+
 impl PartialEq for Current {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -142,7 +414,7 @@ impl Eq for Current {}
 impl PartialOrd for Current {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Some(self.cmp(other))
     }
 }
 
@@ -153,6 +425,13 @@ impl Ord for Current {
     }
 }
 
+impl hash::Hash for Current {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
 impl ops::Add for Current {
     type Output = Self;
 
@@ -179,6 +458,24 @@ impl ops::Sub for Current {
     }
 }
 
+impl iter::Sum for Current {
+    /// Sums the `Current` values, returning zero (0A) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a Current> for Current {
+    /// Sums the `Current` values, returning zero (0A) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 macro_rules! impl_mul_for_integer {
     ($i:ty) => {
         impl ops::Mul<$i> for Current {
@@ -315,41 +612,21 @@ pub trait FromInteger {
     fn amps(self) -> Current;
 }
 
-macro_rules! impl_current_from_integer {
-    ($i:ty) => {
-        impl FromInteger for $i {
-            #[inline]
-            fn micro_amps(self) -> Current {
-                Current::from_micro_amps(self as u64)
-            }
-
-            #[inline]
-            fn milli_amps(self) -> Current {
-                let milliamps = (self as u64)
-                    .checked_mul(1_000)
-                    .expect("Overflow when converting milliamps to microamps");
-                Current::from_micro_amps(milliamps)
-            }
-
-            #[inline]
-            fn amps(self) -> Current {
-                let milliamps = (self as u64)
-                    .checked_mul(1_000_000)
-                    .expect("Overflow when converting amps to microamps");
-                Current::from_micro_amps(milliamps)
-            }
-        }
-    };
-}
-
-impl_current_from_integer!(u8);
-impl_current_from_integer!(u16);
-impl_current_from_integer!(u32);
-impl_current_from_integer!(u64);
-impl_current_from_integer!(i8);
-impl_current_from_integer!(i16);
-impl_current_from_integer!(i32);
-impl_current_from_integer!(i64);
+crate::__define_unit_from_integer!(
+    Current,
+    FromInteger,
+    from_micro_amps,
+    micro_amps,
+    [milli_amps = 1_000, amps = 1_000_000],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
 
 /// Extension trait for simple short-hands for creating `Current` values from floating-point values.
 pub trait FromFloat {
@@ -369,43 +646,352 @@ pub trait FromFloat {
     fn amps(self) -> Current;
 }
 
-macro_rules! impl_current_from_float {
-    ($f:ty) => {
-        impl FromFloat for $f {
-            #[inline]
-            fn micro_amps(self) -> Current {
-                assert_positive_float!(self);
-                Current::from_micro_amps(self as u64)
-            }
+crate::__define_unit_from_float!(
+    Current,
+    FromFloat,
+    from_micro_amps,
+    micro_amps,
+    [milli_amps = 1_000, amps = 1_000_000],
+    f32,
+    f64
+);
 
-            #[inline]
-            fn milli_amps(self) -> Current {
-                assert_positive_float!(self);
-                let milliamps = (self as f64) * 1_000f64;
-                Current::from_micro_amps(milliamps as u64)
-            }
+crate::__define_unit_display!(Current);
 
-            #[inline]
-            fn amps(self) -> Current {
-                assert_positive_float!(self);
-                let milliamps = (self as f64) * 1_000_000f64;
-                Current::from_micro_amps(milliamps as u64)
-            }
-        }
-    };
+impl str::FromStr for Current {
+    type Err = ParseError;
+
+    /// Parses a `Current` value from a string with an optional SI prefix and unit symbol, such as
+    /// `22uA`, `1.5 mA` or `330m`.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["A"], 6).map(Self::from_micro_amps)
+    }
 }
 
-impl_current_from_float!(f32);
-impl_current_from_float!(f64);
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+impl Current {
+    /// Splits the current value into its whole and hundredths parts in the most appropriate unit,
+    /// for formatting without floating-point math.
+    fn scaled_parts(&self) -> (u64, u64, &'static str) {
+        let (scale, unit) = self.display_scale();
 
-impl fmt::Display for Current {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (value, unit) = match self.raw {
-            0..=999 => (self.raw as f64, "μA"),
-            1_000..=999_999 => ((self.raw as f64) / 1_000f64, "mA"),
-            _ => ((self.raw as f64) / 1_000_000f64, "A"),
-        };
+        let whole = self.raw / scale;
+        let fraction = self.raw % scale * 100 / scale;
+
+        (whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Current {
+    fn format(&self, f: defmt::Formatter) {
+        let (whole, fraction, unit) = self.scaled_parts();
+
+        defmt::write!(f, "{=u64}.{=u64:02} {=str}", whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Current {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let (whole, fraction, unit) = self.scaled_parts();
+        let padding = if fraction < 10 { "0" } else { "" };
+
+        ufmt::uwrite!(f, "{}.{}{} {}", whole, padding, fraction, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(1_500_000, (1_500, 0), (1, 500_000); "amps")]
+    #[test_case(22_720, (22, 720), (0, 22_720); "milliamps")]
+    #[test_case(0, (0, 0), (0, 0); "zero")]
+    fn test_to_parts(micro_amps: u64, milli_amps: (u64, u32), amps: (u64, u32)) {
+        let current = Current::from_micro_amps(micro_amps);
+        assert_eq!(current.to_milli_amps_parts(), milli_amps);
+        assert_eq!(current.to_amps_parts(), amps);
+    }
+
+    #[test]
+    fn test_rms_empty_is_none() {
+        assert_eq!(Current::rms(&[]), None);
+    }
+
+    #[test]
+    fn test_rms_from_samples_equals_rms() {
+        let samples = [
+            Current::from_micro_amps(3_000),
+            Current::from_micro_amps(4_000),
+        ];
+
+        assert_eq!(Current::rms_from_samples(&samples), Current::rms(&samples));
+        assert_eq!(Current::rms_from_samples(&[]), None);
+    }
+
+    #[test_case([1_000_000], 1_000_000; "single 1A sample equals 1A")]
+    #[test_case([2_000, 2_000, 2_000], 2_000; "constant 2mA equals 2mA")]
+    #[test_case([0, 0, 0], 0; "all zero equals 0A")]
+    #[test_case([1_000_000, 0], 707_106; "1A square wave at 50% duty equals 707,106μA")]
+    #[test_case([3_000, 4_000], 3_535; "3mA and 4mA equals 3,535μA")]
+    #[test_case([u64::MAX, u64::MAX], u64::MAX; "maximum samples do not overflow")]
+    fn test_rms<const N: usize>(samples: [u64; N], expected_micro_amps: u64) {
+        let currents = samples.map(Current::from_micro_amps);
+
+        let rms = Current::rms(&currents).unwrap();
+
+        assert_eq!(rms.micro_amps(), expected_micro_amps);
+    }
+
+    #[test_case(1_110_720, 1_000_000, 1.11072; "sine wave equals 1.11")]
+    #[test_case(500_000, 500_000, 1.0; "square wave equals one")]
+    fn test_form_factor(rms_micro_amps: u64, avg_micro_amps: u64, expected: f64) {
+        let rms = Current::from_micro_amps(rms_micro_amps);
+        let avg = Current::from_micro_amps(avg_micro_amps);
+
+        assert!((Current::form_factor(rms, avg) - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_form_factor_zero_average_panics() {
+        Current::form_factor(Current::from_micro_amps(1), Current::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negative_integer_panics() {
+        let _ = (-1).milli_amps();
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [
+            Current::from_micro_amps(1),
+            Current::from_micro_amps(2),
+            Current::from_micro_amps(3),
+        ];
+
+        assert_eq!(values.iter().sum::<Current>().micro_amps(), 6);
+        assert_eq!(values.into_iter().sum::<Current>().micro_amps(), 6);
+        assert_eq!(
+            core::iter::empty::<Current>().sum::<Current>(),
+            Current::zero()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sum_overflow_panics() {
+        let _: Current = [
+            Current::from_micro_amps(u64::MAX),
+            Current::from_micro_amps(1),
+        ]
+        .iter()
+        .sum();
+    }
+
+    #[test_case(1, 2, Some(3); "adds values")]
+    #[test_case(u64::MAX, 1, None; "overflow is none")]
+    fn test_checked_add(a: u64, b: u64, expected: Option<u64>) {
+        let result = Current::from_micro_amps(a).checked_add(Current::from_micro_amps(b));
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(3, 2, Some(1); "subtracts values")]
+    #[test_case(2, 3, None; "underflow is none")]
+    fn test_checked_sub(a: u64, b: u64, expected: Option<u64>) {
+        let result = Current::from_micro_amps(a).checked_sub(Current::from_micro_amps(b));
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(3, 2, Some(6); "multiplies values")]
+    #[test_case(u64::MAX, 2, None; "overflow is none")]
+    fn test_checked_mul_int(value: u64, factor: u64, expected: Option<u64>) {
+        let result = Current::from_micro_amps(value).checked_mul_int(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(7, 2, Some(3); "divides and rounds down")]
+    #[test_case(1, 0, None; "division by zero is none")]
+    fn test_checked_div_int(value: u64, divisor: u64, expected: Option<u64>) {
+        let result = Current::from_micro_amps(value).checked_div_int(divisor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    #[test_case(u64::MAX, 2.0, None; "overflow is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Current::from_micro_amps(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 4.0, Some(250); "divides by fractional divisor")]
+    #[test_case(1_000, 0.0, None; "division by zero is none")]
+    #[test_case(1_000, -4.0, None; "negative divisor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    #[test_case(u64::MAX, 0.5, None; "overflow is none")]
+    fn test_checked_div_f64(value: u64, divisor: f64, expected: Option<u64>) {
+        let result = Current::from_micro_amps(value).checked_div_f64(divisor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(5, 0.5, RoundingMode::Nearest, Some(3); "nearest rounds half up")]
+    #[test_case(5, 0.5, RoundingMode::Up, Some(3); "rounds up")]
+    #[test_case(5, 0.5, RoundingMode::Down, Some(2); "rounds down")]
+    #[test_case(u64::MAX, 2.0, RoundingMode::Up, None; "overflow is none")]
+    #[test_case(5, -0.1, RoundingMode::Nearest, None; "negative factor is none")]
+    #[test_case(5, -0.1, RoundingMode::Up, None; "negative factor rounded up is none")]
+    #[test_case(0, -2.0, RoundingMode::Nearest, None; "zero times negative factor is none")]
+    fn test_checked_mul_f64_rounded(
+        value: u64,
+        factor: f64,
+        mode: RoundingMode,
+        expected: Option<u64>,
+    ) {
+        let result = Current::from_micro_amps(value).checked_mul_f64_rounded(factor, mode);
+        assert_eq!(result.map(|i| i.raw), expected);
+    }
+
+    #[test_case(5, 2.0, RoundingMode::Nearest, Some(3); "nearest rounds half up")]
+    #[test_case(7, 2.0, RoundingMode::Down, Some(3); "rounds down")]
+    #[test_case(5, 0.0, RoundingMode::Up, None; "division by zero is none")]
+    #[test_case(5, -20.0, RoundingMode::Up, None; "negative divisor is none")]
+    #[test_case(5, -20.0, RoundingMode::Nearest, None; "negative divisor rounded is none")]
+    fn test_checked_div_f64_rounded(
+        value: u64,
+        divisor: f64,
+        mode: RoundingMode,
+        expected: Option<u64>,
+    ) {
+        let result = Current::from_micro_amps(value).checked_div_f64_rounded(divisor, mode);
+        assert_eq!(result.map(|i| i.raw), expected);
+    }
+
+    #[test_case(2.5, RoundingMode::Nearest, Ok(3); "nearest rounds half up")]
+    #[test_case(2.1, RoundingMode::Up, Ok(3); "rounds up")]
+    #[test_case(-0.4, RoundingMode::Nearest, Ok(0); "small negative rounds to zero")]
+    #[test_case(-0.6, RoundingMode::Nearest, Err(UnitError::Negative); "negative")]
+    fn test_try_from_rounded(value: f64, mode: RoundingMode, expected: Result<u64, UnitError>) {
+        let result = Current::try_from_rounded(value, CurrentUnit::MicroAmps, mode);
+        assert_eq!(result.map(|i| i.raw), expected);
+    }
+
+    #[test_case(1_500_000, "1.50 A"; "amps")]
+    #[test_case(1_500, "1.50 mA"; "milliamps")]
+    #[test_case(15, "15.00 μA"; "microamps")]
+    fn test_display(micro_amps: u64, expected: &str) {
+        let current = Current::from_micro_amps(micro_amps);
+        assert_eq!(format!("{current}"), expected);
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let current = Current::from_micro_amps(1_500_000);
+        assert_eq!(format!("{current:.0}"), "2 A");
+        assert_eq!(format!("{current:.4}"), "1.5000 A");
+        assert_eq!(format!("{current:.6}"), "1.500000 A");
+    }
+
+    #[test_case("22uA", Ok(22); "micro prefix")]
+    #[test_case("1.5 mA", Ok(1_500); "milli prefix with space")]
+    #[test_case("330m", Ok(330_000); "prefix without symbol")]
+    #[test_case("2A", Ok(2_000_000); "amps")]
+    #[test_case("100nA", Ok(0); "rounds down to nearest microamp")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Current>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(22_720, CurrentUnit::MilliAmps, 2, "22.72 mA"; "milliamps")]
+    #[test_case(1_500_000, CurrentUnit::Amps, 1, "1.5 A"; "amps")]
+    #[test_case(15, CurrentUnit::MicroAmps, 0, "15 μA"; "microamps")]
+    fn test_format_into(micro_amps: u64, unit: CurrentUnit, precision: usize, expected: &str) {
+        let mut buf = [0u8; 32];
+        let current = Current::from_micro_amps(micro_amps);
+        assert_eq!(current.format_into(&mut buf, unit, precision), Ok(expected));
+    }
+
+    #[test]
+    fn test_format_into_buffer_too_small() {
+        let mut buf = [0u8; 4];
+        let current = Current::from_micro_amps(22_720);
+        assert!(current
+            .format_into(&mut buf, CurrentUnit::MilliAmps, 2)
+            .is_err());
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test_case(1_500_000, "1.50 A"; "amps")]
+    #[test_case(1_505, "1.50 mA"; "truncates to hundredths")]
+    #[test_case(7, "7.00 μA"; "microamps")]
+    fn test_udisplay(micro_amps: u64, expected: &str) {
+        let current = Current::from_micro_amps(micro_amps);
+        let mut output = alloc::string::String::new();
+        ufmt::uwrite!(output, "{}", current).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test_case(1_500_000, Some(1_500); "whole microamps")]
+    #[test_case(1_500_999, None; "fraction of a microamp")]
+    #[test_case(999, None; "less than one microamp")]
+    fn test_from_nano_amps(nano: u64, expected_micro: Option<u64>) {
+        let i = Current::from_nano_amps(nano);
+
+        assert_eq!(i.map(|i| i.raw), expected_micro);
+        assert_eq!(
+            i.map(|i| i.nano_amps()),
+            expected_micro.map(|micro| micro as f64 * 1_000.0)
+        );
+    }
+
+    #[test_case(2_000.0, Ok(2); "whole microunits")]
+    #[test_case(2_500.0, Err(UnitError::Inexact); "fractional microunits")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    fn test_try_from_nano_amps(nano: f64, expected_micro: Result<u64, UnitError>) {
+        let result = Current::try_from_nano_amps(nano);
+        assert_eq!(result.map(|i| i.raw), expected_micro);
+    }
+
+    #[test_case(Current::try_from_milli_amps(1.5), Ok(Current::from_micro_amps(1_500)); "valid value")]
+    #[test_case(Current::try_from_milli_amps(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Current::try_from_milli_amps(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Current::try_from_milli_amps(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Current::try_from_milli_amps(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(result: Result<Current, UnitError>, expected: Result<Current, UnitError>) {
+        assert_eq!(result, expected);
+    }
 
-        write!(f, "{value:.2} {unit}")
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Current::from_micro_amps(1500),
+            &[
+                Token::Struct {
+                    name: "Current",
+                    len: 1,
+                },
+                Token::Str("micro_amps"),
+                Token::U64(1500),
+                Token::StructEnd,
+            ],
+        );
     }
 }
@@ -0,0 +1,155 @@
+use crate::display;
+use crate::math::try_scale_f64_to_u64;
+use crate::{Current, UnitError};
+use core::fmt;
+
+/// Represents a current density, stored as whole microamps per square millimeter (μA/mm²) as a
+/// 64-bit value.
+///
+/// Conductors are sized by the current density they carry, such as a few amps per square
+/// millimeter for enclosed wiring, and chip metallization is limited by it to avoid
+/// electromigration.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// // 10A through 2.5mm² of wire
+/// let density = CurrentDensity::from_current(10.amps(), 2_500_000).unwrap();
+///
+/// assert_eq!(density.amps_per_square_milli_meter(), 4.0);
+/// assert_eq!(density.current_for(1_500_000), Some(6.amps())); // through 1.5mm²
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CurrentDensity {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_amps_per_square_milli_meter"))]
+    raw: u64,
+}
+
+impl CurrentDensity {
+    /// Creates a new `CurrentDensity` from a number of whole microamps per square millimeter
+    /// (μA/mm²).
+    #[inline]
+    pub const fn from_micro_amps_per_square_milli_meter(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `CurrentDensity` from a number of fractional amps per square millimeter
+    /// (A/mm²).
+    ///
+    /// Will be rounded down to the nearest whole microamp per square millimeter (μA/mm²).
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    pub fn try_from_amps_per_square_milli_meter(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_micro_amps_per_square_milli_meter)
+    }
+
+    /// Calculates the current density of a current flowing through a cross-sectional area in
+    /// whole square micrometers (μm²).
+    ///
+    /// Will be rounded down to the nearest whole microamp per square millimeter (μA/mm²).
+    /// Returns `None` if the area is zero or the result would overflow.
+    pub fn from_current(current: Current, square_micro_meters: u64) -> Option<Self> {
+        if square_micro_meters == 0 {
+            return None;
+        }
+
+        let raw = current.micro_amps() as u128 * 1_000_000 / square_micro_meters as u128;
+
+        u64::try_from(raw)
+            .ok()
+            .map(Self::from_micro_amps_per_square_milli_meter)
+    }
+
+    /// Returns the current density in whole microamps per square millimeter (μA/mm²).
+    #[inline]
+    pub const fn micro_amps_per_square_milli_meter(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the current density in fractional amps per square millimeter (A/mm²).
+    #[inline]
+    pub fn amps_per_square_milli_meter(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Calculates the current flowing at this density through a cross-sectional area in whole
+    /// square micrometers (μm²).
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Returns `None` if the result would overflow.
+    pub fn current_for(&self, square_micro_meters: u64) -> Option<Current> {
+        let micro_amps = self.raw as u128 * square_micro_meters as u128 / 1_000_000;
+
+        u64::try_from(micro_amps).ok().map(Current::from_micro_amps)
+    }
+
+    /// Returns whether the current density is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `CurrentDensity` value of zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_micro_amps_per_square_milli_meter(0)
+    }
+}
+
+impl fmt::Display for CurrentDensity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(
+            f,
+            format_args!("{}.{:06} A/mm²", self.raw / 1_000_000, self.raw % 1_000_000),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(4.0, Ok(4_000_000); "whole")]
+    #[test_case(0.5, Ok(500_000); "fraction")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(1e14, Err(UnitError::Overflow); "overflow")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    fn test_try_from_amps_per_square_milli_meter(value: f64, expected: Result<u64, UnitError>) {
+        let density = CurrentDensity::try_from_amps_per_square_milli_meter(value)
+            .map(|d| d.micro_amps_per_square_milli_meter());
+        assert_eq!(density, expected);
+    }
+
+    #[test_case(10_000_000, 2_500_000, Some(4_000_000); "wire")]
+    #[test_case(1_000, 1, Some(1_000_000_000); "on chip")]
+    #[test_case(1, 3_000_000, Some(0); "rounds down")]
+    #[test_case(1_000_000, 0, None; "zero area")]
+    #[test_case(u64::MAX, 1, None; "overflow")]
+    fn test_from_current(micro_amps: u64, square_micro_meters: u64, expected: Option<u64>) {
+        let density =
+            CurrentDensity::from_current(Current::from_micro_amps(micro_amps), square_micro_meters);
+        assert_eq!(
+            density.map(|d| d.micro_amps_per_square_milli_meter()),
+            expected
+        );
+    }
+
+    #[test_case(4_000_000, 1_500_000, Some(6_000_000); "wire")]
+    #[test_case(4_000_000, 0, Some(0); "zero area")]
+    #[test_case(u64::MAX, u64::MAX, None; "overflow")]
+    fn test_current_for(density: u64, square_micro_meters: u64, expected: Option<u64>) {
+        let current = CurrentDensity::from_micro_amps_per_square_milli_meter(density)
+            .current_for(square_micro_meters);
+        assert_eq!(current.map(|c| c.micro_amps()), expected);
+    }
+
+    #[test_case(4_000_000, "4.000000 A/mm²"; "whole")]
+    #[test_case(0, "0.000000 A/mm²"; "zero")]
+    fn test_display(density: u64, expected: &str) {
+        let density = CurrentDensity::from_micro_amps_per_square_milli_meter(density);
+        assert_eq!(format!("{density}"), expected);
+    }
+}
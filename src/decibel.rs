@@ -0,0 +1,213 @@
+use crate::display;
+use crate::math::f64_to_i64;
+use crate::UnitError;
+use core::{fmt, ops};
+
+/// Represents a logarithmic level or gain, stored as whole millidecibels (mdB) as a signed 32-bit
+/// value.
+///
+/// A `Decibel` value does not record its reference level, which is given by the method that
+/// creates it. For example, `Power::to_dbm` is relative to one milliwatt, and `Voltage::to_dbv` is
+/// relative to one volt. Gains can be added and subtracted, as decibels multiply when chained.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let gain = Decibel::from_milli_decibels(20_000); // 20dB
+/// let loss = Decibel::from_milli_decibels(-3_000); // -3dB
+///
+/// assert_eq!((gain + loss).decibels(), 17.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Decibel {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_decibels"))]
+    raw: i32,
+}
+
+impl Decibel {
+    /// Creates a new `Decibel` from a number of whole millidecibels (mdB).
+    #[inline]
+    pub const fn from_milli_decibels(value: i32) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Decibel` from a number of fractional decibels (dB).
+    ///
+    /// Will be rounded toward zero to the nearest whole millidecibel (mdB).
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    pub fn try_from_decibels(value: f64) -> Result<Self, UnitError> {
+        if value.is_nan() {
+            return Err(UnitError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(UnitError::Infinite);
+        }
+
+        f64_to_i64(value * 1_000f64)
+            .and_then(|raw| i32::try_from(raw).ok())
+            .map(Self::from_milli_decibels)
+            .ok_or(UnitError::Overflow)
+    }
+
+    /// Returns the level in whole millidecibels (mdB).
+    #[inline]
+    pub const fn milli_decibels(&self) -> i32 {
+        self.raw
+    }
+
+    /// Returns the level in fractional decibels (dB).
+    #[inline]
+    pub fn decibels(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns whether the level is below its reference, or the gain is a loss.
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.raw < 0
+    }
+
+    /// Returns a `Decibel` value of zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_milli_decibels(0)
+    }
+
+    /// Adds another `Decibel` value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_milli_decibels)
+    }
+
+    /// Subtracts another `Decibel` value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_milli_decibels)
+    }
+
+    /// Creates a new `Decibel` from a number of fractional decibels (dB), rounded to the nearest
+    /// whole millidecibel (mdB) so that exact levels survive floating-point error.
+    #[cfg(all(feature = "libm", any(feature = "voltage", feature = "power")))]
+    pub(crate) fn from_decibels_rounded(value: f64) -> Option<Self> {
+        f64_to_i64(libm::round(value * 1_000f64))
+            .and_then(|raw| i32::try_from(raw).ok())
+            .map(Self::from_milli_decibels)
+    }
+}
+
+impl ops::Add for Decibel {
+    type Output = Decibel;
+
+    /// Adds two `Decibel` values together, returning a new value.
+    /// Panics if the result would overflow.
+    fn add(self, other: Decibel) -> Decibel {
+        self.checked_add(other)
+            .expect("Overflow when adding decibel values")
+    }
+}
+
+impl ops::Sub for Decibel {
+    type Output = Decibel;
+
+    /// Subtracts one `Decibel` value from another, returning a new value.
+    /// Panics if the result would overflow.
+    fn sub(self, other: Decibel) -> Decibel {
+        self.checked_sub(other)
+            .expect("Overflow when subtracting decibel values")
+    }
+}
+
+impl ops::Neg for Decibel {
+    type Output = Decibel;
+
+    /// Inverts the level, turning a gain into a loss or vice versa.
+    /// Panics if the result would overflow.
+    fn neg(self) -> Decibel {
+        self.raw
+            .checked_neg()
+            .map(Self::from_milli_decibels)
+            .expect("Overflow when negating decibel value")
+    }
+}
+
+impl fmt::Display for Decibel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let magnitude = self.raw.unsigned_abs();
+
+        display::write_padded(
+            f,
+            format_args!("{}{}.{:03} dB", sign, magnitude / 1_000, magnitude % 1_000),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(3.0103, Ok(3_010); "positive")]
+    #[test_case(-6.0206, Ok(-6_020); "negative")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    #[test_case(3e6, Err(UnitError::Overflow); "overflow")]
+    fn test_try_from_decibels(value: f64, expected: Result<i32, UnitError>) {
+        let level = Decibel::try_from_decibels(value).map(|db| db.milli_decibels());
+        assert_eq!(level, expected);
+    }
+
+    #[test]
+    fn test_decibels() {
+        assert_eq!(Decibel::from_milli_decibels(-3_010).decibels(), -3.01);
+        assert!(Decibel::from_milli_decibels(-1).is_negative());
+        assert!(!Decibel::zero().is_negative());
+    }
+
+    #[test_case(20_000, -3_000, Some(17_000), Some(23_000); "gain and loss")]
+    #[test_case(i32::MAX, 1, None, Some(i32::MAX - 1); "add overflow")]
+    #[test_case(i32::MIN, 1, Some(i32::MIN + 1), None; "sub overflow")]
+    fn test_checked_add_sub(lhs: i32, rhs: i32, sum: Option<i32>, difference: Option<i32>) {
+        let lhs = Decibel::from_milli_decibels(lhs);
+        let rhs = Decibel::from_milli_decibels(rhs);
+
+        assert_eq!(lhs.checked_add(rhs).map(|db| db.milli_decibels()), sum);
+        assert_eq!(
+            lhs.checked_sub(rhs).map(|db| db.milli_decibels()),
+            difference
+        );
+    }
+
+    #[test]
+    fn test_neg() {
+        let level = Decibel::from_milli_decibels(3_010);
+        assert_eq!((-level).milli_decibels(), -3_010);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_neg_overflow_panics() {
+        let _ = -Decibel::from_milli_decibels(i32::MIN);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow_panics() {
+        let _ = Decibel::from_milli_decibels(i32::MAX) + Decibel::from_milli_decibels(1);
+    }
+
+    #[test_case(3_010, "3.010 dB"; "positive")]
+    #[test_case(-60_000, "-60.000 dB"; "negative")]
+    #[test_case(-5, "-0.005 dB"; "negative fraction")]
+    #[test_case(0, "0.000 dB"; "zero")]
+    fn test_display(milli_decibels: i32, expected: &str) {
+        let level = Decibel::from_milli_decibels(milli_decibels);
+        assert_eq!(format!("{level}"), expected);
+    }
+}
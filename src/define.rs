@@ -0,0 +1,387 @@
+//! Support for the `define_unit!` macro, which creates custom unit types outside of this crate.
+
+#[doc(hidden)]
+pub use crate::display::write_padded;
+
+/// Defines a custom unit type backed by a whole number of its smallest denomination as a `u64`,
+/// with the same operators, `Display` and extension traits as the built-in units.
+///
+/// The first denomination is the base unit, and names the constructor and integer getter. Each
+/// further denomination gives its size in base units, and names a fractional getter. The two
+/// extension traits are implemented for the integer and floating-point types respectively.
+///
+/// ```rust
+/// use ohms::define_unit;
+///
+/// define_unit! {
+///     /// Represents a magnetic flux value, stored as whole nanowebers (nWb).
+///     pub struct MagneticFlux {
+///         from_nano_webers, nano_webers => "nWb";
+///         micro_webers = 1_000 => "μWb",
+///         milli_webers = 1_000_000 => "mWb",
+///         webers = 1_000_000_000 => "Wb",
+///     }
+///
+///     pub trait FluxFromInteger;
+///     pub trait FluxFromFloat;
+/// }
+///
+/// let flux = 5.milli_webers() + 250.micro_webers();
+///
+/// assert_eq!(flux.nano_webers(), 5_250_000);
+/// assert_eq!(flux.webers(), 0.00525);
+/// assert_eq!(format!("{flux:.1}"), "5.2 mWb");
+/// ```
+#[macro_export]
+macro_rules! define_unit {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $from:ident, $base:ident => $base_symbol:literal;
+            $($unit:ident = $scale:literal => $symbol:literal),* $(,)?
+        }
+
+        $int_vis:vis trait $from_integer:ident;
+        $float_vis:vis trait $from_float:ident;
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        $vis struct $name {
+            raw: u64,
+        }
+
+        impl $name {
+            #[doc = ::core::concat!("Creates a new `", ::core::stringify!($name), "` from a number of whole ", $base_symbol, ".")]
+            #[inline]
+            pub const fn $from(value: u64) -> Self {
+                Self { raw: value }
+            }
+
+            #[doc = ::core::concat!("Returns the value in whole ", $base_symbol, ".")]
+            #[inline]
+            pub const fn $base(&self) -> u64 {
+                self.raw
+            }
+
+            $(
+                #[doc = ::core::concat!("Returns the value in fractional ", $symbol, ".")]
+                #[inline]
+                pub fn $unit(&self) -> f64 {
+                    self.raw as f64 / $scale as f64
+                }
+            )*
+
+            /// Returns whether the value is zero.
+            #[inline]
+            pub const fn is_zero(&self) -> bool {
+                self.raw == 0
+            }
+
+            /// Returns a value of zero.
+            #[inline]
+            pub const fn zero() -> Self {
+                Self::$from(0)
+            }
+
+            /// Adds two values together, returning `None` if the result would overflow.
+            #[inline]
+            pub fn checked_add(self, other: Self) -> Option<Self> {
+                self.raw.checked_add(other.raw).map(Self::$from)
+            }
+
+            /// Subtracts one value from another, returning `None` if the result would overflow.
+            #[inline]
+            pub fn checked_sub(self, other: Self) -> Option<Self> {
+                self.raw.checked_sub(other.raw).map(Self::$from)
+            }
+
+            /// Multiplies the value by an integer value, returning `None` if the result would
+            /// overflow.
+            #[inline]
+            pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+                self.raw.checked_mul(factor).map(Self::$from)
+            }
+
+            /// Divides the value by an integer value, returning `None` if the divisor is zero.
+            #[inline]
+            pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+                self.raw.checked_div(divisor).map(Self::$from)
+            }
+
+            /// Returns the magnitude of the difference between two values.
+            #[inline]
+            pub const fn abs_diff(self, other: Self) -> Self {
+                Self::$from(self.raw.abs_diff(other.raw))
+            }
+
+            /// Returns the symbol of the unit the value is displayed in.
+            #[inline]
+            pub const fn display_unit(&self) -> &'static str {
+                self.display_scale().1
+            }
+
+            /// Returns the number of base units per displayed unit, along with its symbol.
+            const fn display_scale(&self) -> (u64, &'static str) {
+                let mut display = (1u64, $base_symbol);
+                $(
+                    if self.raw >= $scale {
+                        display = ($scale, $symbol);
+                    }
+                )*
+                display
+            }
+        }
+
+        impl ::core::ops::Add for $name {
+            type Output = Self;
+
+            /// Adds two values together, panicking if the result would overflow.
+            #[inline]
+            fn add(self, other: Self) -> Self {
+                self.checked_add(other)
+                    .expect(::core::concat!("Overflow when adding ", ::core::stringify!($name), " values"))
+            }
+        }
+
+        impl ::core::ops::Sub for $name {
+            type Output = Self;
+
+            /// Subtracts one value from another, panicking if the result would overflow.
+            #[inline]
+            fn sub(self, other: Self) -> Self {
+                self.checked_sub(other)
+                    .expect(::core::concat!("Overflow when subtracting ", ::core::stringify!($name), " values"))
+            }
+        }
+
+        impl ::core::ops::Mul<u64> for $name {
+            type Output = Self;
+
+            /// Multiplies the value by an integer value, panicking if the result would overflow.
+            #[inline]
+            fn mul(self, factor: u64) -> Self {
+                self.checked_mul_int(factor)
+                    .expect(::core::concat!("Overflow when multiplying ", ::core::stringify!($name), " value"))
+            }
+        }
+
+        impl ::core::ops::Div<u64> for $name {
+            type Output = Self;
+
+            /// Divides the value by an integer value, panicking if the divisor is zero.
+            #[inline]
+            fn div(self, divisor: u64) -> Self {
+                self.checked_div_int(divisor)
+                    .expect(::core::concat!("Cannot divide ", ::core::stringify!($name), " value by zero"))
+            }
+        }
+
+        impl ::core::iter::Sum for $name {
+            /// Sums the values, panicking if the total would overflow.
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::zero(), |total, value| total + value)
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a $name> for $name {
+            /// Sums the values, panicking if the total would overflow.
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        $crate::__define_unit_display!($name);
+
+        #[doc = ::core::concat!("Extension trait for simple short-hands for creating `", ::core::stringify!($name), "` values from integer values.")]
+        $int_vis trait $from_integer {
+            #[doc = ::core::concat!("Creates a new `", ::core::stringify!($name), "` from a number of whole ", $base_symbol, ".")]
+            fn $base(self) -> $name;
+
+            $(
+                #[doc = ::core::concat!("Creates a new `", ::core::stringify!($name), "` from a number of whole ", $symbol, ".")]
+                fn $unit(self) -> $name;
+            )*
+        }
+
+        $crate::__define_unit_from_integer!(
+            $name, $from_integer, $from, $base, [$($unit = $scale),*],
+            u8, u16, u32, u64, i8, i16, i32, i64
+        );
+
+        #[doc = ::core::concat!("Extension trait for simple short-hands for creating `", ::core::stringify!($name), "` values from floating-point values.")]
+        $float_vis trait $from_float {
+            #[doc = ::core::concat!("Creates a new `", ::core::stringify!($name), "` from a number of fractional ", $base_symbol, ".")]
+            ///
+            /// The fractional part is rounded down to the nearest whole base unit.
+            fn $base(self) -> $name;
+
+            $(
+                #[doc = ::core::concat!("Creates a new `", ::core::stringify!($name), "` from a number of fractional ", $symbol, ".")]
+                ///
+                /// The fractional part is rounded down to the nearest whole base unit.
+                fn $unit(self) -> $name;
+            )*
+        }
+
+        $crate::__define_unit_from_float!(
+            $name, $from_float, $from, $base, [$($unit = $scale),*],
+            f32, f64
+        );
+    };
+}
+
+// The macros below are shared with the built-in unit types, which are defined the same way.
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_unit_display {
+    ($name:ident) => {
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                let precision = f.precision().unwrap_or(2);
+                let (scale, unit) = self.display_scale();
+                let value = (self.raw as f64) / (scale as f64);
+
+                $crate::define::write_padded(f, ::core::format_args!("{value:.precision$} {unit}"))
+            }
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_unit_from_integer {
+    ($name:ident, $trait:ident, $from:ident, $base:ident, [$($unit:ident = $scale:literal),*], $int:ty) => {
+        impl $trait for $int {
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn $base(self) -> $name {
+                if self < 0 {
+                    panic!(::core::concat!(::core::stringify!($name), " cannot be negative"));
+                }
+
+                $name::$from(self as u64)
+            }
+
+            $(
+                #[inline]
+                fn $unit(self) -> $name {
+                    let value = $trait::$base(self)
+                        .raw
+                        .checked_mul($scale)
+                        .expect(::core::concat!("Overflow when converting ", ::core::stringify!($unit)));
+                    $name::$from(value)
+                }
+            )*
+        }
+    };
+    ($name:ident, $trait:ident, $from:ident, $base:ident, $units:tt, $int:ty, $($rest:ty),+) => {
+        $crate::__define_unit_from_integer!($name, $trait, $from, $base, $units, $int);
+        $crate::__define_unit_from_integer!($name, $trait, $from, $base, $units, $($rest),+);
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_unit_from_float {
+    ($name:ident, $trait:ident, $from:ident, $base:ident, [$($unit:ident = $scale:literal),*], $float:ty) => {
+        impl $trait for $float {
+            #[inline]
+            fn $base(self) -> $name {
+                $crate::assert_positive_float!(self);
+                $name::$from(self as u64)
+            }
+
+            $(
+                #[inline]
+                fn $unit(self) -> $name {
+                    $crate::assert_positive_float!(self);
+                    let scale: u64 = $scale;
+                    $name::$from((self as f64 * scale as f64) as u64)
+                }
+            )*
+        }
+    };
+    ($name:ident, $trait:ident, $from:ident, $base:ident, $units:tt, $float:ty, $($rest:ty),+) => {
+        $crate::__define_unit_from_float!($name, $trait, $from, $base, $units, $float);
+        $crate::__define_unit_from_float!($name, $trait, $from, $base, $units, $($rest),+);
+    };
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use alloc::format;
+    use test_case::test_case;
+
+    crate::define_unit! {
+        /// Represents a luminous intensity value, stored as whole millicandela (mcd).
+        pub struct LuminousIntensity {
+            from_milli_candela, milli_candela => "mcd";
+            candela = 1_000 => "cd",
+            kilo_candela = 1_000_000 => "kcd",
+        }
+
+        pub trait FromInteger;
+        pub trait FromFloat;
+    }
+
+    #[test]
+    fn test_constructors_and_getters() {
+        use FromFloat as _;
+        use FromInteger as _;
+
+        assert_eq!(
+            LuminousIntensity::from_milli_candela(1_500).milli_candela(),
+            1_500
+        );
+        assert_eq!(3.candela().milli_candela(), 3_000);
+        assert_eq!(2u8.kilo_candela().candela(), 2_000.0);
+        assert_eq!(1.5.candela().milli_candela(), 1_500);
+        assert_eq!(2.5.milli_candela().milli_candela(), 2);
+        assert_eq!(0.5f32.kilo_candela().kilo_candela(), 0.5);
+        assert_eq!(LuminousIntensity::zero(), LuminousIntensity::default());
+        assert!(LuminousIntensity::zero().is_zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_negative_integer_panics() {
+        use FromInteger as _;
+        let _ = (-1).candela();
+    }
+
+    #[test]
+    fn test_operators() {
+        let a = LuminousIntensity::from_milli_candela(3_000);
+        let b = LuminousIntensity::from_milli_candela(1_000);
+
+        assert_eq!((a + b).milli_candela(), 4_000);
+        assert_eq!((a - b).milli_candela(), 2_000);
+        assert_eq!((a * 2).milli_candela(), 6_000);
+        assert_eq!((a / 3).milli_candela(), 1_000);
+        assert_eq!(b.abs_diff(a).milli_candela(), 2_000);
+        assert_eq!(
+            [a, b].iter().sum::<LuminousIntensity>().milli_candela(),
+            4_000
+        );
+        assert!(a > b);
+        assert_eq!(b.checked_sub(a), None);
+    }
+
+    #[test_case(500, "500.00 mcd"; "base unit")]
+    #[test_case(1_500, "1.50 cd"; "scaled unit")]
+    #[test_case(2_000_000, "2.00 kcd"; "largest unit")]
+    fn test_display(milli_candela: u64, expected: &str) {
+        let value = LuminousIntensity::from_milli_candela(milli_candela);
+
+        assert_eq!(format!("{value}"), expected);
+        assert_eq!(value.display_unit(), expected.rsplit(' ').next().unwrap());
+    }
+
+    #[test]
+    fn test_display_width() {
+        let value = LuminousIntensity::from_milli_candela(1_500);
+        assert_eq!(format!("{value:>10.1}"), "    1.5 cd");
+    }
+}
@@ -0,0 +1,262 @@
+use core::fmt::{self, Write};
+
+/// Counts the number of characters written to it, without storing them.
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Writes the formatted arguments, padded to the width of the formatter using its fill character.
+///
+/// Values are right-aligned like numbers unless another alignment is requested.
+pub fn write_padded(f: &mut fmt::Formatter, args: fmt::Arguments) -> fmt::Result {
+    let width = match f.width() {
+        Some(width) => width,
+        None => return f.write_fmt(args),
+    };
+
+    let mut counter = CharCounter(0);
+    counter.write_fmt(args)?;
+
+    let padding = width.saturating_sub(counter.0);
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        _ => (padding, 0),
+    };
+
+    let fill = f.fill();
+
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+
+    f.write_fmt(args)?;
+
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+/// Writes text into a byte buffer, failing if the buffer is too small.
+#[cfg(any(
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
+struct BufferWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+#[cfg(any(
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
+impl Write for BufferWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len.checked_add(s.len()).ok_or(fmt::Error)?;
+        let target = self.buf.get_mut(self.len..end).ok_or(fmt::Error)?;
+
+        target.copy_from_slice(s.as_bytes());
+        self.len = end;
+
+        Ok(())
+    }
+}
+
+/// Formats a value of whole base units into a byte buffer, scaled down to a unit that is `scale`
+/// base units and rounded to `precision` decimal places, returning the written string.
+///
+/// Only integer math is used, so no floating-point formatting code is pulled in.
+#[cfg(any(
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
+pub(crate) fn format_into<'a>(
+    buf: &'a mut [u8],
+    negative: bool,
+    value: u64,
+    scale: u64,
+    precision: usize,
+    unit: &str,
+) -> Result<&'a str, fmt::Error> {
+    // Decimal places beyond the resolution of the base unit are always zero
+    let mut significant = 0;
+    let mut remaining = scale;
+
+    while remaining >= 10 {
+        remaining /= 10;
+        significant += 1;
+    }
+
+    let significant = precision.min(significant);
+    let factor = 10u128.pow(significant as u32);
+    let rounded = (value as u128 * factor + scale as u128 / 2) / scale as u128;
+
+    let whole = rounded / factor;
+    let fraction = rounded % factor;
+
+    let mut writer = BufferWriter { buf, len: 0 };
+
+    if negative {
+        writer.write_char('-')?;
+    }
+
+    write!(writer, "{whole}")?;
+
+    if precision > 0 {
+        writer.write_char('.')?;
+
+        if significant > 0 {
+            write!(writer, "{fraction:0significant$}")?;
+        }
+
+        for _ in significant..precision {
+            writer.write_char('0')?;
+        }
+    }
+
+    write!(writer, " {unit}")?;
+
+    let len = writer.len;
+    core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+}
+
+/// Formats a value of whole base units into a byte buffer in RKM notation, with the `marker` for
+/// a unit that is `scale` base units taking the place of the decimal point, such as `4k7`.
+///
+/// Trailing zeros in the fractional part are omitted, so the value is never rounded.
+#[cfg(any(feature = "voltage", feature = "resistance"))]
+pub(crate) fn format_rkm(
+    buf: &mut [u8],
+    negative: bool,
+    value: u64,
+    scale: u64,
+    marker: char,
+) -> Result<&str, fmt::Error> {
+    let mut digits = 0;
+    let mut remaining = scale;
+
+    while remaining >= 10 {
+        remaining /= 10;
+        digits += 1;
+    }
+
+    let mut fraction = value % scale;
+
+    while digits > 0 && fraction.is_multiple_of(10) {
+        fraction /= 10;
+        digits -= 1;
+    }
+
+    let mut writer = BufferWriter { buf, len: 0 };
+
+    if negative {
+        writer.write_char('-')?;
+    }
+
+    write!(writer, "{}{marker}", value / scale)?;
+
+    if fraction > 0 {
+        write!(writer, "{fraction:0digits$}")?;
+    }
+
+    let len = writer.len;
+    core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    #[cfg(any(
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    ))]
+    use test_case::test_case;
+
+    struct Padded(&'static str);
+
+    impl fmt::Display for Padded {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            write_padded(f, format_args!("{}", self.0))
+        }
+    }
+
+    #[test]
+    fn test_write_padded() {
+        assert_eq!(format!("{}", Padded("1.00 μA")), "1.00 μA");
+        assert_eq!(format!("{:10}", Padded("1.00 μA")), "   1.00 μA");
+        assert_eq!(format!("{:<10}", Padded("1.00 μA")), "1.00 μA   ");
+        assert_eq!(format!("{:^10}", Padded("1.00 μA")), " 1.00 μA  ");
+        assert_eq!(format!("{:*>10}", Padded("1.00 μA")), "***1.00 μA");
+        assert_eq!(format!("{:4}", Padded("1.00 μA")), "1.00 μA");
+    }
+
+    #[cfg(any(
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    ))]
+    #[test_case(false, 1_500, 1_000, 2, "1.50 mA"; "two decimal places")]
+    #[test_case(false, 1_505, 1_000, 2, "1.51 mA"; "rounds half up")]
+    #[test_case(false, 1_499, 1_000, 0, "1 mA"; "no decimal places")]
+    #[test_case(false, 1_500, 1_000, 5, "1.50000 mA"; "pads beyond resolution")]
+    #[test_case(false, 15, 1, 2, "15.00 μA"; "base unit")]
+    #[test_case(true, 3_300_000, 1_000_000, 1, "-3.3 V"; "negative")]
+    #[test_case(false, u64::MAX, 1, 0, "18446744073709551615 μA"; "maximum")]
+    fn test_format_into(negative: bool, value: u64, scale: u64, precision: usize, expected: &str) {
+        let mut buf = [0u8; 32];
+        let unit = expected.rsplit(' ').next().unwrap();
+
+        assert_eq!(
+            format_into(&mut buf, negative, value, scale, precision, unit),
+            Ok(expected)
+        );
+    }
+
+    #[cfg(any(
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    ))]
+    #[test]
+    fn test_format_into_buffer_too_small() {
+        let mut buf = [0u8; 6];
+        assert_eq!(
+            format_into(&mut buf, false, 1_500, 1_000, 2, "mA"),
+            Err(fmt::Error)
+        );
+    }
+
+    #[cfg(any(feature = "voltage", feature = "resistance"))]
+    #[test_case(false, 4_700, 1_000, 'k', "4k7"; "marker as decimal point")]
+    #[test_case(false, 10_000, 1_000, 'k', "10k"; "whole value")]
+    #[test_case(false, 470, 1_000, 'k', "0k47"; "fractional value")]
+    #[test_case(false, 4_705, 1_000, 'k', "4k705"; "leading fractional zero")]
+    #[test_case(false, 15, 1, 'R', "15R"; "base unit")]
+    #[test_case(true, 3_300, 1_000, 'V', "-3V3"; "negative")]
+    fn test_format_rkm(negative: bool, value: u64, scale: u64, marker: char, expected: &str) {
+        let mut buf = [0u8; 32];
+        assert_eq!(
+            format_rkm(&mut buf, negative, value, scale, marker),
+            Ok(expected)
+        );
+    }
+}
@@ -0,0 +1,213 @@
+#[cfg(feature = "current")]
+use crate::Current;
+#[cfg(feature = "power")]
+use crate::Power;
+use crate::{Resistance, Voltage};
+
+/// Represents a resistive voltage divider, with `r_top` between the input and the output and
+/// `r_bottom` between the output and ground.
+///
+/// The output voltage is `Vout = Vin × R2 / (R1 + R2)`, assuming no load on the output.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
+/// use ohms::prelude::*;
+///
+/// let divider = VoltageDivider::new(10.kilo_ohms(), 3_300.ohms());
+///
+/// let vout = divider.vout(12.volts()); // 2.977V
+/// let current = divider.current(12.volts()); // 902μA
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VoltageDivider {
+    r_top: Resistance,
+    r_bottom: Resistance,
+}
+
+impl VoltageDivider {
+    /// Creates a new `VoltageDivider` from its top and bottom resistors.
+    #[inline]
+    pub const fn new(r_top: Resistance, r_bottom: Resistance) -> Self {
+        Self { r_top, r_bottom }
+    }
+
+    /// Returns the top resistor, between the input and the output.
+    #[inline]
+    pub const fn r_top(&self) -> Resistance {
+        self.r_top
+    }
+
+    /// Returns the bottom resistor, between the output and ground.
+    #[inline]
+    pub const fn r_bottom(&self) -> Resistance {
+        self.r_bottom
+    }
+
+    /// Returns the combined resistance of both resistors, as seen by the input.
+    ///
+    /// Panics if the resistance would overflow.
+    pub fn total_resistance(&self) -> Resistance {
+        self.r_top
+            .checked_add(self.r_bottom)
+            .expect("Resistance would overflow")
+    }
+
+    /// Returns the ratio of the output to the input voltage, `R2 / (R1 + R2)`.
+    ///
+    /// Returns `NaN` if both resistors are zero.
+    pub fn ratio(&self) -> f64 {
+        let r_bottom = self.r_bottom.milli_ohms() as f64;
+        r_bottom / (self.r_top.milli_ohms() as f64 + r_bottom)
+    }
+
+    /// Calculates the unloaded output voltage for the given input voltage.
+    ///
+    /// Will be rounded towards zero to the nearest whole microvolt (μV).
+    /// Panics if both resistors are zero.
+    pub fn vout(&self, vin: Voltage) -> Voltage {
+        let total = self.total_milli_ohms();
+
+        if total == 0 {
+            panic!("Resistance cannot be zero, divider ratio is undefined");
+        }
+
+        let micro_volts = vin.micro_volts() as i128 * self.r_bottom.milli_ohms() as i128 / total;
+
+        // The output is never larger in magnitude than the input, so this cannot overflow
+        Voltage::from_micro_volts(micro_volts as i64)
+    }
+
+    /// Calculates the input voltage required to produce the given unloaded output voltage.
+    ///
+    /// Will be rounded towards zero to the nearest whole microvolt (μV).
+    /// Panics if the bottom resistor is zero or the voltage would overflow.
+    pub fn vin_for(&self, vout: Voltage) -> Voltage {
+        if self.r_bottom.is_zero() {
+            panic!("Resistance cannot be zero, output would always be zero");
+        }
+
+        let micro_volts = vout.micro_volts() as i128 * self.total_milli_ohms()
+            / self.r_bottom.milli_ohms() as i128;
+
+        Voltage::from_micro_volts(i64::try_from(micro_volts).expect("Voltage would overflow"))
+    }
+
+    /// Calculates the current through both resistors for the given input voltage.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Panics if both resistors are zero.
+    #[cfg(feature = "current")]
+    pub fn current(&self, vin: Voltage) -> Current {
+        vin / self.total_resistance()
+    }
+
+    /// Calculates the total power dissipated by both resistors for the given input voltage.
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW).
+    /// Panics if both resistors are zero.
+    #[cfg(feature = "power")]
+    pub fn power(&self, vin: Voltage) -> Power {
+        vin.power_across(self.total_resistance())
+    }
+
+    /// Calculates the power dissipated by the top resistor for the given input voltage.
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW).
+    /// Panics if both resistors are zero.
+    #[cfg(all(feature = "current", feature = "power"))]
+    pub fn power_top(&self, vin: Voltage) -> Power {
+        self.current(vin).power_through(self.r_top)
+    }
+
+    /// Calculates the power dissipated by the bottom resistor for the given input voltage.
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW).
+    /// Panics if both resistors are zero.
+    #[cfg(all(feature = "current", feature = "power"))]
+    pub fn power_bottom(&self, vin: Voltage) -> Power {
+        self.current(vin).power_through(self.r_bottom)
+    }
+
+    fn total_milli_ohms(&self) -> i128 {
+        self.r_top.milli_ohms() as i128 + self.r_bottom.milli_ohms() as i128
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn divider(r_top_milli_ohms: u64, r_bottom_milli_ohms: u64) -> VoltageDivider {
+        VoltageDivider::new(
+            Resistance::from_milli_ohms(r_top_milli_ohms),
+            Resistance::from_milli_ohms(r_bottom_milli_ohms),
+        )
+    }
+
+    #[test_case(10_000_000, 10_000_000, 0.5; "equal resistors halve")]
+    #[test_case(30_000_000, 10_000_000, 0.25; "3:1 quarters")]
+    #[test_case(0, 10_000_000, 1.0; "zero top passes through")]
+    #[test_case(10_000_000, 0, 0.0; "zero bottom grounds output")]
+    fn test_ratio(r_top: u64, r_bottom: u64, expected: f64) {
+        assert_eq!(divider(r_top, r_bottom).ratio(), expected);
+    }
+
+    #[test_case(10_000_000, 10_000_000, 5_000_000, 2_500_000; "5V, 10kΩ, 10kΩ equals 2.5V")]
+    #[test_case(10_000_000, 3_300_000, 12_000_000, 2_977_443; "12V, 10kΩ, 3.3kΩ equals 2.977V")]
+    #[test_case(10_000_000, 10_000_000, -5_000_000, -2_500_000; "negative input")]
+    #[test_case(1, 1, i64::MAX, i64::MAX / 2; "maximum input")]
+    #[test_case(u64::MAX, u64::MAX, 2_000_000, 1_000_000; "maximum resistance")]
+    fn test_vout(r_top: u64, r_bottom: u64, vin: i64, expected: i64) {
+        let vout = divider(r_top, r_bottom).vout(Voltage::from_micro_volts(vin));
+        assert_eq!(vout.micro_volts(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vout_zero_resistance_panics() {
+        divider(0, 0).vout(Voltage::from_micro_volts(5_000_000));
+    }
+
+    #[test_case(10_000_000, 10_000_000, 2_500_000, 5_000_000; "2.5V, 10kΩ, 10kΩ equals 5V")]
+    #[test_case(100_000_000, 22_000_000, 1_250_000, 6_931_818; "1.25V, 100kΩ, 22kΩ equals 6.93V")]
+    #[test_case(10_000_000, 10_000_000, -2_500_000, -5_000_000; "negative output")]
+    fn test_vin_for(r_top: u64, r_bottom: u64, vout: i64, expected: i64) {
+        let vin = divider(r_top, r_bottom).vin_for(Voltage::from_micro_volts(vout));
+        assert_eq!(vin.micro_volts(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vin_for_zero_bottom_panics() {
+        divider(10_000_000, 0).vin_for(Voltage::from_micro_volts(1_000_000));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vin_for_overflow_panics() {
+        divider(1_000, 1).vin_for(Voltage::from_micro_volts(i64::MAX));
+    }
+
+    #[test]
+    #[cfg(feature = "current")]
+    fn test_current() {
+        let current = divider(10_000_000, 3_300_000).current(Voltage::from_micro_volts(12_000_000));
+        assert_eq!(current.micro_amps(), 902);
+    }
+
+    #[test]
+    #[cfg(all(feature = "current", feature = "power"))]
+    fn test_power() {
+        let divider = divider(30_000, 10_000);
+        let vin = Voltage::from_micro_volts(4_000_000);
+
+        // 4V across 40Ω is 100mA, dissipating 300mW in 30Ω and 100mW in 10Ω
+        assert_eq!(divider.power(vin).micro_watts(), 400_000);
+        assert_eq!(divider.power_top(vin).micro_watts(), 300_000);
+        assert_eq!(divider.power_bottom(vin).micro_watts(), 100_000);
+    }
+}
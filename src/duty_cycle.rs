@@ -0,0 +1,313 @@
+use crate::display;
+use crate::UnitError;
+#[cfg(feature = "voltage")]
+use crate::Voltage;
+use core::fmt;
+#[cfg(feature = "voltage")]
+use core::ops;
+
+/// The number of milli-percent in a duty cycle of 100%.
+const FULL_MILLI_PERCENT: u32 = 100_000;
+
+/// Represents the duty cycle of a PWM signal, stored as whole milli-percent (m%) between 0% and
+/// 100%.
+///
+/// This is the fraction of each period that the signal is high. Multiplying a `Voltage` by a
+/// `DutyCycle` gives the average output voltage, such as when filtering a PWM pin as a DAC.
+///
+/// ```rust
+/// # #[cfg(feature = "voltage")] {
+/// use ohms::prelude::*;
+///
+/// let duty = DutyCycle::from_milli_percent(25_000).unwrap(); // 25%
+///
+/// assert_eq!(3300.milli_volts() * duty, 825.milli_volts());
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "DutyCycleData"))]
+pub struct DutyCycle {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_percent"))]
+    raw: u32,
+}
+
+/// The serialized form of a `DutyCycle`, which is checked to be no more than 100% when
+/// deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "DutyCycle")]
+struct DutyCycleData {
+    milli_percent: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<DutyCycleData> for DutyCycle {
+    type Error = UnitError;
+
+    fn try_from(data: DutyCycleData) -> Result<Self, Self::Error> {
+        Self::from_milli_percent(data.milli_percent).ok_or(UnitError::Overflow)
+    }
+}
+
+impl DutyCycle {
+    /// Creates a new `DutyCycle` from a number of whole milli-percent (m%).
+    ///
+    /// Returns `None` if the value is greater than 100% (100,000).
+    #[inline]
+    pub const fn from_milli_percent(value: u32) -> Option<Self> {
+        if value > FULL_MILLI_PERCENT {
+            None
+        } else {
+            Some(Self { raw: value })
+        }
+    }
+
+    /// Creates a new `DutyCycle` from a fractional percentage between 0% and 100%.
+    ///
+    /// Will be rounded down to the nearest whole milli-percent (m%).
+    /// Returns an error if the value is NaN, infinite, negative or greater than 100%.
+    pub fn try_from_percent(value: f64) -> Result<Self, UnitError> {
+        if value.is_nan() {
+            return Err(UnitError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(UnitError::Infinite);
+        }
+        if value < 0f64 {
+            return Err(UnitError::Negative);
+        }
+        if value > 100f64 {
+            return Err(UnitError::Overflow);
+        }
+
+        Ok(Self {
+            raw: (value * 1_000f64) as u32,
+        })
+    }
+
+    /// Creates a new `DutyCycle` that produces the target average voltage from a PWM signal
+    /// switching the supply voltage (`D = Vtarget ÷ Vsupply`).
+    ///
+    /// The magnitudes of both voltages are used. Will be rounded to the nearest whole
+    /// milli-percent (m%). Returns `None` if the supply voltage is zero or the target is greater
+    /// than the supply.
+    #[cfg(feature = "voltage")]
+    pub fn from_voltages(target: Voltage, supply: Voltage) -> Option<Self> {
+        let target = target.micro_volts().unsigned_abs() as u128;
+        let supply = supply.micro_volts().unsigned_abs() as u128;
+
+        if supply == 0 || target > supply {
+            return None;
+        }
+
+        let raw = (target * FULL_MILLI_PERCENT as u128 + supply / 2) / supply;
+
+        Self::from_milli_percent(raw as u32)
+    }
+
+    /// Returns the duty cycle in whole milli-percent (m%).
+    #[inline]
+    pub const fn milli_percent(&self) -> u32 {
+        self.raw
+    }
+
+    /// Returns the duty cycle as a fractional percentage between 0% and 100%.
+    #[inline]
+    pub fn percent(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the duty cycle as a fractional ratio between zero and one.
+    #[inline]
+    pub fn ratio(&self) -> f64 {
+        self.raw as f64 / FULL_MILLI_PERCENT as f64
+    }
+
+    /// Returns the complementary duty cycle, which is the fraction of each period that the signal
+    /// is low.
+    #[inline]
+    pub const fn inverted(&self) -> Self {
+        Self {
+            raw: FULL_MILLI_PERCENT - self.raw,
+        }
+    }
+
+    /// Returns whether the signal is always low.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns whether the signal is always high.
+    #[inline]
+    pub const fn is_full(&self) -> bool {
+        self.raw == FULL_MILLI_PERCENT
+    }
+
+    /// Returns a `DutyCycle` value of 0%.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self { raw: 0 }
+    }
+
+    /// Returns a `DutyCycle` value of 100%.
+    #[inline]
+    pub const fn full() -> Self {
+        Self {
+            raw: FULL_MILLI_PERCENT,
+        }
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl ops::Mul<DutyCycle> for Voltage {
+    type Output = Voltage;
+
+    /// Calculates the average voltage of a PWM signal switching this voltage with the duty cycle.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt (μV). The result is never larger
+    /// than the voltage, so it cannot overflow.
+    fn mul(self, duty: DutyCycle) -> Voltage {
+        let micro_volts =
+            self.micro_volts() as i128 * duty.raw as i128 / FULL_MILLI_PERCENT as i128;
+
+        Voltage::from_micro_volts(micro_volts as i64)
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl ops::Mul<Voltage> for DutyCycle {
+    type Output = Voltage;
+
+    /// Calculates the average voltage of a PWM signal switching the voltage with this duty cycle.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt (μV). The result is never larger
+    /// than the voltage, so it cannot overflow.
+    fn mul(self, voltage: Voltage) -> Voltage {
+        voltage * self
+    }
+}
+
+impl fmt::Display for DutyCycle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(
+            f,
+            format_args!("{}.{:03}%", self.raw / 1_000, self.raw % 1_000),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(0, Some(0); "zero")]
+    #[test_case(100_000, Some(100_000); "full")]
+    #[test_case(100_001, None; "over full")]
+    fn test_from_milli_percent(value: u32, expected: Option<u32>) {
+        let duty = DutyCycle::from_milli_percent(value);
+        assert_eq!(duty.map(|d| d.milli_percent()), expected);
+    }
+
+    #[test_case(37.5, Ok(37_500); "fraction")]
+    #[test_case(100.0, Ok(100_000); "full")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(100.1, Err(UnitError::Overflow); "over full")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    fn test_try_from_percent(value: f64, expected: Result<u32, UnitError>) {
+        let duty = DutyCycle::try_from_percent(value).map(|d| d.milli_percent());
+        assert_eq!(duty, expected);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let duty = DutyCycle::from_milli_percent(25_000).unwrap();
+
+        assert_eq!(duty.percent(), 25.0);
+        assert_eq!(duty.ratio(), 0.25);
+        assert_eq!(duty.inverted().milli_percent(), 75_000);
+        assert!(DutyCycle::zero().is_zero());
+        assert!(DutyCycle::full().is_full());
+        assert_eq!(DutyCycle::default(), DutyCycle::zero());
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(3_300_000, 50_000, 1_650_000; "half of 3.3V")]
+    #[test_case(5_000_000, 33_333, 1_666_650; "third of 5V")]
+    #[test_case(-12_000_000, 25_000, -3_000_000; "negative voltage")]
+    #[test_case(i64::MAX, 100_000, i64::MAX; "maximum")]
+    #[test_case(i64::MIN, 100_000, i64::MIN; "minimum")]
+    fn test_mul_voltage(micro_volts: i64, milli_percent: u32, expected: i64) {
+        let voltage = Voltage::from_micro_volts(micro_volts);
+        let duty = DutyCycle::from_milli_percent(milli_percent).unwrap();
+
+        assert_eq!((voltage * duty).micro_volts(), expected);
+        assert_eq!((duty * voltage).micro_volts(), expected);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(1_650_000, 3_300_000, Some(50_000); "half")]
+    #[test_case(1_000_000, 3_000_000, Some(33_333); "rounds down")]
+    #[test_case(2_000_000, 3_000_000, Some(66_667); "rounds up")]
+    #[test_case(-5_000_000, 5_000_000, Some(100_000); "uses magnitude")]
+    #[test_case(1, 0, None; "zero supply")]
+    #[test_case(5_000_001, 5_000_000, None; "target above supply")]
+    fn test_from_voltages(target: i64, supply: i64, expected: Option<u32>) {
+        let duty = DutyCycle::from_voltages(
+            Voltage::from_micro_volts(target),
+            Voltage::from_micro_volts(supply),
+        );
+
+        assert_eq!(duty.map(|d| d.milli_percent()), expected);
+    }
+
+    #[test_case(37_500, "37.500%"; "fraction")]
+    #[test_case(100_000, "100.000%"; "full")]
+    #[test_case(0, "0.000%"; "zero")]
+    fn test_display(milli_percent: u32, expected: &str) {
+        let duty = DutyCycle::from_milli_percent(milli_percent).unwrap();
+        assert_eq!(format!("{duty}"), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &DutyCycle::from_milli_percent(25_000).unwrap(),
+            &[
+                Token::Struct {
+                    name: "DutyCycle",
+                    len: 1,
+                },
+                Token::Str("milli_percent"),
+                Token::U32(25_000),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_over_full() {
+        use serde_test::{assert_de_tokens_error, Token};
+
+        assert_de_tokens_error::<DutyCycle>(
+            &[
+                Token::Struct {
+                    name: "DutyCycle",
+                    len: 1,
+                },
+                Token::Str("milli_percent"),
+                Token::U32(100_001),
+                Token::StructEnd,
+            ],
+            "value is too large",
+        );
+    }
+}
@@ -0,0 +1,692 @@
+use crate::approx;
+use crate::compare;
+use crate::math::{f64_to_u64, try_scale_f64_to_u64};
+use crate::parse::{parse_si_unsigned, ParseError};
+use crate::UnitError;
+use core::{cmp, hash, ops, str};
+
+/// Represents an energy value, stored as whole nanojoules (nJ) as a 64-bit value.
+/// This value can only be positive.
+///
+/// **Reminder:** `1000 nJ = 1 μJ, 1000 μJ = 1 mJ, 1000 mJ = 1 J, 1000 J = 1 kJ, 3600 J = 1 Wh, 1000 Wh = 1 kWh`
+///
+/// This is an immutable type. Any math operators return a new `Energy` value.
+///
+/// # Creating an Energy value
+/// You can create an `Energy` value using the `from_nano_joules` method, or using one of the
+/// extension methods on integer and floating-point types:
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let e1 = Energy::from_nano_joules(1500); // 1.5μJ
+///
+/// // More ergonomic:
+/// let e2 = 250.milli_joules(); // 0.25J
+/// let e3 = 1.5.watt_hours(); // 1.5Wh
+/// ```
+///
+/// # Comparing Energy values
+/// You can compare two `Energy` values using the `==`, `!=`, `<`, `>`, `<=` and `>=` operators.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let e1 = 200.milli_joules(); // 200mJ
+/// let e2 = 1.5.joules(); // 1.5J
+///
+/// if e1 > e2 {
+///     println!("{} is greater than {}", e1, e2);
+/// } else {
+///     println!("{} is less than or equal to {}", e1, e2);
+/// }
+/// ```
+///
+/// # Combining Energy values
+/// You can use the `+` and `-` operators to add and subtract `Energy` values from each other.
+/// The result is a new `Energy` value, rounded down to the nearest whole nanojoule (nJ).
+///
+/// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let e1 = 500.milli_joules(); // 0.5J
+/// let e2 = 1.1.joules(); // 1.1J
+///
+/// let sum = e1 + e2; // 1.6J
+/// let diff = e2 - 300.milli_joules(); // 0.8J
+/// ```
+///
+/// # Scaling Energy values
+/// You can use the `*` and `/` operators to scale `Energy` values by an integer or floating-point value.
+/// The result is a new `Energy` value, rounded down to the nearest whole nanojoule (nJ).
+///
+/// If the result of operation would overflow or underflow, the operation will panic.
+///
+/// If the result of the operation would be infinite or NaN, the operation will panic.
+///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let e1 = 200.milli_joules(); // 200mJ
+/// let e2 = e1 * 3; // 600mJ
+///
+/// let e3 = 1.5.joules(); // 1.5J
+/// let e4 = e3 / 2.5; // 0.6J
+/// ```
+///
+/// # Converting to other denominations
+/// You can use the `nano_joules`, `micro_joules`, `milli_joules`, `joules`, `kilo_joules`,
+/// `milli_watt_hours`, `watt_hours`, and `kilo_watt_hours` methods to convert an `Energy` value to
+/// a numeric value in the specified denomination.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let e1 = 7.2.kilo_joules(); // 7.2kJ
+///
+/// println!("{:.1} kJ is {:.1} Wh", e1.kilo_joules(), e1.watt_hours());
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Energy {
+    #[cfg_attr(feature = "serde", serde(rename = "nano_joules"))]
+    raw: u64,
+}
+
+impl Energy {
+    /// Creates a new `Energy` from a number of whole nanojoules (nJ).
+    ///
+    /// It is recommended to use the `nano_joules`, `micro_joules`, `milli_joules`, `joules`,
+    /// `kilo_joules`, `milli_watt_hours`, `watt_hours`, and `kilo_watt_hours` extension methods on
+    /// integer and floating-point types instead.
+    #[inline]
+    pub const fn from_nano_joules(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Energy` from a number of fractional nanojoules (nJ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_nano_joules(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_nano_joules)
+    }
+
+    /// Creates a new `Energy` from a number of fractional microjoules (μJ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_joules(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_nano_joules)
+    }
+
+    /// Creates a new `Energy` from a number of fractional millijoules (mJ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_joules(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_nano_joules)
+    }
+
+    /// Creates a new `Energy` from a number of fractional joules (J).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_joules(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000f64).map(Self::from_nano_joules)
+    }
+
+    /// Creates a new `Energy` from a number of fractional kilojoules (kJ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_joules(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000_000f64).map(Self::from_nano_joules)
+    }
+
+    /// Creates a new `Energy` from a number of fractional milliwatt-hours (mWh).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_watt_hours(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 3_600_000_000f64).map(Self::from_nano_joules)
+    }
+
+    /// Creates a new `Energy` from a number of fractional watt-hours (Wh).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_watt_hours(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 3_600_000_000_000f64).map(Self::from_nano_joules)
+    }
+
+    /// Creates a new `Energy` from a number of fractional kilowatt-hours (kWh).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_watt_hours(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 3_600_000_000_000_000f64).map(Self::from_nano_joules)
+    }
+
+    /// Returns the energy value in whole nanojoules (nJ).
+    #[inline]
+    pub const fn nano_joules(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the energy value in fractional microjoules (μJ).
+    #[inline]
+    pub fn micro_joules(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the energy value in fractional millijoules (mJ).
+    #[inline]
+    pub fn milli_joules(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Returns the energy value in fractional joules (J).
+    #[inline]
+    pub fn joules(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000f64
+    }
+
+    /// Returns the energy value in fractional kilojoules (kJ).
+    #[inline]
+    pub fn kilo_joules(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_000f64
+    }
+
+    /// Returns the energy value in fractional milliwatt-hours (mWh).
+    #[inline]
+    pub fn milli_watt_hours(&self) -> f64 {
+        self.raw as f64 / 3_600_000_000f64
+    }
+
+    /// Returns the energy value in fractional watt-hours (Wh).
+    #[inline]
+    pub fn watt_hours(&self) -> f64 {
+        self.raw as f64 / 3_600_000_000_000f64
+    }
+
+    /// Returns the energy value in fractional kilowatt-hours (kWh).
+    #[inline]
+    pub fn kilo_watt_hours(&self) -> f64 {
+        self.raw as f64 / 3_600_000_000_000_000f64
+    }
+
+    /// Returns whether the energy value is zero joules (0J).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns an `Energy` value of zero joules (0J).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_nano_joules(0)
+    }
+
+    /// Adds two `Energy` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_nano_joules)
+    }
+
+    /// Subtracts one `Energy` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_nano_joules)
+    }
+
+    /// Multiplies the `Energy` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_nano_joules)
+    }
+
+    /// Divides the `Energy` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_nano_joules)
+    }
+
+    /// Multiplies the `Energy` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole nanojoule (nJ).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_nano_joules)
+    }
+
+    /// Divides the `Energy` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole nanojoule (nJ).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_nano_joules)
+    }
+
+    /// Returns the unit symbol used when displaying the energy value, such as `"μJ"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "nJ"),
+            1_000..=999_999 => (1_000, "μJ"),
+            1_000_000..=999_999_999 => (1_000_000, "mJ"),
+            1_000_000_000..=999_999_999_999 => (1_000_000_000, "J"),
+            _ => (1_000_000_000_000, "kJ"),
+        }
+    }
+}
+
+approx::impl_approx_eq!(Energy, unsigned);
+compare::impl_const_compare!(Energy, unsigned);
+
+impl PartialEq for Energy {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Energy {}
+
+impl PartialOrd for Energy {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Energy {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for Energy {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for Energy {
+    type Output = Self;
+
+    /// Adds two `Energy` values together, returning a new `Energy` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_nano_joules)
+            .expect("Overflow when adding energy values")
+    }
+}
+
+impl ops::Sub for Energy {
+    type Output = Self;
+
+    /// Subtracts one `Energy` value from another, returning a new `Energy` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_nano_joules)
+            .expect("Overflow when subtracting energy values")
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i:ty) => {
+        impl ops::Mul<$i> for Energy {
+            type Output = Self;
+
+            /// Multiplies an `Energy` value by an integer value, returning a new `Energy` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn mul(self, scale_factor: $i) -> Self {
+                if scale_factor < 0 {
+                    panic!("Cannot multiply energy value by negative value")
+                }
+                self.raw
+                    .checked_mul(scale_factor as u64)
+                    .map(Self::from_nano_joules)
+                    .expect("Overflow when multiplying energy value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for Energy {
+    type Output = Self;
+
+    /// Multiplies an `Energy` value by a floating-point value, returning a new `Energy` value.
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for Energy {
+    type Output = Self;
+
+    /// Multiplies an `Energy` value by a floating-point value, returning a new `Energy` value.
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply energy value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply energy value by NaN"),
+            _ if scale_factor.is_sign_negative() => {
+                panic!("Cannot multiply energy value by negative value")
+            }
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_nano_joules(result as u64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i:ty) => {
+        impl ops::Div<$i> for Energy {
+            type Output = Self;
+
+            /// Divides an `Energy` value by an integer value, returning a new `Energy` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide energy value by zero");
+                } else if divisor < 0 {
+                    panic!("Cannot divide energy value by negative value");
+                }
+                self.raw
+                    .checked_div(divisor as u64)
+                    .map(Self::from_nano_joules)
+                    .expect("Overflow when dividing energy value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for Energy {
+    type Output = Self;
+
+    /// Divides an `Energy` value by a floating-point value, returning a new `Energy` value.
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for Energy {
+    type Output = Self;
+
+    /// Divides an `Energy` value by a floating-point value, returning a new `Energy` value.
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide energy value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide energy value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide energy value by NaN"),
+            _ if divisor.is_sign_negative() => {
+                panic!("Cannot divide energy value by negative value")
+            }
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_nano_joules(result as u64)
+    }
+}
+
+/// Extension trait for simple short-hands for creating `Energy` values from integer values.
+pub trait FromInteger {
+    /// Creates a new `Energy` from a number of whole nanojoules (nJ).
+    fn nano_joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of whole microjoules (μJ).
+    fn micro_joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of whole millijoules (mJ).
+    fn milli_joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of whole joules (J).
+    fn joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of whole kilojoules (kJ).
+    fn kilo_joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of whole milliwatt-hours (mWh).
+    fn milli_watt_hours(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of whole watt-hours (Wh).
+    fn watt_hours(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of whole kilowatt-hours (kWh).
+    fn kilo_watt_hours(self) -> Energy;
+}
+
+crate::__define_unit_from_integer!(
+    Energy,
+    FromInteger,
+    from_nano_joules,
+    nano_joules,
+    [
+        micro_joules = 1_000,
+        milli_joules = 1_000_000,
+        joules = 1_000_000_000,
+        kilo_joules = 1_000_000_000_000,
+        milli_watt_hours = 3_600_000_000,
+        watt_hours = 3_600_000_000_000,
+        kilo_watt_hours = 3_600_000_000_000_000
+    ],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
+
+/// Extension trait for simple short-hands for creating `Energy` values from floating-point values.
+pub trait FromFloat {
+    /// Creates a new `Energy` from a number of fractional nanojoules (nJ).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanojoule (nJ).
+    fn nano_joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of fractional microjoules (μJ).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanojoule (nJ).
+    fn micro_joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of fractional millijoules (mJ).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanojoule (nJ).
+    fn milli_joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of fractional joules (J).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanojoule (nJ).
+    fn joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of fractional kilojoules (kJ).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanojoule (nJ).
+    fn kilo_joules(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of fractional milliwatt-hours (mWh).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanojoule (nJ).
+    fn milli_watt_hours(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of fractional watt-hours (Wh).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanojoule (nJ).
+    fn watt_hours(self) -> Energy;
+
+    /// Creates a new `Energy` from a number of fractional kilowatt-hours (kWh).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanojoule (nJ).
+    fn kilo_watt_hours(self) -> Energy;
+}
+
+crate::__define_unit_from_float!(
+    Energy,
+    FromFloat,
+    from_nano_joules,
+    nano_joules,
+    [
+        micro_joules = 1_000,
+        milli_joules = 1_000_000,
+        joules = 1_000_000_000,
+        kilo_joules = 1_000_000_000_000,
+        milli_watt_hours = 3_600_000_000,
+        watt_hours = 3_600_000_000_000,
+        kilo_watt_hours = 3_600_000_000_000_000
+    ],
+    f32,
+    f64
+);
+
+crate::__define_unit_display!(Energy);
+
+impl str::FromStr for Energy {
+    type Err = ParseError;
+
+    /// Parses a `Energy` value from a string with an optional SI prefix and unit symbol, such as
+    /// `1.5kJ`, `250 mJ` or `10`.
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["J"], 9).map(Self::from_nano_joules)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(1.watt_hours(), 3_600_000_000_000; "1Wh equals 3,600J")]
+    #[test_case(1.milli_watt_hours(), 3_600_000_000; "1mWh equals 3.6J")]
+    #[test_case(1.kilo_watt_hours(), 3_600_000_000_000_000; "1kWh equals 3.6MJ")]
+    #[test_case(0.5.kilo_watt_hours(), 1_800_000_000_000_000; "0.5kWh")]
+    #[test_case(2.5.kilo_joules(), 2_500_000_000_000; "2.5kJ")]
+    #[test_case(250.micro_joules(), 250_000; "250μJ")]
+    fn test_extension_methods(energy: Energy, expected_nano_joules: u64) {
+        assert_eq!(energy.nano_joules(), expected_nano_joules);
+    }
+
+    #[test_case(250, "250.00 nJ"; "nanojoules")]
+    #[test_case(1_500, "1.50 μJ"; "microjoules")]
+    #[test_case(1_500_000, "1.50 mJ"; "millijoules")]
+    #[test_case(1_500_000_000, "1.50 J"; "joules")]
+    #[test_case(3_600_000_000_000, "3.60 kJ"; "kilojoules")]
+    fn test_display(nano_joules: u64, expected: &str) {
+        let energy = Energy::from_nano_joules(nano_joules);
+        assert_eq!(format!("{energy}"), expected);
+    }
+
+    #[test_case("1.5kJ", Ok(1_500_000_000_000); "kilo prefix")]
+    #[test_case("250 mJ", Ok(250_000_000); "milli prefix with space")]
+    #[test_case("10", Ok(10_000_000_000); "joules without symbol")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Energy>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Energy::from_nano_joules(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(Energy::try_from_micro_joules(1.5), Ok(Energy::from_nano_joules(1_500)); "valid value")]
+    #[test_case(Energy::try_from_micro_joules(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Energy::try_from_micro_joules(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Energy::try_from_micro_joules(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Energy::try_from_micro_joules(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(result: Result<Energy, UnitError>, expected: Result<Energy, UnitError>) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Energy::from_nano_joules(3600000000000),
+            &[
+                Token::Struct {
+                    name: "Energy",
+                    len: 1,
+                },
+                Token::Str("nano_joules"),
+                Token::U64(3600000000000),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,30 @@
+use core::fmt;
+
+/// Error returned when creating a unit value from a number fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum UnitError {
+    /// The value was NaN.
+    NaN,
+    /// The value was infinite.
+    Infinite,
+    /// The value was negative, but the unit can only be positive.
+    Negative,
+    /// The value is too large to be represented.
+    Overflow,
+    /// The value is not a whole number of the unit's stored resolution.
+    Inexact,
+}
+
+impl fmt::Display for UnitError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            UnitError::NaN => "value is NaN",
+            UnitError::Infinite => "value is infinite",
+            UnitError::Negative => "value is negative",
+            UnitError::Overflow => "value is too large",
+            UnitError::Inexact => "value is not a whole number of the stored unit",
+        };
+
+        f.write_str(message)
+    }
+}
@@ -0,0 +1,409 @@
+//! E-series of preferred values, as defined by IEC 60063.
+//!
+//! Each series divides a decade into a fixed number of logarithmically spaced values, so that
+//! components with a given tolerance cover the whole range without gaps. Values are stored as
+//! three significant digits, such as `470` for 47Ω, 470Ω or 4.7kΩ.
+//!
+//! ```rust
+//! use ohms::eseries::ESeries;
+//! use ohms::prelude::*;
+//!
+//! let r = 5.kilo_ohms().nearest_standard(ESeries::E12, RoundingMode::Nearest);
+//! assert_eq!(r, Some(4.7.kilo_ohms()));
+//!
+//! let values: Vec<_> = ESeries::E6.decade(3).collect(); // 1kΩ to 6.8kΩ
+//! assert_eq!(values[0], 1.kilo_ohms());
+//! ```
+use crate::math::f64_to_u64;
+use crate::Resistance;
+#[cfg(feature = "voltage")]
+use crate::{Voltage, VoltageDivider};
+
+const E6: [u16; 6] = [100, 150, 220, 330, 470, 680];
+
+const E12: [u16; 12] = [100, 120, 150, 180, 220, 270, 330, 390, 470, 560, 680, 820];
+
+const E24: [u16; 24] = [
+    100, 110, 120, 130, 150, 160, 180, 200, 220, 240, 270, 300, 330, 360, 390, 430, 470, 510, 560,
+    620, 680, 750, 820, 910,
+];
+
+const E48: [u16; 48] = [
+    100, 105, 110, 115, 121, 127, 133, 140, 147, 154, 162, 169, 178, 187, 196, 205, 215, 226, 237,
+    249, 261, 274, 287, 301, 316, 332, 348, 365, 383, 402, 422, 442, 464, 487, 511, 536, 562, 590,
+    619, 649, 681, 715, 750, 787, 825, 866, 909, 953,
+];
+
+const E96: [u16; 96] = [
+    100, 102, 105, 107, 110, 113, 115, 118, 121, 124, 127, 130, 133, 137, 140, 143, 147, 150, 154,
+    158, 162, 165, 169, 174, 178, 182, 187, 191, 196, 200, 205, 210, 215, 221, 226, 232, 237, 243,
+    249, 255, 261, 267, 274, 280, 287, 294, 301, 309, 316, 324, 332, 340, 348, 357, 365, 374, 383,
+    392, 402, 412, 422, 432, 442, 453, 464, 475, 487, 499, 511, 523, 536, 549, 562, 576, 590, 604,
+    619, 634, 649, 665, 681, 698, 715, 732, 750, 768, 787, 806, 825, 845, 866, 887, 909, 931, 953,
+    976,
+];
+
+/// The smallest decade that can be represented, starting at 0.1Ω.
+const MIN_DECADE: i32 = -1;
+
+/// The largest decade whose values can all be represented, starting at 1PΩ.
+const MAX_DECADE: i32 = 15;
+
+/// An E-series of preferred values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ESeries {
+    /// 6 values per decade, for ±20% tolerance.
+    E6,
+    /// 12 values per decade, for ±10% tolerance.
+    E12,
+    /// 24 values per decade, for ±5% tolerance.
+    E24,
+    /// 48 values per decade, for ±2% tolerance.
+    E48,
+    /// 96 values per decade, for ±1% tolerance.
+    E96,
+}
+
+impl ESeries {
+    /// Returns the values in a single decade as three significant digits, in ascending order
+    /// from `100`.
+    pub const fn values(self) -> &'static [u16] {
+        match self {
+            ESeries::E6 => &E6,
+            ESeries::E12 => &E12,
+            ESeries::E24 => &E24,
+            ESeries::E48 => &E48,
+            ESeries::E96 => &E96,
+        }
+    }
+
+    /// Returns an iterator over the values in the decade starting at `10^exponent` ohms, in
+    /// ascending order, such as 1kΩ to 9.76kΩ for an exponent of `3`.
+    ///
+    /// The iterator is empty if the exponent is outside the representable range of `-1` (0.1Ω)
+    /// to `15`.
+    pub fn decade(self, exponent: i32) -> impl Iterator<Item = Resistance> {
+        let (values, scale) = match exponent {
+            MIN_DECADE..=MAX_DECADE => (self.values(), 10u64.pow((exponent + 1) as u32)),
+            _ => (&[][..], 0),
+        };
+
+        values
+            .iter()
+            .map(move |&value| Resistance::from_milli_ohms(value as u64 * scale))
+    }
+
+    /// Returns an iterator over the values between `min` and `max` inclusive, in ascending
+    /// order.
+    pub fn range(self, min: Resistance, max: Resistance) -> impl Iterator<Item = Resistance> {
+        (MIN_DECADE..=MAX_DECADE)
+            .flat_map(move |exponent| self.decade(exponent))
+            .skip_while(move |value| *value < min)
+            .take_while(move |value| *value <= max)
+    }
+
+    /// Returns the closest values in the series below and above a value in milliohms, which are
+    /// the same if the value is in the series.
+    pub(crate) fn bounds(self, milli_ohms: u64) -> (Option<u64>, Option<u64>) {
+        let values = self.values();
+        let value = milli_ohms as u128;
+
+        // Finds the decade containing the value, starting from the smallest at 0.1Ω
+        let mut scale = 1u128;
+        while value >= 1_000 * scale {
+            scale *= 10;
+        }
+
+        let mut lower = match scale {
+            1 => None,
+            _ => values.last().map(|&last| last as u128 * scale / 10),
+        };
+
+        for &significand in values {
+            let candidate = significand as u128 * scale;
+
+            if candidate == value {
+                return (Some(milli_ohms), Some(milli_ohms));
+            }
+
+            if candidate > value {
+                return (
+                    lower.map(|lower| lower as u64),
+                    u64::try_from(candidate).ok(),
+                );
+            }
+
+            lower = Some(candidate);
+        }
+
+        let upper = values[0] as u128 * scale * 10;
+        (lower.map(|lower| lower as u64), u64::try_from(upper).ok())
+    }
+}
+
+/// A pair of resistors forming a voltage divider, found by [`find_divider`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DividerMatch {
+    r_top: Resistance,
+    r_bottom: Resistance,
+    error: f64,
+}
+
+impl DividerMatch {
+    /// Returns the top resistor, between the input and the output.
+    #[inline]
+    pub const fn r_top(&self) -> Resistance {
+        self.r_top
+    }
+
+    /// Returns the bottom resistor, between the output and ground.
+    #[inline]
+    pub const fn r_bottom(&self) -> Resistance {
+        self.r_bottom
+    }
+
+    /// Returns the ratio of the output to the input voltage, `R2 / (R1 + R2)`.
+    pub fn ratio(&self) -> f64 {
+        let r_bottom = self.r_bottom.milli_ohms() as f64;
+        r_bottom / (self.r_top.milli_ohms() as f64 + r_bottom)
+    }
+
+    /// Returns the relative error of the ratio from the target, such as `0.01` for 1% too high.
+    #[inline]
+    pub fn error(&self) -> f64 {
+        self.error
+    }
+
+    /// Returns the matched pair as a [`VoltageDivider`].
+    #[cfg(feature = "voltage")]
+    #[inline]
+    pub const fn divider(&self) -> VoltageDivider {
+        VoltageDivider::new(self.r_top, self.r_bottom)
+    }
+}
+
+/// Finds the pair of resistors from an E-series whose divider ratio `R2 / (R1 + R2)` best matches
+/// the target ratio.
+///
+/// Only pairs with a combined resistance between `min_total` and `max_total` are considered, and
+/// the relative error of the ratio must be no more than `max_error`, such as `0.01` for 1%.
+/// Returns `None` if the ratio is not between zero and one, or no pair meets the constraints.
+///
+/// ```rust
+/// use ohms::eseries::{find_divider, ESeries};
+/// use ohms::prelude::*;
+///
+/// let divider = find_divider(ESeries::E24, 0.5, 10.kilo_ohms(), 100.kilo_ohms(), 0.01).unwrap();
+/// assert_eq!(divider.r_top(), divider.r_bottom());
+/// ```
+pub fn find_divider(
+    series: ESeries,
+    ratio: f64,
+    min_total: Resistance,
+    max_total: Resistance,
+    max_error: f64,
+) -> Option<DividerMatch> {
+    if !(ratio > 0f64 && ratio < 1f64) {
+        return None;
+    }
+
+    let mut best: Option<DividerMatch> = None;
+
+    for r_bottom in series.range(Resistance::zero(), max_total) {
+        let ideal_top = r_bottom.milli_ohms() as f64 * (1f64 - ratio) / ratio;
+        let Some(ideal_top) = f64_to_u64(ideal_top) else {
+            continue;
+        };
+
+        let (lower, upper) = series.bounds(ideal_top);
+
+        for r_top in [lower, upper].into_iter().flatten() {
+            let r_top = Resistance::from_milli_ohms(r_top);
+            let total = r_top.milli_ohms().saturating_add(r_bottom.milli_ohms());
+
+            if total < min_total.milli_ohms() || total > max_total.milli_ohms() {
+                continue;
+            }
+
+            let mut candidate = DividerMatch {
+                r_top,
+                r_bottom,
+                error: 0f64,
+            };
+            candidate.error = (candidate.ratio() - ratio) / ratio;
+
+            if candidate.error.abs() > max_error {
+                continue;
+            }
+
+            match best {
+                Some(best) if best.error.abs() <= candidate.error.abs() => {}
+                _ => best = Some(candidate),
+            }
+        }
+    }
+
+    best
+}
+
+/// Finds the pair of resistors from an E-series that best divide the input voltage down to the
+/// target output voltage.
+///
+/// This is a shorthand for [`find_divider`] with a ratio of `vout / vin`.
+/// Returns `None` if the output is not between zero and the input, or no pair meets the
+/// constraints.
+#[cfg(feature = "voltage")]
+pub fn find_divider_for_voltage(
+    series: ESeries,
+    vin: Voltage,
+    vout: Voltage,
+    min_total: Resistance,
+    max_total: Resistance,
+    max_error: f64,
+) -> Option<DividerMatch> {
+    if vin.is_zero() {
+        return None;
+    }
+
+    let ratio = vout.micro_volts() as f64 / vin.micro_volts() as f64;
+    find_divider(series, ratio, min_total, max_total, max_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(ESeries::E6, 6)]
+    #[test_case(ESeries::E12, 12)]
+    #[test_case(ESeries::E24, 24)]
+    #[test_case(ESeries::E48, 48)]
+    #[test_case(ESeries::E96, 96)]
+    fn test_values(series: ESeries, expected_len: usize) {
+        let values = series.values();
+
+        assert_eq!(values.len(), expected_len);
+        assert_eq!(values[0], 100);
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(values.iter().all(|&value| value < 1_000));
+    }
+
+    #[test]
+    fn test_decade() {
+        let mut values = ESeries::E6.decade(3);
+
+        assert_eq!(values.next(), Some(Resistance::from_milli_ohms(1_000_000)));
+        assert_eq!(values.nth(4), Some(Resistance::from_milli_ohms(6_800_000)));
+        assert_eq!(values.next(), None);
+    }
+
+    #[test_case(-1, Some(100); "smallest decade")]
+    #[test_case(15, Some(1_000_000_000_000_000_000); "largest decade")]
+    #[test_case(-2, None; "below range")]
+    #[test_case(16, None; "above range")]
+    fn test_decade_first(exponent: i32, expected: Option<u128>) {
+        let first = ESeries::E12.decade(exponent).next();
+        assert_eq!(first.map(|r| r.milli_ohms() as u128), expected);
+    }
+
+    #[test]
+    fn test_range() {
+        let min = Resistance::from_milli_ohms(800_000);
+        let max = Resistance::from_milli_ohms(2_200_000);
+        let mut values = ESeries::E6.range(min, max);
+
+        assert_eq!(values.next(), Some(Resistance::from_milli_ohms(1_000_000)));
+        assert_eq!(values.next(), Some(Resistance::from_milli_ohms(1_500_000)));
+        assert_eq!(values.next(), Some(Resistance::from_milli_ohms(2_200_000)));
+        assert_eq!(values.next(), None);
+    }
+
+    #[test_case(4_700_000, (Some(4_700_000), Some(4_700_000)); "exact value")]
+    #[test_case(5_000_000, (Some(4_700_000), Some(5_600_000)); "between values")]
+    #[test_case(9_000_000, (Some(8_200_000), Some(10_000_000)); "next decade")]
+    #[test_case(50, (None, Some(100)); "below smallest value")]
+    #[test_case(u64::MAX, (Some(18_000_000_000_000_000_000), None); "above largest value")]
+    fn test_bounds(milli_ohms: u64, expected: (Option<u64>, Option<u64>)) {
+        assert_eq!(ESeries::E12.bounds(milli_ohms), expected);
+    }
+
+    #[test_case(0.5, 1_000_000, 1_000_000; "half")]
+    #[test_case(0.25, 3_000_000, 1_000_000; "quarter")]
+    #[test_case(0.2, 4_000_000, 1_000_000; "fifth")]
+    fn test_find_divider(ratio: f64, r_top: u64, r_bottom: u64) {
+        let divider = find_divider(
+            ESeries::E24,
+            ratio,
+            Resistance::from_milli_ohms(1_000_000),
+            Resistance::from_milli_ohms(100_000_000),
+            0.01,
+        )
+        .unwrap();
+
+        assert_eq!(
+            divider.r_top().milli_ohms() * r_bottom,
+            divider.r_bottom().milli_ohms() * r_top
+        );
+        assert_eq!(divider.error(), 0.0);
+    }
+
+    #[test]
+    fn test_find_divider_best_error() {
+        let min = Resistance::from_milli_ohms(10_000_000);
+        let max = Resistance::from_milli_ohms(100_000_000);
+        let divider = find_divider(ESeries::E96, 0.3, min, max, 1.0).unwrap();
+
+        // No other pair within the total resistance range is closer to the target ratio
+        for r_top in ESeries::E96.range(Resistance::zero(), max) {
+            for r_bottom in ESeries::E96.range(Resistance::zero(), max) {
+                let total = r_top.milli_ohms() + r_bottom.milli_ohms();
+
+                if total < min.milli_ohms() || total > max.milli_ohms() {
+                    continue;
+                }
+
+                let ratio = r_bottom.milli_ohms() as f64 / total as f64;
+                assert!((ratio - 0.3).abs() / 0.3 >= divider.error().abs());
+            }
+        }
+    }
+
+    #[test_case(0.0; "zero ratio")]
+    #[test_case(1.0; "unity ratio")]
+    #[test_case(f64::NAN; "NaN ratio")]
+    fn test_find_divider_invalid_ratio(ratio: f64) {
+        let min = Resistance::from_milli_ohms(1_000_000);
+        let max = Resistance::from_milli_ohms(100_000_000);
+
+        assert_eq!(find_divider(ESeries::E24, ratio, min, max, 1.0), None);
+    }
+
+    #[test]
+    fn test_find_divider_constraints() {
+        let min = Resistance::from_milli_ohms(1_200_000);
+        let max = Resistance::from_milli_ohms(1_300_000);
+
+        // The only pair within the total resistance range is 1kΩ and 220Ω
+        assert_eq!(find_divider(ESeries::E6, 0.5, min, max, 0.01), None);
+        assert!(find_divider(ESeries::E6, 0.18, min, max, 0.01).is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "voltage")]
+    fn test_find_divider_for_voltage() {
+        let divider = find_divider_for_voltage(
+            ESeries::E24,
+            Voltage::from_micro_volts(12_000_000),
+            Voltage::from_micro_volts(3_000_000),
+            Resistance::from_milli_ohms(1_000_000),
+            Resistance::from_milli_ohms(100_000_000),
+            0.01,
+        )
+        .unwrap();
+
+        assert_eq!(
+            divider.r_top().milli_ohms(),
+            divider.r_bottom().milli_ohms() * 3
+        );
+    }
+}
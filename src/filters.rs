@@ -0,0 +1,301 @@
+//! Streaming filters for smoothing noisy samples, such as voltage or current readings from an ADC.
+//!
+//! The filters work with any of the unit types and use integer math only, so they are cheap
+//! enough to run in an interrupt handler.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "voltage", feature = "current"))] {
+//! use ohms::filters::{ExponentialFilter, MovingAverage};
+//! use ohms::prelude::*;
+//!
+//! let mut ema = ExponentialFilter::new(2); // Each sample has a weight of 1/4
+//! ema.update(4.volts());
+//! assert_eq!(ema.update(0.volts()), 3.volts());
+//!
+//! let mut sma = MovingAverage::<Current, 4>::new();
+//! sma.update(1.amps());
+//! assert_eq!(sma.update(2.amps()), 1.5.amps());
+//! # }
+//! ```
+use crate::scalar::Scalar;
+
+/// The largest shift factor of an `ExponentialFilter`.
+const MAX_SHIFT: u8 = 32;
+
+/// A single-pole IIR low-pass filter, also known as an exponential moving average.
+///
+/// Each new sample is given a weight of `1 ÷ 2ⁿ`, where `n` is the shift factor, so the filter
+/// can be updated with a subtraction and a shift rather than a division. A larger shift factor
+/// gives a smoother but slower response, settling to within 1% of a step after about `4.6 × 2ⁿ`
+/// samples.
+///
+/// The filter keeps `n` extra bits of precision internally, so small changes are not lost to
+/// rounding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ExponentialFilter<T> {
+    shift: u8,
+    state: Option<i128>,
+    marker: core::marker::PhantomData<T>,
+}
+
+impl<T: Scalar> ExponentialFilter<T> {
+    /// Creates a new `ExponentialFilter` with the given shift factor.
+    ///
+    /// A shift factor of zero passes every sample through unchanged.
+    /// Panics if the shift factor is greater than 32.
+    pub const fn new(shift: u8) -> Self {
+        if shift > MAX_SHIFT {
+            panic!("Filter shift factor must be at most 32");
+        }
+
+        Self {
+            shift,
+            state: None,
+            marker: core::marker::PhantomData,
+        }
+    }
+
+    /// Returns the shift factor of the filter.
+    pub const fn shift(&self) -> u8 {
+        self.shift
+    }
+
+    /// Adds a sample to the filter, returning the new filtered value.
+    ///
+    /// The first sample after creating or resetting the filter is returned unchanged, so the
+    /// output does not have to settle from zero.
+    pub fn update(&mut self, sample: T) -> T {
+        let sample = sample.to_raw();
+
+        let state = match self.state {
+            Some(state) => state + sample - (state >> self.shift),
+            None => sample << self.shift,
+        };
+        self.state = Some(state);
+
+        Self::output(state, self.shift)
+    }
+
+    /// Returns the current filtered value, or `None` if no samples have been added.
+    pub fn value(&self) -> Option<T> {
+        self.state.map(|state| Self::output(state, self.shift))
+    }
+
+    /// Clears the filter, so the next sample is returned unchanged.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+
+    /// The output is always between the smallest and largest samples, so it can be represented.
+    fn output(state: i128, shift: u8) -> T {
+        T::from_raw(state >> shift).expect("Filtered value is within the range of the samples")
+    }
+}
+
+/// A simple moving average over the last `N` samples.
+///
+/// The samples are kept in a ring buffer and a running total is updated with each new sample, so
+/// the cost of an update does not depend on the size of the window. Only the running total is
+/// widened, so each slot takes no more space than a sample. Choosing a power of two for
+/// `N` allows the division to be compiled into a shift.
+///
+/// Until the window is full, the average is taken over the samples added so far.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct MovingAverage<T, const N: usize> {
+    samples: [T; N],
+    index: usize,
+    len: usize,
+    total: i128,
+}
+
+impl<T: Scalar, const N: usize> MovingAverage<T, N> {
+    /// Creates a new, empty `MovingAverage`.
+    ///
+    /// Panics if the window size `N` is zero.
+    pub const fn new() -> Self {
+        if N == 0 {
+            panic!("Moving average window size must not be zero");
+        }
+
+        Self {
+            samples: [T::ZERO; N],
+            index: 0,
+            len: 0,
+            total: 0,
+        }
+    }
+
+    /// Returns the number of samples in the window, which is at most `N`.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no samples have been added.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the window contains `N` samples.
+    pub const fn is_full(&self) -> bool {
+        self.len == N
+    }
+
+    /// Adds a sample to the window, replacing the oldest sample once it is full, and returns the
+    /// new average.
+    ///
+    /// Will be rounded toward zero to the nearest whole base unit of `T`.
+    pub fn update(&mut self, sample: T) -> T {
+        self.total += sample.to_raw() - self.samples[self.index].to_raw();
+        self.samples[self.index] = sample;
+        self.index = (self.index + 1) % N;
+        self.len = (self.len + 1).min(N);
+
+        self.average()
+    }
+
+    /// Returns the average of the samples in the window, or `None` if no samples have been added.
+    ///
+    /// Will be rounded toward zero to the nearest whole base unit of `T`.
+    pub fn value(&self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.average())
+        }
+    }
+
+    /// Removes all of the samples from the window.
+    pub fn reset(&mut self) {
+        *self = Self::new();
+    }
+
+    /// The average is always between the smallest and largest samples, so it can be represented.
+    fn average(&self) -> T {
+        T::from_raw(self.total / self.len as i128)
+            .expect("Average is within the range of the samples")
+    }
+}
+
+impl<T: Scalar, const N: usize> Default for MovingAverage<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Frequency, Temperature};
+    use test_case::test_case;
+
+    #[test_case(0, &[5, 100, -20], 5, -20; "passes through with zero shift")]
+    #[test_case(1, &[1_000, 0, 0], 1_000, 250; "halves each step")]
+    #[test_case(2, &[4_000, 0], 4_000, 3_000; "quarter weight")]
+    #[test_case(2, &[-4_000, 0], -4_000, -3_000; "negative samples")]
+    fn test_exponential_filter(shift: u8, samples: &[i64], first: i64, last: i64) {
+        let mut filter = ExponentialFilter::new(shift);
+        let mut outputs = samples
+            .iter()
+            .map(|&sample| filter.update(Temperature::from_milli_degrees_celsius(sample)));
+
+        assert_eq!(outputs.next().unwrap().milli_degrees_celsius(), first);
+        assert_eq!(outputs.last().unwrap().milli_degrees_celsius(), last);
+    }
+
+    #[test]
+    fn test_exponential_filter_keeps_precision() {
+        let mut filter = ExponentialFilter::new(4);
+        filter.update(Frequency::from_milli_hertz(0));
+
+        // Each step is smaller than one base unit, but the filter still converges
+        for _ in 0..200 {
+            filter.update(Frequency::from_milli_hertz(10));
+        }
+
+        assert_eq!(filter.value(), Some(Frequency::from_milli_hertz(10)));
+    }
+
+    #[test]
+    fn test_exponential_filter_reset() {
+        let mut filter = ExponentialFilter::new(3);
+        assert_eq!(filter.value(), None);
+
+        filter.update(Frequency::from_milli_hertz(100));
+        filter.reset();
+
+        assert_eq!(filter.value(), None);
+        assert_eq!(
+            filter.update(Frequency::from_milli_hertz(7)),
+            Frequency::from_milli_hertz(7)
+        );
+    }
+
+    #[test]
+    fn test_exponential_filter_extremes() {
+        let mut filter = ExponentialFilter::new(32);
+        filter.update(Frequency::from_milli_hertz(u64::MAX));
+
+        assert_eq!(
+            filter.update(Frequency::from_milli_hertz(u64::MAX)),
+            Frequency::from_milli_hertz(u64::MAX)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_exponential_filter_shift_too_large() {
+        ExponentialFilter::<Frequency>::new(33);
+    }
+
+    #[test_case(&[10], 10; "single sample")]
+    #[test_case(&[10, 20], 15; "partial window")]
+    #[test_case(&[10, 20, 30], 20; "full window")]
+    #[test_case(&[10, 20, 30, 70], 40; "oldest sample replaced")]
+    #[test_case(&[-10, -5, -5], -6; "rounds toward zero")]
+    fn test_moving_average(samples: &[i64], expected: i64) {
+        let mut average = MovingAverage::<Temperature, 3>::new();
+        let mut last = Temperature::zero();
+
+        for &sample in samples {
+            last = average.update(Temperature::from_milli_degrees_celsius(sample));
+        }
+
+        assert_eq!(last.milli_degrees_celsius(), expected);
+        assert_eq!(average.value(), Some(last));
+        assert_eq!(average.len(), samples.len().min(3));
+        assert_eq!(average.is_full(), samples.len() >= 3);
+    }
+
+    #[test]
+    fn test_moving_average_extremes() {
+        let mut average = MovingAverage::<Frequency, 2>::new();
+        average.update(Frequency::from_milli_hertz(u64::MAX));
+
+        assert_eq!(
+            average.update(Frequency::from_milli_hertz(u64::MAX)),
+            Frequency::from_milli_hertz(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn test_moving_average_reset() {
+        let mut average = MovingAverage::<Frequency, 4>::default();
+        assert!(average.is_empty());
+        assert_eq!(average.value(), None);
+
+        average.update(Frequency::from_milli_hertz(100));
+        average.reset();
+
+        assert!(average.is_empty());
+        assert_eq!(
+            average.update(Frequency::from_milli_hertz(8)),
+            Frequency::from_milli_hertz(8)
+        );
+    }
+
+    #[test]
+    fn test_moving_average_size() {
+        let size = core::mem::size_of::<MovingAverage<Frequency, 8>>();
+        assert!(size < 8 * core::mem::size_of::<i128>());
+    }
+}
@@ -0,0 +1,617 @@
+use crate::approx;
+use crate::compare;
+use crate::math::{f64_to_u64, try_scale_f64_to_u64};
+use crate::parse::{parse_si_unsigned, ParseError};
+use crate::UnitError;
+use core::{cmp, hash, ops, str};
+
+/// Represents a frequency value, stored as whole millihertz (mHz) as a 64-bit value.
+/// This value can only be positive.
+///
+/// **Reminder:** `1000 mHz = 1 Hz, 1000 Hz = 1 kHz, 1000 kHz = 1 MHz, 1000 MHz = 1 GHz`
+///
+/// This is an immutable type. Any math operators return a new `Frequency` value.
+///
+/// # Creating a Frequency value
+/// You can create a `Frequency` value using the `from_milli_hertz` method, or using one of the
+/// extension methods on integer and floating-point types:
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let f1 = Frequency::from_milli_hertz(50000); // 50Hz
+///
+/// // More ergonomic:
+/// let f2 = 100.kilo_hertz(); // 100kHz
+/// let f3 = 2.4.giga_hertz(); // 2.4GHz
+/// ```
+///
+/// # Comparing Frequency values
+/// You can compare two `Frequency` values using the `==`, `!=`, `<`, `>`, `<=` and `>=` operators.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let f1 = 440.hertz(); // 440Hz
+/// let f2 = 1.kilo_hertz(); // 1kHz
+///
+/// if f1 > f2 {
+///     println!("{} is greater than {}", f1, f2);
+/// } else {
+///     println!("{} is less than or equal to {}", f1, f2);
+/// }
+/// ```
+///
+/// # Combining Frequency values
+/// You can use the `+` and `-` operators to add and subtract `Frequency` values from each other.
+/// The result is a new `Frequency` value, rounded down to the nearest whole millihertz (mHz).
+///
+/// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let f1 = 500.kilo_hertz(); // 500kHz
+/// let f2 = 1.2.mega_hertz(); // 1.2MHz
+///
+/// let sum = f1 + f2; // 1.7MHz
+/// let diff = f2 - 200.kilo_hertz(); // 1MHz
+/// ```
+///
+/// # Scaling Frequency values
+/// You can use the `*` and `/` operators to scale `Frequency` values by an integer or floating-point value.
+/// The result is a new `Frequency` value, rounded down to the nearest whole millihertz (mHz).
+///
+/// If the result of operation would overflow or underflow, the operation will panic.
+///
+/// If the result of the operation would be infinite or NaN, the operation will panic.
+///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let f1 = 60.hertz(); // 60Hz
+/// let f2 = f1 * 3; // 180Hz
+///
+/// let f3 = 16.mega_hertz(); // 16MHz
+/// let f4 = f3 / 2.5; // 6.4MHz
+/// ```
+///
+/// # Converting to other denominations
+/// You can use the `milli_hertz`, `hertz`, `kilo_hertz`, `mega_hertz`, and `giga_hertz` methods to
+/// convert a `Frequency` value to a numeric value in the specified denomination.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let f1 = 32.768.kilo_hertz(); // 32.768kHz
+///
+/// println!("{} kHz is {} Hz", f1.kilo_hertz(), f1.hertz());
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Frequency {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_hertz"))]
+    raw: u64,
+}
+
+impl Frequency {
+    /// Creates a new `Frequency` from a number of whole millihertz (mHz).
+    ///
+    /// It is recommended to use the `milli_hertz`, `hertz`, `kilo_hertz`, `mega_hertz`, and
+    /// `giga_hertz` extension methods on integer and floating-point types instead.
+    #[inline]
+    pub const fn from_milli_hertz(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Frequency` from a number of fractional millihertz (mHz).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_hertz(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_milli_hertz)
+    }
+
+    /// Creates a new `Frequency` from a number of fractional hertz (Hz).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_hertz(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_milli_hertz)
+    }
+
+    /// Creates a new `Frequency` from a number of fractional kilohertz (kHz).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_hertz(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_milli_hertz)
+    }
+
+    /// Creates a new `Frequency` from a number of fractional megahertz (MHz).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_mega_hertz(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000f64).map(Self::from_milli_hertz)
+    }
+
+    /// Creates a new `Frequency` from a number of fractional gigahertz (GHz).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_giga_hertz(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000_000f64).map(Self::from_milli_hertz)
+    }
+
+    /// Returns the frequency value in whole millihertz (mHz).
+    #[inline]
+    pub const fn milli_hertz(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the frequency value in fractional hertz (Hz).
+    #[inline]
+    pub fn hertz(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the frequency value in fractional kilohertz (kHz).
+    #[inline]
+    pub fn kilo_hertz(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Returns the frequency value in fractional megahertz (MHz).
+    #[inline]
+    pub fn mega_hertz(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000f64
+    }
+
+    /// Returns the frequency value in fractional gigahertz (GHz).
+    #[inline]
+    pub fn giga_hertz(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_000f64
+    }
+
+    /// Returns whether the frequency value is zero hertz (0Hz).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `Frequency` value of zero hertz (0Hz).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_milli_hertz(0)
+    }
+
+    /// Adds two `Frequency` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_milli_hertz)
+    }
+
+    /// Subtracts one `Frequency` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_milli_hertz)
+    }
+
+    /// Multiplies the `Frequency` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_milli_hertz)
+    }
+
+    /// Divides the `Frequency` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_milli_hertz)
+    }
+
+    /// Multiplies the `Frequency` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole millihertz (mHz).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_milli_hertz)
+    }
+
+    /// Divides the `Frequency` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole millihertz (mHz).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_milli_hertz)
+    }
+
+    /// Returns the unit symbol used when displaying the frequency value, such as `"Hz"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "mHz"),
+            1_000..=999_999 => (1_000, "Hz"),
+            1_000_000..=999_999_999 => (1_000_000, "kHz"),
+            1_000_000_000..=999_999_999_999 => (1_000_000_000, "MHz"),
+            _ => (1_000_000_000_000, "GHz"),
+        }
+    }
+}
+
+approx::impl_approx_eq!(Frequency, unsigned);
+compare::impl_const_compare!(Frequency, unsigned);
+
+impl PartialEq for Frequency {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Frequency {}
+
+impl PartialOrd for Frequency {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Frequency {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for Frequency {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for Frequency {
+    type Output = Self;
+
+    /// Adds two `Frequency` values together, returning a new `Frequency` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_milli_hertz)
+            .expect("Overflow when adding frequency values")
+    }
+}
+
+impl ops::Sub for Frequency {
+    type Output = Self;
+
+    /// Subtracts one `Frequency` value from another, returning a new `Frequency` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_milli_hertz)
+            .expect("Overflow when subtracting frequency values")
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i:ty) => {
+        impl ops::Mul<$i> for Frequency {
+            type Output = Self;
+
+            /// Multiplies a `Frequency` value by an integer value, returning a new `Frequency` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn mul(self, scale_factor: $i) -> Self {
+                if scale_factor < 0 {
+                    panic!("Cannot multiply frequency value by negative value")
+                }
+                self.raw
+                    .checked_mul(scale_factor as u64)
+                    .map(Self::from_milli_hertz)
+                    .expect("Overflow when multiplying frequency value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for Frequency {
+    type Output = Self;
+
+    /// Multiplies a `Frequency` value by a floating-point value, returning a new `Frequency` value.
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for Frequency {
+    type Output = Self;
+
+    /// Multiplies a `Frequency` value by a floating-point value, returning a new `Frequency` value.
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply frequency value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply frequency value by NaN"),
+            _ if scale_factor.is_sign_negative() => {
+                panic!("Cannot multiply frequency value by negative value")
+            }
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_milli_hertz(result as u64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i:ty) => {
+        impl ops::Div<$i> for Frequency {
+            type Output = Self;
+
+            /// Divides a `Frequency` value by an integer value, returning a new `Frequency` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide frequency value by zero");
+                } else if divisor < 0 {
+                    panic!("Cannot divide frequency value by negative value");
+                }
+                self.raw
+                    .checked_div(divisor as u64)
+                    .map(Self::from_milli_hertz)
+                    .expect("Overflow when dividing frequency value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for Frequency {
+    type Output = Self;
+
+    /// Divides a `Frequency` value by a floating-point value, returning a new `Frequency` value.
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for Frequency {
+    type Output = Self;
+
+    /// Divides a `Frequency` value by a floating-point value, returning a new `Frequency` value.
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide frequency value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide frequency value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide frequency value by NaN"),
+            _ if divisor.is_sign_negative() => {
+                panic!("Cannot divide frequency value by negative value")
+            }
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_milli_hertz(result as u64)
+    }
+}
+
+/// Extension trait for simple short-hands for creating `Frequency` values from integer values.
+pub trait FromInteger {
+    /// Creates a new `Frequency` from a number of whole millihertz (mHz).
+    fn milli_hertz(self) -> Frequency;
+
+    /// Creates a new `Frequency` from a number of whole hertz (Hz).
+    fn hertz(self) -> Frequency;
+
+    /// Creates a new `Frequency` from a number of whole kilohertz (kHz).
+    fn kilo_hertz(self) -> Frequency;
+
+    /// Creates a new `Frequency` from a number of whole megahertz (MHz).
+    fn mega_hertz(self) -> Frequency;
+
+    /// Creates a new `Frequency` from a number of whole gigahertz (GHz).
+    fn giga_hertz(self) -> Frequency;
+}
+
+crate::__define_unit_from_integer!(
+    Frequency,
+    FromInteger,
+    from_milli_hertz,
+    milli_hertz,
+    [
+        hertz = 1_000,
+        kilo_hertz = 1_000_000,
+        mega_hertz = 1_000_000_000,
+        giga_hertz = 1_000_000_000_000
+    ],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
+
+/// Extension trait for simple short-hands for creating `Frequency` values from floating-point values.
+pub trait FromFloat {
+    /// Creates a new `Frequency` from a number of fractional millihertz (mHz).
+    ///
+    /// The fractional part is rounded down to the nearest whole millihertz (mHz).
+    fn milli_hertz(self) -> Frequency;
+
+    /// Creates a new `Frequency` from a number of fractional hertz (Hz).
+    ///
+    /// The fractional part is rounded down to the nearest whole millihertz (mHz).
+    fn hertz(self) -> Frequency;
+
+    /// Creates a new `Frequency` from a number of fractional kilohertz (kHz).
+    ///
+    /// The fractional part is rounded down to the nearest whole millihertz (mHz).
+    fn kilo_hertz(self) -> Frequency;
+
+    /// Creates a new `Frequency` from a number of fractional megahertz (MHz).
+    ///
+    /// The fractional part is rounded down to the nearest whole millihertz (mHz).
+    fn mega_hertz(self) -> Frequency;
+
+    /// Creates a new `Frequency` from a number of fractional gigahertz (GHz).
+    ///
+    /// The fractional part is rounded down to the nearest whole millihertz (mHz).
+    fn giga_hertz(self) -> Frequency;
+}
+
+crate::__define_unit_from_float!(
+    Frequency,
+    FromFloat,
+    from_milli_hertz,
+    milli_hertz,
+    [
+        hertz = 1_000,
+        kilo_hertz = 1_000_000,
+        mega_hertz = 1_000_000_000,
+        giga_hertz = 1_000_000_000_000
+    ],
+    f32,
+    f64
+);
+
+crate::__define_unit_display!(Frequency);
+
+impl str::FromStr for Frequency {
+    type Err = ParseError;
+
+    /// Parses a `Frequency` value from a string with an optional SI prefix and unit symbol, such as
+    /// `50Hz`, `16 MHz` or `32.768k`.
+    ///
+    /// Will be rounded down to the nearest whole millihertz (mHz).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["Hz"], 3).map(Self::from_milli_hertz)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(50.hertz(), 50_000; "50Hz")]
+    #[test_case(32.768.kilo_hertz(), 32_768_000; "32.768kHz")]
+    #[test_case(16.mega_hertz(), 16_000_000_000; "16MHz")]
+    #[test_case(2.4.giga_hertz(), 2_400_000_000_000; "2.4GHz")]
+    fn test_extension_methods(frequency: Frequency, expected_milli_hertz: u64) {
+        assert_eq!(frequency.milli_hertz(), expected_milli_hertz);
+    }
+
+    #[test_case(500, "500.00 mHz"; "millihertz")]
+    #[test_case(50_000, "50.00 Hz"; "hertz")]
+    #[test_case(32_768_000, "32.77 kHz"; "kilohertz")]
+    #[test_case(16_000_000_000, "16.00 MHz"; "megahertz")]
+    #[test_case(2_400_000_000_000, "2.40 GHz"; "gigahertz")]
+    fn test_display(milli_hertz: u64, expected: &str) {
+        let frequency = Frequency::from_milli_hertz(milli_hertz);
+        assert_eq!(format!("{frequency}"), expected);
+    }
+
+    #[test_case("50Hz", Ok(50_000); "hertz")]
+    #[test_case("16 MHz", Ok(16_000_000_000); "mega prefix with space")]
+    #[test_case("32.768k", Ok(32_768_000); "prefix without symbol")]
+    #[test_case("500mHz", Ok(500); "milli prefix")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Frequency>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Frequency::from_milli_hertz(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(Frequency::try_from_hertz(1.5), Ok(Frequency::from_milli_hertz(1_500)); "valid value")]
+    #[test_case(Frequency::try_from_hertz(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Frequency::try_from_hertz(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Frequency::try_from_hertz(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Frequency::try_from_hertz(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(result: Result<Frequency, UnitError>, expected: Result<Frequency, UnitError>) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Frequency::from_milli_hertz(50_000),
+            &[
+                Token::Struct {
+                    name: "Frequency",
+                    len: 1,
+                },
+                Token::Str("milli_hertz"),
+                Token::U64(50_000),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
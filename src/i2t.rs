@@ -0,0 +1,218 @@
+use crate::display;
+use crate::math::f64_to_u64;
+use crate::{Current, Percent, UnitError};
+use core::time::Duration;
+use core::{fmt, iter, ops};
+
+/// Represents the let-through energy of a current pulse (I²t), stored as whole milliampere squared
+/// seconds (mA²s), which are millionths of an ampere squared second, as a 64-bit value.
+///
+/// Fuse datasheets give the melting I²t of a fuse, which the I²t of any pulse it should survive
+/// must stay well below. Pulses in a train add together.
+///
+/// ```rust
+/// use ohms::prelude::*;
+/// use core::time::Duration;
+///
+/// // A 10A inrush pulse lasting 2ms
+/// let pulse = I2t::from_pulse(10.amps(), Duration::from_millis(2)).unwrap();
+/// assert_eq!(pulse.amp_squared_seconds(), 0.2);
+///
+/// let rating = I2t::from_milli_amp_squared_seconds(1_500_000); // 1.5A²s
+/// assert!(pulse < rating);
+/// assert_eq!(pulse.percent_of(rating).unwrap().milli_percent(), 13_333); // 13.333%
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct I2t {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_amp_squared_seconds"))]
+    raw: u64,
+}
+
+impl I2t {
+    /// Creates a new `I2t` from a number of whole milliampere squared seconds (mA²s).
+    #[inline]
+    pub const fn from_milli_amp_squared_seconds(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `I2t` from a number of fractional ampere squared seconds (A²s).
+    ///
+    /// Will be rounded down to the nearest whole milliampere squared second (mA²s).
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    pub fn try_from_amp_squared_seconds(value: f64) -> Result<Self, UnitError> {
+        if value.is_nan() {
+            return Err(UnitError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(UnitError::Infinite);
+        }
+        if value < 0f64 {
+            return Err(UnitError::Negative);
+        }
+
+        f64_to_u64(value * 1_000_000f64)
+            .map(Self::from_milli_amp_squared_seconds)
+            .ok_or(UnitError::Overflow)
+    }
+
+    /// Calculates the I²t of a rectangular current pulse.
+    ///
+    /// Will be rounded up to the nearest whole milliampere squared second (mA²s), so a pulse is
+    /// never underestimated. Returns `None` if the result would overflow.
+    pub fn from_pulse(current: Current, duration: Duration) -> Option<Self> {
+        let micro_amps = current.micro_amps() as u128;
+
+        // (μA)² × ns is in units of 10⁻²¹ A²s
+        let raw = micro_amps
+            .checked_mul(micro_amps)?
+            .checked_mul(duration.as_nanos())?
+            .div_ceil(1_000_000_000_000_000);
+
+        u64::try_from(raw)
+            .ok()
+            .map(Self::from_milli_amp_squared_seconds)
+    }
+
+    /// Returns the I²t in whole milliampere squared seconds (mA²s).
+    #[inline]
+    pub const fn milli_amp_squared_seconds(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the I²t in fractional ampere squared seconds (A²s).
+    #[inline]
+    pub fn amp_squared_seconds(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Returns whether the I²t is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns an `I2t` value of zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_milli_amp_squared_seconds(0)
+    }
+
+    /// Adds two `I2t` values, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_milli_amp_squared_seconds)
+    }
+
+    /// Returns this I²t as a percentage of a fuse rating, such as its melting I²t.
+    ///
+    /// Will be rounded to the nearest whole milli-percent (m%).
+    /// Returns `None` if the rating is zero or the result would overflow.
+    #[inline]
+    pub fn percent_of(&self, rating: I2t) -> Option<Percent> {
+        Percent::from_ratio(self.raw, rating.raw)
+    }
+}
+
+impl ops::Add for I2t {
+    type Output = Self;
+
+    /// Adds two `I2t` values, returning a new value.
+    /// Panics if the result would overflow.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("Overflow when adding I2t values")
+    }
+}
+
+impl iter::Sum for I2t {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a I2t> for I2t {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl fmt::Display for I2t {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(
+            f,
+            format_args!("{}.{:06} A²s", self.raw / 1_000_000, self.raw % 1_000_000),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(1.5, Ok(1_500_000); "fraction")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(1e14, Err(UnitError::Overflow); "overflow")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    fn test_try_from_amp_squared_seconds(value: f64, expected: Result<u64, UnitError>) {
+        let i2t = I2t::try_from_amp_squared_seconds(value).map(|v| v.milli_amp_squared_seconds());
+        assert_eq!(i2t, expected);
+    }
+
+    #[test_case(10_000_000, Duration::from_millis(2), Some(200_000); "inrush")]
+    #[test_case(1_000_000, Duration::from_secs(1), Some(1_000_000); "one amp for a second")]
+    #[test_case(100_000, Duration::from_micros(10), Some(1); "rounds up")]
+    #[test_case(0, Duration::from_secs(1), Some(0); "zero current")]
+    #[test_case(5_000_000, Duration::ZERO, Some(0); "zero duration")]
+    #[test_case(u64::MAX, Duration::from_secs(1), None; "overflow")]
+    fn test_from_pulse(micro_amps: u64, duration: Duration, expected: Option<u64>) {
+        let i2t = I2t::from_pulse(Current::from_micro_amps(micro_amps), duration);
+        assert_eq!(i2t.map(|v| v.milli_amp_squared_seconds()), expected);
+    }
+
+    #[test_case(200_000, 1_500_000, Some(13_333); "within rating")]
+    #[test_case(3_000_000, 1_500_000, Some(200_000); "over rating")]
+    #[test_case(200_000, 0, None; "zero rating")]
+    #[test_case(u64::MAX, 1, None; "overflow")]
+    fn test_percent_of(i2t: u64, rating: u64, expected: Option<u32>) {
+        let percent = I2t::from_milli_amp_squared_seconds(i2t)
+            .percent_of(I2t::from_milli_amp_squared_seconds(rating));
+        assert_eq!(percent.map(|p| p.milli_percent()), expected);
+    }
+
+    #[test]
+    fn test_pulse_train() {
+        let pulse = I2t::from_pulse(
+            Current::from_micro_amps(2_000_000),
+            Duration::from_millis(5),
+        )
+        .unwrap();
+
+        let total: I2t = [pulse, pulse, pulse].iter().sum();
+        assert_eq!(total.milli_amp_squared_seconds(), 60_000);
+        assert_eq!(
+            I2t::from_milli_amp_squared_seconds(u64::MAX).checked_add(pulse),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow_panics() {
+        let _ =
+            I2t::from_milli_amp_squared_seconds(u64::MAX) + I2t::from_milli_amp_squared_seconds(1);
+    }
+
+    #[test_case(1_500_000, "1.500000 A²s"; "fraction")]
+    #[test_case(0, "0.000000 A²s"; "zero")]
+    fn test_display(i2t: u64, expected: &str) {
+        let i2t = I2t::from_milli_amp_squared_seconds(i2t);
+        assert_eq!(format!("{i2t}"), expected);
+    }
+}
@@ -0,0 +1,276 @@
+use crate::display;
+use crate::math::isqrt;
+use crate::Resistance;
+use core::{fmt, ops};
+
+/// Represents a complex impedance value, stored as a resistive (real) part and a reactive
+/// (imaginary) part, both in whole milliohms (mΩ).
+///
+/// The resistive part can only be positive. The reactive part is positive for inductive loads and
+/// negative for capacitive loads.
+///
+/// **Reminder:** `Z = R + jX, |Z| = √(R² + X²)`
+///
+/// This is an immutable type. Any math operators return a new `Impedance` value.
+///
+/// # Creating an Impedance value
+/// You can create an `Impedance` value using the `from_milli_ohms` method, or from a purely
+/// resistive `Resistance` value:
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let z1 = Impedance::from_milli_ohms(100_000, 50_000); // 100Ω + j50Ω
+/// let z2 = Impedance::from(220.ohms()); // 220Ω + j0Ω
+/// ```
+///
+/// # Combining Impedance values
+/// You can use the `+` operator to combine `Impedance` values connected in series.
+///
+/// If the result of the operation would overflow, the operation will panic.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let z1 = Impedance::from_milli_ohms(100_000, 50_000); // 100Ω + j50Ω
+/// let z2 = Impedance::from_milli_ohms(100_000, -50_000); // 100Ω - j50Ω
+///
+/// let sum = z1 + z2; // 200Ω + j0Ω
+/// ```
+///
+/// # AC Ohm's Law
+/// Dividing a `Voltage` by an `Impedance` returns the magnitude of the `Current` through it, and
+/// multiplying a `Current` by an `Impedance` returns the magnitude of the `Voltage` across it.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
+/// use ohms::prelude::*;
+///
+/// let z = Impedance::from_milli_ohms(30_000, 40_000); // 30Ω + j40Ω, |Z| = 50Ω
+///
+/// let current = 10.volts() / z; // 200mA
+/// let voltage = 2.amps() * z; // 100V
+/// # }
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Impedance {
+    #[cfg_attr(feature = "serde", serde(rename = "resistance_milli_ohms"))]
+    resistance: u64,
+    #[cfg_attr(feature = "serde", serde(rename = "reactance_milli_ohms"))]
+    reactance: i64,
+}
+
+impl Impedance {
+    /// Creates a new `Impedance` from its resistive and reactive parts, in whole milliohms (mΩ).
+    #[inline]
+    pub const fn from_milli_ohms(resistance: u64, reactance: i64) -> Self {
+        Self {
+            resistance,
+            reactance,
+        }
+    }
+
+    /// Returns the resistive (real) part of the impedance.
+    #[inline]
+    pub const fn resistance(&self) -> Resistance {
+        Resistance::from_milli_ohms(self.resistance)
+    }
+
+    /// Returns the reactive (imaginary) part of the impedance in whole milliohms (mΩ).
+    ///
+    /// This is positive for inductive loads and negative for capacitive loads.
+    #[inline]
+    pub const fn reactance_milli_ohms(&self) -> i64 {
+        self.reactance
+    }
+
+    /// Returns the reactive (imaginary) part of the impedance in fractional ohms (Ω).
+    ///
+    /// This is positive for inductive loads and negative for capacitive loads.
+    #[inline]
+    pub fn reactance_ohms(&self) -> f64 {
+        self.reactance as f64 / 1_000f64
+    }
+
+    /// Returns the magnitude of the impedance (`|Z| = √(R² + X²)`).
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    pub fn magnitude(&self) -> Resistance {
+        let resistance = self.resistance as u128;
+        let reactance = self.reactance.unsigned_abs() as u128;
+
+        let sum_of_squares = (resistance * resistance)
+            .checked_add(reactance * reactance)
+            .expect("Impedance would overflow");
+
+        let magnitude = isqrt(sum_of_squares);
+
+        Resistance::from_milli_ohms(u64::try_from(magnitude).expect("Impedance would overflow"))
+    }
+
+    /// Returns the phase angle of the impedance in radians, between `-π/2` and `π/2`.
+    ///
+    /// A positive angle indicates an inductive load, where the current lags the voltage.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn phase(&self) -> f64 {
+        libm::atan2(self.reactance as f64, self.resistance as f64)
+    }
+
+    /// Returns the phase angle of the impedance in degrees, between `-90°` and `90°`.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn phase_degrees(&self) -> f64 {
+        self.phase().to_degrees()
+    }
+
+    /// Returns whether the impedance is purely resistive, with no reactive part.
+    #[inline]
+    pub const fn is_resistive(&self) -> bool {
+        self.reactance == 0
+    }
+
+    /// Returns whether the impedance is zero ohms (0Ω).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.resistance == 0 && self.reactance == 0
+    }
+
+    /// Returns an `Impedance` value of zero ohms (0Ω).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_milli_ohms(0, 0)
+    }
+
+    /// Adds two `Impedance` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let resistance = self.resistance.checked_add(other.resistance)?;
+        let reactance = self.reactance.checked_add(other.reactance)?;
+
+        Some(Self::from_milli_ohms(resistance, reactance))
+    }
+}
+
+impl From<Resistance> for Impedance {
+    /// Creates a purely resistive `Impedance` from a `Resistance` value.
+    #[inline]
+    fn from(resistance: Resistance) -> Self {
+        Self::from_milli_ohms(resistance.milli_ohms(), 0)
+    }
+}
+
+impl ops::Add for Impedance {
+    type Output = Self;
+
+    /// Adds two `Impedance` values together, returning a new `Impedance` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("Overflow when adding impedance values")
+    }
+}
+
+impl fmt::Display for Impedance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let resistance = self.resistance as f64 / 1_000f64;
+        let sign = if self.reactance < 0 { "-" } else { "+" };
+        let reactance = self.reactance.unsigned_abs() as f64 / 1_000f64;
+
+        display::write_padded(
+            f,
+            format_args!("{resistance:.precision$} {sign} j{reactance:.precision$} Ω"),
+        )
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(30_000, 40_000, 50_000; "3-4-5 triangle")]
+    #[test_case(30_000, -40_000, 50_000; "capacitive reactance")]
+    #[test_case(100_000, 0, 100_000; "purely resistive")]
+    #[test_case(0, -2_000, 2_000; "purely reactive")]
+    #[test_case(1_000, 1_000, 1_414; "rounds down to nearest milliohm")]
+    #[test_case(u64::MAX, 0, u64::MAX; "maximum resistance")]
+    fn test_magnitude(resistance: u64, reactance: i64, expected_milli_ohms: u64) {
+        let impedance = Impedance::from_milli_ohms(resistance, reactance);
+        assert_eq!(impedance.magnitude().milli_ohms(), expected_milli_ohms);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_magnitude_overflow_panics() {
+        let _ = Impedance::from_milli_ohms(u64::MAX, i64::MIN).magnitude();
+    }
+
+    #[cfg(feature = "libm")]
+    #[test_case(1_000, 0, 0.0; "purely resistive")]
+    #[test_case(1_000, 1_000, 45.0; "inductive")]
+    #[test_case(1_000, -1_000, -45.0; "capacitive")]
+    #[test_case(0, 1_000, 90.0; "purely inductive")]
+    fn test_phase_degrees(resistance: u64, reactance: i64, expected: f64) {
+        let impedance = Impedance::from_milli_ohms(resistance, reactance);
+        assert!((impedance.phase_degrees() - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_add() {
+        let z1 = Impedance::from_milli_ohms(100_000, 50_000);
+        let z2 = Impedance::from_milli_ohms(20_000, -80_000);
+
+        assert_eq!(z1 + z2, Impedance::from_milli_ohms(120_000, -30_000));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow_panics() {
+        let _ = Impedance::from_milli_ohms(0, i64::MAX) + Impedance::from_milli_ohms(0, 1);
+    }
+
+    #[test]
+    fn test_from_resistance() {
+        let impedance = Impedance::from(Resistance::from_milli_ohms(220_000));
+
+        assert!(impedance.is_resistive());
+        assert_eq!(impedance.resistance().milli_ohms(), 220_000);
+    }
+
+    #[test_case(100_000, 50_000, "100.00 + j50.00 Ω"; "inductive")]
+    #[test_case(100_000, -50_000, "100.00 - j50.00 Ω"; "capacitive")]
+    #[test_case(4_700, 0, "4.70 + j0.00 Ω"; "purely resistive")]
+    fn test_display(resistance: u64, reactance: i64, expected: &str) {
+        let impedance = Impedance::from_milli_ohms(resistance, reactance);
+        assert_eq!(format!("{impedance}"), expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Impedance::from_milli_ohms(100000, -50000),
+            &[
+                Token::Struct {
+                    name: "Impedance",
+                    len: 2,
+                },
+                Token::Str("resistance_milli_ohms"),
+                Token::U64(100000),
+                Token::Str("reactance_milli_ohms"),
+                Token::I64(-50000),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,723 @@
+use crate::approx;
+use crate::compare;
+#[cfg(feature = "resistance")]
+use crate::math::f64_to_i64;
+use crate::math::{f64_to_u64, try_scale_f64_to_u64};
+use crate::parse::{parse_si_unsigned, ParseError};
+use crate::Frequency;
+use crate::UnitError;
+#[cfg(feature = "current")]
+use crate::{Current, Energy};
+#[cfg(feature = "resistance")]
+use crate::{Impedance, Resistance};
+use core::f64::consts::PI;
+use core::{cmp, hash, ops, str};
+
+/// Represents an inductance value, stored as whole nanohenries (nH) as a 64-bit value.
+/// This value can only be positive.
+///
+/// **Reminder:** `1000 nH = 1 μH, 1000 μH = 1 mH, 1000 mH = 1 H`
+///
+/// This is an immutable type. Any math operators return a new `Inductance` value.
+///
+/// # Creating an Inductance value
+/// You can create an `Inductance` value using the `from_nano_henries` method, or using one of the
+/// extension methods on integer and floating-point types:
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let l1 = Inductance::from_nano_henries(1500); // 1.5μH
+///
+/// // More ergonomic:
+/// let l2 = 22.micro_henries(); // 22μH
+/// let l3 = 4.7.milli_henries(); // 4.7mH
+/// ```
+///
+/// # Comparing Inductance values
+/// You can compare two `Inductance` values using the `==`, `!=`, `<`, `>`, `<=` and `>=` operators.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let l1 = 220.micro_henries(); // 220μH
+/// let l2 = 1.milli_henries(); // 1mH
+///
+/// if l1 > l2 {
+///     println!("{} is greater than {}", l1, l2);
+/// } else {
+///     println!("{} is less than or equal to {}", l1, l2);
+/// }
+/// ```
+///
+/// # Combining Inductance values
+/// You can use the `+` and `-` operators to add and subtract `Inductance` values from each other.
+/// The result is a new `Inductance` value, rounded down to the nearest whole nanohenry (nH).
+///
+/// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let l1 = 330.micro_henries(); // 330μH
+/// let l2 = 1.5.milli_henries(); // 1.5mH
+///
+/// let sum = l1 + l2; // 1.83mH
+/// let diff = l2 - 470.micro_henries(); // 1.03mH
+/// ```
+///
+/// # Scaling Inductance values
+/// You can use the `*` and `/` operators to scale `Inductance` values by an integer or floating-point value.
+/// The result is a new `Inductance` value, rounded down to the nearest whole nanohenry (nH).
+///
+/// If the result of operation would overflow or underflow, the operation will panic.
+///
+/// If the result of the operation would be infinite or NaN, the operation will panic.
+///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let l1 = 10.micro_henries(); // 10μH
+/// let l2 = l1 * 3; // 30μH
+///
+/// let l3 = 100.micro_henries(); // 100μH
+/// let l4 = l3 / 2.5; // 40μH
+/// ```
+///
+/// # Converting to other denominations
+/// You can use the `nano_henries`, `micro_henries`, `milli_henries`, and `henries` methods to
+/// convert an `Inductance` value to a numeric value in the specified denomination.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let l1 = 0.47.milli_henries(); // 0.47mH
+///
+/// println!("{} mH is {} μH", l1.milli_henries(), l1.micro_henries());
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Inductance {
+    #[cfg_attr(feature = "serde", serde(rename = "nano_henries"))]
+    raw: u64,
+}
+
+impl Inductance {
+    /// Creates a new `Inductance` from a number of whole nanohenries (nH).
+    ///
+    /// It is recommended to use the `nano_henries`, `micro_henries`, `milli_henries`, and `henries`
+    /// extension methods on integer and floating-point types instead.
+    #[inline]
+    pub const fn from_nano_henries(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Inductance` from a number of fractional nanohenries (nH).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_nano_henries(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_nano_henries)
+    }
+
+    /// Creates a new `Inductance` from a number of fractional microhenries (μH).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_henries(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_nano_henries)
+    }
+
+    /// Creates a new `Inductance` from a number of fractional millihenries (mH).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_henries(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_nano_henries)
+    }
+
+    /// Creates a new `Inductance` from a number of fractional henries (H).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_henries(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000f64).map(Self::from_nano_henries)
+    }
+
+    /// Returns the inductance value in whole nanohenries (nH).
+    #[inline]
+    pub const fn nano_henries(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the inductance value in fractional microhenries (μH).
+    #[inline]
+    pub fn micro_henries(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the inductance value in fractional millihenries (mH).
+    #[inline]
+    pub fn milli_henries(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Returns the inductance value in fractional henries (H).
+    #[inline]
+    pub fn henries(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000f64
+    }
+
+    /// Returns whether the inductance value is zero henries (0H).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns an `Inductance` value of zero henries (0H).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_nano_henries(0)
+    }
+
+    /// Adds two `Inductance` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_nano_henries)
+    }
+
+    /// Subtracts one `Inductance` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_nano_henries)
+    }
+
+    /// Multiplies the `Inductance` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_nano_henries)
+    }
+
+    /// Divides the `Inductance` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_nano_henries)
+    }
+
+    /// Multiplies the `Inductance` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole nanohenry (nH).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_nano_henries)
+    }
+
+    /// Divides the `Inductance` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole nanohenry (nH).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_nano_henries)
+    }
+
+    /// Calculates the inductive reactance at a given frequency (`XL = 2πfL`).
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    /// Panics if the result would overflow.
+    #[cfg(feature = "resistance")]
+    pub fn reactance(&self, frequency: Frequency) -> Resistance {
+        let milli_ohms = (*self * frequency) * 1_000f64;
+        Resistance::from_milli_ohms(f64_to_u64(milli_ohms).expect("Reactance would overflow"))
+    }
+
+    /// Calculates the inductive reactance at a given frequency (`XL = 2πfL`).
+    ///
+    /// The result is a purely reactive `Impedance` with a positive reactive part, as the current
+    /// through an inductor lags the voltage across it.
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    #[cfg(feature = "resistance")]
+    pub fn reactance_at(&self, frequency: Frequency) -> Impedance {
+        let milli_ohms = 2f64 * PI * frequency.milli_hertz() as f64 * self.raw as f64 / 1e9f64;
+        let reactance = f64_to_i64(milli_ohms).expect("Reactance would overflow");
+
+        Impedance::from_milli_ohms(0, reactance)
+    }
+
+    /// Calculates the energy stored in the inductance when carrying a given current (`E = ½LI²`).
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    #[cfg(feature = "current")]
+    pub fn energy_at(&self, current: Current) -> Energy {
+        let micro_amps = current.micro_amps() as u128;
+
+        let nano_joules = (micro_amps * micro_amps)
+            .checked_mul(self.raw as u128)
+            .expect("Energy would overflow")
+            / 2_000_000_000_000u128;
+
+        Energy::from_nano_joules(u64::try_from(nano_joules).expect("Energy would overflow"))
+    }
+
+    /// Returns the unit symbol used when displaying the inductance value, such as `"μH"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "nH"),
+            1_000..=999_999 => (1_000, "μH"),
+            1_000_000..=999_999_999 => (1_000_000, "mH"),
+            _ => (1_000_000_000, "H"),
+        }
+    }
+}
+
+approx::impl_approx_eq!(Inductance, unsigned);
+compare::impl_const_compare!(Inductance, unsigned);
+
+impl PartialEq for Inductance {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Inductance {}
+
+impl PartialOrd for Inductance {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Inductance {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for Inductance {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for Inductance {
+    type Output = Self;
+
+    /// Adds two `Inductance` values together, returning a new `Inductance` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_nano_henries)
+            .expect("Overflow when adding inductance values")
+    }
+}
+
+impl ops::Sub for Inductance {
+    type Output = Self;
+
+    /// Subtracts one `Inductance` value from another, returning a new `Inductance` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_nano_henries)
+            .expect("Overflow when subtracting inductance values")
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i:ty) => {
+        impl ops::Mul<$i> for Inductance {
+            type Output = Self;
+
+            /// Multiplies an `Inductance` value by an integer value, returning a new `Inductance` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn mul(self, scale_factor: $i) -> Self {
+                if scale_factor < 0 {
+                    panic!("Cannot multiply inductance value by negative value")
+                }
+                self.raw
+                    .checked_mul(scale_factor as u64)
+                    .map(Self::from_nano_henries)
+                    .expect("Overflow when multiplying inductance value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for Inductance {
+    type Output = Self;
+
+    /// Multiplies an `Inductance` value by a floating-point value, returning a new `Inductance` value.
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for Inductance {
+    type Output = Self;
+
+    /// Multiplies an `Inductance` value by a floating-point value, returning a new `Inductance` value.
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply inductance value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply inductance value by NaN"),
+            _ if scale_factor.is_sign_negative() => {
+                panic!("Cannot multiply inductance value by negative value")
+            }
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_nano_henries(result as u64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i:ty) => {
+        impl ops::Div<$i> for Inductance {
+            type Output = Self;
+
+            /// Divides an `Inductance` value by an integer value, returning a new `Inductance` value.
+            #[inline]
+            #[allow(unused_comparisons)]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide inductance value by zero");
+                } else if divisor < 0 {
+                    panic!("Cannot divide inductance value by negative value");
+                }
+                self.raw
+                    .checked_div(divisor as u64)
+                    .map(Self::from_nano_henries)
+                    .expect("Overflow when dividing inductance value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for Inductance {
+    type Output = Self;
+
+    /// Divides an `Inductance` value by a floating-point value, returning a new `Inductance` value.
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for Inductance {
+    type Output = Self;
+
+    /// Divides an `Inductance` value by a floating-point value, returning a new `Inductance` value.
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide inductance value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide inductance value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide inductance value by NaN"),
+            _ if divisor.is_sign_negative() => {
+                panic!("Cannot divide inductance value by negative value")
+            }
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_nano_henries(result as u64)
+    }
+}
+
+impl ops::Mul<Frequency> for Inductance {
+    type Output = f64;
+
+    /// Calculates the inductive reactance in ohms (Ω) at a given frequency (`XL = 2πfL`).
+    #[inline]
+    fn mul(self, frequency: Frequency) -> Self::Output {
+        2f64 * PI * frequency.hertz() * self.henries()
+    }
+}
+
+impl ops::Mul<Inductance> for Frequency {
+    type Output = f64;
+
+    /// Calculates the inductive reactance in ohms (Ω) at a given frequency (`XL = 2πfL`).
+    #[inline]
+    fn mul(self, inductance: Inductance) -> Self::Output {
+        inductance * self
+    }
+}
+
+/// Extension trait for simple short-hands for creating `Inductance` values from integer values.
+pub trait FromInteger {
+    /// Creates a new `Inductance` from a number of whole nanohenries (nH).
+    fn nano_henries(self) -> Inductance;
+
+    /// Creates a new `Inductance` from a number of whole microhenries (μH).
+    fn micro_henries(self) -> Inductance;
+
+    /// Creates a new `Inductance` from a number of whole millihenries (mH).
+    fn milli_henries(self) -> Inductance;
+
+    /// Creates a new `Inductance` from a number of whole henries (H).
+    fn henries(self) -> Inductance;
+}
+
+crate::__define_unit_from_integer!(
+    Inductance,
+    FromInteger,
+    from_nano_henries,
+    nano_henries,
+    [
+        micro_henries = 1_000,
+        milli_henries = 1_000_000,
+        henries = 1_000_000_000
+    ],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
+
+/// Extension trait for simple short-hands for creating `Inductance` values from floating-point values.
+pub trait FromFloat {
+    /// Creates a new `Inductance` from a number of fractional nanohenries (nH).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanohenry (nH).
+    fn nano_henries(self) -> Inductance;
+
+    /// Creates a new `Inductance` from a number of fractional microhenries (μH).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanohenry (nH).
+    fn micro_henries(self) -> Inductance;
+
+    /// Creates a new `Inductance` from a number of fractional millihenries (mH).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanohenry (nH).
+    fn milli_henries(self) -> Inductance;
+
+    /// Creates a new `Inductance` from a number of fractional henries (H).
+    ///
+    /// The fractional part is rounded down to the nearest whole nanohenry (nH).
+    fn henries(self) -> Inductance;
+}
+
+crate::__define_unit_from_float!(
+    Inductance,
+    FromFloat,
+    from_nano_henries,
+    nano_henries,
+    [
+        micro_henries = 1_000,
+        milli_henries = 1_000_000,
+        henries = 1_000_000_000
+    ],
+    f32,
+    f64
+);
+
+crate::__define_unit_display!(Inductance);
+
+impl str::FromStr for Inductance {
+    type Err = ParseError;
+
+    /// Parses a `Inductance` value from a string with an optional SI prefix and unit symbol, such as
+    /// `10uH`, `4.7 mH` or `100n`.
+    ///
+    /// Will be rounded down to the nearest whole nanohenry (nH).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["H"], 9).map(Self::from_nano_henries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(22.micro_henries(), 22_000; "22μH")]
+    #[test_case(4.7.milli_henries(), 4_700_000; "4.7mH")]
+    #[test_case(1.henries(), 1_000_000_000; "1H")]
+    #[test_case(1.5.micro_henries(), 1_500; "1.5μH")]
+    fn test_extension_methods(inductance: Inductance, expected_nano_henries: u64) {
+        assert_eq!(inductance.nano_henries(), expected_nano_henries);
+    }
+
+    #[test_case(470, "470.00 nH"; "nanohenries")]
+    #[test_case(22_000, "22.00 μH"; "microhenries")]
+    #[test_case(4_700_000, "4.70 mH"; "millihenries")]
+    #[test_case(1_500_000_000, "1.50 H"; "henries")]
+    fn test_display(nano_henries: u64, expected: &str) {
+        let inductance = Inductance::from_nano_henries(nano_henries);
+        assert_eq!(format!("{inductance}"), expected);
+    }
+
+    #[test_case("10uH", Ok(10_000); "micro prefix")]
+    #[test_case("4.7 mH", Ok(4_700_000); "milli prefix with space")]
+    #[test_case("100n", Ok(100); "prefix without symbol")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Inductance>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Inductance::from_nano_henries(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(10_000, 100_000_000, 6_283; "10μH at 100kHz")]
+    #[test_case(1_000_000, 50_000, 314; "1mH at 50Hz")]
+    #[test_case(1_000_000, 0, 0; "zero frequency equals zero reactance")]
+    fn test_reactance_at(nano_henries: u64, milli_hertz: u64, expected_milli_ohms: i64) {
+        let inductance = Inductance::from_nano_henries(nano_henries);
+        let reactance = inductance.reactance_at(Frequency::from_milli_hertz(milli_hertz));
+
+        assert_eq!(
+            reactance,
+            Impedance::from_milli_ohms(0, expected_milli_ohms)
+        );
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    #[should_panic]
+    fn test_reactance_at_overflow_panics() {
+        let _ = Inductance::from_nano_henries(u64::MAX)
+            .reactance_at(Frequency::from_milli_hertz(1_000_000_000_000));
+    }
+
+    #[cfg(feature = "current")]
+    #[test_case(10_000_000, 2_000_000, 20_000_000; "10mH at 2A equals 20mJ")]
+    #[test_case(47_000, 1_500_000, 52_875; "47μH at 1.5A equals 52.875μJ")]
+    #[test_case(1, 1, 0; "1nH at 1μA rounds down to 0nJ")]
+    fn test_energy_at(nano_henries: u64, micro_amps: u64, expected_nano_joules: u64) {
+        let inductance = Inductance::from_nano_henries(nano_henries);
+        let energy = inductance.energy_at(Current::from_micro_amps(micro_amps));
+
+        assert_eq!(energy.nano_joules(), expected_nano_joules);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    #[should_panic]
+    fn test_energy_at_overflow_panics() {
+        let inductance = Inductance::from_nano_henries(u64::MAX);
+        let _ = inductance.energy_at(Current::from_micro_amps(u64::MAX));
+    }
+
+    #[test_case(Inductance::try_from_micro_henries(1.5), Ok(Inductance::from_nano_henries(1_500)); "valid value")]
+    #[test_case(Inductance::try_from_micro_henries(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Inductance::try_from_micro_henries(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Inductance::try_from_micro_henries(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Inductance::try_from_micro_henries(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(
+        result: Result<Inductance, UnitError>,
+        expected: Result<Inductance, UnitError>,
+    ) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Inductance::from_nano_henries(22_000),
+            &[
+                Token::Struct {
+                    name: "Inductance",
+                    len: 1,
+                },
+                Token::Str("nano_henries"),
+                Token::U64(22_000),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(10_000, 100_000_000, 6_283; "10μH at 100kHz")]
+    #[test_case(1_000_000, 50_000, 314; "1mH at 50Hz")]
+    #[test_case(1_000_000, 0, 0; "zero frequency equals zero reactance")]
+    fn test_reactance(nano_henries: u64, milli_hertz: u64, expected_milli_ohms: u64) {
+        let inductance = Inductance::from_nano_henries(nano_henries);
+        let reactance = inductance.reactance(Frequency::from_milli_hertz(milli_hertz));
+
+        assert_eq!(reactance, Resistance::from_milli_ohms(expected_milli_ohms));
+    }
+
+    #[test]
+    fn test_mul_frequency() {
+        let inductance = Inductance::from_nano_henries(1_000_000);
+        let frequency = Frequency::from_milli_hertz(50_000);
+
+        assert!((inductance * frequency - PI / 10f64).abs() < 1e-12);
+        assert_eq!(frequency * inductance, inductance * frequency);
+    }
+}
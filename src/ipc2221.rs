@@ -0,0 +1,228 @@
+//! Current capacity of PCB traces, following the IPC-2221 design chart.
+//!
+//! The standard fits the current a trace can carry for a given temperature rise to
+//! `I = k × ΔT^0.44 × A^0.725`, where `A` is the cross-sectional area of the trace in square mils
+//! and `k` is 0.048 for external layers and 0.024 for internal layers, which cool less well.
+//!
+//! The fit is only valid for currents up to 35A, widths up to 10mm (400 mil) and temperature rises
+//! of 10°C to 100°C, and is conservative for most modern boards.
+//!
+//! Requires the `libm` feature.
+//!
+//! ```rust
+//! use ohms::ipc2221::{self, CopperWeight, Layer};
+//! use ohms::prelude::*;
+//!
+//! // 0.25mm trace in 1oz copper on an outer layer, 10°C rise
+//! let (weight, rise) = (CopperWeight::ONE_OUNCE, 10.degrees_celsius());
+//!
+//! let current = ipc2221::max_current(Layer::External, 254, weight, rise).unwrap();
+//! assert_eq!(current, 885_517.micro_amps()); // 0.886A
+//!
+//! let width = ipc2221::min_width(Layer::External, 1.amps(), weight, rise).unwrap();
+//! assert_eq!(width, 301); // 0.301mm
+//! ```
+use crate::math::f64_to_u64;
+use crate::{display, Current, Temperature};
+use core::fmt;
+
+/// The thickness of one ounce per square foot of copper in mils.
+const MILS_PER_OUNCE: f64 = 1.378;
+
+/// The number of micrometers in a mil.
+const MICRO_METERS_PER_MIL: f64 = 25.4;
+
+/// The layer of a board a trace is on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Layer {
+    /// An outer layer, exposed to the air.
+    #[default]
+    External,
+    /// An inner layer, surrounded by the board.
+    Internal,
+}
+
+impl Layer {
+    /// Returns the `k` constant of the IPC-2221 fit for the layer.
+    fn k(self) -> f64 {
+        match self {
+            Self::External => 0.048,
+            Self::Internal => 0.024,
+        }
+    }
+}
+
+/// Represents the weight of the copper on a board, stored as whole thousandths of an ounce per
+/// square foot as a 32-bit value, which sets its thickness.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CopperWeight {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_ounces"))]
+    raw: u32,
+}
+
+impl CopperWeight {
+    /// Half an ounce per square foot, about 17μm thick.
+    pub const HALF_OUNCE: Self = Self::from_milli_ounces(500);
+
+    /// One ounce per square foot, about 35μm thick.
+    pub const ONE_OUNCE: Self = Self::from_milli_ounces(1_000);
+
+    /// Two ounces per square foot, about 70μm thick.
+    pub const TWO_OUNCES: Self = Self::from_milli_ounces(2_000);
+
+    /// Creates a new `CopperWeight` from a number of whole thousandths of an ounce per square
+    /// foot.
+    #[inline]
+    pub const fn from_milli_ounces(value: u32) -> Self {
+        Self { raw: value }
+    }
+
+    /// Returns the copper weight in whole thousandths of an ounce per square foot.
+    #[inline]
+    pub const fn milli_ounces(&self) -> u32 {
+        self.raw
+    }
+
+    /// Returns the copper weight in fractional ounces per square foot.
+    #[inline]
+    pub fn ounces(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the thickness of the copper in fractional mils.
+    fn thickness_mils(&self) -> f64 {
+        self.ounces() * MILS_PER_OUNCE
+    }
+}
+
+impl fmt::Display for CopperWeight {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(
+            f,
+            format_args!("{}.{:03} oz", self.raw / 1_000, self.raw % 1_000),
+        )
+    }
+}
+
+/// Estimates the largest current a trace can carry for a temperature rise, given its width in
+/// whole micrometers (μm) and its copper weight.
+///
+/// Will be rounded down to the nearest whole microamp (μA).
+/// Returns `None` if the temperature rise is not positive or the result would overflow.
+pub fn max_current(
+    layer: Layer,
+    width_um: u64,
+    weight: CopperWeight,
+    rise: Temperature,
+) -> Option<Current> {
+    if rise.milli_degrees_celsius() <= 0 {
+        return None;
+    }
+
+    let area = width_um as f64 / MICRO_METERS_PER_MIL * weight.thickness_mils();
+    let amps = layer.k() * libm::pow(rise.degrees_celsius(), 0.44) * libm::pow(area, 0.725);
+
+    f64_to_u64(amps * 1_000_000f64).map(Current::from_micro_amps)
+}
+
+/// Estimates the narrowest trace width in whole micrometers (μm) that can carry a current for a
+/// temperature rise, given its copper weight.
+///
+/// Will be rounded up to the nearest whole micrometer (μm).
+/// Returns `None` if the temperature rise is not positive, the copper weight is zero or the
+/// result would overflow.
+pub fn min_width(
+    layer: Layer,
+    current: Current,
+    weight: CopperWeight,
+    rise: Temperature,
+) -> Option<u64> {
+    if rise.milli_degrees_celsius() <= 0 || weight.raw == 0 {
+        return None;
+    }
+
+    let amps = current.micro_amps() as f64 / 1_000_000f64;
+    let area = libm::pow(
+        amps / (layer.k() * libm::pow(rise.degrees_celsius(), 0.44)),
+        1f64 / 0.725,
+    );
+
+    f64_to_u64(libm::ceil(
+        area / weight.thickness_mils() * MICRO_METERS_PER_MIL,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(Layer::External, 254, 1_000, 10_000, Some(885_517); "external")]
+    #[test_case(Layer::Internal, 254, 1_000, 10_000, Some(442_758); "internal")]
+    #[test_case(Layer::External, 1_000, 2_000, 20_000, Some(5_362_810); "wide and heavy")]
+    #[test_case(Layer::External, 0, 1_000, 10_000, Some(0); "zero width")]
+    #[test_case(Layer::External, 254, 1_000, 0, None; "no rise")]
+    #[test_case(Layer::External, 254, 1_000, -10_000, None; "negative rise")]
+    #[test_case(Layer::External, u64::MAX, u32::MAX, i64::MAX, None; "overflow")]
+    fn test_max_current(
+        layer: Layer,
+        width_um: u64,
+        milli_ounces: u32,
+        milli_celsius: i64,
+        expected: Option<u64>,
+    ) {
+        let current = max_current(
+            layer,
+            width_um,
+            CopperWeight::from_milli_ounces(milli_ounces),
+            Temperature::from_milli_degrees_celsius(milli_celsius),
+        );
+        assert_eq!(current.map(|c| c.micro_amps()), expected);
+    }
+
+    #[test_case(Layer::External, 1_000_000, 1_000, 10_000, Some(301); "external")]
+    #[test_case(Layer::Internal, 1_000_000, 1_000, 10_000, Some(782); "internal")]
+    #[test_case(Layer::External, 5_000_000, 1_000, 20_000, Some(1_816); "5A")]
+    #[test_case(Layer::External, 0, 1_000, 10_000, Some(0); "no current")]
+    #[test_case(Layer::External, 1_000_000, 0, 10_000, None; "no copper")]
+    #[test_case(Layer::External, 1_000_000, 1_000, 0, None; "no rise")]
+    #[test_case(Layer::External, u64::MAX, 1, 1, None; "overflow")]
+    fn test_min_width(
+        layer: Layer,
+        micro_amps: u64,
+        milli_ounces: u32,
+        milli_celsius: i64,
+        expected: Option<u64>,
+    ) {
+        let width = min_width(
+            layer,
+            Current::from_micro_amps(micro_amps),
+            CopperWeight::from_milli_ounces(milli_ounces),
+            Temperature::from_milli_degrees_celsius(milli_celsius),
+        );
+        assert_eq!(width, expected);
+    }
+
+    #[test_case(Layer::External; "external")]
+    #[test_case(Layer::Internal; "internal")]
+    fn test_round_trip(layer: Layer) {
+        let rise = Temperature::from_milli_degrees_celsius(30_000);
+        let target = Current::from_micro_amps(3_000_000);
+
+        let width = min_width(layer, target, CopperWeight::TWO_OUNCES, rise).unwrap();
+        let current = max_current(layer, width, CopperWeight::TWO_OUNCES, rise).unwrap();
+
+        assert!(current >= target);
+        let narrower = max_current(layer, width - 1, CopperWeight::TWO_OUNCES, rise).unwrap();
+        assert!(narrower < target);
+    }
+
+    #[test_case(500, "0.500 oz"; "half")]
+    #[test_case(2_000, "2.000 oz"; "two")]
+    fn test_copper_weight_display(milli_ounces: u32, expected: &str) {
+        let weight = CopperWeight::from_milli_ounces(milli_ounces);
+        assert_eq!(format!("{weight}"), expected);
+    }
+}
@@ -1,6 +1,135 @@
-use crate::{Current, Power, Resistance, Voltage};
+#[cfg(all(
+    feature = "libm",
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
+use crate::math::f64_to_i64;
+#[cfg(feature = "resistance")]
+use crate::math::f64_to_u64;
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+use crate::math::mul_div;
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    any(feature = "resistance", feature = "power")
+))]
+use crate::math::mul_div_rounded;
+#[cfg(any(feature = "voltage", feature = "resistance"))]
+use crate::Capacitance;
+#[cfg(any(feature = "voltage", feature = "current"))]
+use crate::Capacity;
+#[cfg(any(feature = "voltage", feature = "current"))]
+use crate::Charge;
+#[cfg(all(feature = "voltage", feature = "current"))]
+use crate::Conductance;
+#[cfg(feature = "current")]
+use crate::Current;
+#[cfg(any(feature = "voltage", feature = "power"))]
+use crate::Energy;
+#[cfg(feature = "power")]
+use crate::Power;
+use crate::RoundingMode;
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    any(feature = "resistance", feature = "power")
+))]
+use crate::SignedCurrent;
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+use crate::SignedPower;
+#[cfg(feature = "voltage")]
+use crate::Voltage;
+#[cfg(feature = "resistance")]
+use crate::{Frequency, Resistance};
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+use crate::{Impedance, NonZeroCurrent, NonZeroResistance};
+#[cfg(feature = "resistance")]
+use core::f64::consts::PI;
+#[cfg(any(
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
 use core::ops;
+#[cfg(any(feature = "current", feature = "resistance", feature = "power"))]
+use core::time::Duration;
 
+/// Division between unit types that returns `None` instead of panicking.
+///
+/// This mirrors the `/` operator for the Ohm's Law relations, for use where a zero divisor or an
+/// overflow must be handled rather than panicking.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
+/// use ohms::prelude::*;
+///
+/// let current = 5.volts().checked_div(Resistance::zero());
+/// assert_eq!(current, None);
+/// # }
+/// ```
+pub trait CheckedDiv<Rhs> {
+    /// The resulting type of the division.
+    type Output;
+
+    /// Divides `self` by `rhs`, returning `None` if `rhs` is zero or the result would overflow.
+    fn checked_div(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Multiplication between unit types that returns `None` instead of panicking.
+///
+/// This mirrors the `*` operator for the Ohm's Law relations, for use where an overflow must be
+/// handled rather than panicking.
+pub trait CheckedMul<Rhs> {
+    /// The resulting type of the multiplication.
+    type Output;
+
+    /// Multiplies `self` by `rhs`, returning `None` if the result would overflow.
+    fn checked_mul(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+/// Division between unit types that rounds the result in a chosen direction, returning `None`
+/// instead of panicking.
+///
+/// The `/` operator and [`CheckedDiv`] always round down, which biases results that are converted
+/// repeatedly. This rounds to the nearest value, or up or down, instead.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
+/// use ohms::prelude::*;
+///
+/// let current = 5.volts().div_rounded(3.ohms(), RoundingMode::Nearest);
+/// assert_eq!(current, Some(1_666_667.micro_amps()));
+/// # }
+/// ```
+pub trait RoundedDiv<Rhs> {
+    /// The resulting type of the division.
+    type Output;
+
+    /// Divides `self` by `rhs`, rounding in the direction of `mode`.
+    ///
+    /// Returns `None` if `rhs` is zero or the result would overflow.
+    fn div_rounded(self, rhs: Rhs, mode: RoundingMode) -> Option<Self::Output>;
+}
+
+/// Multiplication between unit types that rounds the result in a chosen direction, returning
+/// `None` instead of panicking.
+///
+/// The `*` operator and [`CheckedMul`] always round down. This rounds to the nearest value, or up
+/// or down, instead.
+pub trait RoundedMul<Rhs> {
+    /// The resulting type of the multiplication.
+    type Output;
+
+    /// Multiplies `self` by `rhs`, rounding in the direction of `mode`.
+    ///
+    /// Returns `None` if the result would overflow.
+    fn mul_rounded(self, rhs: Rhs, mode: RoundingMode) -> Option<Self::Output>;
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
 impl ops::Div<Resistance> for Voltage {
     type Output = Current;
 
@@ -13,41 +142,26 @@ impl ops::Div<Resistance> for Voltage {
             panic!("Resistance cannot be zero, infinite current would result");
         }
 
-        let micro_volts = self.micro_volts().unsigned_abs();
-
-        let nano_volts = micro_volts
-            .checked_mul(1_000u64)
-            .expect("Voltage would overflow");
-
-        let micro_amps = nano_volts
-            .checked_div(resistance.milli_ohms() as u64)
-            .expect("Current would overflow");
-
-        Current::from_micro_amps(micro_amps as u64)
+        self.checked_div(resistance)
+            .expect("Current would overflow")
     }
 }
 
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
 impl ops::Mul<Resistance> for Current {
     type Output = Voltage;
 
     /// Calculates the voltage across a resistive load given the current through it.
     ///
     /// Will be rounded down to the nearest whole microvolt (μV).
+    /// Panics if the result would overflow.
     fn mul(self, resistance: Resistance) -> Self::Output {
-        let micro_amps = self.micro_amps();
-
-        let nano_volts = micro_amps
-            .checked_mul(resistance.milli_ohms())
-            .expect("Voltage would overflow");
-
-        let micro_volts = nano_volts
-            .checked_div(1_000u64)
-            .expect("Voltage would overflow");
-
-        Voltage::from_micro_volts(micro_volts as i64)
+        self.checked_mul(resistance)
+            .expect("Voltage would overflow")
     }
 }
 
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
 impl ops::Mul<Current> for Resistance {
     type Output = Voltage;
 
@@ -59,6 +173,7 @@ impl ops::Mul<Current> for Resistance {
     }
 }
 
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
 impl ops::Div<Current> for Voltage {
     type Output = Resistance;
 
@@ -71,20 +186,234 @@ impl ops::Div<Current> for Voltage {
             panic!("Current cannot be zero, infinite resistance would result");
         }
 
+        self.checked_div(current)
+            .expect("Resistance would overflow")
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl CheckedDiv<Resistance> for Voltage {
+    type Output = Current;
+
+    /// Calculates the current through a resistive load given the voltage across it.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Returns `None` if the resistance is zero or the result would overflow.
+    fn checked_div(self, resistance: Resistance) -> Option<Self::Output> {
+        self.div_rounded(resistance, RoundingMode::Down)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl RoundedDiv<Resistance> for Voltage {
+    type Output = Current;
+
+    /// Calculates the current through a resistive load given the voltage across it.
+    ///
+    /// Will be rounded to a whole microamp (μA) in the direction of `mode`.
+    /// Returns `None` if the resistance is zero or the result would overflow.
+    fn div_rounded(self, resistance: Resistance, mode: RoundingMode) -> Option<Self::Output> {
+        let micro_volts = self.micro_volts().unsigned_abs();
+
+        mul_div_rounded(micro_volts, 1_000, resistance.milli_ohms(), mode)
+            .map(Current::from_micro_amps)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl CheckedMul<Resistance> for Current {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a resistive load given the current through it.
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    /// Returns `None` if the result would overflow.
+    fn checked_mul(self, resistance: Resistance) -> Option<Self::Output> {
+        self.mul_rounded(resistance, RoundingMode::Down)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl RoundedMul<Resistance> for Current {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a resistive load given the current through it.
+    ///
+    /// Will be rounded to a whole microvolt (μV) in the direction of `mode`.
+    /// Returns `None` if the result would overflow.
+    fn mul_rounded(self, resistance: Resistance, mode: RoundingMode) -> Option<Self::Output> {
+        let micro_volts = mul_div_rounded(self.micro_amps(), resistance.milli_ohms(), 1_000, mode)?;
+
+        i64::try_from(micro_volts)
+            .ok()
+            .map(Voltage::from_micro_volts)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl RoundedMul<Current> for Resistance {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a resistive load given the current through it.
+    ///
+    /// Will be rounded to a whole microvolt (μV) in the direction of `mode`.
+    /// Returns `None` if the result would overflow.
+    fn mul_rounded(self, current: Current, mode: RoundingMode) -> Option<Self::Output> {
+        current.mul_rounded(self, mode)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl CheckedMul<Current> for Resistance {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a resistive load given the current through it.
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    /// Returns `None` if the result would overflow.
+    fn checked_mul(self, current: Current) -> Option<Self::Output> {
+        current.checked_mul(self)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl CheckedDiv<Current> for Voltage {
+    type Output = Resistance;
+
+    /// Calculates the resistance of a resistive load given the voltage across it and the current.
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    /// Returns `None` if the current is zero or the result would overflow.
+    fn checked_div(self, current: Current) -> Option<Self::Output> {
+        self.div_rounded(current, RoundingMode::Down)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl RoundedDiv<Current> for Voltage {
+    type Output = Resistance;
+
+    /// Calculates the resistance of a resistive load given the voltage across it and the current.
+    ///
+    /// Will be rounded to a whole milliohm (mΩ) in the direction of `mode`.
+    /// Returns `None` if the current is zero or the result would overflow.
+    fn div_rounded(self, current: Current, mode: RoundingMode) -> Option<Self::Output> {
         let micro_volts = self.micro_volts().unsigned_abs();
 
-        let nano_volts = micro_volts
-            .checked_mul(1_000u64)
-            .expect("Voltage would overflow");
+        mul_div_rounded(micro_volts, 1_000, current.micro_amps(), mode)
+            .map(Resistance::from_milli_ohms)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl ops::Mul<Resistance> for SignedCurrent {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a resistive load given the current through it, keeping the
+    /// direction of the current as the polarity of the voltage.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt (μV).
+    /// Panics if the result would overflow.
+    fn mul(self, resistance: Resistance) -> Self::Output {
+        self.checked_mul(resistance)
+            .expect("Voltage would overflow")
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl ops::Mul<SignedCurrent> for Resistance {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a resistive load given the current through it, keeping the
+    /// direction of the current as the polarity of the voltage.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt (μV).
+    fn mul(self, current: SignedCurrent) -> Self::Output {
+        current * self
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl CheckedMul<Resistance> for SignedCurrent {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a resistive load given the current through it, keeping the
+    /// direction of the current as the polarity of the voltage.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt (μV).
+    /// Returns `None` if the result would overflow.
+    fn checked_mul(self, resistance: Resistance) -> Option<Self::Output> {
+        let voltage = self.magnitude().checked_mul(resistance)?;
+
+        match self.is_negative() {
+            true => voltage.checked_neg(),
+            false => Some(voltage),
+        }
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl CheckedMul<SignedCurrent> for Resistance {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a resistive load given the current through it, keeping the
+    /// direction of the current as the polarity of the voltage.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt (μV).
+    /// Returns `None` if the result would overflow.
+    fn checked_mul(self, current: SignedCurrent) -> Option<Self::Output> {
+        current.checked_mul(self)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl Voltage {
+    /// Calculates the current through a resistive load given the voltage across it, keeping the
+    /// polarity of the voltage as the direction of the current.
+    ///
+    /// Unlike dividing by the resistance, a negative voltage results in a negative current.
+    ///
+    /// Will be rounded toward zero to the nearest whole microamp (μA).
+    /// Panics if the resistance is zero or the result would overflow.
+    pub fn signed_current(&self, resistance: Resistance) -> SignedCurrent {
+        let current = SignedCurrent::try_from(*self / resistance).expect("Current would overflow");
+
+        match self.is_negative() {
+            true => -current,
+            false => current,
+        }
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl ops::Div<NonZeroResistance> for Voltage {
+    type Output = Option<Current>;
+
+    /// Calculates the current through a resistive load given the voltage across it.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// As the resistance cannot be zero, this never panics and returns `None` only if the result
+    /// would overflow.
+    fn div(self, resistance: NonZeroResistance) -> Self::Output {
+        self.checked_div(resistance.get())
+    }
+}
 
-        let milli_ohms = nano_volts
-            .checked_div(current.micro_amps())
-            .expect("Resistance would overflow");
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl ops::Div<NonZeroCurrent> for Voltage {
+    type Output = Option<Resistance>;
 
-        Resistance::from_milli_ohms(milli_ohms)
+    /// Calculates the resistance of a resistive load given the voltage across it and the current.
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    /// As the current cannot be zero, this never panics and returns `None` only if the result would
+    /// overflow.
+    fn div(self, current: NonZeroCurrent) -> Self::Output {
+        self.checked_div(current.get())
     }
 }
 
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
 impl ops::Mul<Current> for Voltage {
     type Output = Power;
 
@@ -96,6 +425,7 @@ impl ops::Mul<Current> for Voltage {
     }
 }
 
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
 impl ops::Mul<Voltage> for Current {
     type Output = Power;
 
@@ -103,109 +433,1472 @@ impl ops::Mul<Voltage> for Current {
     ///
     /// Will be rounded down to the nearest whole microwatt (μW).
     fn mul(self, voltage: Voltage) -> Self::Output {
+        self.mul_rounded(voltage, RoundingMode::Down)
+            .expect("Power would overflow")
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl RoundedMul<Voltage> for Current {
+    type Output = Power;
+
+    /// Calculates the power dissipated by a resistive load given the voltage across it and the current.
+    ///
+    /// Will be rounded to a whole microwatt (μW) in the direction of `mode`.
+    /// Returns `None` if the result would overflow.
+    fn mul_rounded(self, voltage: Voltage, mode: RoundingMode) -> Option<Self::Output> {
         let micro_volts = voltage.micro_volts().unsigned_abs();
-        let micro_amps = self.micro_amps();
-        let pico_watts = micro_volts
-            .checked_mul(micro_amps)
-            .expect("Power would overflow");
 
-        let micro_watts = pico_watts
-            .checked_div(1_000_000u64)
-            .expect("Power would overflow");
+        mul_div_rounded(micro_volts, self.micro_amps(), 1_000_000, mode)
+            .map(Power::from_micro_watts)
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl RoundedMul<Current> for Voltage {
+    type Output = Power;
 
-        Power::from_micro_watts(micro_watts)
+    /// Calculates the power dissipated by a resistive load given the voltage across it and the current.
+    ///
+    /// Will be rounded to a whole microwatt (μW) in the direction of `mode`.
+    /// Returns `None` if the result would overflow.
+    fn mul_rounded(self, current: Current, mode: RoundingMode) -> Option<Self::Output> {
+        current.mul_rounded(self, mode)
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use test_case::test_case;
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl ops::Mul<SignedCurrent> for Voltage {
+    type Output = SignedPower;
 
-    #[test_case(5_000_000, 100_000, 50_000; "positive 5V, 100Ω equals 50,000μA")]
-    #[test_case(-5_000_000, 100_000, 50_000; "negative 5V, 100Ω equals 50,000μA")]
-    #[test_case(3_300_000, 4_700_000, 702; "positive 3.3V, 4.7kΩ equals 702μA")]
-    fn test_current_equals_voltage_over_resistance(
-        micro_volts: i64,
-        milli_ohms: u64,
-        expected_micro_amps: u64,
-    ) {
-        let v = Voltage::from_micro_volts(micro_volts);
-        let r = Resistance::from_milli_ohms(milli_ohms);
-        let current = v / r;
+    /// Calculates the power flowing through a load given the voltage across it and the current,
+    /// where a negative result means power is flowing back into the source.
+    ///
+    /// Will be rounded toward zero to the nearest whole microwatt (μW).
+    /// Panics if the result would overflow.
+    fn mul(self, current: SignedCurrent) -> Self::Output {
+        self.checked_mul(current).expect("Power would overflow")
+    }
+}
 
-        assert_eq!(current.micro_amps(), expected_micro_amps);
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl ops::Mul<Voltage> for SignedCurrent {
+    type Output = SignedPower;
+
+    /// Calculates the power flowing through a load given the voltage across it and the current,
+    /// where a negative result means power is flowing back into the source.
+    ///
+    /// Will be rounded toward zero to the nearest whole microwatt (μW).
+    fn mul(self, voltage: Voltage) -> Self::Output {
+        voltage * self
     }
+}
 
-    #[test_case(25_000, 75_000, 1_875_000; "25mA, 75Ω equals 1_875_000μV")]
-    #[test_case(39_000, 162_000, 6_318_000; "39mA, 162Ω equals 6_318_000μV")]
-    fn test_voltage_equals_current_times_resistance(
-        micro_amps: u64,
-        milli_ohms: u64,
-        expected_micro_volts: i64,
-    ) {
-        let i = Current::from_micro_amps(micro_amps);
-        let r = Resistance::from_milli_ohms(milli_ohms);
-        let voltage = i * r;
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl CheckedMul<SignedCurrent> for Voltage {
+    type Output = SignedPower;
 
-        assert_eq!(voltage.micro_volts(), expected_micro_volts);
+    /// Calculates the power flowing through a load given the voltage across it and the current,
+    /// where a negative result means power is flowing back into the source.
+    ///
+    /// Will be rounded toward zero to the nearest whole microwatt (μW).
+    /// Returns `None` if the result would overflow.
+    fn checked_mul(self, current: SignedCurrent) -> Option<Self::Output> {
+        let micro_volts = self.micro_volts().unsigned_abs();
+        let micro_watts = mul_div(micro_volts, current.magnitude().micro_amps(), 1_000_000)?;
+        let power = SignedPower::try_from(Power::from_micro_watts(micro_watts)).ok()?;
+
+        match self.is_negative() != current.is_negative() {
+            true => power.checked_neg(),
+            false => Some(power),
+        }
     }
+}
 
-    #[test_case(25_000, 75_000, 1_875_000; "25mA, 75Ω equals 1_875_000μV")]
-    #[test_case(39_000, 162_000, 6_318_000; "39mA, 162Ω equals 6_318_000μV")]
-    fn test_voltage_equals_resistance_times_current(
-        micro_amps: u64,
-        milli_ohms: u64,
-        expected_micro_volts: i64,
-    ) {
-        let r = Resistance::from_milli_ohms(milli_ohms);
-        let i = Current::from_micro_amps(micro_amps);
-        let voltage = r * i;
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl CheckedMul<Voltage> for SignedCurrent {
+    type Output = SignedPower;
 
-        assert_eq!(voltage.micro_volts(), expected_micro_volts);
+    /// Calculates the power flowing through a load given the voltage across it and the current,
+    /// where a negative result means power is flowing back into the source.
+    ///
+    /// Will be rounded toward zero to the nearest whole microwatt (μW).
+    /// Returns `None` if the result would overflow.
+    fn checked_mul(self, voltage: Voltage) -> Option<Self::Output> {
+        voltage.checked_mul(self)
     }
+}
 
-    #[test_case(1_875_000, 25_000, 75_000; "positive 1.875V, 25mA equals 75Ω")]
-    #[test_case(-1_875_000, 25_000, 75_000; "negative 1.875V, 25mA equals 75Ω")]
-    #[test_case(6_318_000, 39_000, 162_000; "positive 6.318V, 39mA equals 162Ω")]
-    #[test_case(-6_318_000, 39_000, 162_000; "negative 6.318V, 39mA equals 162Ω")]
-    fn test_resistance_equals_voltage_over_current(
-        micro_volts: i64,
-        micro_amps: u64,
-        expected_milli_ohms: u64,
-    ) {
-        let v = Voltage::from_micro_volts(micro_volts);
-        let i = Current::from_micro_amps(micro_amps);
-        let resistance = v / i;
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl ops::Div<Voltage> for Power {
+    type Output = Current;
 
-        assert_eq!(resistance.milli_ohms(), expected_milli_ohms);
+    /// Calculates the current drawn by a load given its power dissipation and the voltage across it.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Panics if the voltage is zero.
+    fn div(self, voltage: Voltage) -> Self::Output {
+        if voltage.is_zero() {
+            panic!("Voltage cannot be zero, infinite current would result");
+        }
+
+        let pico_watts = self.micro_watts() as u128 * 1_000_000u128;
+        let micro_amps = pico_watts / voltage.micro_volts().unsigned_abs() as u128;
+
+        Current::from_micro_amps(u64::try_from(micro_amps).expect("Current would overflow"))
     }
+}
 
-    #[test_case(5_000_000, 1_000_000, 5_000_000; "positive 5V, 1A equals 5W")]
-    #[test_case(-5_000_000, 1_000_000, 5_000_000; "negative 5V, 1A equals 5W")]
-    fn test_power_equals_voltage_times_current(
-        micro_volts: i64,
-        micro_amps: u64,
-        expected_micro_watts: u64,
-    ) {
-        let v = Voltage::from_micro_volts(micro_volts);
-        let i = Current::from_micro_amps(micro_amps);
-        let power = v * i;
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl ops::Div<Current> for Power {
+    type Output = Voltage;
 
-        assert_eq!(power.micro_watts(), expected_micro_watts);
+    /// Calculates the voltage across a load given its power dissipation and the current through it.
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    /// Panics if the current is zero.
+    fn div(self, current: Current) -> Self::Output {
+        if current.is_zero() {
+            panic!("Current cannot be zero, infinite voltage would result");
+        }
+
+        let pico_watts = self.micro_watts() as u128 * 1_000_000u128;
+        let micro_volts = pico_watts / current.micro_amps() as u128;
+
+        Voltage::from_micro_volts(i64::try_from(micro_volts).expect("Voltage would overflow"))
     }
+}
 
-    #[test_case(5_000_000, 1_000_000, 5_000_000; "positive 5V, 1A equals 5W")]
-    #[test_case(-5_000_000, 1_000_000, 5_000_000; "negative 5V, 1A equals 5W")]
-    fn test_power_equals_current_times_voltage(
-        micro_volts: i64,
-        micro_amps: u64,
-        expected_micro_watts: u64,
-    ) {
-        let v = Voltage::from_micro_volts(micro_volts);
-        let i = Current::from_micro_amps(micro_amps);
-        let power = i * v;
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl ops::Div<Impedance> for Voltage {
+    type Output = Current;
 
-        assert_eq!(power.micro_watts(), expected_micro_watts);
+    /// Calculates the magnitude of the current through a load given the magnitude of the voltage
+    /// across it and its impedance.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Panics if the impedance is zero.
+    fn div(self, impedance: Impedance) -> Self::Output {
+        if impedance.is_zero() {
+            panic!("Impedance cannot be zero, infinite current would result");
+        }
+
+        self / impedance.magnitude()
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl ops::Mul<Impedance> for Current {
+    type Output = Voltage;
+
+    /// Calculates the magnitude of the voltage across a load given the magnitude of the current
+    /// through it and its impedance.
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    fn mul(self, impedance: Impedance) -> Self::Output {
+        self * impedance.magnitude()
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl ops::Mul<Current> for Impedance {
+    type Output = Voltage;
+
+    /// Calculates the magnitude of the voltage across a load given the magnitude of the current
+    /// through it and its impedance.
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    fn mul(self, current: Current) -> Self::Output {
+        current * self
+    }
+}
+
+#[cfg(feature = "resistance")]
+impl ops::Mul<Capacitance> for Resistance {
+    type Output = Duration;
+
+    /// Calculates the time constant of an RC circuit (`τ = RC`).
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole microsecond (μs).
+    fn mul(self, capacitance: Capacitance) -> Self::Output {
+        let femto_seconds = (self.milli_ohms() as u128)
+            .checked_mul(capacitance.pico_farads() as u128)
+            .expect("Time constant would overflow");
+
+        let micro_seconds = femto_seconds
+            .checked_div(1_000_000_000u128)
+            .expect("Time constant would overflow");
+
+        Duration::from_micros(u64::try_from(micro_seconds).expect("Time constant would overflow"))
+    }
+}
+
+#[cfg(feature = "resistance")]
+impl ops::Mul<Resistance> for Capacitance {
+    type Output = Duration;
+
+    /// Calculates the time constant of an RC circuit (`τ = RC`).
+    ///
+    /// Will be rounded down to the nearest whole microsecond (μs).
+    fn mul(self, resistance: Resistance) -> Self::Output {
+        resistance * self
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl ops::Mul<Voltage> for Capacitance {
+    type Output = Charge;
+
+    /// Calculates the charge stored in a capacitance given the voltage across it (`Q = CV`).
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole microcoulomb (μC).
+    fn mul(self, voltage: Voltage) -> Self::Output {
+        let atto_coulombs = (self.pico_farads() as u128)
+            .checked_mul(voltage.micro_volts().unsigned_abs() as u128)
+            .expect("Charge would overflow");
+
+        let micro_coulombs = atto_coulombs
+            .checked_div(1_000_000_000_000u128)
+            .expect("Charge would overflow");
+
+        Charge::from_micro_coulombs(u64::try_from(micro_coulombs).expect("Charge would overflow"))
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl ops::Mul<Capacitance> for Voltage {
+    type Output = Charge;
+
+    /// Calculates the charge stored in a capacitance given the voltage across it (`Q = CV`).
+    ///
+    /// Will be rounded down to the nearest whole microcoulomb (μC).
+    fn mul(self, capacitance: Capacitance) -> Self::Output {
+        capacitance * self
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl ops::Div<Voltage> for Charge {
+    type Output = Capacitance;
+
+    /// Calculates the capacitance given the charge stored and the voltage across it (`C = Q / V`).
+    ///
+    /// Will be rounded down to the nearest whole picofarad (pF).
+    /// Panics if the voltage is zero.
+    fn div(self, voltage: Voltage) -> Self::Output {
+        if voltage.is_zero() {
+            panic!("Voltage cannot be zero, infinite capacitance would result");
+        }
+
+        let atto_coulombs = self.micro_coulombs() as u128 * 1_000_000_000_000u128;
+        let pico_farads = atto_coulombs / voltage.micro_volts().unsigned_abs() as u128;
+
+        Capacitance::from_pico_farads(
+            u64::try_from(pico_farads).expect("Capacitance would overflow"),
+        )
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl ops::Div<Capacitance> for Charge {
+    type Output = Voltage;
+
+    /// Calculates the voltage across a capacitance given the charge stored (`V = Q / C`).
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    /// Panics if the capacitance is zero.
+    fn div(self, capacitance: Capacitance) -> Self::Output {
+        if capacitance.is_zero() {
+            panic!("Capacitance cannot be zero, infinite voltage would result");
+        }
+
+        let atto_coulombs = self.micro_coulombs() as u128 * 1_000_000_000_000u128;
+        let micro_volts = atto_coulombs / capacitance.pico_farads() as u128;
+
+        Voltage::from_micro_volts(i64::try_from(micro_volts).expect("Voltage would overflow"))
+    }
+}
+
+/// Calculates the cutoff frequency of a first-order RC filter (`f = 1 / 2πRC`).
+///
+/// Will be rounded down to the nearest whole millihertz (mHz).
+/// Panics if the resistance or capacitance is zero.
+///
+/// ```rust
+/// use ohms::prelude::*;
+/// use ohms::cutoff_frequency;
+///
+/// let f = cutoff_frequency(1.kilo_ohms(), 100.nano_farads());
+/// assert_eq!(f.milli_hertz(), 1_591_549);
+/// ```
+#[cfg(feature = "resistance")]
+pub fn cutoff_frequency(resistance: Resistance, capacitance: Capacitance) -> Frequency {
+    if resistance.is_zero() || capacitance.is_zero() {
+        panic!("Resistance and capacitance cannot be zero, infinite frequency would result");
+    }
+
+    let milli_hertz =
+        1e18f64 / (2f64 * PI * resistance.milli_ohms() as f64 * capacitance.pico_farads() as f64);
+
+    Frequency::from_milli_hertz(f64_to_u64(milli_hertz).expect("Frequency would overflow"))
+}
+
+/// Calculates the resistance required for a first-order RC filter to have the given cutoff
+/// frequency with the given capacitance (`R = 1 / 2πfC`).
+///
+/// Will be rounded down to the nearest whole milliohm (mΩ).
+/// Panics if the frequency or capacitance is zero.
+#[cfg(feature = "resistance")]
+pub fn resistance_for_cutoff(frequency: Frequency, capacitance: Capacitance) -> Resistance {
+    if frequency.is_zero() || capacitance.is_zero() {
+        panic!("Frequency and capacitance cannot be zero, infinite resistance would result");
+    }
+
+    let milli_ohms =
+        1e18f64 / (2f64 * PI * frequency.milli_hertz() as f64 * capacitance.pico_farads() as f64);
+
+    Resistance::from_milli_ohms(f64_to_u64(milli_ohms).expect("Resistance would overflow"))
+}
+
+/// Calculates the capacitance required for a first-order RC filter to have the given cutoff
+/// frequency with the given resistance (`C = 1 / 2πfR`).
+///
+/// Will be rounded down to the nearest whole picofarad (pF).
+/// Panics if the frequency or resistance is zero.
+#[cfg(feature = "resistance")]
+pub fn capacitance_for_cutoff(frequency: Frequency, resistance: Resistance) -> Capacitance {
+    if frequency.is_zero() || resistance.is_zero() {
+        panic!("Frequency and resistance cannot be zero, infinite capacitance would result");
+    }
+
+    let pico_farads =
+        1e18f64 / (2f64 * PI * frequency.milli_hertz() as f64 * resistance.milli_ohms() as f64);
+
+    Capacitance::from_pico_farads(f64_to_u64(pico_farads).expect("Capacitance would overflow"))
+}
+
+#[cfg(all(feature = "voltage", feature = "current"))]
+impl ops::Mul<Conductance> for Voltage {
+    type Output = Current;
+
+    /// Calculates the current through a load given the voltage across it and its conductance.
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    fn mul(self, conductance: Conductance) -> Self::Output {
+        let femto_amps = (self.micro_volts().unsigned_abs() as u128)
+            .checked_mul(conductance.nano_siemens() as u128)
+            .expect("Current would overflow");
+
+        let micro_amps = femto_amps
+            .checked_div(1_000_000_000u128)
+            .expect("Current would overflow");
+
+        Current::from_micro_amps(u64::try_from(micro_amps).expect("Current would overflow"))
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "current"))]
+impl ops::Mul<Voltage> for Conductance {
+    type Output = Current;
+
+    /// Calculates the current through a load given the voltage across it and its conductance.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    fn mul(self, voltage: Voltage) -> Self::Output {
+        voltage * self
+    }
+}
+
+#[cfg(feature = "power")]
+impl ops::Mul<Duration> for Power {
+    type Output = Energy;
+
+    /// Calculates the energy consumed by a load given its power dissipation over a duration.
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    fn mul(self, duration: Duration) -> Self::Output {
+        let femto_joules = (self.micro_watts() as u128)
+            .checked_mul(duration.as_nanos())
+            .expect("Energy would overflow");
+
+        let nano_joules = femto_joules
+            .checked_div(1_000_000u128)
+            .expect("Energy would overflow");
+
+        Energy::from_nano_joules(u64::try_from(nano_joules).expect("Energy would overflow"))
+    }
+}
+
+#[cfg(feature = "power")]
+impl ops::Mul<Power> for Duration {
+    type Output = Energy;
+
+    /// Calculates the energy consumed by a load given its power dissipation over a duration.
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    fn mul(self, power: Power) -> Self::Output {
+        power * self
+    }
+}
+
+#[cfg(feature = "power")]
+impl ops::Div<Duration> for Energy {
+    type Output = Power;
+
+    /// Calculates the average power dissipated by a load given the energy consumed over a duration.
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW).
+    /// Panics if the duration is zero.
+    fn div(self, duration: Duration) -> Self::Output {
+        if duration.is_zero() {
+            panic!("Duration cannot be zero, infinite power would result");
+        }
+
+        let femto_joules = self.nano_joules() as u128 * 1_000_000u128;
+        let micro_watts = femto_joules / duration.as_nanos();
+
+        Power::from_micro_watts(u64::try_from(micro_watts).expect("Power would overflow"))
+    }
+}
+
+#[cfg(feature = "current")]
+impl ops::Mul<Duration> for Current {
+    type Output = Charge;
+
+    /// Calculates the charge transferred by a constant current over a duration.
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole microcoulomb (μC).
+    fn mul(self, duration: Duration) -> Self::Output {
+        let femto_coulombs = (self.micro_amps() as u128)
+            .checked_mul(duration.as_nanos())
+            .expect("Charge would overflow");
+
+        let micro_coulombs = femto_coulombs
+            .checked_div(1_000_000_000u128)
+            .expect("Charge would overflow");
+
+        Charge::from_micro_coulombs(u64::try_from(micro_coulombs).expect("Charge would overflow"))
+    }
+}
+
+#[cfg(feature = "current")]
+impl ops::Mul<Current> for Duration {
+    type Output = Charge;
+
+    /// Calculates the charge transferred by a constant current over a duration.
+    ///
+    /// Will be rounded down to the nearest whole microcoulomb (μC).
+    fn mul(self, current: Current) -> Self::Output {
+        current * self
+    }
+}
+
+#[cfg(feature = "current")]
+impl ops::Div<Duration> for Charge {
+    type Output = Current;
+
+    /// Calculates the average current given the charge transferred over a duration.
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Panics if the duration is zero.
+    fn div(self, duration: Duration) -> Self::Output {
+        if duration.is_zero() {
+            panic!("Duration cannot be zero, infinite current would result");
+        }
+
+        let femto_coulombs = self.micro_coulombs() as u128 * 1_000_000_000u128;
+        let micro_amps = femto_coulombs / duration.as_nanos();
+
+        Current::from_micro_amps(u64::try_from(micro_amps).expect("Current would overflow"))
+    }
+}
+
+#[cfg(feature = "current")]
+impl ops::Div<Current> for Capacity {
+    type Output = Duration;
+
+    /// Calculates the runtime of a battery given a constant load current (`t = C / I`).
+    ///
+    /// Will be rounded down to the nearest whole nanosecond (ns).
+    /// Panics if the current is zero.
+    fn div(self, current: Current) -> Self::Output {
+        if current.is_zero() {
+            panic!("Current cannot be zero, infinite runtime would result");
+        }
+
+        let nano_seconds =
+            self.micro_amp_hours() as u128 * 3_600_000_000_000u128 / current.micro_amps() as u128;
+
+        Duration::new(
+            u64::try_from(nano_seconds / 1_000_000_000).expect("Runtime would overflow"),
+            (nano_seconds % 1_000_000_000) as u32,
+        )
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl ops::Mul<Voltage> for Capacity {
+    type Output = Energy;
+
+    /// Calculates the energy stored in a battery given its nominal voltage (`E = CV`).
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    fn mul(self, voltage: Voltage) -> Self::Output {
+        // One microamp-hour at one microvolt is 3.6 nanojoules
+        let deci_nano_joules = (self.micro_amp_hours() as u128)
+            .checked_mul(voltage.micro_volts().unsigned_abs() as u128)
+            .and_then(|product| product.checked_mul(36))
+            .expect("Energy would overflow");
+
+        let nano_joules = deci_nano_joules
+            .checked_div(10u128)
+            .expect("Energy would overflow");
+
+        Energy::from_nano_joules(u64::try_from(nano_joules).expect("Energy would overflow"))
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl ops::Mul<Capacity> for Voltage {
+    type Output = Energy;
+
+    /// Calculates the energy stored in a battery given its nominal voltage (`E = CV`).
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    fn mul(self, capacity: Capacity) -> Self::Output {
+        capacity * self
+    }
+}
+
+#[cfg(all(feature = "current", feature = "resistance", feature = "power"))]
+impl Current {
+    /// Calculates the power dissipated by a resistive load given the current through it (`P = I²R`).
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW).
+    pub fn power_through(&self, resistance: Resistance) -> Power {
+        let micro_amps = self.micro_amps() as u128;
+
+        let femto_watts = (micro_amps * micro_amps)
+            .checked_mul(resistance.milli_ohms() as u128)
+            .expect("Power would overflow");
+
+        let micro_watts = femto_watts / 1_000_000_000u128;
+
+        Power::from_micro_watts(u64::try_from(micro_watts).expect("Power would overflow"))
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "resistance", feature = "power"))]
+impl Voltage {
+    /// Calculates the power dissipated by a resistive load given the voltage across it (`P = V²/R`).
+    ///
+    /// Uses 128-bit intermediate values, so only a result that cannot be represented will overflow.
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW).
+    /// Panics if the resistance is zero.
+    pub fn power_across(&self, resistance: Resistance) -> Power {
+        if resistance.is_zero() {
+            panic!("Resistance cannot be zero, infinite power would result");
+        }
+
+        let micro_volts = self.micro_volts().unsigned_abs() as u128;
+        let micro_ohms = resistance.milli_ohms() as u128 * 1_000u128;
+
+        let micro_watts = (micro_volts * micro_volts) / micro_ohms;
+
+        Power::from_micro_watts(u64::try_from(micro_watts).expect("Power would overflow"))
+    }
+}
+
+#[cfg(all(
+    feature = "libm",
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
+impl Resistance {
+    /// Calculates the current through the resistance that would dissipate the given power
+    /// (`I = √(P/R)`).
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Panics if the resistance is zero.
+    ///
+    /// Requires the `libm` feature.
+    pub fn current_for_power(&self, power: Power) -> Current {
+        if self.is_zero() {
+            panic!("Resistance cannot be zero, infinite current would result");
+        }
+
+        let micro_amps_squared =
+            power.micro_watts() as f64 * 1_000_000_000f64 / self.milli_ohms() as f64;
+        let micro_amps =
+            f64_to_u64(libm::sqrt(micro_amps_squared)).expect("Current would overflow");
+
+        Current::from_micro_amps(micro_amps)
+    }
+
+    /// Calculates the voltage across the resistance that would dissipate the given power
+    /// (`V = √(P·R)`).
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    ///
+    /// Requires the `libm` feature.
+    pub fn voltage_for_power(&self, power: Power) -> Voltage {
+        let micro_volts_squared = power.micro_watts() as f64 * self.milli_ohms() as f64 * 1_000f64;
+        let micro_volts =
+            f64_to_i64(libm::sqrt(micro_volts_squared)).expect("Voltage would overflow");
+
+        Voltage::from_micro_volts(micro_volts)
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    )
+))]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(5_000_000, 220_000, Some(22_727); "5V, 220Ω equals 22.727mA")]
+    #[test_case(-5_000_000, 220_000, Some(22_727); "negative voltage uses magnitude")]
+    #[test_case(5_000_000, 0, None; "zero resistance is none")]
+    #[test_case(i64::MAX, 1, None; "overflow is none")]
+    #[test_case(i64::MAX, 1_000_000, Some(9_223_372_036_854_775); "large intermediate")]
+    fn test_checked_current_equals_voltage_over_resistance(
+        micro_volts: i64,
+        milli_ohms: u64,
+        expected_micro_amps: Option<u64>,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+
+        assert_eq!(
+            v.checked_div(r).map(|i| i.micro_amps()),
+            expected_micro_amps
+        );
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(500_000, 4_700_000, Some(2_350_000_000); "0.5A, 4.7kΩ equals 2.35V")]
+    #[test_case(u64::MAX, 2, Some(36_893_488_147_419_103); "large current, small resistance")]
+    #[test_case(u64::MAX / 10, 5_000, Some(9_223_372_036_854_775_805); "large intermediate")]
+    #[test_case(u64::MAX / 10, 6_000, None; "too large for voltage is none")]
+    fn test_checked_voltage_equals_current_times_resistance(
+        micro_amps: u64,
+        milli_ohms: u64,
+        expected_micro_volts: Option<i64>,
+    ) {
+        let i = Current::from_micro_amps(micro_amps);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+
+        assert_eq!(
+            i.checked_mul(r).map(|v| v.micro_volts()),
+            expected_micro_volts
+        );
+        assert_eq!(
+            r.checked_mul(i).map(|v| v.micro_volts()),
+            expected_micro_volts
+        );
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(5_000_000, 220_000, Some(22_727); "5V over 220Ω")]
+    #[test_case(i64::MAX, 1, None; "overflow is none")]
+    fn test_voltage_over_non_zero_resistance(
+        micro_volts: i64,
+        milli_ohms: u64,
+        expected_micro_amps: Option<u64>,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let r = NonZeroResistance::new(Resistance::from_milli_ohms(milli_ohms)).unwrap();
+
+        assert_eq!((v / r).map(|i| i.micro_amps()), expected_micro_amps);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(5_000_000, 1_000_000, Some(5_000); "5V at 1A")]
+    #[test_case(i64::MIN, 1, None; "overflow is none")]
+    fn test_voltage_over_non_zero_current(
+        micro_volts: i64,
+        micro_amps: u64,
+        expected_milli_ohms: Option<u64>,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let i = NonZeroCurrent::new(Current::from_micro_amps(micro_amps)).unwrap();
+
+        assert_eq!((v / i).map(|r| r.milli_ohms()), expected_milli_ohms);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(5_000_000, 1_000_000, Some(5_000); "5V, 1A equals 5Ω")]
+    #[test_case(5_000_000, 0, None; "zero current is none")]
+    #[test_case(i64::MIN, 1, None; "overflow is none")]
+    #[test_case(i64::MIN, 1_000_000, Some(9_223_372_036_854_775); "large intermediate")]
+    fn test_checked_resistance_equals_voltage_over_current(
+        micro_volts: i64,
+        micro_amps: u64,
+        expected_milli_ohms: Option<u64>,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let i = Current::from_micro_amps(micro_amps);
+
+        assert_eq!(
+            v.checked_div(i).map(|r| r.milli_ohms()),
+            expected_milli_ohms
+        );
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(5_000_000, 100_000, 50_000; "positive 5V, 100Ω equals 50,000μA")]
+    #[test_case(-5_000_000, 100_000, 50_000; "negative 5V, 100Ω equals 50,000μA")]
+    #[test_case(3_300_000, 4_700_000, 702; "positive 3.3V, 4.7kΩ equals 702μA")]
+    fn test_current_equals_voltage_over_resistance(
+        micro_volts: i64,
+        milli_ohms: u64,
+        expected_micro_amps: u64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+        let current = v / r;
+
+        assert_eq!(current.micro_amps(), expected_micro_amps);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(5_000_000, 3_000, RoundingMode::Nearest, Some(1_666_667); "nearest rounds up")]
+    #[test_case(4_000_000, 3_000, RoundingMode::Nearest, Some(1_333_333); "nearest rounds down")]
+    #[test_case(5_000_000, 3_000, RoundingMode::Down, Some(1_666_666); "rounds down")]
+    #[test_case(-4_000_000, 3_000, RoundingMode::Up, Some(1_333_334); "negative rounds up")]
+    #[test_case(5_000_000, 0, RoundingMode::Nearest, None; "zero resistance")]
+    fn test_current_equals_voltage_over_resistance_rounded(
+        micro_volts: i64,
+        milli_ohms: u64,
+        mode: RoundingMode,
+        expected_micro_amps: Option<u64>,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+        let current = v.div_rounded(r, mode);
+
+        assert_eq!(current.map(|i| i.micro_amps()), expected_micro_amps);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(1_000_000, 3_000, RoundingMode::Nearest, Some(333_333); "nearest")]
+    #[test_case(1_000_000, 3_000, RoundingMode::Up, Some(333_334); "up")]
+    #[test_case(1_000_000, 0, RoundingMode::Up, None; "zero current")]
+    fn test_resistance_equals_voltage_over_current_rounded(
+        micro_volts: i64,
+        micro_amps: u64,
+        mode: RoundingMode,
+        expected_milli_ohms: Option<u64>,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let i = Current::from_micro_amps(micro_amps);
+        let resistance = v.div_rounded(i, mode);
+
+        assert_eq!(resistance.map(|r| r.milli_ohms()), expected_milli_ohms);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(1_500, 1_001, RoundingMode::Nearest, Some(1_502); "nearest")]
+    #[test_case(1_500, 1_001, RoundingMode::Down, Some(1_501); "down")]
+    #[test_case(u64::MAX, 1_000_000, RoundingMode::Nearest, None; "overflow")]
+    fn test_voltage_equals_current_times_resistance_rounded(
+        micro_amps: u64,
+        milli_ohms: u64,
+        mode: RoundingMode,
+        expected_micro_volts: Option<i64>,
+    ) {
+        let i = Current::from_micro_amps(micro_amps);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+
+        assert_eq!(
+            i.mul_rounded(r, mode).map(|v| v.micro_volts()),
+            expected_micro_volts
+        );
+        assert_eq!(
+            r.mul_rounded(i, mode).map(|v| v.micro_volts()),
+            expected_micro_volts
+        );
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(25_000, 75_000, 1_875_000; "25mA, 75Ω equals 1_875_000μV")]
+    #[test_case(39_000, 162_000, 6_318_000; "39mA, 162Ω equals 6_318_000μV")]
+    fn test_voltage_equals_current_times_resistance(
+        micro_amps: u64,
+        milli_ohms: u64,
+        expected_micro_volts: i64,
+    ) {
+        let i = Current::from_micro_amps(micro_amps);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+        let voltage = i * r;
+
+        assert_eq!(voltage.micro_volts(), expected_micro_volts);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(25_000, 75_000, 1_875_000; "25mA, 75Ω equals 1_875_000μV")]
+    #[test_case(39_000, 162_000, 6_318_000; "39mA, 162Ω equals 6_318_000μV")]
+    fn test_voltage_equals_resistance_times_current(
+        micro_amps: u64,
+        milli_ohms: u64,
+        expected_micro_volts: i64,
+    ) {
+        let r = Resistance::from_milli_ohms(milli_ohms);
+        let i = Current::from_micro_amps(micro_amps);
+        let voltage = r * i;
+
+        assert_eq!(voltage.micro_volts(), expected_micro_volts);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(25_000, 75_000, 1_875_000; "25mA, 75Ω equals 1_875_000μV")]
+    #[test_case(-25_000, 75_000, -1_875_000; "-25mA, 75Ω equals -1_875_000μV")]
+    fn test_voltage_equals_signed_current_times_resistance(
+        micro_amps: i64,
+        milli_ohms: u64,
+        expected_micro_volts: i64,
+    ) {
+        let i = SignedCurrent::from_micro_amps(micro_amps);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+
+        assert_eq!((i * r).micro_volts(), expected_micro_volts);
+        assert_eq!((r * i).micro_volts(), expected_micro_volts);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test]
+    fn test_checked_signed_current_times_resistance_overflow() {
+        let i = SignedCurrent::from_micro_amps(i64::MIN);
+        let r = Resistance::from_milli_ohms(2_000);
+
+        assert_eq!(i.checked_mul(r), None);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(1_875_000, 75_000, 25_000; "positive 1.875V, 75Ω equals 25mA")]
+    #[test_case(-1_875_000, 75_000, -25_000; "negative 1.875V, 75Ω equals -25mA")]
+    fn test_signed_current_equals_voltage_over_resistance(
+        micro_volts: i64,
+        milli_ohms: u64,
+        expected_micro_amps: i64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+
+        assert_eq!(v.signed_current(r).micro_amps(), expected_micro_amps);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test_case(1_500_000, 1_000_001, RoundingMode::Nearest, Some(1_500_002); "nearest")]
+    #[test_case(1_500_000, 1_000_001, RoundingMode::Down, Some(1_500_001); "down")]
+    #[test_case(1_500_000, 1_000_001, RoundingMode::Up, Some(1_500_002); "up")]
+    fn test_power_equals_voltage_times_current_rounded(
+        micro_volts: i64,
+        micro_amps: u64,
+        mode: RoundingMode,
+        expected_micro_watts: Option<u64>,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let i = Current::from_micro_amps(micro_amps);
+
+        assert_eq!(
+            v.mul_rounded(i, mode).map(|p| p.micro_watts()),
+            expected_micro_watts
+        );
+        assert_eq!(
+            i.mul_rounded(v, mode).map(|p| p.micro_watts()),
+            expected_micro_watts
+        );
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test_case(12_000_000, 500_000, 6_000_000; "12V, 500mA equals 6W")]
+    #[test_case(12_000_000, -500_000, -6_000_000; "12V, -500mA equals -6W")]
+    #[test_case(-12_000_000, -500_000, 6_000_000; "-12V, -500mA equals 6W")]
+    fn test_signed_power_equals_voltage_times_signed_current(
+        micro_volts: i64,
+        micro_amps: i64,
+        expected_micro_watts: i64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let i = SignedCurrent::from_micro_amps(micro_amps);
+
+        assert_eq!((v * i).micro_watts(), expected_micro_watts);
+        assert_eq!((i * v).micro_watts(), expected_micro_watts);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test]
+    fn test_checked_voltage_times_signed_current_overflow() {
+        let v = Voltage::from_micro_volts(i64::MIN);
+        let i = SignedCurrent::from_micro_amps(i64::MIN);
+
+        assert_eq!(v.checked_mul(i), None);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(1_875_000, 25_000, 75_000; "positive 1.875V, 25mA equals 75Ω")]
+    #[test_case(-1_875_000, 25_000, 75_000; "negative 1.875V, 25mA equals 75Ω")]
+    #[test_case(6_318_000, 39_000, 162_000; "positive 6.318V, 39mA equals 162Ω")]
+    #[test_case(-6_318_000, 39_000, 162_000; "negative 6.318V, 39mA equals 162Ω")]
+    fn test_resistance_equals_voltage_over_current(
+        micro_volts: i64,
+        micro_amps: u64,
+        expected_milli_ohms: u64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let i = Current::from_micro_amps(micro_amps);
+        let resistance = v / i;
+
+        assert_eq!(resistance.milli_ohms(), expected_milli_ohms);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test_case(5_000_000, 1_000_000, 5_000_000; "positive 5V, 1A equals 5W")]
+    #[test_case(-5_000_000, 1_000_000, 5_000_000; "negative 5V, 1A equals 5W")]
+    #[test_case(20_000_000_000, 1_000_000_000, 20_000_000_000_000; "20kV, 1kA equals 20MW")]
+    fn test_power_equals_voltage_times_current(
+        micro_volts: i64,
+        micro_amps: u64,
+        expected_micro_watts: u64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let i = Current::from_micro_amps(micro_amps);
+        let power = v * i;
+
+        assert_eq!(power.micro_watts(), expected_micro_watts);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test_case(5_000_000, 1_000_000, 5_000_000; "positive 5V, 1A equals 5W")]
+    #[test_case(-5_000_000, 1_000_000, 5_000_000; "negative 5V, 1A equals 5W")]
+    fn test_power_equals_current_times_voltage(
+        micro_volts: i64,
+        micro_amps: u64,
+        expected_micro_watts: u64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let i = Current::from_micro_amps(micro_amps);
+        let power = i * v;
+
+        assert_eq!(power.micro_watts(), expected_micro_watts);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test_case(5_000_000, 5_000_000, 1_000_000; "5W, positive 5V equals 1A")]
+    #[test_case(5_000_000, -5_000_000, 1_000_000; "5W, negative 5V equals 1A")]
+    #[test_case(1_000, 3_300_000, 303; "1mW, 3.3V equals 303μA")]
+    #[test_case(u64::MAX, i64::MAX, 2_000_000; "maximum power does not overflow intermediate")]
+    fn test_current_equals_power_over_voltage(
+        micro_watts: u64,
+        micro_volts: i64,
+        expected_micro_amps: u64,
+    ) {
+        let p = Power::from_micro_watts(micro_watts);
+        let v = Voltage::from_micro_volts(micro_volts);
+        let current = p / v;
+
+        assert_eq!(current.micro_amps(), expected_micro_amps);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test]
+    #[should_panic]
+    fn test_power_over_zero_voltage_panics() {
+        let _ = Power::from_micro_watts(1_000) / Voltage::zero();
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test_case(5_000_000, 1_000_000, 5_000_000; "5W, 1A equals 5V")]
+    #[test_case(1_000, 250_000, 4_000; "1mW, 250mA equals 4mV")]
+    #[test_case(u64::MAX, u64::MAX, 1_000_000; "maximum power does not overflow intermediate")]
+    fn test_voltage_equals_power_over_current(
+        micro_watts: u64,
+        micro_amps: u64,
+        expected_micro_volts: i64,
+    ) {
+        let p = Power::from_micro_watts(micro_watts);
+        let i = Current::from_micro_amps(micro_amps);
+        let voltage = p / i;
+
+        assert_eq!(voltage.micro_volts(), expected_micro_volts);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test]
+    #[should_panic]
+    fn test_power_over_zero_current_panics() {
+        let _ = Power::from_micro_watts(1_000) / Current::zero();
+    }
+
+    #[cfg(all(feature = "current", feature = "resistance", feature = "power"))]
+    #[test_case(1_000_000, 10_000, 10_000_000; "1A, 10Ω equals 10W")]
+    #[test_case(20_000, 220_000, 88_000; "20mA, 220Ω equals 88mW")]
+    #[test_case(1, 1, 0; "rounds down to nearest microwatt")]
+    #[test_case(100_000_000, 1_000_000_000, 10_000_000_000_000_000; "100A, 1MΩ equals 10GW")]
+    fn test_power_equals_current_squared_times_resistance(
+        micro_amps: u64,
+        milli_ohms: u64,
+        expected_micro_watts: u64,
+    ) {
+        let i = Current::from_micro_amps(micro_amps);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+        let power = i.power_through(r);
+
+        assert_eq!(power.micro_watts(), expected_micro_watts);
+    }
+
+    #[cfg(all(feature = "current", feature = "resistance", feature = "power"))]
+    #[test]
+    #[should_panic]
+    fn test_power_through_overflow_panics() {
+        let i = Current::from_micro_amps(u64::MAX);
+        let _ = i.power_through(Resistance::from_milli_ohms(1_000));
+    }
+
+    #[cfg(all(feature = "voltage", feature = "resistance", feature = "power"))]
+    #[test_case(5_000_000, 1_000_000, 25_000; "positive 5V, 1kΩ equals 25mW")]
+    #[test_case(-5_000_000, 1_000_000, 25_000; "negative 5V, 1kΩ equals 25mW")]
+    #[test_case(12_000_000, 10_000, 14_400_000; "12V, 10Ω equals 14.4W")]
+    #[test_case(1_000_000_000, 1_000, 1_000_000_000_000; "1kV, 1Ω equals 1MW")]
+    fn test_power_equals_voltage_squared_over_resistance(
+        micro_volts: i64,
+        milli_ohms: u64,
+        expected_micro_watts: u64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+        let power = v.power_across(r);
+
+        assert_eq!(power.micro_watts(), expected_micro_watts);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "resistance", feature = "power"))]
+    #[test]
+    #[should_panic]
+    fn test_power_across_zero_resistance_panics() {
+        let _ = Voltage::from_micro_volts(1).power_across(Resistance::zero());
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(10_000_000, 30_000, 40_000, 200_000; "10V across 30Ω + j40Ω equals 200mA")]
+    #[test_case(10_000_000, 30_000, -40_000, 200_000; "capacitive load uses magnitude")]
+    #[test_case(5_000_000, 100_000, 0, 50_000; "purely resistive load")]
+    fn test_current_equals_voltage_over_impedance(
+        micro_volts: i64,
+        resistance: u64,
+        reactance: i64,
+        expected_micro_amps: u64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let z = Impedance::from_milli_ohms(resistance, reactance);
+
+        assert_eq!((v / z).micro_amps(), expected_micro_amps);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test]
+    #[should_panic]
+    fn test_voltage_over_zero_impedance_panics() {
+        let _ = Voltage::from_micro_volts(1) / Impedance::zero();
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test_case(2_000_000, 30_000, 40_000, 100_000_000; "2A through 30Ω + j40Ω equals 100V")]
+    #[test_case(100_000, 0, -1_000_000, 100_000_000; "purely reactive load")]
+    fn test_voltage_equals_current_times_impedance(
+        micro_amps: u64,
+        resistance: u64,
+        reactance: i64,
+        expected_micro_volts: i64,
+    ) {
+        let i = Current::from_micro_amps(micro_amps);
+        let z = Impedance::from_milli_ohms(resistance, reactance);
+
+        assert_eq!((i * z).micro_volts(), expected_micro_volts);
+        assert_eq!((z * i).micro_volts(), expected_micro_volts);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(10_000_000, 100_000, 1_000; "10kΩ, 100nF equals 1ms")]
+    #[test_case(1_000_000, 4_700_000, 4_700; "1kΩ, 4.7μF equals 4.7ms")]
+    #[test_case(100_000, 1_000, 0; "100Ω, 1nF rounds down to 0μs")]
+    #[test_case(u64::MAX, 1_000_000_000, u64::MAX; "maximum values do not overflow intermediate")]
+    fn test_time_constant_equals_resistance_times_capacitance(
+        milli_ohms: u64,
+        pico_farads: u64,
+        expected_micros: u64,
+    ) {
+        let r = Resistance::from_milli_ohms(milli_ohms);
+        let c = Capacitance::from_pico_farads(pico_farads);
+
+        assert_eq!(r * c, Duration::from_micros(expected_micros));
+        assert_eq!(c * r, Duration::from_micros(expected_micros));
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    #[should_panic]
+    fn test_time_constant_overflow_panics() {
+        let _ =
+            Resistance::from_milli_ohms(u64::MAX) * Capacitance::from_pico_farads(2_000_000_000);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(100_000_000, 12_000_000, 1_200; "100μF at 12V equals 1.2mC")]
+    #[test_case(1_000_000_000_000, -5_000_000, 5_000_000; "1F at -5V equals 5C")]
+    #[test_case(1_000, 1_000_000, 0; "1nF at 1V rounds down to 0μC")]
+    fn test_charge_equals_capacitance_times_voltage(
+        pico_farads: u64,
+        micro_volts: i64,
+        expected_micro_coulombs: u64,
+    ) {
+        let c = Capacitance::from_pico_farads(pico_farads);
+        let v = Voltage::from_micro_volts(micro_volts);
+
+        assert_eq!((c * v).micro_coulombs(), expected_micro_coulombs);
+        assert_eq!((v * c).micro_coulombs(), expected_micro_coulombs);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(1_200, 12_000_000, 100_000_000; "1.2mC at 12V equals 100μF")]
+    #[test_case(5_000_000, -5_000_000, 1_000_000_000_000; "negative voltage uses magnitude")]
+    fn test_capacitance_equals_charge_over_voltage(
+        micro_coulombs: u64,
+        micro_volts: i64,
+        expected_pico_farads: u64,
+    ) {
+        let q = Charge::from_micro_coulombs(micro_coulombs);
+        let v = Voltage::from_micro_volts(micro_volts);
+
+        assert_eq!((q / v).pico_farads(), expected_pico_farads);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    #[should_panic]
+    fn test_charge_over_zero_voltage_panics() {
+        let _ = Charge::from_micro_coulombs(1) / Voltage::zero();
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(1_200, 100_000_000, 12_000_000; "1.2mC in 100μF equals 12V")]
+    #[test_case(1, 3_000_000, 333_333; "rounds down to nearest microvolt")]
+    fn test_voltage_equals_charge_over_capacitance(
+        micro_coulombs: u64,
+        pico_farads: u64,
+        expected_micro_volts: i64,
+    ) {
+        let q = Charge::from_micro_coulombs(micro_coulombs);
+        let c = Capacitance::from_pico_farads(pico_farads);
+
+        assert_eq!((q / c).micro_volts(), expected_micro_volts);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    #[should_panic]
+    fn test_charge_over_zero_capacitance_panics() {
+        let _ = Charge::from_micro_coulombs(1) / Capacitance::zero();
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(1_000_000, 100_000, 1_591_549; "1kΩ, 100nF equals 1.59kHz")]
+    #[test_case(10_000_000, 10_000_000, 1_591; "10kΩ, 10μF equals 1.59Hz")]
+    fn test_cutoff_frequency(milli_ohms: u64, pico_farads: u64, expected_milli_hertz: u64) {
+        let f = cutoff_frequency(
+            Resistance::from_milli_ohms(milli_ohms),
+            Capacitance::from_pico_farads(pico_farads),
+        );
+
+        assert_eq!(f.milli_hertz(), expected_milli_hertz);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    #[should_panic]
+    fn test_cutoff_frequency_zero_capacitance_panics() {
+        let _ = cutoff_frequency(Resistance::from_milli_ohms(1_000), Capacitance::zero());
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(1_591_549, 100_000, 1_000_000; "1.59kHz, 100nF equals 1kΩ")]
+    #[test_case(1_000_000, 10_000, 15_915_494; "1kHz, 10nF equals 15.9kΩ")]
+    fn test_resistance_for_cutoff(milli_hertz: u64, pico_farads: u64, expected_milli_ohms: u64) {
+        let r = resistance_for_cutoff(
+            Frequency::from_milli_hertz(milli_hertz),
+            Capacitance::from_pico_farads(pico_farads),
+        );
+
+        assert_eq!(r.milli_ohms(), expected_milli_ohms);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(1_000_000, 1_000_000, 159_154; "1kHz, 1kΩ equals 159nF")]
+    #[test_case(50_000, 10_000_000, 318_309; "50Hz, 10kΩ equals 318nF")]
+    fn test_capacitance_for_cutoff(milli_hertz: u64, milli_ohms: u64, expected_pico_farads: u64) {
+        let c = capacitance_for_cutoff(
+            Frequency::from_milli_hertz(milli_hertz),
+            Resistance::from_milli_ohms(milli_ohms),
+        );
+
+        assert_eq!(c.pico_farads(), expected_pico_farads);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    #[should_panic]
+    fn test_capacitance_for_cutoff_zero_frequency_panics() {
+        let _ = capacitance_for_cutoff(Frequency::zero(), Resistance::from_milli_ohms(1_000));
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current"))]
+    #[test_case(5_000_000, 1_000_000, 5_000; "5V, 1mS equals 5mA")]
+    #[test_case(12_000_000, 1_000_000_000, 12_000_000; "12V, 1S equals 12A")]
+    #[test_case(-5_000_000, 1_000_000, 5_000; "-5V, 1mS equals 5mA")]
+    #[test_case(1, 1, 0; "1μV, 1nS rounds down to 0A")]
+    fn test_current_equals_voltage_times_conductance(
+        micro_volts: i64,
+        nano_siemens: u64,
+        expected_micro_amps: u64,
+    ) {
+        let v = Voltage::from_micro_volts(micro_volts);
+        let g = Conductance::from_nano_siemens(nano_siemens);
+
+        assert_eq!((v * g).micro_amps(), expected_micro_amps);
+        assert_eq!((g * v).micro_amps(), expected_micro_amps);
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(1_000_000, 1, 0, 1_000_000_000; "1W for 1s equals 1J")]
+    #[test_case(5_000, 3_600, 0, 18_000_000_000; "5mW for 1h equals 18J")]
+    #[test_case(1, 0, 1_000, 0; "1μW for 1μs rounds down to 0nJ")]
+    #[test_case(1_000_000, 0, 1_500_000, 1_500_000; "1W for 1.5ms equals 1.5mJ")]
+    #[test_case(u64::MAX, 0, 1_000, u64::MAX / 1_000; "maximum power does not overflow intermediate")]
+    fn test_energy_equals_power_times_duration(
+        micro_watts: u64,
+        secs: u64,
+        nanos: u32,
+        expected_nano_joules: u64,
+    ) {
+        let p = Power::from_micro_watts(micro_watts);
+        let t = Duration::new(secs, nanos);
+
+        assert_eq!((p * t).nano_joules(), expected_nano_joules);
+        assert_eq!((t * p).nano_joules(), expected_nano_joules);
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    #[should_panic]
+    fn test_power_times_duration_overflow_panics() {
+        let _ = Power::from_micro_watts(u64::MAX) * Duration::from_secs(1);
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(1_000_000_000, 1, 0, 1_000_000; "1J over 1s equals 1W")]
+    #[test_case(18_000_000_000, 3_600, 0, 5_000; "18J over 1h equals 5mW")]
+    #[test_case(1_500_000, 0, 1_500_000, 1_000_000; "1.5mJ over 1.5ms equals 1W")]
+    #[test_case(u64::MAX, 1, 0, u64::MAX / 1_000; "maximum energy does not overflow intermediate")]
+    fn test_power_equals_energy_over_duration(
+        nano_joules: u64,
+        secs: u64,
+        nanos: u32,
+        expected_micro_watts: u64,
+    ) {
+        let e = Energy::from_nano_joules(nano_joules);
+        let t = Duration::new(secs, nanos);
+
+        assert_eq!((e / t).micro_watts(), expected_micro_watts);
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    #[should_panic]
+    fn test_energy_over_zero_duration_panics() {
+        let _ = Energy::from_nano_joules(1) / Duration::ZERO;
+    }
+
+    #[cfg(feature = "current")]
+    #[test_case(1_000_000, 1, 0, 1_000_000; "1A for 1s equals 1C")]
+    #[test_case(500_000, 3_600, 0, 1_800_000_000; "500mA for 1h equals 500mAh")]
+    #[test_case(1, 0, 1_000, 0; "1μA for 1μs rounds down to 0μC")]
+    #[test_case(u64::MAX, 0, 1_000, u64::MAX / 1_000_000; "maximum current does not overflow intermediate")]
+    fn test_charge_equals_current_times_duration(
+        micro_amps: u64,
+        secs: u64,
+        nanos: u32,
+        expected_micro_coulombs: u64,
+    ) {
+        let i = Current::from_micro_amps(micro_amps);
+        let t = Duration::new(secs, nanos);
+
+        assert_eq!((i * t).micro_coulombs(), expected_micro_coulombs);
+        assert_eq!((t * i).micro_coulombs(), expected_micro_coulombs);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    #[should_panic]
+    fn test_current_times_duration_overflow_panics() {
+        let _ = Current::from_micro_amps(u64::MAX) * Duration::from_secs(2);
+    }
+
+    #[cfg(feature = "current")]
+    #[test_case(1_000_000, 1, 0, 1_000_000; "1C over 1s equals 1A")]
+    #[test_case(1_800_000_000, 3_600, 0, 500_000; "500mAh over 1h equals 500mA")]
+    #[test_case(1_500, 0, 1_500_000, 1_000_000; "1.5mC over 1.5ms equals 1A")]
+    fn test_current_equals_charge_over_duration(
+        micro_coulombs: u64,
+        secs: u64,
+        nanos: u32,
+        expected_micro_amps: u64,
+    ) {
+        let q = Charge::from_micro_coulombs(micro_coulombs);
+        let t = Duration::new(secs, nanos);
+
+        assert_eq!((q / t).micro_amps(), expected_micro_amps);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    #[should_panic]
+    fn test_charge_over_zero_duration_panics() {
+        let _ = Charge::from_micro_coulombs(1) / Duration::ZERO;
+    }
+
+    #[cfg(feature = "current")]
+    #[test_case(2_000_000, 250_000, 28_800, 0; "2000mAh at 250mA equals 8h")]
+    #[test_case(1_000, 3_000, 1_200, 0; "1mAh at 3mA equals 20min")]
+    #[test_case(1, 7, 514, 285_714_285; "rounds down to nanoseconds")]
+    #[test_case(u64::MAX, u64::MAX, 3_600, 0; "maximum")]
+    fn test_runtime_equals_capacity_over_current(
+        micro_amp_hours: u64,
+        micro_amps: u64,
+        expected_secs: u64,
+        expected_nanos: u32,
+    ) {
+        let c = Capacity::from_micro_amp_hours(micro_amp_hours);
+        let i = Current::from_micro_amps(micro_amps);
+
+        assert_eq!(c / i, Duration::new(expected_secs, expected_nanos));
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    #[should_panic]
+    fn test_capacity_over_zero_current_panics() {
+        let _ = Capacity::from_micro_amp_hours(1) / Current::zero();
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    #[should_panic]
+    fn test_capacity_over_current_overflow_panics() {
+        let _ = Capacity::from_micro_amp_hours(u64::MAX) / Current::from_micro_amps(1);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(2_000_000, 3_700_000, 26_640_000_000_000; "2000mAh at 3.7V equals 7.4Wh")]
+    #[test_case(1, -1, 3; "negative voltage uses magnitude, rounds down")]
+    #[test_case(0, 3_700_000, 0; "zero capacity equals 0J")]
+    fn test_energy_equals_capacity_times_voltage(
+        micro_amp_hours: u64,
+        micro_volts: i64,
+        expected_nano_joules: u64,
+    ) {
+        let c = Capacity::from_micro_amp_hours(micro_amp_hours);
+        let v = Voltage::from_micro_volts(micro_volts);
+
+        assert_eq!((c * v).nano_joules(), expected_nano_joules);
+        assert_eq!((v * c).nano_joules(), expected_nano_joules);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    #[should_panic]
+    fn test_capacity_times_voltage_overflow_panics() {
+        let _ = Capacity::from_micro_amp_hours(u64::MAX) * Voltage::from_micro_volts(i64::MAX);
+    }
+
+    #[cfg(all(
+        feature = "libm",
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    ))]
+    #[test_case(1_000_000, 100_000, 100_000; "1W, 100Ω equals 100mA")]
+    #[test_case(250_000, 1_000_000, 15_811; "250mW, 1kΩ equals 15,811μA")]
+    #[test_case(0, 1_000, 0; "zero power equals 0A")]
+    fn test_current_equals_root_power_over_resistance(
+        micro_watts: u64,
+        milli_ohms: u64,
+        expected_micro_amps: u64,
+    ) {
+        let p = Power::from_micro_watts(micro_watts);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+
+        assert_eq!(r.current_for_power(p).micro_amps(), expected_micro_amps);
+    }
+
+    #[cfg(all(
+        feature = "libm",
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    ))]
+    #[test]
+    #[should_panic]
+    fn test_current_for_power_zero_resistance_panics() {
+        Resistance::zero().current_for_power(Power::from_micro_watts(1));
+    }
+
+    #[cfg(all(
+        feature = "libm",
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    ))]
+    #[test_case(1_000_000, 100_000, 10_000_000; "1W, 100Ω equals 10V")]
+    #[test_case(250_000, 1_000_000, 15_811_388; "250mW, 1kΩ equals 15.81V")]
+    #[test_case(1_000_000, 0, 0; "zero resistance equals 0V")]
+    fn test_voltage_equals_root_power_times_resistance(
+        micro_watts: u64,
+        milli_ohms: u64,
+        expected_micro_volts: i64,
+    ) {
+        let p = Power::from_micro_watts(micro_watts);
+        let r = Resistance::from_milli_ohms(milli_ohms);
+
+        assert_eq!(r.voltage_for_power(p).micro_volts(), expected_micro_volts);
     }
 }
@@ -1,15 +1,28 @@
 //! Electrical unit types for embedded targets focusing on ease-of-use and performance
 //!
 //! Supported units:
-//! - Current (μA, mA, A)
-//! - Resistance (mΩ, Ω, kΩ, MΩ)
-//! - Voltage (μV, mV, V, kV)
-//! - Power (μW, mW, W, kW)
+//! - Current (nA, μA, mA, A)
+//! - Resistance (mΩ, Ω, kΩ, MΩ, GΩ, TΩ)
+//! - Voltage (nV, μV, mV, V, kV)
+//! - Power (nW, μW, mW, W, kW)
+//! - Energy (nJ, μJ, mJ, J, kJ, mWh, Wh, kWh)
+//! - Capacitance (pF, nF, μF, mF, F)
+//! - Inductance (nH, μH, mH, H)
+//! - Frequency (mHz, Hz, kHz, MHz, GHz)
+//! - Charge (μC, mC, C, mAh, Ah)
+//! - Capacity (μAh, mAh, Ah)
+//! - Conductance (nS, μS, mS, S)
+//! - Temperature (m°C, °C, K)
+//! - Impedance (R + jX, in mΩ)
 //!
 //! Each unit type has a corresponding extension trait for creating values from integer and floating point values.
 //!
 //! Unit types can easily be converted to and from different denominations.
 //!
+//! `Voltage`, `Current` and `Power` are stored as whole microunits, so their nano denominations
+//! only accept values that are a whole number of microunits: `from_nano_*` returns `None` and
+//! `try_from_nano_*` returns `UnitError::Inexact` for anything finer.
+//!
 //! Ohm's Law is implemented for `Voltage` and `Current` types, allowing you to easily calculate
 //! between the three units using the `/` and `*` operators.
 //!
@@ -17,6 +30,7 @@
 //!
 //! Determine the resistance of a 5V, 1A load:
 //! ```rust
+//! # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
 //! use ohms::prelude::*;
 //!
 //! let voltage = 5.volts();
@@ -24,10 +38,12 @@
 //!
 //! let resistance = voltage / current; // 5V / 1A = 5Ω
 //! println!("Resistance: {} Ω", resistance.ohms());
+//! # }
 //! ```
 //!
 //! Determine the current of a 5V, 220Ω load:
 //! ```rust
+//! # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
 //! use ohms::prelude::*;
 //!
 //! let voltage = 5.volts();
@@ -35,10 +51,12 @@
 //!
 //! let current = voltage / resistance; // 5V / 220Ω = 22.72mA
 //! println!("Current: {} mA", current.milli_amps());
+//! # }
 //! ```
 //!
 //! Determine the voltage of a 0.5A, 4.7kΩ load:
 //! ```rust
+//! # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
 //! use ohms::prelude::*;
 //!
 //! let current = 0.5.amps();
@@ -46,21 +64,221 @@
 //!
 //! let voltage = current * resistance; // 0.5A * 4.7kΩ = 2.35V
 //! println!("Voltage: {} V", voltage.volts());
+//! # }
 //! ```
 #![no_std]
 
+#[cfg(any(feature = "alloc", test))]
+extern crate alloc;
+
+#[cfg(any(feature = "voltage", feature = "current"))]
+mod ac;
+#[cfg(feature = "power")]
+mod apparent_power;
+mod approx;
 mod assert;
+#[cfg(all(feature = "current", feature = "resistance"))]
+pub mod awg;
+#[cfg(feature = "resistance")]
+mod bridge;
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+pub mod cable;
+mod calibration;
+mod capacitance;
+mod capacity;
+mod charge;
+#[cfg(feature = "resistance")]
+pub mod color_code;
+#[cfg(any(
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
+pub mod compact;
+mod compare;
+mod conductance;
+#[cfg(feature = "voltage")]
+pub mod converter;
+#[cfg(feature = "current")]
 mod current;
+#[cfg(feature = "current")]
+mod current_density;
+mod decibel;
+#[doc(hidden)]
+pub mod define;
+mod display;
+#[cfg(all(feature = "voltage", feature = "resistance"))]
+mod divider;
+mod duty_cycle;
+mod energy;
+mod error;
+#[cfg(feature = "resistance")]
+pub mod eseries;
+pub mod filters;
+mod frequency;
+#[cfg(feature = "current")]
+mod i2t;
+#[cfg(feature = "resistance")]
+mod impedance;
+mod inductance;
+#[cfg(all(feature = "current", feature = "libm"))]
+pub mod ipc2221;
 mod law;
+#[doc(hidden)]
+pub mod literal;
+pub mod materials;
+mod math;
+#[cfg(all(
+    feature = "measurements",
+    any(
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    )
+))]
+mod measurements;
+#[cfg(any(feature = "current", feature = "power"))]
+pub mod metering;
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+pub mod network;
+#[cfg(any(feature = "current", feature = "resistance"))]
+mod nonzero;
+#[cfg(all(feature = "resistance", feature = "libm"))]
+pub mod ntc;
+mod parse;
+mod percent;
+mod phase;
+#[cfg(feature = "power")]
 mod power;
+mod ppm;
 pub mod prelude;
+#[cfg(feature = "power")]
+mod reactive_power;
+#[cfg(feature = "resistance")]
 mod resistance;
+mod resistivity;
+mod rounding;
+mod scalar;
+#[cfg(feature = "current")]
+mod signed_current;
+#[cfg(feature = "power")]
+mod signed_power;
+#[cfg(feature = "resistance")]
+pub mod smd_code;
+#[cfg(all(
+    feature = "alloc",
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance"
+))]
+mod spice;
+mod temperature;
+mod thermal;
+#[cfg(feature = "voltage")]
+pub mod three_phase;
+mod tolerance;
+#[cfg(feature = "uom")]
+mod uom;
+#[cfg(feature = "voltage")]
 mod voltage;
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
+pub mod zener;
 
-pub use current::{Current, FromFloat as CurrentFromFloat, FromInteger as CurrentFromInteger};
-pub use law::*;
-pub use power::{FromFloat as PowerFromFloat, FromInteger as PowerFromInteger, Power};
+#[cfg(feature = "current")]
+pub use ac::AcCurrent;
+#[cfg(any(feature = "voltage", feature = "current"))]
+pub use ac::AcMeasure;
+#[cfg(feature = "voltage")]
+pub use ac::AcVoltage;
+#[cfg(feature = "power")]
+pub use apparent_power::ApparentPower;
+#[cfg(all(feature = "resistance", feature = "voltage"))]
+pub use bridge::wheatstone_sensitivity;
+#[cfg(feature = "resistance")]
+pub use bridge::{delta_to_wye, wheatstone_balance_resistor, wye_to_delta};
+pub use calibration::{CalTable, LinearCal};
+pub use capacitance::{
+    Capacitance, FromFloat as CapacitanceFromFloat, FromInteger as CapacitanceFromInteger,
+};
+pub use capacity::Capacity;
+pub use charge::{Charge, FromFloat as ChargeFromFloat, FromInteger as ChargeFromInteger};
+pub use conductance::{
+    Conductance, FromFloat as ConductanceFromFloat, FromInteger as ConductanceFromInteger,
+};
+#[cfg(feature = "current")]
+pub use current::{
+    Current, CurrentUnit, FromFloat as CurrentFromFloat, FromInteger as CurrentFromInteger,
+};
+#[cfg(feature = "current")]
+pub use current_density::CurrentDensity;
+pub use decibel::Decibel;
+#[cfg(all(feature = "voltage", feature = "resistance"))]
+pub use divider::VoltageDivider;
+pub use duty_cycle::DutyCycle;
+pub use energy::{Energy, FromFloat as EnergyFromFloat, FromInteger as EnergyFromInteger};
+pub use error::UnitError;
+pub use frequency::{
+    Frequency, FromFloat as FrequencyFromFloat, FromInteger as FrequencyFromInteger,
+};
+#[cfg(feature = "current")]
+pub use i2t::I2t;
+#[cfg(feature = "resistance")]
+pub use impedance::Impedance;
+pub use inductance::{
+    FromFloat as InductanceFromFloat, FromInteger as InductanceFromInteger, Inductance,
+};
+#[cfg(feature = "resistance")]
+pub use law::{capacitance_for_cutoff, cutoff_frequency, resistance_for_cutoff};
+pub use law::{CheckedDiv, CheckedMul, RoundedDiv, RoundedMul};
+#[cfg(feature = "current")]
+pub use nonzero::NonZeroCurrent;
+#[cfg(feature = "resistance")]
+pub use nonzero::NonZeroResistance;
+pub use parse::ParseError;
+pub use percent::Percent;
+#[cfg(feature = "power")]
+pub use phase::{apparent_power, real_power};
+pub use phase::{PhaseAngle, PowerFactor};
+#[cfg(feature = "power")]
+pub use power::{FromFloat as PowerFromFloat, FromInteger as PowerFromInteger, Power, PowerUnit};
+pub use ppm::Ppm;
+#[cfg(feature = "power")]
+pub use reactive_power::ReactivePower;
+#[cfg(feature = "resistance")]
 pub use resistance::{
     FromFloat as ResistanceFromFloat, FromInteger as ResistanceFromInteger, Resistance,
+    ResistanceUnit,
+};
+#[cfg(feature = "resistance")]
+pub use resistivity::wire_resistance;
+pub use resistivity::Resistivity;
+pub use rounding::RoundingMode;
+#[cfg(feature = "current")]
+pub use signed_current::SignedCurrent;
+#[cfg(feature = "power")]
+pub use signed_power::SignedPower;
+#[cfg(all(
+    feature = "alloc",
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance"
+))]
+pub use spice::SpiceNetlist;
+pub use temperature::{
+    FromFloat as TemperatureFromFloat, FromInteger as TemperatureFromInteger, Temperature,
+};
+pub use thermal::ThermalResistance;
+#[cfg(feature = "power")]
+pub use thermal::{junction_temperature, PowerDerating};
+pub use tolerance::Toleranced;
+#[cfg(feature = "voltage")]
+pub use voltage::{
+    FromFloat as VoltageFromFloat, FromInteger as VoltageFromInteger, Voltage, VoltageUnit,
 };
-pub use voltage::{FromFloat as VoltageFromFloat, FromInteger as VoltageFromInteger, Voltage};
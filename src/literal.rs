@@ -0,0 +1,795 @@
+//! Support for the unit literal macros, such as `volts!(3.3)`.
+//!
+//! The macros parse their literal argument in a `const` context, so the value is scaled to the
+//! base unit at compile time and an invalid literal is a compile-time error.
+
+/// Parses a decimal literal into its sign and magnitude in units of `10^-exponent`.
+///
+/// Panics if the literal is not a plain decimal number, has more decimal places than the
+/// exponent allows, or is too large to be represented.
+const fn parse(literal: &str, exponent: u32) -> (bool, u64) {
+    let bytes = literal.as_bytes();
+    let mut index = 0;
+
+    let negative = !bytes.is_empty() && bytes[0] == b'-';
+    if negative {
+        index += 1;
+
+        while index < bytes.len() && bytes[index] == b' ' {
+            index += 1;
+        }
+    }
+
+    let mut value: u64 = 0;
+    let mut digits = 0;
+    let mut decimals: Option<u32> = None;
+
+    while index < bytes.len() {
+        let byte = bytes[index];
+        index += 1;
+
+        let digit = match byte {
+            b'0'..=b'9' => (byte - b'0') as u64,
+            b'_' => continue,
+            b'.' if decimals.is_none() => {
+                decimals = Some(0);
+                continue;
+            }
+            _ => panic!("Unit literal must be a plain decimal number"),
+        };
+
+        if let Some(places) = decimals {
+            if places == exponent {
+                panic!("Unit literal has too many decimal places for the base unit");
+            }
+
+            decimals = Some(places + 1);
+        }
+
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add(digit) {
+                Some(value) => value,
+                None => panic!("Unit literal is too large"),
+            },
+            None => panic!("Unit literal is too large"),
+        };
+        digits += 1;
+    }
+
+    if digits == 0 {
+        panic!("Unit literal must contain at least one digit");
+    }
+
+    let places = match decimals {
+        Some(places) => places,
+        None => 0,
+    };
+
+    match value.checked_mul(10u64.pow(exponent - places)) {
+        Some(value) => (negative, value),
+        None => panic!("Unit literal is too large"),
+    }
+}
+
+/// Parses an unsigned decimal literal, scaled by `10^exponent` and then the multiplier.
+#[doc(hidden)]
+pub const fn unsigned(literal: &str, exponent: u32, multiplier: u64) -> u64 {
+    let (negative, value) = parse(literal, exponent);
+
+    if negative && value != 0 {
+        panic!("Unit literal cannot be negative");
+    }
+
+    match value.checked_mul(multiplier) {
+        Some(value) => value,
+        None => panic!("Unit literal is too large"),
+    }
+}
+
+/// Parses a signed decimal literal, scaled by `10^exponent` and then shifted by the offset.
+#[doc(hidden)]
+pub const fn signed(literal: &str, exponent: u32, offset: i64) -> i64 {
+    let (negative, value) = parse(literal, exponent);
+
+    let value = if negative {
+        if value > i64::MAX as u64 + 1 {
+            panic!("Unit literal is too large");
+        }
+
+        (value as i64).wrapping_neg()
+    } else {
+        if value > i64::MAX as u64 {
+            panic!("Unit literal is too large");
+        }
+
+        value as i64
+    };
+
+    match value.checked_add(offset) {
+        Some(value) => value,
+        None => panic!("Unit literal is too large"),
+    }
+}
+
+/// Creates a `Capacitance` constant from a literal number of picofarads (pF).
+#[macro_export]
+macro_rules! pico_farads {
+    ($value:literal) => {{
+        const VALUE: $crate::Capacitance = $crate::Capacitance::from_pico_farads(
+            $crate::literal::unsigned(::core::stringify!($value), 0, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Capacitance` constant from a literal number of nanofarads (nF).
+#[macro_export]
+macro_rules! nano_farads {
+    ($value:literal) => {{
+        const VALUE: $crate::Capacitance = $crate::Capacitance::from_pico_farads(
+            $crate::literal::unsigned(::core::stringify!($value), 3, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Capacitance` constant from a literal number of microfarads (μF).
+#[macro_export]
+macro_rules! micro_farads {
+    ($value:literal) => {{
+        const VALUE: $crate::Capacitance = $crate::Capacitance::from_pico_farads(
+            $crate::literal::unsigned(::core::stringify!($value), 6, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Capacitance` constant from a literal number of millifarads (mF).
+#[macro_export]
+macro_rules! milli_farads {
+    ($value:literal) => {{
+        const VALUE: $crate::Capacitance = $crate::Capacitance::from_pico_farads(
+            $crate::literal::unsigned(::core::stringify!($value), 9, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Capacitance` constant from a literal number of farads (F).
+#[macro_export]
+macro_rules! farads {
+    ($value:literal) => {{
+        const VALUE: $crate::Capacitance = $crate::Capacitance::from_pico_farads(
+            $crate::literal::unsigned(::core::stringify!($value), 12, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Charge` constant from a literal number of microcoulombs (μC).
+#[macro_export]
+macro_rules! micro_coulombs {
+    ($value:literal) => {{
+        const VALUE: $crate::Charge = $crate::Charge::from_micro_coulombs(
+            $crate::literal::unsigned(::core::stringify!($value), 0, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Charge` constant from a literal number of millicoulombs (mC).
+#[macro_export]
+macro_rules! milli_coulombs {
+    ($value:literal) => {{
+        const VALUE: $crate::Charge = $crate::Charge::from_micro_coulombs(
+            $crate::literal::unsigned(::core::stringify!($value), 3, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Charge` constant from a literal number of coulombs (C).
+#[macro_export]
+macro_rules! coulombs {
+    ($value:literal) => {{
+        const VALUE: $crate::Charge = $crate::Charge::from_micro_coulombs(
+            $crate::literal::unsigned(::core::stringify!($value), 6, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Charge` constant from a literal number of milliamp-hours (mAh).
+#[macro_export]
+macro_rules! milli_amp_hours {
+    ($value:literal) => {{
+        const VALUE: $crate::Charge = $crate::Charge::from_micro_coulombs(
+            $crate::literal::unsigned(::core::stringify!($value), 5, 36),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Charge` constant from a literal number of amp-hours (Ah).
+#[macro_export]
+macro_rules! amp_hours {
+    ($value:literal) => {{
+        const VALUE: $crate::Charge = $crate::Charge::from_micro_coulombs(
+            $crate::literal::unsigned(::core::stringify!($value), 8, 36),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Conductance` constant from a literal number of nanosiemens (nS).
+#[macro_export]
+macro_rules! nano_siemens {
+    ($value:literal) => {{
+        const VALUE: $crate::Conductance = $crate::Conductance::from_nano_siemens(
+            $crate::literal::unsigned(::core::stringify!($value), 0, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Conductance` constant from a literal number of microsiemens (μS).
+#[macro_export]
+macro_rules! micro_siemens {
+    ($value:literal) => {{
+        const VALUE: $crate::Conductance = $crate::Conductance::from_nano_siemens(
+            $crate::literal::unsigned(::core::stringify!($value), 3, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Conductance` constant from a literal number of millisiemens (mS).
+#[macro_export]
+macro_rules! milli_siemens {
+    ($value:literal) => {{
+        const VALUE: $crate::Conductance = $crate::Conductance::from_nano_siemens(
+            $crate::literal::unsigned(::core::stringify!($value), 6, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Conductance` constant from a literal number of siemens (S).
+#[macro_export]
+macro_rules! siemens {
+    ($value:literal) => {{
+        const VALUE: $crate::Conductance = $crate::Conductance::from_nano_siemens(
+            $crate::literal::unsigned(::core::stringify!($value), 9, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Current` constant from a literal number of microamps (μA).
+#[macro_export]
+macro_rules! micro_amps {
+    ($value:literal) => {{
+        const VALUE: $crate::Current = $crate::Current::from_micro_amps($crate::literal::unsigned(
+            ::core::stringify!($value),
+            0,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Current` constant from a literal number of milliamps (mA).
+///
+/// ```rust
+/// # #[cfg(feature = "current")] {
+/// use ohms::{milli_amps, Current};
+///
+/// const VALUE: Current = milli_amps!(250);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! milli_amps {
+    ($value:literal) => {{
+        const VALUE: $crate::Current = $crate::Current::from_micro_amps($crate::literal::unsigned(
+            ::core::stringify!($value),
+            3,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Current` constant from a literal number of amps (A).
+#[macro_export]
+macro_rules! amps {
+    ($value:literal) => {{
+        const VALUE: $crate::Current = $crate::Current::from_micro_amps($crate::literal::unsigned(
+            ::core::stringify!($value),
+            6,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Energy` constant from a literal number of nanojoules (nJ).
+#[macro_export]
+macro_rules! nano_joules {
+    ($value:literal) => {{
+        const VALUE: $crate::Energy = $crate::Energy::from_nano_joules($crate::literal::unsigned(
+            ::core::stringify!($value),
+            0,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Energy` constant from a literal number of microjoules (μJ).
+#[macro_export]
+macro_rules! micro_joules {
+    ($value:literal) => {{
+        const VALUE: $crate::Energy = $crate::Energy::from_nano_joules($crate::literal::unsigned(
+            ::core::stringify!($value),
+            3,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Energy` constant from a literal number of millijoules (mJ).
+#[macro_export]
+macro_rules! milli_joules {
+    ($value:literal) => {{
+        const VALUE: $crate::Energy = $crate::Energy::from_nano_joules($crate::literal::unsigned(
+            ::core::stringify!($value),
+            6,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Energy` constant from a literal number of joules (J).
+#[macro_export]
+macro_rules! joules {
+    ($value:literal) => {{
+        const VALUE: $crate::Energy = $crate::Energy::from_nano_joules($crate::literal::unsigned(
+            ::core::stringify!($value),
+            9,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Energy` constant from a literal number of kilojoules (kJ).
+#[macro_export]
+macro_rules! kilo_joules {
+    ($value:literal) => {{
+        const VALUE: $crate::Energy = $crate::Energy::from_nano_joules($crate::literal::unsigned(
+            ::core::stringify!($value),
+            12,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Energy` constant from a literal number of milliwatt-hours (mWh).
+#[macro_export]
+macro_rules! milli_watt_hours {
+    ($value:literal) => {{
+        const VALUE: $crate::Energy = $crate::Energy::from_nano_joules($crate::literal::unsigned(
+            ::core::stringify!($value),
+            8,
+            36,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Energy` constant from a literal number of watt-hours (Wh).
+#[macro_export]
+macro_rules! watt_hours {
+    ($value:literal) => {{
+        const VALUE: $crate::Energy = $crate::Energy::from_nano_joules($crate::literal::unsigned(
+            ::core::stringify!($value),
+            11,
+            36,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Energy` constant from a literal number of kilowatt-hours (kWh).
+#[macro_export]
+macro_rules! kilo_watt_hours {
+    ($value:literal) => {{
+        const VALUE: $crate::Energy = $crate::Energy::from_nano_joules($crate::literal::unsigned(
+            ::core::stringify!($value),
+            14,
+            36,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Frequency` constant from a literal number of millihertz (mHz).
+#[macro_export]
+macro_rules! milli_hertz {
+    ($value:literal) => {{
+        const VALUE: $crate::Frequency = $crate::Frequency::from_milli_hertz(
+            $crate::literal::unsigned(::core::stringify!($value), 0, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Frequency` constant from a literal number of hertz (Hz).
+#[macro_export]
+macro_rules! hertz {
+    ($value:literal) => {{
+        const VALUE: $crate::Frequency = $crate::Frequency::from_milli_hertz(
+            $crate::literal::unsigned(::core::stringify!($value), 3, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Frequency` constant from a literal number of kilohertz (kHz).
+#[macro_export]
+macro_rules! kilo_hertz {
+    ($value:literal) => {{
+        const VALUE: $crate::Frequency = $crate::Frequency::from_milli_hertz(
+            $crate::literal::unsigned(::core::stringify!($value), 6, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Frequency` constant from a literal number of megahertz (MHz).
+#[macro_export]
+macro_rules! mega_hertz {
+    ($value:literal) => {{
+        const VALUE: $crate::Frequency = $crate::Frequency::from_milli_hertz(
+            $crate::literal::unsigned(::core::stringify!($value), 9, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Frequency` constant from a literal number of gigahertz (GHz).
+#[macro_export]
+macro_rules! giga_hertz {
+    ($value:literal) => {{
+        const VALUE: $crate::Frequency = $crate::Frequency::from_milli_hertz(
+            $crate::literal::unsigned(::core::stringify!($value), 12, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Inductance` constant from a literal number of nanohenries (nH).
+#[macro_export]
+macro_rules! nano_henries {
+    ($value:literal) => {{
+        const VALUE: $crate::Inductance = $crate::Inductance::from_nano_henries(
+            $crate::literal::unsigned(::core::stringify!($value), 0, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Inductance` constant from a literal number of microhenries (μH).
+#[macro_export]
+macro_rules! micro_henries {
+    ($value:literal) => {{
+        const VALUE: $crate::Inductance = $crate::Inductance::from_nano_henries(
+            $crate::literal::unsigned(::core::stringify!($value), 3, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Inductance` constant from a literal number of millihenries (mH).
+#[macro_export]
+macro_rules! milli_henries {
+    ($value:literal) => {{
+        const VALUE: $crate::Inductance = $crate::Inductance::from_nano_henries(
+            $crate::literal::unsigned(::core::stringify!($value), 6, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Inductance` constant from a literal number of henries (H).
+#[macro_export]
+macro_rules! henries {
+    ($value:literal) => {{
+        const VALUE: $crate::Inductance = $crate::Inductance::from_nano_henries(
+            $crate::literal::unsigned(::core::stringify!($value), 9, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Power` constant from a literal number of microwatts (μW).
+#[macro_export]
+macro_rules! micro_watts {
+    ($value:literal) => {{
+        const VALUE: $crate::Power = $crate::Power::from_micro_watts($crate::literal::unsigned(
+            ::core::stringify!($value),
+            0,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Power` constant from a literal number of milliwatts (mW).
+#[macro_export]
+macro_rules! milli_watts {
+    ($value:literal) => {{
+        const VALUE: $crate::Power = $crate::Power::from_micro_watts($crate::literal::unsigned(
+            ::core::stringify!($value),
+            3,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Power` constant from a literal number of watts (W).
+#[macro_export]
+macro_rules! watts {
+    ($value:literal) => {{
+        const VALUE: $crate::Power = $crate::Power::from_micro_watts($crate::literal::unsigned(
+            ::core::stringify!($value),
+            6,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Power` constant from a literal number of kilowatts (kW).
+#[macro_export]
+macro_rules! kilo_watts {
+    ($value:literal) => {{
+        const VALUE: $crate::Power = $crate::Power::from_micro_watts($crate::literal::unsigned(
+            ::core::stringify!($value),
+            9,
+            1,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Resistance` constant from a literal number of milliohms (mΩ).
+#[macro_export]
+macro_rules! milli_ohms {
+    ($value:literal) => {{
+        const VALUE: $crate::Resistance = $crate::Resistance::from_milli_ohms(
+            $crate::literal::unsigned(::core::stringify!($value), 0, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Resistance` constant from a literal number of ohms (Ω).
+#[macro_export]
+macro_rules! ohms {
+    ($value:literal) => {{
+        const VALUE: $crate::Resistance = $crate::Resistance::from_milli_ohms(
+            $crate::literal::unsigned(::core::stringify!($value), 3, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Resistance` constant from a literal number of kilohms (kΩ).
+///
+/// ```rust
+/// # #[cfg(feature = "resistance")] {
+/// use ohms::{kilo_ohms, Resistance};
+///
+/// const VALUE: Resistance = kilo_ohms!(4.7);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! kilo_ohms {
+    ($value:literal) => {{
+        const VALUE: $crate::Resistance = $crate::Resistance::from_milli_ohms(
+            $crate::literal::unsigned(::core::stringify!($value), 6, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Resistance` constant from a literal number of megohms (MΩ).
+#[macro_export]
+macro_rules! mega_ohms {
+    ($value:literal) => {{
+        const VALUE: $crate::Resistance = $crate::Resistance::from_milli_ohms(
+            $crate::literal::unsigned(::core::stringify!($value), 9, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Resistance` constant from a literal number of gigaohms (GΩ).
+#[macro_export]
+macro_rules! giga_ohms {
+    ($value:literal) => {{
+        const VALUE: $crate::Resistance = $crate::Resistance::from_milli_ohms(
+            $crate::literal::unsigned(::core::stringify!($value), 12, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Resistance` constant from a literal number of teraohms (TΩ).
+#[macro_export]
+macro_rules! tera_ohms {
+    ($value:literal) => {{
+        const VALUE: $crate::Resistance = $crate::Resistance::from_milli_ohms(
+            $crate::literal::unsigned(::core::stringify!($value), 15, 1),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Temperature` constant from a literal number of millidegrees Celsius (m°C).
+#[macro_export]
+macro_rules! milli_degrees_celsius {
+    ($value:literal) => {{
+        const VALUE: $crate::Temperature = $crate::Temperature::from_milli_degrees_celsius(
+            $crate::literal::signed(::core::stringify!($value), 0, 0),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Temperature` constant from a literal number of degrees Celsius (°C).
+#[macro_export]
+macro_rules! degrees_celsius {
+    ($value:literal) => {{
+        const VALUE: $crate::Temperature = $crate::Temperature::from_milli_degrees_celsius(
+            $crate::literal::signed(::core::stringify!($value), 3, 0),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Temperature` constant from a literal number of kelvin (K).
+#[macro_export]
+macro_rules! kelvin {
+    ($value:literal) => {{
+        const VALUE: $crate::Temperature = $crate::Temperature::from_milli_degrees_celsius(
+            $crate::literal::signed(::core::stringify!($value), 3, -273150),
+        );
+        VALUE
+    }};
+}
+
+/// Creates a `Voltage` constant from a literal number of microvolts (μV).
+#[macro_export]
+macro_rules! micro_volts {
+    ($value:literal) => {{
+        const VALUE: $crate::Voltage = $crate::Voltage::from_micro_volts($crate::literal::signed(
+            ::core::stringify!($value),
+            0,
+            0,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Voltage` constant from a literal number of millivolts (mV).
+#[macro_export]
+macro_rules! milli_volts {
+    ($value:literal) => {{
+        const VALUE: $crate::Voltage = $crate::Voltage::from_micro_volts($crate::literal::signed(
+            ::core::stringify!($value),
+            3,
+            0,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Voltage` constant from a literal number of volts (V).
+///
+/// ```rust
+/// # #[cfg(feature = "voltage")] {
+/// use ohms::{volts, Voltage};
+///
+/// const VALUE: Voltage = volts!(3.3);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! volts {
+    ($value:literal) => {{
+        const VALUE: $crate::Voltage = $crate::Voltage::from_micro_volts($crate::literal::signed(
+            ::core::stringify!($value),
+            6,
+            0,
+        ));
+        VALUE
+    }};
+}
+
+/// Creates a `Voltage` constant from a literal number of kilovolts (kV).
+#[macro_export]
+macro_rules! kilo_volts {
+    ($value:literal) => {{
+        const VALUE: $crate::Voltage = $crate::Voltage::from_micro_volts($crate::literal::signed(
+            ::core::stringify!($value),
+            9,
+            0,
+        ));
+        VALUE
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    use crate::{Charge, Current, Resistance, Temperature, Voltage};
+    use test_case::test_case;
+
+    #[test_case("3.3", 6, 3_300_000; "decimal")]
+    #[test_case("250", 3, 250_000; "integer")]
+    #[test_case("4.70", 3, 4_700; "trailing zero")]
+    #[test_case("1_000", 0, 1_000; "underscores")]
+    #[test_case("0.000001", 6, 1; "smallest")]
+    #[test_case("-0", 0, 0; "negative zero")]
+    fn test_unsigned(literal: &str, exponent: u32, expected: u64) {
+        assert_eq!(unsigned(literal, exponent, 1), expected);
+    }
+
+    #[test_case("-3.3", 6, 0, -3_300_000; "negative")]
+    #[test_case("- 3.3", 6, 0, -3_300_000; "negative with space")]
+    #[test_case("25", 3, 0, 25_000; "positive")]
+    #[test_case("0", 3, -273_150, -273_150; "offset")]
+    #[test_case("-9223372036854775808", 0, 0, i64::MIN; "minimum")]
+    fn test_signed(literal: &str, exponent: u32, offset: i64, expected: i64) {
+        assert_eq!(signed(literal, exponent, offset), expected);
+    }
+
+    #[test_case("1.0001", 3; "too many decimal places")]
+    #[test_case("1e3", 3; "exponent")]
+    #[test_case("3u32", 3; "type suffix")]
+    #[test_case("", 3; "empty")]
+    #[test_case(".", 3; "no digits")]
+    #[test_case("1.2.3", 3; "multiple decimal points")]
+    #[test_case("-1", 3; "negative unsigned")]
+    #[test_case("18446744073709551616", 0; "too large")]
+    #[should_panic]
+    fn test_unsigned_invalid_panics(literal: &str, exponent: u32) {
+        unsigned(literal, exponent, 1);
+    }
+
+    #[test]
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    fn test_macros_are_const() {
+        const SUPPLY: Voltage = volts!(3.3);
+        const NEGATIVE_RAIL: Voltage = volts!(-12);
+        const PULL_UP: Resistance = kilo_ohms!(4.7);
+        const LIMIT: Current = milli_amps!(250);
+        const CAPACITY: Charge = milli_amp_hours!(2_000);
+        const ROOM: Temperature = kelvin!(298.15);
+        const INSULATION: Resistance = giga_ohms!(1.5);
+
+        assert_eq!(SUPPLY.micro_volts(), 3_300_000);
+        assert_eq!(NEGATIVE_RAIL.micro_volts(), -12_000_000);
+        assert_eq!(PULL_UP.milli_ohms(), 4_700_000);
+        assert_eq!(LIMIT.micro_amps(), 250_000);
+        assert_eq!(CAPACITY.micro_coulombs(), 7_200_000_000);
+        assert_eq!(ROOM.milli_degrees_celsius(), 25_000);
+        assert_eq!(INSULATION.milli_ohms(), 1_500_000_000_000);
+    }
+}
@@ -0,0 +1,156 @@
+//! Resistivities and temperature coefficients of common conductor materials.
+//!
+//! Each `Material` is a constant, so it can be used in `const` contexts and passed straight to
+//! `wire_resistance`. Values are typical for the pure (or standard alloy) material at 20°C, and
+//! real wire may differ by a few percent.
+//!
+//! ```rust
+//! use ohms::materials;
+//! use ohms::prelude::*;
+//!
+//! const BUS_BAR: Resistivity = materials::COPPER.resistivity();
+//! assert_eq!(BUS_BAR.pico_ohm_meters(), 17_241);
+//!
+//! let hot = materials::COPPER.resistivity_at(80.degrees_celsius());
+//! assert_eq!(hot.pico_ohm_meters(), 21_306); // 60°C above the reference
+//! ```
+use crate::{Ppm, Resistivity, Temperature};
+
+/// The temperature the resistivity of each material is specified at.
+pub const REFERENCE_TEMPERATURE: Temperature = Temperature::from_milli_degrees_celsius(20_000);
+
+/// A conductor material with its resistivity and temperature coefficient.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Material {
+    name: &'static str,
+    resistivity: Resistivity,
+    tcr: Ppm,
+}
+
+impl Material {
+    /// Creates a new `Material` from its name, its resistivity at the reference temperature and
+    /// its temperature coefficient of resistance (TCR) in parts per million per degree Celsius
+    /// (ppm/°C).
+    #[inline]
+    pub const fn new(name: &'static str, resistivity: Resistivity, tcr: Ppm) -> Self {
+        Self {
+            name,
+            resistivity,
+            tcr,
+        }
+    }
+
+    /// Returns the name of the material.
+    #[inline]
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the resistivity at the reference temperature of 20°C.
+    #[inline]
+    pub const fn resistivity(&self) -> Resistivity {
+        self.resistivity
+    }
+
+    /// Returns the temperature coefficient of resistance (TCR) in parts per million per degree
+    /// Celsius (ppm/°C).
+    #[inline]
+    pub const fn tcr(&self) -> Ppm {
+        self.tcr
+    }
+
+    /// Calculates the resistivity at a temperature, following `ρ = ρ₀ × (1 + α·ΔT)` from the
+    /// reference temperature of 20°C.
+    ///
+    /// Will be rounded down to the nearest whole picoohm meter (pΩ·m), saturating at zero and at
+    /// the largest representable resistivity.
+    pub fn resistivity_at(&self, temperature: Temperature) -> Resistivity {
+        let delta_t = temperature.milli_degrees_celsius() as i128
+            - REFERENCE_TEMPERATURE.milli_degrees_celsius() as i128;
+
+        // ppm/°C × m°C is parts per billion
+        let factor = 1_000_000_000i128.saturating_add(self.tcr.ppm() as i128 * delta_t);
+        if factor <= 0 {
+            return Resistivity::zero();
+        }
+
+        let pico_ohm_meters =
+            (self.resistivity.pico_ohm_meters() as i128).saturating_mul(factor) / 1_000_000_000;
+        Resistivity::from_pico_ohm_meters(u64::try_from(pico_ohm_meters).unwrap_or(u64::MAX))
+    }
+}
+
+/// Annealed copper, as defined by the International Annealed Copper Standard (IACS).
+pub const COPPER: Material = Material::new(
+    "copper",
+    Resistivity::from_pico_ohm_meters(17_241),
+    Ppm::new(3_930),
+);
+
+/// Aluminum.
+pub const ALUMINUM: Material = Material::new(
+    "aluminum",
+    Resistivity::from_pico_ohm_meters(26_500),
+    Ppm::new(3_900),
+);
+
+/// Gold.
+pub const GOLD: Material = Material::new(
+    "gold",
+    Resistivity::from_pico_ohm_meters(22_140),
+    Ppm::new(3_400),
+);
+
+/// Nichrome (80% nickel, 20% chromium), as used for heating elements.
+pub const NICHROME: Material = Material::new(
+    "nichrome",
+    Resistivity::from_pico_ohm_meters(1_100_000),
+    Ppm::new(400),
+);
+
+/// Every material in this module, in order of increasing resistivity.
+pub const ALL: &[Material] = &[COPPER, GOLD, ALUMINUM, NICHROME];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn test_all_in_order() {
+        assert!(ALL
+            .windows(2)
+            .all(|pair| pair[0].resistivity() < pair[1].resistivity()));
+        assert!(ALL.iter().any(|m| m.name() == "nichrome"));
+    }
+
+    #[test_case(COPPER, 20_000, 17_241; "copper at reference")]
+    #[test_case(COPPER, 80_000, 21_306; "copper hot")]
+    #[test_case(COPPER, -20_000, 14_530; "copper cold")]
+    #[test_case(NICHROME, 520_000, 1_320_000; "nichrome glowing")]
+    #[test_case(COPPER, -300_000, 0; "saturates at zero")]
+    fn test_resistivity_at(material: Material, milli_celsius: i64, expected: u64) {
+        let resistivity =
+            material.resistivity_at(Temperature::from_milli_degrees_celsius(milli_celsius));
+        assert_eq!(resistivity.pico_ohm_meters(), expected);
+    }
+
+    #[test]
+    fn test_resistivity_at_saturates() {
+        let material = Material::new(
+            "test",
+            Resistivity::from_pico_ohm_meters(u64::MAX),
+            Ppm::new(1),
+        );
+        let resistivity =
+            material.resistivity_at(Temperature::from_milli_degrees_celsius(i64::MAX));
+        assert_eq!(resistivity.pico_ohm_meters(), u64::MAX);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    fn test_wire_resistance() {
+        let resistance = crate::wire_resistance(ALUMINUM.resistivity(), 10_000, 2.5).unwrap();
+        assert_eq!(resistance.milli_ohms(), 106);
+    }
+}
@@ -0,0 +1,279 @@
+use crate::{RoundingMode, UnitError};
+
+/// Calculates the integer square root of a value, rounded down to the nearest whole number.
+///
+/// Uses Newton's method so that no floating-point math is required.
+#[cfg(any(
+    feature = "voltage",
+    feature = "current",
+    feature = "resistance",
+    feature = "power"
+))]
+pub(crate) const fn isqrt(value: u128) -> u128 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = value / 2 + (value & 1);
+
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+
+    x
+}
+
+/// Calculates the mean of the squares of a set of values, rounded down to the nearest whole number.
+///
+/// Each square is divided by the number of values as it is accumulated, so the sum cannot overflow.
+/// Returns `None` if there are no values.
+#[cfg(any(feature = "voltage", feature = "current"))]
+pub(crate) fn mean_square(values: impl ExactSizeIterator<Item = u64>) -> Option<u128> {
+    if values.len() == 0 {
+        return None;
+    }
+
+    let count = values.len() as u128;
+    let (quotients, remainders) = values.fold((0u128, 0u128), |(quotients, remainders), value| {
+        let square = value as u128 * value as u128;
+        (quotients + square / count, remainders + square % count)
+    });
+
+    Some(quotients + remainders / count)
+}
+
+/// Calculates `value × multiplier ÷ divisor` using a 128-bit intermediate product, rounded down to
+/// the nearest whole number.
+///
+/// Returns `None` if the divisor is zero or the result is too large to be represented.
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+pub(crate) const fn mul_div(value: u64, multiplier: u64, divisor: u64) -> Option<u64> {
+    mul_div_rounded(value, multiplier, divisor, RoundingMode::Down)
+}
+
+/// Calculates `value × multiplier ÷ divisor` using a 128-bit intermediate product, rounded to a
+/// whole number in the given direction.
+///
+/// Returns `None` if the divisor is zero or the result is too large to be represented.
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    any(feature = "resistance", feature = "power")
+))]
+pub(crate) const fn mul_div_rounded(
+    value: u64,
+    multiplier: u64,
+    divisor: u64,
+    mode: RoundingMode,
+) -> Option<u64> {
+    if divisor == 0 {
+        return None;
+    }
+
+    let result = mode.div_u128(value as u128 * multiplier as u128, divisor as u128);
+
+    if result > u64::MAX as u128 {
+        None
+    } else {
+        Some(result as u64)
+    }
+}
+
+/// Converts a floating-point value to an unsigned integer, rounding down to the nearest whole number.
+///
+/// Returns `None` if the value is NaN, infinite, negative or too large to be represented.
+pub(crate) fn f64_to_u64(value: f64) -> Option<u64> {
+    if value.is_nan() || value < 0f64 || value >= u64::MAX as f64 {
+        return None;
+    }
+
+    Some(value as u64)
+}
+
+/// Converts a floating-point value to a signed integer, rounding toward zero.
+///
+/// Returns `None` if the value is NaN, infinite or too large to be represented.
+pub(crate) fn f64_to_i64(value: f64) -> Option<i64> {
+    if value.is_nan() || value < i64::MIN as f64 || value >= i64::MAX as f64 {
+        return None;
+    }
+
+    Some(value as i64)
+}
+
+/// Scales a floating-point value into an unsigned integer, rounding down to the nearest whole number.
+///
+/// Returns an error if the value is NaN, infinite, negative or the result is too large to be
+/// represented.
+pub(crate) fn try_scale_f64_to_u64(value: f64, scale: f64) -> Result<u64, UnitError> {
+    if value.is_nan() {
+        return Err(UnitError::NaN);
+    }
+    if value.is_infinite() {
+        return Err(UnitError::Infinite);
+    }
+    if value < 0f64 {
+        return Err(UnitError::Negative);
+    }
+
+    f64_to_u64(value * scale).ok_or(UnitError::Overflow)
+}
+
+/// Scales a floating-point value into a signed integer, rounding toward zero.
+///
+/// Returns an error if the value is NaN, infinite or the result is too large to be represented.
+pub(crate) fn try_scale_f64_to_i64(value: f64, scale: f64) -> Result<i64, UnitError> {
+    if value.is_nan() {
+        return Err(UnitError::NaN);
+    }
+    if value.is_infinite() {
+        return Err(UnitError::Infinite);
+    }
+
+    f64_to_i64(value * scale).ok_or(UnitError::Overflow)
+}
+
+/// Scales a floating-point value into an unsigned integer, rounding to a whole number in the given
+/// direction.
+///
+/// Returns an error if the value is NaN, infinite, rounds to a negative number or the result is
+/// too large to be represented.
+#[cfg(any(feature = "current", feature = "resistance", feature = "power"))]
+pub(crate) fn try_round_f64_to_u64(
+    value: f64,
+    scale: f64,
+    mode: RoundingMode,
+) -> Result<u64, UnitError> {
+    try_scale_f64_to_u64(mode.round_f64(value * scale), 1f64)
+}
+
+/// Scales a floating-point value into a signed integer, rounding to a whole number in the given
+/// direction.
+///
+/// Returns an error if the value is NaN, infinite or the result is too large to be represented.
+pub(crate) fn try_round_f64_to_i64(
+    value: f64,
+    scale: f64,
+    mode: RoundingMode,
+) -> Result<i64, UnitError> {
+    try_scale_f64_to_i64(mode.round_f64(value * scale), 1f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[cfg(any(
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    ))]
+    #[test_case(0, 0; "zero")]
+    #[test_case(1, 1; "one")]
+    #[test_case(2, 1; "two rounds down")]
+    #[test_case(3, 1; "three rounds down")]
+    #[test_case(4, 2; "four")]
+    #[test_case(99, 9; "ninety-nine rounds down")]
+    #[test_case(1_000_000, 1_000; "one million")]
+    #[test_case(u64::MAX as u128 * u64::MAX as u128, u64::MAX as u128; "u64 max squared")]
+    #[test_case(u128::MAX, u64::MAX as u128; "u128 max")]
+    fn test_isqrt(value: u128, expected: u128) {
+        assert_eq!(isqrt(value), expected);
+    }
+
+    #[cfg(any(feature = "voltage", feature = "current"))]
+    #[test_case(&[], None; "empty")]
+    #[test_case(&[3, 4], Some(12); "rounds down")]
+    #[test_case(&[u64::MAX; 3], Some(u64::MAX as u128 * u64::MAX as u128); "maximum values")]
+    fn test_mean_square(values: &[u64], expected: Option<u128>) {
+        assert_eq!(mean_square(values.iter().copied()), expected);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test_case(6, 7, 4, Some(10); "rounds down")]
+    #[test_case(u64::MAX, 1_000, 1_000, Some(u64::MAX); "intermediate exceeds 64 bits")]
+    #[test_case(u64::MAX, 2, 1, None; "result too large")]
+    #[test_case(1, 1, 0, None; "zero divisor")]
+    fn test_mul_div(value: u64, multiplier: u64, divisor: u64, expected: Option<u64>) {
+        assert_eq!(mul_div(value, multiplier, divisor), expected);
+    }
+
+    #[cfg(all(
+        feature = "voltage",
+        feature = "current",
+        any(feature = "resistance", feature = "power")
+    ))]
+    #[test_case(6, 7, 4, RoundingMode::Nearest, Some(11); "rounds to nearest")]
+    #[test_case(5, 1, 4, RoundingMode::Up, Some(2); "rounds up")]
+    #[test_case(u64::MAX, 1, 1, RoundingMode::Up, Some(u64::MAX); "exact maximum")]
+    #[test_case(1, 1, 0, RoundingMode::Nearest, None; "zero divisor")]
+    fn test_mul_div_rounded(
+        value: u64,
+        multiplier: u64,
+        divisor: u64,
+        mode: RoundingMode,
+        expected: Option<u64>,
+    ) {
+        assert_eq!(mul_div_rounded(value, multiplier, divisor, mode), expected);
+    }
+
+    #[test_case(0.0, Some(0); "zero")]
+    #[test_case(1.9, Some(1); "rounds down")]
+    #[test_case(-0.5, None; "negative")]
+    #[test_case(f64::NAN, None; "NaN")]
+    #[test_case(f64::INFINITY, None; "infinity")]
+    #[test_case(u64::MAX as f64, None; "too large")]
+    fn test_f64_to_u64(value: f64, expected: Option<u64>) {
+        assert_eq!(f64_to_u64(value), expected);
+    }
+
+    #[test_case(0.0, Some(0); "zero")]
+    #[test_case(-1.9, Some(-1); "rounds toward zero")]
+    #[test_case(i64::MIN as f64, Some(i64::MIN); "minimum")]
+    #[test_case(f64::NAN, None; "NaN")]
+    #[test_case(f64::NEG_INFINITY, None; "negative infinity")]
+    #[test_case(i64::MAX as f64, None; "too large")]
+    fn test_f64_to_i64(value: f64, expected: Option<i64>) {
+        assert_eq!(f64_to_i64(value), expected);
+    }
+
+    #[test_case(1.5, 1_000.0, Ok(1_500); "scales value")]
+    #[test_case(0.0, 1_000.0, Ok(0); "zero")]
+    #[test_case(f64::NAN, 1.0, Err(UnitError::NaN); "NaN")]
+    #[test_case(f64::INFINITY, 1.0, Err(UnitError::Infinite); "infinity")]
+    #[test_case(-1.0, 1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(1e19, 10.0, Err(UnitError::Overflow); "too large")]
+    fn test_try_scale_f64_to_u64(value: f64, scale: f64, expected: Result<u64, UnitError>) {
+        assert_eq!(try_scale_f64_to_u64(value, scale), expected);
+    }
+
+    #[test_case(-1.5, 1_000.0, Ok(-1_500); "scales negative value")]
+    #[test_case(f64::NAN, 1.0, Err(UnitError::NaN); "NaN")]
+    #[test_case(f64::NEG_INFINITY, 1.0, Err(UnitError::Infinite); "negative infinity")]
+    #[test_case(-1e19, 1.0, Err(UnitError::Overflow); "too small")]
+    fn test_try_scale_f64_to_i64(value: f64, scale: f64, expected: Result<i64, UnitError>) {
+        assert_eq!(try_scale_f64_to_i64(value, scale), expected);
+    }
+
+    #[cfg(any(feature = "current", feature = "resistance", feature = "power"))]
+    #[test_case(1.0005, RoundingMode::Nearest, Ok(1_001); "rounds to nearest")]
+    #[test_case(1.0001, RoundingMode::Up, Ok(1_001); "rounds up")]
+    #[test_case(1.0009, RoundingMode::Down, Ok(1_000); "rounds down")]
+    #[test_case(-0.0004, RoundingMode::Nearest, Ok(0); "rounds small negative to zero")]
+    #[test_case(-0.0006, RoundingMode::Nearest, Err(UnitError::Negative); "negative")]
+    #[test_case(-0.0001, RoundingMode::Down, Err(UnitError::Negative); "negative rounded down")]
+    fn test_try_round_f64_to_u64(value: f64, mode: RoundingMode, expected: Result<u64, UnitError>) {
+        assert_eq!(try_round_f64_to_u64(value, 1_000.0, mode), expected);
+    }
+
+    #[test_case(-1.0004, RoundingMode::Nearest, Ok(-1_000); "rounds to nearest")]
+    #[test_case(-1.0001, RoundingMode::Down, Ok(-1_001); "rounds down")]
+    #[test_case(f64::NAN, RoundingMode::Up, Err(UnitError::NaN); "NaN")]
+    fn test_try_round_f64_to_i64(value: f64, mode: RoundingMode, expected: Result<i64, UnitError>) {
+        assert_eq!(try_round_f64_to_i64(value, 1_000.0, mode), expected);
+    }
+}
@@ -0,0 +1,166 @@
+//! Conversions between unit types and `measurements` types, behind the `measurements` feature.
+//!
+//! `Voltage`, `Current`, `Resistance` and `Power` each convert into the `measurements` type of the
+//! same name with `From`, and back with `TryFrom`, rounding to the nearest whole base unit of this
+//! crate. Values round trip exactly as long as they fit in the 53-bit precision of an `f64`.
+#[cfg(feature = "current")]
+use crate::{Current, CurrentUnit};
+#[cfg(feature = "power")]
+use crate::{Power, PowerUnit};
+#[cfg(feature = "resistance")]
+use crate::{Resistance, ResistanceUnit};
+use crate::{RoundingMode, UnitError};
+#[cfg(feature = "voltage")]
+use crate::{Voltage, VoltageUnit};
+
+#[cfg(feature = "voltage")]
+impl From<Voltage> for ::measurements::Voltage {
+    #[inline]
+    fn from(value: Voltage) -> Self {
+        Self::from_microvolts(value.micro_volts() as f64)
+    }
+}
+
+#[cfg(feature = "voltage")]
+impl TryFrom<::measurements::Voltage> for Voltage {
+    type Error = UnitError;
+
+    #[inline]
+    fn try_from(value: ::measurements::Voltage) -> Result<Self, Self::Error> {
+        Voltage::try_from_rounded(value.as_volts(), VoltageUnit::Volts, RoundingMode::Nearest)
+    }
+}
+
+#[cfg(feature = "current")]
+impl From<Current> for ::measurements::Current {
+    #[inline]
+    fn from(value: Current) -> Self {
+        Self::from_microamperes(value.micro_amps() as f64)
+    }
+}
+
+#[cfg(feature = "current")]
+impl TryFrom<::measurements::Current> for Current {
+    type Error = UnitError;
+
+    #[inline]
+    fn try_from(value: ::measurements::Current) -> Result<Self, Self::Error> {
+        Current::try_from_rounded(value.as_amperes(), CurrentUnit::Amps, RoundingMode::Nearest)
+    }
+}
+
+#[cfg(feature = "resistance")]
+impl From<Resistance> for ::measurements::Resistance {
+    #[inline]
+    fn from(value: Resistance) -> Self {
+        Self::from_ohms(value.milli_ohms() as f64 / 1_000f64)
+    }
+}
+
+#[cfg(feature = "resistance")]
+impl TryFrom<::measurements::Resistance> for Resistance {
+    type Error = UnitError;
+
+    #[inline]
+    fn try_from(value: ::measurements::Resistance) -> Result<Self, Self::Error> {
+        Resistance::try_from_rounded(value.as_ohms(), ResistanceUnit::Ohms, RoundingMode::Nearest)
+    }
+}
+
+#[cfg(feature = "power")]
+impl From<Power> for ::measurements::Power {
+    #[inline]
+    fn from(value: Power) -> Self {
+        Self::from_microwatts(value.micro_watts() as f64)
+    }
+}
+
+#[cfg(feature = "power")]
+impl TryFrom<::measurements::Power> for Power {
+    type Error = UnitError;
+
+    #[inline]
+    fn try_from(value: ::measurements::Power) -> Result<Self, Self::Error> {
+        Power::try_from_rounded(value.as_watts(), PowerUnit::Watts, RoundingMode::Nearest)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[cfg(feature = "voltage")]
+    #[test_case(3_300_000; "positive")]
+    #[test_case(-12_345_678; "negative")]
+    #[test_case(1; "one microvolt")]
+    #[test_case(0; "zero")]
+    fn test_voltage_round_trip(micro_volts: i64) {
+        let voltage = ::measurements::Voltage::from(Voltage::from_micro_volts(micro_volts));
+        let voltage = Voltage::try_from(voltage).map(|v| v.micro_volts());
+        assert_eq!(voltage, Ok(micro_volts));
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(1.5, Ok(1_500_000); "fraction")]
+    #[test_case(-0.0000016, Ok(-2); "rounds negative")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    fn test_voltage_try_from(volts: f64, expected: Result<i64, UnitError>) {
+        let voltage = Voltage::try_from(::measurements::Voltage::from_volts(volts));
+        assert_eq!(voltage.map(|v| v.micro_volts()), expected);
+    }
+
+    #[cfg(feature = "current")]
+    #[test_case(1.5, Ok(1_500_000); "fraction")]
+    #[test_case(0.0000004, Ok(0); "rounds down")]
+    #[test_case(0.0000006, Ok(1); "rounds up")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(1e14, Err(UnitError::Overflow); "overflow")]
+    fn test_current_try_from(amps: f64, expected: Result<u64, UnitError>) {
+        let current = Current::try_from(::measurements::Current::from_amperes(amps));
+        assert_eq!(current.map(|c| c.micro_amps()), expected);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_current_round_trip() {
+        let current = Current::from_micro_amps(22_720);
+        let converted = ::measurements::Current::from(current);
+
+        assert_eq!(converted.as_milliamperes(), 22.72);
+        assert_eq!(Current::try_from(converted), Ok(current));
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(4_700_000; "kilo ohms")]
+    #[test_case(1; "one milliohm")]
+    #[test_case(0; "zero")]
+    fn test_resistance_round_trip(milli_ohms: u64) {
+        let resistance = ::measurements::Resistance::from(Resistance::from_milli_ohms(milli_ohms));
+        let resistance = Resistance::try_from(resistance).map(|r| r.milli_ohms());
+        assert_eq!(resistance, Ok(milli_ohms));
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    fn test_resistance_units() {
+        let resistance = ::measurements::Resistance::from(Resistance::from_milli_ohms(4_700_000));
+        assert_eq!(resistance.as_kiloohms(), 4.7);
+
+        let resistance = Resistance::try_from(::measurements::Resistance::from_ohms(-1.0));
+        assert_eq!(resistance, Err(UnitError::Negative));
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(250_000, 250.0; "milliwatts")]
+    #[test_case(1, 0.001; "one microwatt")]
+    fn test_power_round_trip(micro_watts: u64, milli_watts: f64) {
+        let power = Power::from_micro_watts(micro_watts);
+        let converted = ::measurements::Power::from(power);
+
+        assert_eq!(converted.as_milliwatts(), milli_watts);
+        assert_eq!(Power::try_from(converted), Ok(power));
+    }
+}
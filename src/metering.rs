@@ -0,0 +1,420 @@
+//! Accumulators for integrating samples over time, such as counting the charge drawn from a
+//! battery for a fuel gauge or the energy used by a smart plug.
+//!
+//! The accumulators keep their totals in 128-bit integers at a finer resolution than the unit
+//! types, so short samples are not lost to rounding, and saturate rather than overflow.
+//!
+//! ```rust
+//! # #[cfg(feature = "current")] {
+//! use core::time::Duration;
+//! use ohms::metering::CoulombCounter;
+//! use ohms::prelude::*;
+//!
+//! let mut counter = CoulombCounter::with_sample_period(Duration::from_millis(100));
+//! for _ in 0..36_000 {
+//!     counter.sample(500.milli_amps()); // One hour of samples
+//! }
+//!
+//! assert_eq!(counter.charge(), 500.milli_amp_hours());
+//! # }
+//! ```
+#[cfg(feature = "current")]
+use crate::{Charge, Current};
+#[cfg(feature = "power")]
+use crate::{Energy, SignedPower};
+use core::time::Duration;
+
+/// The number of femtocoulombs (fC) in a microcoulomb (μC), which is one microamp (μA) for one
+/// nanosecond (ns).
+#[cfg(feature = "current")]
+const FEMTO_COULOMBS_PER_MICRO_COULOMB: u128 = 1_000_000_000;
+
+/// The number of femtojoules (fJ) in a nanojoule (nJ), which is one microwatt (μW) for one
+/// nanosecond (ns).
+#[cfg(feature = "power")]
+const FEMTO_JOULES_PER_NANO_JOULE: u128 = 1_000_000;
+
+/// Accumulates current samples into the total charge transferred, also known as coulomb counting.
+///
+/// Each sample is a `Current` held for a duration, either given with the sample or taken from a
+/// fixed sample period. The total is kept in femtocoulombs (fC), which is exact for any whole
+/// number of microamps (μA) and nanoseconds (ns), and saturates at the largest `Charge`.
+#[cfg(feature = "current")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CoulombCounter {
+    sample_period: Option<Duration>,
+    femto_coulombs: u128,
+}
+
+#[cfg(feature = "current")]
+impl CoulombCounter {
+    /// Creates a new `CoulombCounter` with no fixed sample period.
+    ///
+    /// Samples must be added with `add`, which takes the duration of each sample.
+    pub const fn new() -> Self {
+        Self {
+            sample_period: None,
+            femto_coulombs: 0,
+        }
+    }
+
+    /// Creates a new `CoulombCounter` with a fixed sample period, so samples can be added with
+    /// `sample`.
+    pub const fn with_sample_period(period: Duration) -> Self {
+        Self {
+            sample_period: Some(period),
+            femto_coulombs: 0,
+        }
+    }
+
+    /// Returns the fixed sample period of the counter, or `None` if it has none.
+    pub const fn sample_period(&self) -> Option<Duration> {
+        self.sample_period
+    }
+
+    /// Adds a current sample held for the given duration to the total charge.
+    pub fn add(&mut self, current: Current, duration: Duration) {
+        let femto_coulombs = (current.micro_amps() as u128).saturating_mul(duration.as_nanos());
+
+        self.femto_coulombs = self
+            .femto_coulombs
+            .saturating_add(femto_coulombs)
+            .min(Self::MAX_FEMTO_COULOMBS);
+    }
+
+    /// Adds a current sample held for the fixed sample period to the total charge.
+    ///
+    /// Panics if the counter was created without a sample period.
+    pub fn sample(&mut self, current: Current) {
+        let period = self
+            .sample_period
+            .expect("Coulomb counter has no fixed sample period");
+
+        self.add(current, period);
+    }
+
+    /// Returns the total charge transferred since the counter was created or reset.
+    ///
+    /// Will be rounded down to the nearest whole microcoulomb (μC).
+    pub fn charge(&self) -> Charge {
+        Charge::from_micro_coulombs((self.femto_coulombs / FEMTO_COULOMBS_PER_MICRO_COULOMB) as u64)
+    }
+
+    /// Returns whether the total charge has reached the largest `Charge` and stopped counting.
+    pub fn is_saturated(&self) -> bool {
+        self.femto_coulombs == Self::MAX_FEMTO_COULOMBS
+    }
+
+    /// Resets the total charge to zero, keeping the sample period.
+    pub fn reset(&mut self) {
+        self.femto_coulombs = 0;
+    }
+
+    /// The largest total that can be returned as a `Charge`.
+    const MAX_FEMTO_COULOMBS: u128 = u64::MAX as u128 * FEMTO_COULOMBS_PER_MICRO_COULOMB;
+}
+
+/// Accumulates power samples into the total energy imported and exported, as in an energy meter.
+///
+/// Each sample is a `SignedPower` held for a duration, either given with the sample or taken from
+/// a fixed sample period. Positive samples add to the imported total and negative samples add to
+/// the exported total, such as for a load that can also feed power back to its supply.
+///
+/// The totals are kept in femtojoules (fJ), which is exact for any whole number of microwatts (μW)
+/// and nanoseconds (ns), and each saturates at the largest `Energy`. Use `rollover` to read and
+/// clear the totals at the end of each billing or reporting period without losing the fractions
+/// of a nanojoule (nJ) still being counted.
+///
+/// ```rust
+/// use core::time::Duration;
+/// use ohms::metering::EnergyMeter;
+/// use ohms::prelude::*;
+///
+/// let mut meter = EnergyMeter::with_sample_period(Duration::from_secs(1));
+/// meter.sample(SignedPower::from_micro_watts(60_000_000)); // Drawing 60W
+/// meter.sample(SignedPower::from_micro_watts(-20_000_000)); // Feeding back 20W
+///
+/// let reading = meter.rollover();
+/// assert_eq!(reading.imported(), 60.joules());
+/// assert_eq!(reading.exported(), 20.joules());
+/// assert_eq!(meter.imported(), Energy::zero());
+/// ```
+#[cfg(feature = "power")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EnergyMeter {
+    sample_period: Option<Duration>,
+    imported: u128,
+    exported: u128,
+}
+
+#[cfg(feature = "power")]
+impl EnergyMeter {
+    /// Creates a new `EnergyMeter` with no fixed sample period.
+    ///
+    /// Samples must be added with `add`, which takes the duration of each sample.
+    pub const fn new() -> Self {
+        Self {
+            sample_period: None,
+            imported: 0,
+            exported: 0,
+        }
+    }
+
+    /// Creates a new `EnergyMeter` with a fixed sample period, so samples can be added with
+    /// `sample`.
+    pub const fn with_sample_period(period: Duration) -> Self {
+        Self {
+            sample_period: Some(period),
+            imported: 0,
+            exported: 0,
+        }
+    }
+
+    /// Returns the fixed sample period of the meter, or `None` if it has none.
+    pub const fn sample_period(&self) -> Option<Duration> {
+        self.sample_period
+    }
+
+    /// Adds a power sample held for the given duration to the imported or exported total.
+    pub fn add(&mut self, power: SignedPower, duration: Duration) {
+        let femto_joules =
+            (power.micro_watts().unsigned_abs() as u128).saturating_mul(duration.as_nanos());
+
+        let total = if power.is_negative() {
+            &mut self.exported
+        } else {
+            &mut self.imported
+        };
+        *total = total
+            .saturating_add(femto_joules)
+            .min(Self::MAX_FEMTO_JOULES);
+    }
+
+    /// Adds a power sample held for the fixed sample period to the imported or exported total.
+    ///
+    /// Panics if the meter was created without a sample period.
+    pub fn sample(&mut self, power: SignedPower) {
+        let period = self
+            .sample_period
+            .expect("Energy meter has no fixed sample period");
+
+        self.add(power, period);
+    }
+
+    /// Returns the total energy imported since the meter was created, reset or rolled over.
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    pub fn imported(&self) -> Energy {
+        Self::energy(self.imported)
+    }
+
+    /// Returns the total energy exported since the meter was created, reset or rolled over.
+    ///
+    /// Will be rounded down to the nearest whole nanojoule (nJ).
+    pub fn exported(&self) -> Energy {
+        Self::energy(self.exported)
+    }
+
+    /// Returns whether either total has reached the largest `Energy` and stopped counting.
+    pub fn is_saturated(&self) -> bool {
+        self.imported == Self::MAX_FEMTO_JOULES || self.exported == Self::MAX_FEMTO_JOULES
+    }
+
+    /// Returns the imported and exported totals without clearing them.
+    pub fn snapshot(&self) -> EnergyReading {
+        EnergyReading {
+            imported: self.imported(),
+            exported: self.exported(),
+        }
+    }
+
+    /// Returns the imported and exported totals and subtracts them from the meter, starting a new
+    /// period.
+    ///
+    /// Fractions of a nanojoule (nJ) are kept, so no energy is lost across many rollovers.
+    pub fn rollover(&mut self) -> EnergyReading {
+        let reading = self.snapshot();
+
+        self.imported %= FEMTO_JOULES_PER_NANO_JOULE;
+        self.exported %= FEMTO_JOULES_PER_NANO_JOULE;
+
+        reading
+    }
+
+    /// Resets both totals to zero, keeping the sample period.
+    pub fn reset(&mut self) {
+        self.imported = 0;
+        self.exported = 0;
+    }
+
+    fn energy(femto_joules: u128) -> Energy {
+        Energy::from_nano_joules((femto_joules / FEMTO_JOULES_PER_NANO_JOULE) as u64)
+    }
+
+    /// The largest total that can be returned as an `Energy`.
+    const MAX_FEMTO_JOULES: u128 = u64::MAX as u128 * FEMTO_JOULES_PER_NANO_JOULE;
+}
+
+/// The energy imported and exported by an `EnergyMeter` over a period.
+#[cfg(feature = "power")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EnergyReading {
+    imported: Energy,
+    exported: Energy,
+}
+
+#[cfg(feature = "power")]
+impl EnergyReading {
+    /// Returns the energy imported over the period.
+    pub const fn imported(&self) -> Energy {
+        self.imported
+    }
+
+    /// Returns the energy exported over the period.
+    pub const fn exported(&self) -> Energy {
+        self.exported
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[cfg(feature = "current")]
+    #[test_case(&[(1_000_000, 3_600_000_000_000)], 3_600_000_000; "1A for 1h equals 1Ah")]
+    #[test_case(&[(1_000, 1_000); 1_000], 1; "sub-microcoulomb samples are kept")]
+    #[test_case(&[(1, 999)], 0; "rounds down")]
+    #[test_case(&[(500_000, 1_000_000), (0, 5_000_000), (250_000, 2_000_000)], 1_000; "mixed samples")]
+    fn test_add(samples: &[(u64, u64)], expected_micro_coulombs: u64) {
+        let mut counter = CoulombCounter::new();
+        for &(micro_amps, nanos) in samples {
+            counter.add(
+                Current::from_micro_amps(micro_amps),
+                Duration::from_nanos(nanos),
+            );
+        }
+
+        assert_eq!(counter.charge().micro_coulombs(), expected_micro_coulombs);
+        assert!(!counter.is_saturated());
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_sample() {
+        let mut counter = CoulombCounter::with_sample_period(Duration::from_millis(10));
+        for _ in 0..100 {
+            counter.sample(Current::from_micro_amps(2_000_000));
+        }
+
+        assert_eq!(counter.sample_period(), Some(Duration::from_millis(10)));
+        assert_eq!(counter.charge().micro_coulombs(), 2_000_000);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    #[should_panic]
+    fn test_sample_without_period_panics() {
+        CoulombCounter::new().sample(Current::from_micro_amps(1));
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_saturates() {
+        let mut counter = CoulombCounter::new();
+        counter.add(Current::from_micro_amps(u64::MAX), Duration::MAX);
+        counter.add(Current::from_micro_amps(u64::MAX), Duration::MAX);
+
+        assert!(counter.is_saturated());
+        assert_eq!(counter.charge().micro_coulombs(), u64::MAX);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_reset() {
+        let mut counter = CoulombCounter::with_sample_period(Duration::from_secs(1));
+        counter.sample(Current::from_micro_amps(1_000));
+        counter.reset();
+
+        assert_eq!(counter.charge(), Charge::zero());
+        assert_eq!(counter.sample_period(), Some(Duration::from_secs(1)));
+        assert_eq!(
+            counter,
+            CoulombCounter::with_sample_period(Duration::from_secs(1))
+        );
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(&[(1_000_000, 3_600_000_000_000)], 3_600_000_000_000, 0; "1W for 1h equals 1Wh")]
+    #[test_case(&[(-1_000_000, 3_600_000_000_000)], 0, 3_600_000_000_000; "exported")]
+    #[test_case(&[(1, 1_000); 1_000], 1, 0; "sub-nanojoule samples are kept")]
+    #[test_case(&[(1, 999)], 0, 0; "rounds down")]
+    #[test_case(&[(2_000, 1_000_000), (-500, 2_000_000), (0, 5_000_000)], 2_000, 1_000; "mixed samples")]
+    fn test_energy_meter_add(samples: &[(i64, u64)], imported: u64, exported: u64) {
+        let mut meter = EnergyMeter::new();
+        for &(micro_watts, nanos) in samples {
+            meter.add(
+                SignedPower::from_micro_watts(micro_watts),
+                Duration::from_nanos(nanos),
+            );
+        }
+
+        assert_eq!(meter.imported().nano_joules(), imported);
+        assert_eq!(meter.exported().nano_joules(), exported);
+        assert!(!meter.is_saturated());
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    #[should_panic]
+    fn test_energy_meter_sample_without_period_panics() {
+        EnergyMeter::new().sample(SignedPower::from_micro_watts(1));
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    fn test_energy_meter_saturates() {
+        let mut meter = EnergyMeter::new();
+        meter.add(SignedPower::from_micro_watts(i64::MIN), Duration::MAX);
+        meter.add(SignedPower::from_micro_watts(i64::MIN), Duration::MAX);
+
+        assert!(meter.is_saturated());
+        assert_eq!(meter.exported().nano_joules(), u64::MAX);
+        assert_eq!(meter.imported(), Energy::zero());
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    fn test_energy_meter_rollover_keeps_remainder() {
+        // 1.5nJ imported and 0.75nJ exported per sample
+        let mut meter = EnergyMeter::with_sample_period(Duration::from_nanos(1_500));
+        meter.sample(SignedPower::from_micro_watts(1_000));
+        meter.sample(SignedPower::from_micro_watts(-500));
+
+        let reading = meter.rollover();
+        assert_eq!(reading.imported().nano_joules(), 1);
+        assert_eq!(reading.exported().nano_joules(), 0);
+        assert_eq!(meter.snapshot(), EnergyReading::default());
+
+        meter.sample(SignedPower::from_micro_watts(1_000));
+        meter.sample(SignedPower::from_micro_watts(-500));
+
+        let reading = meter.rollover();
+        assert_eq!(reading.imported().nano_joules(), 2);
+        assert_eq!(reading.exported().nano_joules(), 1);
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    fn test_energy_meter_reset() {
+        let mut meter = EnergyMeter::with_sample_period(Duration::from_secs(1));
+        meter.sample(SignedPower::from_micro_watts(1_000));
+        meter.sample(SignedPower::from_micro_watts(-1_000));
+        meter.reset();
+
+        assert_eq!(
+            meter,
+            EnergyMeter::with_sample_period(Duration::from_secs(1))
+        );
+    }
+}
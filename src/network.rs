@@ -0,0 +1,331 @@
+//! Thevenin and Norton equivalents of linear source networks.
+//!
+//! Any network of sources and resistors, seen from a pair of terminals, behaves like either a
+//! voltage source in series with a resistance (Thevenin) or a current source in parallel with a
+//! resistance (Norton). This makes it simple to model the effect of a load on a sensor output.
+//!
+//! ```rust
+//! use ohms::network::Thevenin;
+//! use ohms::prelude::*;
+//!
+//! let divider = VoltageDivider::new(10.kilo_ohms(), 10.kilo_ohms());
+//! let equivalent = Thevenin::from_divider(5.volts(), divider); // 2.5V behind 5kΩ
+//!
+//! let loaded = equivalent.loaded_voltage(5.kilo_ohms());
+//! assert_eq!(loaded, 1.25.volts());
+//! ```
+
+use crate::{Current, Resistance, Voltage, VoltageDivider};
+
+/// Represents a Thevenin equivalent, an ideal voltage source in series with a resistance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Thevenin {
+    voltage: Voltage,
+    resistance: Resistance,
+}
+
+impl Thevenin {
+    /// Creates a new `Thevenin` equivalent from its open-circuit voltage and series resistance.
+    #[inline]
+    pub const fn new(voltage: Voltage, resistance: Resistance) -> Self {
+        Self {
+            voltage,
+            resistance,
+        }
+    }
+
+    /// Calculates the Thevenin equivalent at the output of a voltage divider driven by an ideal
+    /// source.
+    ///
+    /// The open-circuit voltage is the unloaded divider output, and the resistance is both
+    /// resistors in parallel.
+    /// Panics if both resistors are zero.
+    pub fn from_divider(vin: Voltage, divider: VoltageDivider) -> Self {
+        Self {
+            voltage: divider.vout(vin),
+            resistance: divider.r_top().parallel_with(divider.r_bottom()),
+        }
+    }
+
+    /// Calculates the Thevenin equivalent of a source, such as a battery, from its terminal
+    /// voltage at two different load currents.
+    ///
+    /// The resistance is found with `internal_resistance`, and the open-circuit voltage by adding
+    /// back the voltage it drops at the lighter load. Will be rounded to the nearest whole
+    /// milliohm (mΩ) and microvolt (μV).
+    /// Returns `None` if the points are not valid, as for `internal_resistance`, or the open-circuit
+    /// voltage would overflow.
+    pub fn from_load_points(a: (Voltage, Current), b: (Voltage, Current)) -> Option<Self> {
+        let resistance = internal_resistance(a, b)?;
+
+        let (voltage, current) = if a.1 <= b.1 { a } else { b };
+        let drop = (current.micro_amps() as u128 * resistance.milli_ohms() as u128 + 500) / 1_000;
+        let magnitude = i64::try_from(voltage.micro_volts().unsigned_abs() as u128 + drop).ok()?;
+
+        let voltage = if a.0.is_negative() || b.0.is_negative() {
+            Voltage::from_micro_volts(-magnitude)
+        } else {
+            Voltage::from_micro_volts(magnitude)
+        };
+
+        Some(Self::new(voltage, resistance))
+    }
+
+    /// Returns the open-circuit voltage (`Voc`).
+    #[inline]
+    pub const fn voltage(&self) -> Voltage {
+        self.voltage
+    }
+
+    /// Returns the series resistance (`Rth`).
+    #[inline]
+    pub const fn resistance(&self) -> Resistance {
+        self.resistance
+    }
+
+    /// Calculates the magnitude of the current that would flow if the output were shorted (`Isc`).
+    ///
+    /// Will be rounded down to the nearest whole microamp (μA).
+    /// Panics if the resistance is zero.
+    pub fn short_circuit_current(&self) -> Current {
+        self.voltage / self.resistance
+    }
+
+    /// Calculates the voltage across a load resistance connected to the output.
+    ///
+    /// Will be rounded towards zero to the nearest whole microvolt (μV).
+    /// Panics if both the series and load resistances are zero.
+    pub fn loaded_voltage(&self, load: Resistance) -> Voltage {
+        VoltageDivider::new(self.resistance, load).vout(self.voltage)
+    }
+
+    /// Converts to the equivalent Norton source.
+    ///
+    /// As `Current` is unsigned, the Norton current is the magnitude of the short-circuit current.
+    /// Panics if the resistance is zero.
+    pub fn to_norton(&self) -> Norton {
+        Norton::new(self.short_circuit_current(), self.resistance)
+    }
+}
+
+/// Represents a Norton equivalent, an ideal current source in parallel with a resistance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Norton {
+    current: Current,
+    resistance: Resistance,
+}
+
+impl Norton {
+    /// Creates a new `Norton` equivalent from its short-circuit current and parallel resistance.
+    #[inline]
+    pub const fn new(current: Current, resistance: Resistance) -> Self {
+        Self {
+            current,
+            resistance,
+        }
+    }
+
+    /// Calculates the Norton equivalent at the output of a voltage divider driven by an ideal
+    /// source.
+    ///
+    /// The short-circuit current is the magnitude of the current through the top resistor when the
+    /// output is shorted, and the resistance is both resistors in parallel.
+    /// Panics if the top resistor is zero.
+    pub fn from_divider(vin: Voltage, divider: VoltageDivider) -> Self {
+        Self {
+            current: vin / divider.r_top(),
+            resistance: divider.r_top().parallel_with(divider.r_bottom()),
+        }
+    }
+
+    /// Returns the short-circuit current (`Isc`).
+    #[inline]
+    pub const fn current(&self) -> Current {
+        self.current
+    }
+
+    /// Returns the parallel resistance (`Rn`).
+    #[inline]
+    pub const fn resistance(&self) -> Resistance {
+        self.resistance
+    }
+
+    /// Calculates the voltage that would appear across the output if left open (`Voc`).
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    pub fn open_circuit_voltage(&self) -> Voltage {
+        self.current * self.resistance
+    }
+
+    /// Converts to the equivalent Thevenin source.
+    #[inline]
+    pub fn to_thevenin(&self) -> Thevenin {
+        Thevenin::new(self.open_circuit_voltage(), self.resistance)
+    }
+}
+
+/// Calculates the internal resistance of a source, such as a battery, from its terminal voltage at
+/// two different load currents (`R = ΔV ÷ ΔI`).
+///
+/// The terminal voltage of a real source sags as the load current rises, so the point with the
+/// larger current must not have a larger voltage magnitude. Both voltages must also have the same
+/// sign, as they are measured across the same source.
+///
+/// Will be rounded to the nearest whole milliohm (mΩ).
+/// Returns `None` if the currents are equal, the voltages have opposite signs, the voltage rises
+/// with the current or the result would overflow.
+///
+/// ```rust
+/// use ohms::network::internal_resistance;
+/// use ohms::prelude::*;
+///
+/// let idle = (4_150.milli_volts(), 10.milli_amps());
+/// let loaded = (4_030.milli_volts(), 510.milli_amps());
+///
+/// assert_eq!(internal_resistance(idle, loaded), Some(240.milli_ohms()));
+/// ```
+pub fn internal_resistance(a: (Voltage, Current), b: (Voltage, Current)) -> Option<Resistance> {
+    if (a.0.is_negative() && b.0.is_positive()) || (a.0.is_positive() && b.0.is_negative()) {
+        return None;
+    }
+
+    let ((light_voltage, light_current), (heavy_voltage, heavy_current)) =
+        if a.1 <= b.1 { (a, b) } else { (b, a) };
+
+    let light_voltage = light_voltage.micro_volts().unsigned_abs() as u128;
+    let heavy_voltage = heavy_voltage.micro_volts().unsigned_abs() as u128;
+    if light_current == heavy_current || heavy_voltage > light_voltage {
+        return None;
+    }
+
+    let delta_current = (heavy_current.micro_amps() - light_current.micro_amps()) as u128;
+    let milli_ohms = ((light_voltage - heavy_voltage) * 1_000 + delta_current / 2) / delta_current;
+
+    u64::try_from(milli_ohms)
+        .ok()
+        .map(Resistance::from_milli_ohms)
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn divider(r_top_milli_ohms: u64, r_bottom_milli_ohms: u64) -> VoltageDivider {
+        VoltageDivider::new(
+            Resistance::from_milli_ohms(r_top_milli_ohms),
+            Resistance::from_milli_ohms(r_bottom_milli_ohms),
+        )
+    }
+
+    #[test_case(5_000_000, 10_000_000, 10_000_000, 2_500_000, 5_000_000; "5V, 10kΩ, 10kΩ")]
+    #[test_case(12_000_000, 30_000_000, 10_000_000, 3_000_000, 7_500_000; "12V, 30kΩ, 10kΩ")]
+    #[test_case(-5_000_000, 10_000_000, 10_000_000, -2_500_000, 5_000_000; "negative source")]
+    fn test_thevenin_from_divider(
+        vin: i64,
+        r_top: u64,
+        r_bottom: u64,
+        expected_micro_volts: i64,
+        expected_milli_ohms: u64,
+    ) {
+        let thevenin =
+            Thevenin::from_divider(Voltage::from_micro_volts(vin), divider(r_top, r_bottom));
+
+        assert_eq!(thevenin.voltage().micro_volts(), expected_micro_volts);
+        assert_eq!(thevenin.resistance().milli_ohms(), expected_milli_ohms);
+    }
+
+    #[test_case(5_000_000, 1_250_000; "equal load halves")]
+    #[test_case(15_000_000, 1_875_000; "larger load")]
+    #[test_case(0, 0; "shorted load")]
+    fn test_thevenin_loaded_voltage(load_milli_ohms: u64, expected_micro_volts: i64) {
+        let thevenin = Thevenin::new(
+            Voltage::from_micro_volts(2_500_000),
+            Resistance::from_milli_ohms(5_000_000),
+        );
+
+        let loaded = thevenin.loaded_voltage(Resistance::from_milli_ohms(load_milli_ohms));
+        assert_eq!(loaded.micro_volts(), expected_micro_volts);
+    }
+
+    #[test]
+    fn test_thevenin_to_norton() {
+        let thevenin = Thevenin::new(
+            Voltage::from_micro_volts(2_500_000),
+            Resistance::from_milli_ohms(5_000_000),
+        );
+
+        let norton = thevenin.to_norton();
+        assert_eq!(norton.current().micro_amps(), 500);
+        assert_eq!(norton.resistance(), thevenin.resistance());
+        assert_eq!(norton.to_thevenin(), thevenin);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_thevenin_to_norton_zero_resistance_panics() {
+        Thevenin::new(Voltage::from_micro_volts(1_000_000), Resistance::zero()).to_norton();
+    }
+
+    #[test]
+    fn test_norton_from_divider() {
+        let norton = Norton::from_divider(
+            Voltage::from_micro_volts(12_000_000),
+            divider(30_000_000, 10_000_000),
+        );
+
+        assert_eq!(norton.current().micro_amps(), 400);
+        assert_eq!(norton.resistance().milli_ohms(), 7_500_000);
+        assert_eq!(norton.open_circuit_voltage().micro_volts(), 3_000_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_norton_from_divider_zero_top_panics() {
+        Norton::from_divider(Voltage::from_micro_volts(1_000_000), divider(0, 10_000));
+    }
+
+    fn point(micro_volts: i64, micro_amps: u64) -> (Voltage, Current) {
+        (
+            Voltage::from_micro_volts(micro_volts),
+            Current::from_micro_amps(micro_amps),
+        )
+    }
+
+    #[test_case(point(4_150_000, 10_000), point(4_030_000, 510_000), Some(240); "battery")]
+    #[test_case(point(4_030_000, 510_000), point(4_150_000, 10_000), Some(240); "either order")]
+    #[test_case(point(-12_000_000, 0), point(-11_900_000, 1_000_000), Some(100); "negative source")]
+    #[test_case(point(5_000_000, 0), point(0, 3), Some(1_666_666_667); "rounds to nearest")]
+    #[test_case(point(5_000_000, 100), point(5_000_000, 200), Some(0); "ideal source")]
+    #[test_case(point(5_000_000, 100), point(4_000_000, 100), None; "equal currents")]
+    #[test_case(point(4_000_000, 100), point(5_000_000, 200), None; "voltage rises with current")]
+    #[test_case(point(1_000_000, 100), point(-1_000_000, 200), None; "opposite signs")]
+    #[test_case(point(i64::MAX, 0), point(0, 1), None; "overflow")]
+    fn test_internal_resistance(
+        a: (Voltage, Current),
+        b: (Voltage, Current),
+        expected_milli_ohms: Option<u64>,
+    ) {
+        let resistance = internal_resistance(a, b);
+        assert_eq!(resistance.map(|r| r.milli_ohms()), expected_milli_ohms);
+    }
+
+    #[test_case(point(4_150_000, 10_000), point(4_030_000, 510_000), Some((4_152_400, 240)); "battery")]
+    #[test_case(point(-11_900_000, 1_000_000), point(-12_000_000, 0), Some((-12_000_000, 100)); "negative source")]
+    #[test_case(point(5_000_000, 100), point(4_000_000, 100), None; "invalid points")]
+    #[test_case(point(i64::MAX, 1_000_000), point(i64::MAX - 1_000, 2_000_000), None; "overflow")]
+    fn test_thevenin_from_load_points(
+        a: (Voltage, Current),
+        b: (Voltage, Current),
+        expected: Option<(i64, u64)>,
+    ) {
+        let thevenin = Thevenin::from_load_points(a, b);
+        assert_eq!(
+            thevenin.map(|t| (t.voltage().micro_volts(), t.resistance().milli_ohms())),
+            expected
+        );
+    }
+}
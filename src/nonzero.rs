@@ -0,0 +1,149 @@
+#[cfg(feature = "current")]
+use crate::Current;
+#[cfg(feature = "resistance")]
+use crate::Resistance;
+use core::fmt;
+
+/// A `Resistance` value that is known not to be zero ohms (0Ω).
+///
+/// Like `core::num::NonZeroU64`, this allows division by a resistance to be proven free of
+/// panics at the type level. The division returns `None` if the result would overflow.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
+/// use ohms::prelude::*;
+///
+/// let resistance = NonZeroResistance::new(220.ohms()).unwrap();
+/// let current = 5.volts() / resistance; // Some(22.72mA)
+///
+/// assert_eq!(NonZeroResistance::new(Resistance::zero()), None);
+/// # }
+/// ```
+#[cfg(feature = "resistance")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NonZeroResistance(Resistance);
+
+#[cfg(feature = "resistance")]
+impl NonZeroResistance {
+    /// Creates a new `NonZeroResistance` if the given resistance is not zero ohms (0Ω).
+    #[inline]
+    pub const fn new(resistance: Resistance) -> Option<Self> {
+        if resistance.is_zero() {
+            None
+        } else {
+            Some(Self(resistance))
+        }
+    }
+
+    /// Returns the resistance value.
+    #[inline]
+    pub const fn get(self) -> Resistance {
+        self.0
+    }
+}
+
+#[cfg(feature = "resistance")]
+impl From<NonZeroResistance> for Resistance {
+    #[inline]
+    fn from(resistance: NonZeroResistance) -> Self {
+        resistance.get()
+    }
+}
+
+#[cfg(feature = "resistance")]
+impl fmt::Display for NonZeroResistance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// A `Current` value that is known not to be zero amps (0A).
+///
+/// Like `core::num::NonZeroU64`, this allows division by a current to be proven free of
+/// panics at the type level. The division returns `None` if the result would overflow.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
+/// use ohms::prelude::*;
+///
+/// let current = NonZeroCurrent::new(1.amps()).unwrap();
+/// let resistance = 5.volts() / current; // Some(5Ω)
+///
+/// assert_eq!(NonZeroCurrent::new(Current::zero()), None);
+/// # }
+/// ```
+#[cfg(feature = "current")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct NonZeroCurrent(Current);
+
+#[cfg(feature = "current")]
+impl NonZeroCurrent {
+    /// Creates a new `NonZeroCurrent` if the given current is not zero amps (0A).
+    #[inline]
+    pub const fn new(current: Current) -> Option<Self> {
+        if current.is_zero() {
+            None
+        } else {
+            Some(Self(current))
+        }
+    }
+
+    /// Returns the current value.
+    #[inline]
+    pub const fn get(self) -> Current {
+        self.0
+    }
+}
+
+#[cfg(feature = "current")]
+impl From<NonZeroCurrent> for Current {
+    #[inline]
+    fn from(current: NonZeroCurrent) -> Self {
+        current.get()
+    }
+}
+
+#[cfg(feature = "current")]
+impl fmt::Display for NonZeroCurrent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "resistance")]
+    use alloc::format;
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    fn test_non_zero_resistance() {
+        let resistance = Resistance::from_milli_ohms(4_700);
+
+        assert_eq!(NonZeroResistance::new(Resistance::zero()), None);
+        assert_eq!(
+            NonZeroResistance::new(resistance).map(|r| r.get()),
+            Some(resistance)
+        );
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_non_zero_current() {
+        let current = Current::from_micro_amps(1_500);
+
+        assert_eq!(NonZeroCurrent::new(Current::zero()), None);
+        assert_eq!(
+            NonZeroCurrent::new(current).map(Current::from),
+            Some(current)
+        );
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    fn test_display() {
+        let resistance = NonZeroResistance::new(Resistance::from_milli_ohms(4_700)).unwrap();
+        assert_eq!(format!("{resistance}"), format!("{}", resistance.get()));
+    }
+}
@@ -0,0 +1,255 @@
+//! Conversions between the resistance and temperature of an NTC thermistor.
+//!
+//! The beta model describes a thermistor by its resistance `R0` at a reference temperature `T0`
+//! (usually 25°C) and its beta constant `β`, all of which are given on its datasheet:
+//!
+//! `1 ÷ T = 1 ÷ T0 + ln(R ÷ R0) ÷ β`
+//!
+//! Requires the `libm` feature.
+//!
+//! ```rust
+//! use ohms::ntc::Thermistor;
+//! use ohms::prelude::*;
+//!
+//! // 10kΩ at 25°C, β = 3950K
+//! let ntc = Thermistor::new(10.kilo_ohms(), 25.degrees_celsius(), 3_950);
+//!
+//! let temperature = ntc.temperature(3_588.ohms()).unwrap();
+//! assert_eq!(temperature.milli_degrees_celsius(), 50_001); // 50°C
+//! ```
+use crate::math::f64_to_u64;
+use crate::{Resistance, RoundingMode, Temperature};
+
+/// The beta model of an NTC thermistor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "ThermistorData"))]
+pub struct Thermistor {
+    r0: Resistance,
+    t0: Temperature,
+    beta: u32,
+}
+
+/// The serialized form of a `Thermistor`, which is checked like `Thermistor::new` when
+/// deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Thermistor")]
+struct ThermistorData {
+    r0: Resistance,
+    t0: Temperature,
+    beta: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<ThermistorData> for Thermistor {
+    type Error = &'static str;
+
+    fn try_from(data: ThermistorData) -> Result<Self, Self::Error> {
+        validate(data.r0, data.t0, data.beta)?;
+
+        Ok(Self {
+            r0: data.r0,
+            t0: data.t0,
+            beta: data.beta,
+        })
+    }
+}
+
+impl Thermistor {
+    /// Creates a new `Thermistor` from its resistance at a reference temperature and its beta
+    /// constant in kelvin (K).
+    ///
+    /// Panics if the resistance or beta constant is zero, or the reference temperature is not
+    /// above absolute zero.
+    pub fn new(r0: Resistance, t0: Temperature, beta: u32) -> Self {
+        if let Err(message) = validate(r0, t0, beta) {
+            panic!("{}", message);
+        }
+
+        Self { r0, t0, beta }
+    }
+
+    /// Returns the resistance at the reference temperature (`R0`).
+    #[inline]
+    pub const fn r0(&self) -> Resistance {
+        self.r0
+    }
+
+    /// Returns the reference temperature (`T0`).
+    #[inline]
+    pub const fn t0(&self) -> Temperature {
+        self.t0
+    }
+
+    /// Returns the beta constant in kelvin (K).
+    #[inline]
+    pub const fn beta(&self) -> u32 {
+        self.beta
+    }
+
+    /// Calculates the temperature of the thermistor from its measured resistance.
+    ///
+    /// Will be rounded to the nearest whole millidegree Celsius (m°C).
+    /// Returns `None` if the resistance is zero or the temperature cannot be represented.
+    pub fn temperature(&self, resistance: Resistance) -> Option<Temperature> {
+        if resistance.is_zero() {
+            return None;
+        }
+
+        let ratio = resistance.milli_ohms() as f64 / self.r0.milli_ohms() as f64;
+        let inverse_kelvin = 1f64 / self.t0.kelvin() + libm::log(ratio) / self.beta as f64;
+
+        // Very small resistances give a negative inverse, which has no physical temperature
+        if inverse_kelvin <= 0f64 {
+            return None;
+        }
+
+        Temperature::try_from_kelvin_rounded(1f64 / inverse_kelvin, RoundingMode::Nearest).ok()
+    }
+
+    /// Calculates the resistance of the thermistor at a temperature.
+    ///
+    /// Will be rounded to the nearest whole milliohm (mΩ).
+    /// Returns `None` if the temperature is not above absolute zero or the resistance cannot be
+    /// represented.
+    pub fn resistance(&self, temperature: Temperature) -> Option<Resistance> {
+        let temperature = temperature.kelvin();
+        if temperature <= 0f64 {
+            return None;
+        }
+
+        let exponent = self.beta as f64 * (1f64 / temperature - 1f64 / self.t0.kelvin());
+        let milli_ohms = self.r0.milli_ohms() as f64 * libm::exp(exponent);
+
+        f64_to_u64(libm::round(milli_ohms)).map(Resistance::from_milli_ohms)
+    }
+}
+
+/// Checks the parameters of a thermistor, returning the reason they are invalid.
+fn validate(r0: Resistance, t0: Temperature, beta: u32) -> Result<(), &'static str> {
+    if r0.is_zero() {
+        return Err("Thermistor reference resistance cannot be zero");
+    }
+    if beta == 0 {
+        return Err("Thermistor beta constant cannot be zero");
+    }
+    if t0.kelvin() <= 0f64 {
+        return Err("Thermistor reference temperature must be above absolute zero");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn ntc_10k() -> Thermistor {
+        Thermistor::new(
+            Resistance::from_milli_ohms(10_000_000),
+            Temperature::from_milli_degrees_celsius(25_000),
+            3_950,
+        )
+    }
+
+    #[test_case(10_000_000, Some(25_000); "reference point")]
+    #[test_case(3_588_000, Some(50_001); "warmer")]
+    #[test_case(33_620_604, Some(0); "freezing")]
+    #[test_case(0, None; "zero resistance")]
+    #[test_case(1, None; "no physical temperature")]
+    fn test_temperature(milli_ohms: u64, expected: Option<i64>) {
+        let temperature = ntc_10k().temperature(Resistance::from_milli_ohms(milli_ohms));
+        assert_eq!(temperature.map(|t| t.milli_degrees_celsius()), expected);
+    }
+
+    #[test_case(25_000, Some(10_000_000); "reference point")]
+    #[test_case(50_000, Some(3_588_183); "warmer")]
+    #[test_case(0, Some(33_620_604); "freezing")]
+    #[test_case(-273_150, None; "absolute zero")]
+    #[test_case(-270_000, None; "overflow")]
+    fn test_resistance(milli_celsius: i64, expected: Option<u64>) {
+        let resistance =
+            ntc_10k().resistance(Temperature::from_milli_degrees_celsius(milli_celsius));
+        assert_eq!(resistance.map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test_case(-40_000; "cold")]
+    #[test_case(85_000; "hot")]
+    #[test_case(150_000; "very hot")]
+    fn test_round_trip(milli_celsius: i64) {
+        let ntc = ntc_10k();
+        let temperature = Temperature::from_milli_degrees_celsius(milli_celsius);
+
+        let resistance = ntc.resistance(temperature).unwrap();
+        let measured = ntc.temperature(resistance).unwrap();
+
+        assert!((measured.milli_degrees_celsius() - milli_celsius).abs() <= 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_beta_panics() {
+        Thermistor::new(
+            Resistance::from_milli_ohms(10_000_000),
+            Temperature::from_milli_degrees_celsius(25_000),
+            0,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_r0_panics() {
+        Thermistor::new(
+            Resistance::zero(),
+            Temperature::from_milli_degrees_celsius(25_000),
+            3_950,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_absolute_zero_t0_panics() {
+        Thermistor::new(
+            Resistance::from_milli_ohms(10_000_000),
+            Temperature::from_milli_degrees_celsius(-273_150),
+            3_950,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_zero_beta() {
+        use serde_test::{assert_de_tokens_error, Token};
+
+        assert_de_tokens_error::<Thermistor>(
+            &[
+                Token::Struct {
+                    name: "Thermistor",
+                    len: 3,
+                },
+                Token::Str("r0"),
+                Token::Struct {
+                    name: "Resistance",
+                    len: 1,
+                },
+                Token::Str("milli_ohms"),
+                Token::U64(10_000_000),
+                Token::StructEnd,
+                Token::Str("t0"),
+                Token::Struct {
+                    name: "Temperature",
+                    len: 1,
+                },
+                Token::Str("milli_degrees_celsius"),
+                Token::I64(25_000),
+                Token::StructEnd,
+                Token::Str("beta"),
+                Token::U32(0),
+                Token::StructEnd,
+            ],
+            "Thermistor beta constant cannot be zero",
+        );
+    }
+}
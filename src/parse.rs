@@ -0,0 +1,250 @@
+use core::fmt;
+
+/// Error returned when parsing a unit value from a string fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    /// The string was empty.
+    Empty,
+    /// The numeric part of the string was not a valid number.
+    InvalidNumber,
+    /// The unit or multiplier suffix was not recognized.
+    InvalidUnit,
+    /// The value is too large to be represented.
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            ParseError::Empty => "cannot parse value from empty string",
+            ParseError::InvalidNumber => "invalid number",
+            ParseError::InvalidUnit => "invalid unit or multiplier",
+            ParseError::Overflow => "value is too large",
+        };
+
+        f.write_str(message)
+    }
+}
+
+/// Parses a decimal number from its whole and fractional digits, scaled by `10^exponent`.
+///
+/// Fractional digits beyond the scale are validated and then truncated, so the result is rounded
+/// down to the nearest whole number.
+pub(crate) fn parse_decimal(whole: &str, fraction: &str, exponent: u32) -> Result<u64, ParseError> {
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(ParseError::InvalidNumber);
+    }
+
+    let mut value = 0u64;
+    for digit in whole.chars() {
+        value = push_digit(value, digit)?;
+    }
+
+    let mut remaining = exponent;
+    for digit in fraction.chars() {
+        if remaining == 0 {
+            digit.to_digit(10).ok_or(ParseError::InvalidNumber)?;
+            continue;
+        }
+
+        value = push_digit(value, digit)?;
+        remaining -= 1;
+    }
+
+    10u64
+        .checked_pow(remaining)
+        .and_then(|scale| value.checked_mul(scale))
+        .ok_or(ParseError::Overflow)
+}
+
+/// Parses an unsigned value with an optional SI prefix and unit symbol, such as `4.7k`, `330m`,
+/// `3.3 V` or `22uA`, into whole base units.
+///
+/// `exponent` is the power of ten between the unit named by `symbols` and the base unit, such as
+/// `6` for amps (A) stored as microamps (μA). Will be rounded down to the nearest whole base unit.
+pub(crate) fn parse_si_unsigned(
+    s: &str,
+    symbols: &[&str],
+    exponent: i32,
+) -> Result<u64, ParseError> {
+    let s = s.trim();
+    let s = symbols
+        .iter()
+        .find_map(|symbol| s.strip_suffix(symbol))
+        .unwrap_or(s)
+        .trim_end();
+
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let (number, exponent) = match s.chars().last() {
+        Some(prefix) if prefix.is_alphabetic() => {
+            let prefix_exponent = match prefix {
+                'p' => -12,
+                'n' => -9,
+                'u' | 'μ' | 'µ' => -6,
+                'm' => -3,
+                'k' | 'K' => 3,
+                'M' => 6,
+                'G' => 9,
+                'T' => 12,
+                _ => return Err(ParseError::InvalidUnit),
+            };
+
+            (
+                s[..s.len() - prefix.len_utf8()].trim_end(),
+                exponent + prefix_exponent,
+            )
+        }
+        _ => (s, exponent),
+    };
+
+    let number = number.strip_prefix('+').unwrap_or(number);
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    if exponent >= 0 {
+        return parse_decimal(whole, fraction, exponent as u32);
+    }
+
+    let value = parse_decimal(whole, fraction, 0)?;
+
+    Ok(10u64
+        .checked_pow(exponent.unsigned_abs())
+        .map_or(0, |scale| value / scale))
+}
+
+/// Parses a signed value with an optional SI prefix and unit symbol, such as `-3.3 V` or `5mV`,
+/// into whole base units.
+///
+/// See [`parse_si_unsigned`] for the meaning of `exponent`.
+pub(crate) fn parse_si_signed(s: &str, symbols: &[&str], exponent: i32) -> Result<i64, ParseError> {
+    let s = s.trim();
+
+    match s.strip_prefix('-') {
+        Some(magnitude) => {
+            let magnitude = parse_si_unsigned(magnitude, symbols, exponent)?;
+            0i64.checked_sub_unsigned(magnitude)
+                .ok_or(ParseError::Overflow)
+        }
+        None => {
+            let value = parse_si_unsigned(s, symbols, exponent)?;
+            i64::try_from(value).map_err(|_| ParseError::Overflow)
+        }
+    }
+}
+
+/// Parses a value in RKM notation, where a marker letter takes the place of the decimal point,
+/// such as `4k7` or `3V3`, into whole base units.
+///
+/// `multiplier` returns the power of ten between each accepted marker and the base unit. Fractional
+/// digits beyond the scale are truncated, so the result is rounded down to the nearest whole number.
+#[cfg(any(feature = "voltage", feature = "resistance"))]
+pub(crate) fn parse_rkm(
+    s: &str,
+    multiplier: impl Fn(char) -> Option<u32>,
+) -> Result<u64, ParseError> {
+    let s = s.trim();
+
+    if s.is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let (index, marker) = s
+        .char_indices()
+        .find(|(_, c)| !c.is_ascii_digit())
+        .ok_or(ParseError::InvalidUnit)?;
+    let exponent = multiplier(marker).ok_or(ParseError::InvalidUnit)?;
+
+    parse_decimal(&s[..index], &s[index + marker.len_utf8()..], exponent)
+}
+
+fn push_digit(value: u64, digit: char) -> Result<u64, ParseError> {
+    let digit = digit.to_digit(10).ok_or(ParseError::InvalidNumber)?;
+
+    value
+        .checked_mul(10)
+        .and_then(|value| value.checked_add(digit as u64))
+        .ok_or(ParseError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("1", "", 0, Ok(1); "whole number")]
+    #[test_case("4", "7", 3, Ok(4_700); "fraction scaled up")]
+    #[test_case("", "5", 3, Ok(500); "fraction only")]
+    #[test_case("1", "2345", 2, Ok(123); "extra fraction digits truncated")]
+    #[test_case("", "", 0, Err(ParseError::InvalidNumber); "no digits")]
+    #[test_case("1a", "", 0, Err(ParseError::InvalidNumber); "invalid whole digit")]
+    #[test_case("1", "2x", 1, Err(ParseError::InvalidNumber); "invalid truncated digit")]
+    #[test_case("18446744073709551615", "", 0, Ok(u64::MAX); "maximum value")]
+    #[test_case("18446744073709551616", "", 0, Err(ParseError::Overflow); "whole overflow")]
+    #[test_case("18446744073709551615", "", 1, Err(ParseError::Overflow); "scale overflow")]
+    fn test_parse_decimal(
+        whole: &str,
+        fraction: &str,
+        exponent: u32,
+        expected: Result<u64, ParseError>,
+    ) {
+        assert_eq!(parse_decimal(whole, fraction, exponent), expected);
+    }
+
+    #[test_case("4.7k", Ok(4_700); "kilo prefix")]
+    #[test_case("330m", Ok(0); "milli prefix rounds down")]
+    #[test_case("3.3 V", Ok(3); "unit symbol with space")]
+    #[test_case("22uV", Ok(0); "micro prefix rounds down")]
+    #[test_case(" +12 ", Ok(12); "whitespace and plus sign")]
+    #[test_case("1.5 kV", Ok(1_500); "prefix and symbol")]
+    #[test_case("2.5T", Ok(2_500_000_000_000); "tera prefix")]
+    #[test_case("", Err(ParseError::Empty); "empty string")]
+    #[test_case("V", Err(ParseError::Empty); "symbol only")]
+    #[test_case("k", Err(ParseError::InvalidNumber); "prefix only")]
+    #[test_case("4.7x", Err(ParseError::InvalidUnit); "unknown prefix")]
+    #[test_case("4..7", Err(ParseError::InvalidNumber); "multiple decimal points")]
+    #[test_case("-1", Err(ParseError::InvalidNumber); "negative value")]
+    #[test_case("99999999T", Err(ParseError::Overflow); "overflow")]
+    fn test_parse_si_unsigned(s: &str, expected: Result<u64, ParseError>) {
+        assert_eq!(parse_si_unsigned(s, &["V"], 0), expected);
+    }
+
+    #[test_case("22uA", Ok(22); "micro prefix")]
+    #[test_case("22µA", Ok(22); "micro sign")]
+    #[test_case("1.5mA", Ok(1_500); "milli prefix")]
+    #[test_case("100nA", Ok(0); "below resolution")]
+    fn test_parse_si_unsigned_scaled(s: &str, expected: Result<u64, ParseError>) {
+        assert_eq!(parse_si_unsigned(s, &["A"], 6), expected);
+    }
+
+    #[test_case("-3.3V", Ok(-3_300_000); "negative")]
+    #[test_case("5 mV", Ok(5_000); "positive")]
+    #[test_case("-9223372036854775808uV", Ok(i64::MIN); "minimum value")]
+    #[test_case("9223372036854775808uV", Err(ParseError::Overflow); "positive overflow")]
+    #[test_case("--1V", Err(ParseError::InvalidNumber); "double negative")]
+    fn test_parse_si_signed(s: &str, expected: Result<i64, ParseError>) {
+        assert_eq!(parse_si_signed(s, &["V"], 6), expected);
+    }
+
+    #[cfg(any(feature = "voltage", feature = "resistance"))]
+    #[test_case("4k7", Ok(4_700); "marker as decimal point")]
+    #[test_case("10k", Ok(10_000); "trailing marker")]
+    #[test_case("k47", Ok(470); "leading marker")]
+    #[test_case(" 2k2 ", Ok(2_200); "whitespace is ignored")]
+    #[test_case("4k7001", Ok(4_700); "extra digits are rounded down")]
+    #[test_case("", Err(ParseError::Empty); "empty string")]
+    #[test_case("470", Err(ParseError::InvalidUnit); "missing marker")]
+    #[test_case("4x7", Err(ParseError::InvalidUnit); "unknown marker")]
+    #[test_case("4.7k", Err(ParseError::InvalidUnit); "decimal point")]
+    #[test_case("4k7k", Err(ParseError::InvalidNumber); "multiple markers")]
+    #[test_case("k", Err(ParseError::InvalidNumber); "marker without digits")]
+    fn test_parse_rkm(s: &str, expected: Result<u64, ParseError>) {
+        let multiplier = |marker| match marker {
+            'k' => Some(3),
+            _ => None,
+        };
+
+        assert_eq!(parse_rkm(s, multiplier), expected);
+    }
+}
@@ -0,0 +1,139 @@
+use crate::display;
+use crate::math::f64_to_u64;
+use crate::UnitError;
+use core::fmt;
+
+/// Represents a percentage, stored as whole milli-percent (m%) as a 32-bit value.
+/// This value can only be positive, but may be greater than 100%.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let efficiency = Percent::from_milli_percent(92_500); // 92.5%
+///
+/// assert_eq!(efficiency.percent(), 92.5);
+/// assert_eq!(efficiency.ratio(), 0.925);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Percent {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_percent"))]
+    raw: u32,
+}
+
+impl Percent {
+    /// Creates a new `Percent` from a number of whole milli-percent (m%).
+    #[inline]
+    pub const fn from_milli_percent(value: u32) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Percent` from a number of fractional percent (%).
+    ///
+    /// Will be rounded down to the nearest whole milli-percent (m%).
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    pub fn try_from_percent(value: f64) -> Result<Self, UnitError> {
+        if value.is_nan() {
+            return Err(UnitError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(UnitError::Infinite);
+        }
+        if value < 0f64 {
+            return Err(UnitError::Negative);
+        }
+
+        f64_to_u64(value * 1_000f64)
+            .and_then(|raw| u32::try_from(raw).ok())
+            .map(Self::from_milli_percent)
+            .ok_or(UnitError::Overflow)
+    }
+
+    /// Returns the percentage in whole milli-percent (m%).
+    #[inline]
+    pub const fn milli_percent(&self) -> u32 {
+        self.raw
+    }
+
+    /// Returns the percentage in fractional percent (%).
+    #[inline]
+    pub fn percent(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the percentage as a fractional ratio, where 100% is one.
+    #[inline]
+    pub fn ratio(&self) -> f64 {
+        self.raw as f64 / 100_000f64
+    }
+
+    /// Returns whether the percentage is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `Percent` value of zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_milli_percent(0)
+    }
+
+    /// Creates a new `Percent` from the ratio of two whole numbers, rounded to the nearest whole
+    /// milli-percent (m%). Returns `None` if the denominator is zero or the result would overflow.
+    #[cfg(any(feature = "current", feature = "power"))]
+    pub(crate) fn from_ratio(numerator: u64, denominator: u64) -> Option<Self> {
+        if denominator == 0 {
+            return None;
+        }
+
+        let denominator = denominator as u128;
+        let raw = (numerator as u128 * 100_000 + denominator / 2) / denominator;
+
+        u32::try_from(raw).ok().map(Self::from_milli_percent)
+    }
+}
+
+impl fmt::Display for Percent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(
+            f,
+            format_args!("{}.{:03}%", self.raw / 1_000, self.raw % 1_000),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(92.5, Ok(92_500); "fraction")]
+    #[test_case(150.0, Ok(150_000); "over 100%")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(5e6, Err(UnitError::Overflow); "overflow")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    fn test_try_from_percent(value: f64, expected: Result<u32, UnitError>) {
+        let percent = Percent::try_from_percent(value).map(|p| p.milli_percent());
+        assert_eq!(percent, expected);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let percent = Percent::from_milli_percent(12_500);
+
+        assert_eq!(percent.percent(), 12.5);
+        assert_eq!(percent.ratio(), 0.125);
+        assert!(Percent::zero().is_zero());
+        assert!(!percent.is_zero());
+    }
+
+    #[test_case(92_500, "92.500%"; "fraction")]
+    #[test_case(0, "0.000%"; "zero")]
+    fn test_display(milli_percent: u32, expected: &str) {
+        let percent = Percent::from_milli_percent(milli_percent);
+        assert_eq!(format!("{percent}"), expected);
+    }
+}
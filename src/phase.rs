@@ -0,0 +1,513 @@
+use crate::display;
+use crate::math::f64_to_i64;
+use crate::UnitError;
+#[cfg(feature = "power")]
+use crate::{ApparentPower, Power};
+use core::fmt;
+
+/// The number of millidegrees in a full turn.
+const FULL_TURN_MILLI_DEGREES: i32 = 360_000;
+
+/// The number of millionths in a power factor of one.
+const UNITY_MILLIONTHS: u32 = 1_000_000;
+
+/// Represents a phase angle, stored as whole millidegrees (m°) as a signed 32-bit value, such as
+/// the angle between the voltage and current of an AC load.
+///
+/// By convention, a positive angle means the current lags the voltage, as in an inductive load,
+/// and a negative angle means the current leads the voltage, as in a capacitive load.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let angle = PhaseAngle::from_milli_degrees(-30_000); // -30°
+///
+/// assert!(angle.is_leading());
+/// assert_eq!(angle.degrees(), -30.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhaseAngle {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_degrees"))]
+    raw: i32,
+}
+
+impl PhaseAngle {
+    /// Creates a new `PhaseAngle` from a number of whole millidegrees (m°).
+    #[inline]
+    pub const fn from_milli_degrees(value: i32) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `PhaseAngle` from a number of fractional degrees (°).
+    ///
+    /// Will be rounded toward zero to the nearest whole millidegree (m°).
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    pub fn try_from_degrees(value: f64) -> Result<Self, UnitError> {
+        Self::try_from_scaled(value, 1_000f64)
+    }
+
+    /// Creates a new `PhaseAngle` from a number of fractional radians (rad).
+    ///
+    /// Will be rounded toward zero to the nearest whole millidegree (m°).
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    pub fn try_from_radians(value: f64) -> Result<Self, UnitError> {
+        Self::try_from_scaled(value, 180_000f64 / core::f64::consts::PI)
+    }
+
+    /// Returns the angle in whole millidegrees (m°).
+    #[inline]
+    pub const fn milli_degrees(&self) -> i32 {
+        self.raw
+    }
+
+    /// Returns the angle in fractional degrees (°).
+    #[inline]
+    pub fn degrees(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the angle in fractional radians (rad).
+    #[inline]
+    pub fn radians(&self) -> f64 {
+        self.raw as f64 * core::f64::consts::PI / 180_000f64
+    }
+
+    /// Returns whether the current lags the voltage, as in an inductive load.
+    #[inline]
+    pub const fn is_lagging(&self) -> bool {
+        self.normalized().raw > 0
+    }
+
+    /// Returns whether the current leads the voltage, as in a capacitive load.
+    #[inline]
+    pub const fn is_leading(&self) -> bool {
+        self.normalized().raw < 0
+    }
+
+    /// Returns the equivalent angle between -180° (exclusive) and 180° (inclusive).
+    pub const fn normalized(&self) -> Self {
+        let half_turn = FULL_TURN_MILLI_DEGREES / 2;
+        let wrapped = self.raw.rem_euclid(FULL_TURN_MILLI_DEGREES);
+
+        if wrapped > half_turn {
+            Self::from_milli_degrees(wrapped - FULL_TURN_MILLI_DEGREES)
+        } else {
+            Self::from_milli_degrees(wrapped)
+        }
+    }
+
+    /// Returns the power factor of a load with this phase angle, which is the magnitude of its
+    /// cosine.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn power_factor(&self) -> PowerFactor {
+        PowerFactor::from_phase_angle(*self)
+    }
+
+    /// Returns a `PhaseAngle` value of zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_milli_degrees(0)
+    }
+
+    fn try_from_scaled(value: f64, scale: f64) -> Result<Self, UnitError> {
+        if value.is_nan() {
+            return Err(UnitError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(UnitError::Infinite);
+        }
+
+        f64_to_i64(value * scale)
+            .and_then(|raw| i32::try_from(raw).ok())
+            .map(Self::from_milli_degrees)
+            .ok_or(UnitError::Overflow)
+    }
+}
+
+impl fmt::Display for PhaseAngle {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let magnitude = self.raw.unsigned_abs();
+
+        display::write_padded(
+            f,
+            format_args!("{}{}.{:03}°", sign, magnitude / 1_000, magnitude % 1_000),
+        )
+    }
+}
+
+/// Represents the power factor of an AC load, stored as whole millionths between zero and one.
+///
+/// This is the ratio of the real power delivered to the load to its apparent power, where one
+/// is a purely resistive load. Whether the load is leading or lagging is given by its
+/// `PhaseAngle`.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let pf = PowerFactor::try_from_ratio(0.8).unwrap();
+///
+/// assert_eq!(pf.millionths(), 800_000);
+/// assert_eq!(pf.ratio(), 0.8);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "PowerFactorData"))]
+pub struct PowerFactor {
+    #[cfg_attr(feature = "serde", serde(rename = "millionths"))]
+    raw: u32,
+}
+
+/// The serialized form of a `PowerFactor`, which is checked to be no more than one when
+/// deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "PowerFactor")]
+struct PowerFactorData {
+    millionths: u32,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<PowerFactorData> for PowerFactor {
+    type Error = UnitError;
+
+    fn try_from(data: PowerFactorData) -> Result<Self, Self::Error> {
+        Self::from_millionths(data.millionths).ok_or(UnitError::Overflow)
+    }
+}
+
+impl PowerFactor {
+    /// Creates a new `PowerFactor` from a number of whole millionths.
+    ///
+    /// Returns `None` if the value is greater than one (1,000,000).
+    #[inline]
+    pub const fn from_millionths(value: u32) -> Option<Self> {
+        if value > UNITY_MILLIONTHS {
+            None
+        } else {
+            Some(Self { raw: value })
+        }
+    }
+
+    /// Creates a new `PowerFactor` from a fractional ratio between zero and one.
+    ///
+    /// Will be rounded down to the nearest whole millionth.
+    /// Returns an error if the value is NaN, infinite, negative or greater than one.
+    pub fn try_from_ratio(value: f64) -> Result<Self, UnitError> {
+        if value.is_nan() {
+            return Err(UnitError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(UnitError::Infinite);
+        }
+        if value < 0f64 {
+            return Err(UnitError::Negative);
+        }
+        if value > 1f64 {
+            return Err(UnitError::Overflow);
+        }
+
+        Ok(Self {
+            raw: (value * UNITY_MILLIONTHS as f64) as u32,
+        })
+    }
+
+    /// Creates a new `PowerFactor` from the phase angle between the voltage and current, which is
+    /// the magnitude of its cosine.
+    ///
+    /// Will be rounded down to the nearest whole millionth.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn from_phase_angle(angle: PhaseAngle) -> Self {
+        let ratio = libm::fabs(libm::cos(angle.radians())).min(1f64);
+
+        Self {
+            raw: (ratio * UNITY_MILLIONTHS as f64) as u32,
+        }
+    }
+
+    /// Returns the power factor in whole millionths.
+    #[inline]
+    pub const fn millionths(&self) -> u32 {
+        self.raw
+    }
+
+    /// Returns the power factor as a fractional ratio between zero and one.
+    #[inline]
+    pub fn ratio(&self) -> f64 {
+        self.raw as f64 / UNITY_MILLIONTHS as f64
+    }
+
+    /// Returns the magnitude of the phase angle between the voltage and current, between 0° and
+    /// 90°.
+    ///
+    /// Will be rounded toward zero to the nearest whole millidegree (m°).
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn phase_angle(&self) -> PhaseAngle {
+        let radians = libm::acos(self.ratio());
+
+        PhaseAngle::from_milli_degrees((radians * 180_000f64 / core::f64::consts::PI) as i32)
+    }
+
+    /// Returns whether the power factor is one, as for a purely resistive load.
+    #[inline]
+    pub const fn is_unity(&self) -> bool {
+        self.raw == UNITY_MILLIONTHS
+    }
+
+    /// Returns a `PowerFactor` value of one.
+    #[inline]
+    pub const fn unity() -> Self {
+        Self {
+            raw: UNITY_MILLIONTHS,
+        }
+    }
+}
+
+impl Default for PowerFactor {
+    fn default() -> Self {
+        Self::unity()
+    }
+}
+
+impl fmt::Display for PowerFactor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(
+            f,
+            format_args!(
+                "{}.{:03}",
+                self.raw / UNITY_MILLIONTHS,
+                self.raw % UNITY_MILLIONTHS / 1_000
+            ),
+        )
+    }
+}
+
+/// Calculates the real power delivered to an AC load from its apparent power and its power factor
+/// (`P = S × PF`).
+///
+/// Will be rounded down to the nearest whole microwatt (μW).
+///
+/// ```rust
+/// use ohms::prelude::*;
+/// use ohms::real_power;
+///
+/// let pf = PowerFactor::try_from_ratio(0.8).unwrap();
+///
+/// let apparent = ApparentPower::from_micro_volt_amps(100_000_000); // 100 VA
+///
+/// assert_eq!(real_power(apparent, pf), 80.watts());
+/// ```
+#[cfg(feature = "power")]
+pub fn real_power(apparent: ApparentPower, power_factor: PowerFactor) -> Power {
+    let micro_watts =
+        apparent.micro_volt_amps() as u128 * power_factor.raw as u128 / UNITY_MILLIONTHS as u128;
+
+    Power::from_micro_watts(micro_watts as u64)
+}
+
+/// Calculates the apparent power that must be supplied to an AC load to deliver the given real
+/// power at its power factor (`S = P ÷ PF`).
+///
+/// Will be rounded down to the nearest whole microvolt-ampere (μVA).
+/// Returns `None` if the power factor is zero or the result would overflow.
+#[cfg(feature = "power")]
+pub fn apparent_power(real: Power, power_factor: PowerFactor) -> Option<ApparentPower> {
+    if power_factor.raw == 0 {
+        return None;
+    }
+
+    let micro_volt_amps =
+        real.micro_watts() as u128 * UNITY_MILLIONTHS as u128 / power_factor.raw as u128;
+
+    u64::try_from(micro_volt_amps)
+        .ok()
+        .map(ApparentPower::from_micro_volt_amps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(30.0, Ok(30_000); "degrees")]
+    #[test_case(-45.5, Ok(-45_500); "negative")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "NaN")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinity")]
+    #[test_case(1e7, Err(UnitError::Overflow); "too large")]
+    fn test_phase_angle_try_from_degrees(degrees: f64, expected: Result<i32, UnitError>) {
+        assert_eq!(
+            PhaseAngle::try_from_degrees(degrees).map(|angle| angle.milli_degrees()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_phase_angle_radians() {
+        let angle = PhaseAngle::try_from_radians(core::f64::consts::FRAC_PI_2).unwrap();
+
+        assert_eq!(angle.milli_degrees(), 90_000);
+        assert!((angle.radians() - core::f64::consts::FRAC_PI_2).abs() < 1e-9);
+    }
+
+    #[test_case(0, 0; "zero")]
+    #[test_case(180_000, 180_000; "half turn")]
+    #[test_case(-180_000, 180_000; "negative half turn")]
+    #[test_case(270_000, -90_000; "three quarter turn")]
+    #[test_case(-390_000, -30_000; "more than a negative turn")]
+    #[test_case(i32::MIN, -83_648; "minimum")]
+    fn test_phase_angle_normalized(milli_degrees: i32, expected: i32) {
+        let angle = PhaseAngle::from_milli_degrees(milli_degrees);
+
+        assert_eq!(angle.normalized().milli_degrees(), expected);
+    }
+
+    #[test_case(30_000, true, false; "lagging")]
+    #[test_case(-30_000, false, true; "leading")]
+    #[test_case(330_000, false, true; "wrapped leading")]
+    #[test_case(0, false, false; "in phase")]
+    fn test_phase_angle_direction(milli_degrees: i32, lagging: bool, leading: bool) {
+        let angle = PhaseAngle::from_milli_degrees(milli_degrees);
+
+        assert_eq!(angle.is_lagging(), lagging);
+        assert_eq!(angle.is_leading(), leading);
+    }
+
+    #[test]
+    fn test_phase_angle_display() {
+        assert_eq!(
+            format!("{}", PhaseAngle::from_milli_degrees(36_870)),
+            "36.870°"
+        );
+        assert_eq!(
+            format!("{}", PhaseAngle::from_milli_degrees(-500)),
+            "-0.500°"
+        );
+        assert_eq!(format!("{:>8}", PhaseAngle::zero()), "  0.000°");
+    }
+
+    #[test_case(800_000, Some(800_000); "valid")]
+    #[test_case(1_000_000, Some(1_000_000); "unity")]
+    #[test_case(1_000_001, None; "greater than one")]
+    fn test_power_factor_from_millionths(millionths: u32, expected: Option<u32>) {
+        assert_eq!(
+            PowerFactor::from_millionths(millionths).map(|pf| pf.millionths()),
+            expected
+        );
+    }
+
+    #[test_case(0.95, Ok(950_000); "ratio")]
+    #[test_case(1.0, Ok(1_000_000); "unity")]
+    #[test_case(-0.1, Err(UnitError::Negative); "negative")]
+    #[test_case(1.1, Err(UnitError::Overflow); "greater than one")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "NaN")]
+    fn test_power_factor_try_from_ratio(ratio: f64, expected: Result<u32, UnitError>) {
+        assert_eq!(
+            PowerFactor::try_from_ratio(ratio).map(|pf| pf.millionths()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_power_factor_unity() {
+        assert!(PowerFactor::unity().is_unity());
+        assert_eq!(PowerFactor::default(), PowerFactor::unity());
+        assert_eq!(format!("{}", PowerFactor::unity()), "1.000");
+        assert_eq!(
+            format!("{}", PowerFactor::from_millionths(856_999).unwrap()),
+            "0.856"
+        );
+    }
+
+    #[cfg(feature = "libm")]
+    #[test_case(0, 1_000_000; "in phase")]
+    #[test_case(60_000, 500_000; "sixty degrees")]
+    #[test_case(-60_000, 500_000; "leading")]
+    #[test_case(180_000, 1_000_000; "inverted")]
+    fn test_power_factor_from_phase_angle(milli_degrees: i32, expected: u32) {
+        let pf = PhaseAngle::from_milli_degrees(milli_degrees).power_factor();
+
+        assert!(pf.millionths().abs_diff(expected) <= 1);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    fn test_power_factor_phase_angle() {
+        let pf = PowerFactor::from_millionths(500_000).unwrap();
+
+        assert!(pf.phase_angle().milli_degrees().abs_diff(60_000) <= 1);
+        assert_eq!(PowerFactor::unity().phase_angle(), PhaseAngle::zero());
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(100_000_000, 800_000, 80_000_000; "lagging load")]
+    #[test_case(100_000_000, 1_000_000, 100_000_000; "unity")]
+    #[test_case(3, 500_000, 1; "rounds down")]
+    #[test_case(u64::MAX, 1_000_000, u64::MAX; "maximum")]
+    fn test_real_power(apparent: u64, millionths: u32, expected: u64) {
+        let pf = PowerFactor::from_millionths(millionths).unwrap();
+
+        assert_eq!(
+            real_power(ApparentPower::from_micro_volt_amps(apparent), pf).micro_watts(),
+            expected
+        );
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(80_000_000, 800_000, Some(100_000_000); "lagging load")]
+    #[test_case(1, 0, None; "zero power factor")]
+    #[test_case(u64::MAX, 500_000, None; "overflow")]
+    fn test_apparent_power(real: u64, millionths: u32, expected: Option<u64>) {
+        let pf = PowerFactor::from_millionths(millionths).unwrap();
+
+        assert_eq!(
+            apparent_power(Power::from_micro_watts(real), pf).map(|s| s.micro_volt_amps()),
+            expected
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_power_factor_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &PowerFactor::from_millionths(800_000).unwrap(),
+            &[
+                Token::Struct {
+                    name: "PowerFactor",
+                    len: 1,
+                },
+                Token::Str("millionths"),
+                Token::U32(800_000),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_power_factor_deserialize_above_unity() {
+        use serde_test::{assert_de_tokens_error, Token};
+
+        assert_de_tokens_error::<PowerFactor>(
+            &[
+                Token::Struct {
+                    name: "PowerFactor",
+                    len: 1,
+                },
+                Token::Str("millionths"),
+                Token::U32(5_000_000),
+                Token::StructEnd,
+            ],
+            "value is too large",
+        );
+    }
+}
@@ -1,5 +1,14 @@
-use crate::assert_positive_float;
-use core::{cmp, fmt, ops};
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::{f64_to_u64, try_round_f64_to_u64, try_scale_f64_to_u64};
+use crate::parse::{parse_si_unsigned, ParseError};
+#[cfg(feature = "current")]
+use crate::Current;
+#[cfg(feature = "libm")]
+use crate::Decibel;
+use crate::{Percent, RoundingMode, UnitError};
+use core::{cmp, fmt, hash, iter, ops, str};
 
 /// Represents a power value, stored as whole microwatts (μW) as a 64-bit value.
 /// This value can only be positive.
@@ -43,6 +52,7 @@ use core::{cmp, fmt, ops};
 /// The result is a new `Power` value, rounded down to the nearest whole microwatt (μW).
 ///
 /// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
 ///
 /// ```rust
 /// use ohms::prelude::*;
@@ -62,6 +72,9 @@ use core::{cmp, fmt, ops};
 ///
 /// If the result of the operation would be infinite or NaN, the operation will panic.
 ///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
 /// ```rust
 /// use ohms::prelude::*;
 ///
@@ -84,8 +97,10 @@ use core::{cmp, fmt, ops};
 /// println!("{:.3} W is {:.1} mW", p1.watts(), p1.milli_watts());
 /// ```
 ///
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Power {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_watts"))]
     raw: u64,
 }
 
@@ -99,6 +114,84 @@ impl Power {
         Self { raw: value }
     }
 
+    /// Creates a new `Power` from a number of whole nanowatts (nW).
+    ///
+    /// Returns `None` if the value is not a whole number of microwatts (μW).
+    #[inline]
+    pub const fn from_nano_watts(value: u64) -> Option<Self> {
+        if value.is_multiple_of(1_000) {
+            Some(Self::from_micro_watts(value / 1_000))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `Power` from a number of nanowatts (nW).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative, not a whole number of
+    /// microwatts (μW) or too large to be represented.
+    #[inline]
+    pub fn try_from_nano_watts(value: f64) -> Result<Self, UnitError> {
+        let micro_watts = try_scale_f64_to_u64(value / 1_000f64, 1f64)?;
+        if value % 1_000f64 != 0f64 {
+            return Err(UnitError::Inexact);
+        }
+
+        Ok(Self::from_micro_watts(micro_watts))
+    }
+
+    /// Creates a new `Power` from a number of fractional microwatts (μW).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_watts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_micro_watts)
+    }
+
+    /// Creates a new `Power` from a number of fractional milliwatt (mW).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_watts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_micro_watts)
+    }
+
+    /// Creates a new `Power` from a number of fractional watts (W).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_watts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_micro_watts)
+    }
+
+    /// Creates a new `Power` from a number of fractional kilowatts (kW).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_watts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000f64).map(Self::from_micro_watts)
+    }
+
+    /// Creates a new `Power` from a number of fractional units, rounding to the nearest whole
+    /// microwatt (μW) in the given direction rather than truncating.
+    ///
+    /// Returns an error if the value is NaN, infinite, rounds to a negative number or is too large
+    /// to be represented.
+    #[inline]
+    pub fn try_from_rounded(
+        value: f64,
+        unit: PowerUnit,
+        mode: RoundingMode,
+    ) -> Result<Self, UnitError> {
+        try_round_f64_to_u64(value, unit.scale() as f64, mode).map(Self::from_micro_watts)
+    }
+
+    /// Returns the power value in nanowatts (nW).
+    #[inline]
+    pub fn nano_watts(&self) -> f64 {
+        self.raw as f64 * 1_000_f64
+    }
+
     /// Returns the power value in whole microwatts (μW).
     #[inline]
     pub const fn micro_watts(&self) -> u64 {
@@ -123,6 +216,27 @@ impl Power {
         self.raw as f64 / 1_000_000_000f64
     }
 
+    /// Returns the power value as whole milliwatts (mW) and the remaining microwatts (μW),
+    /// without any floating-point math.
+    #[inline]
+    pub const fn to_milli_watts_parts(&self) -> (u64, u32) {
+        (self.raw / 1_000, (self.raw % 1_000) as u32)
+    }
+
+    /// Returns the power value as whole watts (W) and the remaining microwatts (μW),
+    /// without any floating-point math.
+    #[inline]
+    pub const fn to_watts_parts(&self) -> (u64, u32) {
+        (self.raw / 1_000_000, (self.raw % 1_000_000) as u32)
+    }
+
+    /// Returns the power value as whole kilowatts (kW) and the remaining microwatts (μW),
+    /// without any floating-point math.
+    #[inline]
+    pub const fn to_kilo_watts_parts(&self) -> (u64, u32) {
+        (self.raw / 1_000_000_000, (self.raw % 1_000_000_000) as u32)
+    }
+
     /// Returns whether the power value is zero watts (0W).
     #[inline]
     pub const fn is_zero(&self) -> bool {
@@ -134,8 +248,209 @@ impl Power {
     pub const fn zero() -> Self {
         Self::from_micro_watts(0)
     }
+
+    /// Adds two `Power` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_micro_watts)
+    }
+
+    /// Subtracts one `Power` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_micro_watts)
+    }
+
+    /// Multiplies the `Power` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_micro_watts)
+    }
+
+    /// Divides the `Power` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_micro_watts)
+    }
+
+    /// Multiplies the `Power` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole microwatt (μW).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_micro_watts)
+    }
+
+    /// Divides the `Power` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole microwatt (μW).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_micro_watts)
+    }
+
+    /// Multiplies the `Power` value by a floating-point value, rounding the result to the nearest
+    /// whole microwatt (μW) in the given direction.
+    ///
+    /// Returns `None` if the factor is infinite, NaN, negative or the result would overflow.
+    #[inline]
+    pub fn checked_mul_f64_rounded(self, factor: f64, mode: RoundingMode) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(mode.round_f64(self.raw as f64 * factor)).map(Self::from_micro_watts)
+    }
+
+    /// Divides the `Power` value by a floating-point value, rounding the result to the nearest
+    /// whole microwatt (μW) in the given direction.
+    ///
+    /// Returns `None` if the divisor is zero, infinite, NaN, negative or the result would overflow.
+    #[inline]
+    pub fn checked_div_f64_rounded(self, divisor: f64, mode: RoundingMode) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(mode.round_f64(self.raw as f64 / divisor)).map(Self::from_micro_watts)
+    }
+
+    /// Calculates the efficiency of a converter with this output power, given its input power
+    /// (`η = Pout ÷ Pin`).
+    ///
+    /// Will be rounded to the nearest whole milli-percent (m%). The result may be greater than 100%
+    /// if the measurements are inaccurate. Returns `None` if the input power is zero or the result
+    /// would overflow.
+    pub fn efficiency_against(&self, input: Power) -> Option<Percent> {
+        Percent::from_ratio(self.raw, input.raw)
+    }
+
+    /// Calculates the crest factor of a waveform given its peak and RMS currents.
+    ///
+    /// This is the ratio of the peak current to the RMS current, where a pure sine wave is `√2`.
+    /// Panics if the RMS current is zero.
+    #[cfg(feature = "current")]
+    pub fn crest_factor(peak: Current, rms: Current) -> f64 {
+        if rms.is_zero() {
+            panic!("RMS current cannot be zero");
+        }
+
+        peak.micro_amps() as f64 / rms.micro_amps() as f64
+    }
+
+    /// Converts the power to a level in decibel-milliwatts (dBm), relative to one milliwatt.
+    ///
+    /// Will be rounded to the nearest whole millidecibel (mdB).
+    /// Returns `None` if the power is zero, as its level would be negative infinity.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn to_dbm(&self) -> Option<Decibel> {
+        if self.is_zero() {
+            return None;
+        }
+
+        Decibel::from_decibels_rounded(10f64 * libm::log10(self.raw as f64) - 30f64)
+    }
+
+    /// Creates a new `Power` from a level in decibel-milliwatts (dBm), relative to one milliwatt.
+    ///
+    /// Will be rounded to the nearest whole microwatt (μW).
+    /// Returns `None` if the power would be too large to be represented.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn from_dbm(level: Decibel) -> Option<Self> {
+        let micro_watts = libm::pow(10f64, level.decibels() / 10f64 + 3f64);
+
+        f64_to_u64(libm::round(micro_watts)).map(Self::from_micro_watts)
+    }
+
+    /// Returns the unit symbol used when displaying the power value, such as `"mW"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "μW"),
+            1_000..=999_999 => (1_000, "mW"),
+            1_000_000..=999_999_999 => (1_000_000, "W"),
+            _ => (1_000_000_000, "kW"),
+        }
+    }
+
+    /// Formats the power value into a byte buffer in the given unit, rounded to `precision`
+    /// decimal places, returning the written string, such as `"1.50 W"`.
+    ///
+    /// No floating-point math or heap allocation is used, so this is suitable for rendering values
+    /// to displays on targets without a floating-point unit. Returns an error if the buffer is too
+    /// small to hold the formatted value.
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        unit: PowerUnit,
+        precision: usize,
+    ) -> Result<&'a str, fmt::Error> {
+        display::format_into(buf, false, self.raw, unit.scale(), precision, unit.symbol())
+    }
+}
+
+/// The units a `Power` value can be formatted in, used by [`Power::format_into`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerUnit {
+    /// Microwatts (μW).
+    MicroWatts,
+    /// Milliwatts (mW).
+    MilliWatts,
+    /// Watts (W).
+    Watts,
+    /// Kilowatts (kW).
+    KiloWatts,
+}
+
+impl PowerUnit {
+    /// Returns the number of base units in one of this unit.
+    pub(crate) const fn scale(self) -> u64 {
+        match self {
+            PowerUnit::MicroWatts => 1,
+            PowerUnit::MilliWatts => 1_000,
+            PowerUnit::Watts => 1_000_000,
+            PowerUnit::KiloWatts => 1_000_000_000,
+        }
+    }
+
+    /// Returns the symbol of this unit, such as `"W"`.
+    #[inline]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            PowerUnit::MicroWatts => "μW",
+            PowerUnit::MilliWatts => "mW",
+            PowerUnit::Watts => "W",
+            PowerUnit::KiloWatts => "kW",
+        }
+    }
 }
 
+approx::impl_approx_eq!(Power, unsigned);
+compare::impl_const_compare!(Power, unsigned);
+
 impl PartialEq for Power {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -148,7 +463,7 @@ impl Eq for Power {}
 impl PartialOrd for Power {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Some(self.cmp(other))
     }
 }
 
@@ -159,6 +474,13 @@ impl Ord for Power {
     }
 }
 
+impl hash::Hash for Power {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
 impl ops::Add for Power {
     type Output = Self;
 
@@ -185,6 +507,24 @@ impl ops::Sub for Power {
     }
 }
 
+impl iter::Sum for Power {
+    /// Sums the `Power` values, returning zero (0W) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a Power> for Power {
+    /// Sums the `Power` values, returning zero (0W) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 macro_rules! impl_mul_for_integer {
     ($i:ty) => {
         impl ops::Mul<$i> for Power {
@@ -324,49 +664,25 @@ pub trait FromInteger {
     fn kilo_watts(self) -> Power;
 }
 
-macro_rules! impl_power_from_integer {
-    ($i:ty) => {
-        impl FromInteger for $i {
-            #[inline]
-            fn micro_watts(self) -> Power {
-                Power::from_micro_watts(self as u64)
-            }
-
-            #[inline]
-            fn milli_watts(self) -> Power {
-                let microwatts = (self as u64)
-                    .checked_mul(1_000)
-                    .expect("Overflow when converting milliwatts to microwatts");
-                Power::from_micro_watts(microwatts)
-            }
-
-            #[inline]
-            fn watts(self) -> Power {
-                let microwatts = (self as u64)
-                    .checked_mul(1_000_000)
-                    .expect("Overflow when converting watts to microwatts");
-                Power::from_micro_watts(microwatts)
-            }
-
-            #[inline]
-            fn kilo_watts(self) -> Power {
-                let microwatts = (self as u64)
-                    .checked_mul(1_000_000_000)
-                    .expect("Overflow when converting kilowatts to microwatts");
-                Power::from_micro_watts(microwatts)
-            }
-        }
-    };
-}
-
-impl_power_from_integer!(u8);
-impl_power_from_integer!(u16);
-impl_power_from_integer!(u32);
-impl_power_from_integer!(u64);
-impl_power_from_integer!(i8);
-impl_power_from_integer!(i16);
-impl_power_from_integer!(i32);
-impl_power_from_integer!(i64);
+crate::__define_unit_from_integer!(
+    Power,
+    FromInteger,
+    from_micro_watts,
+    micro_watts,
+    [
+        milli_watts = 1_000,
+        watts = 1_000_000,
+        kilo_watts = 1_000_000_000
+    ],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
 
 /// Extension trait for simple short-hands for creating `Power` values from floating-point values.
 pub trait FromFloat {
@@ -391,51 +707,339 @@ pub trait FromFloat {
     fn kilo_watts(self) -> Power;
 }
 
-macro_rules! impl_power_from_float {
-    ($f:ty) => {
-        impl FromFloat for $f {
-            #[inline]
-            fn micro_watts(self) -> Power {
-                assert_positive_float!(self);
-                Power::from_micro_watts(self as u64)
-            }
+crate::__define_unit_from_float!(
+    Power,
+    FromFloat,
+    from_micro_watts,
+    micro_watts,
+    [
+        milli_watts = 1_000,
+        watts = 1_000_000,
+        kilo_watts = 1_000_000_000
+    ],
+    f32,
+    f64
+);
 
-            #[inline]
-            fn milli_watts(self) -> Power {
-                assert_positive_float!(self);
-                let microwatts = (self as f64) * 1_000f64;
-                Power::from_micro_watts(microwatts as u64)
-            }
+crate::__define_unit_display!(Power);
 
-            #[inline]
-            fn watts(self) -> Power {
-                assert_positive_float!(self);
-                let microwatts = (self as f64) * 1_000_000f64;
-                Power::from_micro_watts(microwatts as u64)
-            }
+impl str::FromStr for Power {
+    type Err = ParseError;
 
-            #[inline]
-            fn kilo_watts(self) -> Power {
-                assert_positive_float!(self);
-                let microwatts = (self as f64) * 1_000_000_000f64;
-                Power::from_micro_watts(microwatts as u64)
-            }
-        }
-    };
+    /// Parses a `Power` value from a string with an optional SI prefix and unit symbol, such as
+    /// `250mW`, `1.5 kW` or `5`.
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["W"], 6).map(Self::from_micro_watts)
+    }
 }
 
-impl_power_from_float!(f32);
-impl_power_from_float!(f64);
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+impl Power {
+    /// Splits the power value into its whole and hundredths parts in the most appropriate unit,
+    /// for formatting without floating-point math.
+    fn scaled_parts(&self) -> (u64, u64, &'static str) {
+        let (scale, unit) = self.display_scale();
 
-impl fmt::Display for Power {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (value, unit) = match self.raw {
-            0..=999 => (self.raw as f64, "μW"),
-            1_000..=999_999 => ((self.raw as f64) / 1_000f64, "mW"),
-            1_000_000..=999_999_999 => ((self.raw as f64) / 1_000_000f64, "W"),
-            _ => ((self.raw as f64) / 1_000_000f64, "kW"),
-        };
+        let whole = self.raw / scale;
+        let fraction = self.raw % scale * 100 / scale;
+
+        (whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Power {
+    fn format(&self, f: defmt::Formatter) {
+        let (whole, fraction, unit) = self.scaled_parts();
+
+        defmt::write!(f, "{=u64}.{=u64:02} {=str}", whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Power {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let (whole, fraction, unit) = self.scaled_parts();
+        let padding = if fraction < 10 { "0" } else { "" };
+
+        ufmt::uwrite!(f, "{}.{}{} {}", whole, padding, fraction, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(920_000, 1_000_000, Some(92_000); "92%")]
+    #[test_case(2_000_000, 3_000_000, Some(66_667); "rounds to nearest")]
+    #[test_case(1_100_000, 1_000_000, Some(110_000); "over 100%")]
+    #[test_case(0, 1_000_000, Some(0); "zero output")]
+    #[test_case(1_000_000, 0, None; "zero input")]
+    #[test_case(u64::MAX, 1, None; "overflow")]
+    fn test_efficiency_against(output: u64, input: u64, expected: Option<u32>) {
+        let efficiency =
+            Power::from_micro_watts(output).efficiency_against(Power::from_micro_watts(input));
+        assert_eq!(efficiency.map(|e| e.milli_percent()), expected);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test_case(1_000, Some(0); "1mW")]
+    #[test_case(1_000_000, Some(30_000); "1W")]
+    #[test_case(2_000, Some(3_010); "2mW")]
+    #[test_case(1, Some(-30_000); "1uW")]
+    #[test_case(0, None; "zero")]
+    fn test_to_dbm(micro_watts: u64, expected: Option<i32>) {
+        let level = Power::from_micro_watts(micro_watts).to_dbm();
+        assert_eq!(level.map(|db| db.milli_decibels()), expected);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test_case(0, Some(1_000); "0dBm")]
+    #[test_case(30_000, Some(1_000_000); "30dBm")]
+    #[test_case(-30_000, Some(1); "-30dBm")]
+    #[test_case(200_000, None; "overflow")]
+    fn test_from_dbm(milli_decibels: i32, expected: Option<u64>) {
+        let power = Power::from_dbm(Decibel::from_milli_decibels(milli_decibels));
+        assert_eq!(power.map(|p| p.micro_watts()), expected);
+    }
+
+    #[test_case(2_500_000, (2, 500_000), (0, 2_500_000); "watts")]
+    #[test_case(1_250_000_000, (1_250, 0), (1, 250_000_000); "kilowatts")]
+    fn test_to_parts(micro_watts: u64, watts: (u64, u32), kilo_watts: (u64, u32)) {
+        let power = Power::from_micro_watts(micro_watts);
+        assert_eq!(power.to_watts_parts(), watts);
+        assert_eq!(power.to_kilo_watts_parts(), kilo_watts);
+    }
+
+    #[cfg(feature = "current")]
+    #[test_case(1_414_000, 1_000_000, 1.414; "sine wave equals 1.414")]
+    #[test_case(500_000, 500_000, 1.0; "square wave equals one")]
+    #[test_case(0, 500_000, 0.0; "zero peak equals zero")]
+    fn test_crest_factor(peak_micro_amps: u64, rms_micro_amps: u64, expected: f64) {
+        let peak = Current::from_micro_amps(peak_micro_amps);
+        let rms = Current::from_micro_amps(rms_micro_amps);
+
+        assert!((Power::crest_factor(peak, rms) - expected).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    #[should_panic]
+    fn test_crest_factor_zero_rms_panics() {
+        Power::crest_factor(Current::from_micro_amps(1), Current::zero());
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [
+            Power::from_micro_watts(1),
+            Power::from_micro_watts(2),
+            Power::from_micro_watts(3),
+        ];
+
+        assert_eq!(values.iter().sum::<Power>().micro_watts(), 6);
+        assert_eq!(values.into_iter().sum::<Power>().micro_watts(), 6);
+        assert_eq!(core::iter::empty::<Power>().sum::<Power>(), Power::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sum_overflow_panics() {
+        let _: Power = [
+            Power::from_micro_watts(u64::MAX),
+            Power::from_micro_watts(1),
+        ]
+        .iter()
+        .sum();
+    }
+
+    #[test_case(1, 2, Some(3); "adds values")]
+    #[test_case(u64::MAX, 1, None; "overflow is none")]
+    fn test_checked_add(a: u64, b: u64, expected: Option<u64>) {
+        let result = Power::from_micro_watts(a).checked_add(Power::from_micro_watts(b));
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(3, 2, Some(1); "subtracts values")]
+    #[test_case(2, 3, None; "underflow is none")]
+    fn test_checked_sub(a: u64, b: u64, expected: Option<u64>) {
+        let result = Power::from_micro_watts(a).checked_sub(Power::from_micro_watts(b));
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(3, 2, Some(6); "multiplies values")]
+    #[test_case(u64::MAX, 2, None; "overflow is none")]
+    fn test_checked_mul_int(value: u64, factor: u64, expected: Option<u64>) {
+        let result = Power::from_micro_watts(value).checked_mul_int(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(7, 2, Some(3); "divides and rounds down")]
+    #[test_case(1, 0, None; "division by zero is none")]
+    fn test_checked_div_int(value: u64, divisor: u64, expected: Option<u64>) {
+        let result = Power::from_micro_watts(value).checked_div_int(divisor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    #[test_case(u64::MAX, 2.0, None; "overflow is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Power::from_micro_watts(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 4.0, Some(250); "divides by fractional divisor")]
+    #[test_case(1_000, 0.0, None; "division by zero is none")]
+    #[test_case(1_000, -4.0, None; "negative divisor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    #[test_case(u64::MAX, 0.5, None; "overflow is none")]
+    fn test_checked_div_f64(value: u64, divisor: f64, expected: Option<u64>) {
+        let result = Power::from_micro_watts(value).checked_div_f64(divisor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(5, 0.5, RoundingMode::Nearest, Some(3); "nearest rounds half up")]
+    #[test_case(5, 0.5, RoundingMode::Up, Some(3); "rounds up")]
+    #[test_case(5, 0.5, RoundingMode::Down, Some(2); "rounds down")]
+    #[test_case(u64::MAX, 2.0, RoundingMode::Up, None; "overflow is none")]
+    #[test_case(5, -0.1, RoundingMode::Nearest, None; "negative factor is none")]
+    #[test_case(5, -0.1, RoundingMode::Up, None; "negative factor rounded up is none")]
+    #[test_case(0, -2.0, RoundingMode::Nearest, None; "zero times negative factor is none")]
+    fn test_checked_mul_f64_rounded(
+        value: u64,
+        factor: f64,
+        mode: RoundingMode,
+        expected: Option<u64>,
+    ) {
+        let result = Power::from_micro_watts(value).checked_mul_f64_rounded(factor, mode);
+        assert_eq!(result.map(|p| p.raw), expected);
+    }
+
+    #[test_case(5, 2.0, RoundingMode::Nearest, Some(3); "nearest rounds half up")]
+    #[test_case(7, 2.0, RoundingMode::Down, Some(3); "rounds down")]
+    #[test_case(5, 0.0, RoundingMode::Up, None; "division by zero is none")]
+    #[test_case(5, -20.0, RoundingMode::Up, None; "negative divisor is none")]
+    #[test_case(5, -20.0, RoundingMode::Nearest, None; "negative divisor rounded is none")]
+    fn test_checked_div_f64_rounded(
+        value: u64,
+        divisor: f64,
+        mode: RoundingMode,
+        expected: Option<u64>,
+    ) {
+        let result = Power::from_micro_watts(value).checked_div_f64_rounded(divisor, mode);
+        assert_eq!(result.map(|p| p.raw), expected);
+    }
+
+    #[test_case(2.5, RoundingMode::Nearest, Ok(3); "nearest rounds half up")]
+    #[test_case(2.1, RoundingMode::Up, Ok(3); "rounds up")]
+    #[test_case(-0.4, RoundingMode::Nearest, Ok(0); "small negative rounds to zero")]
+    #[test_case(-0.6, RoundingMode::Nearest, Err(UnitError::Negative); "negative")]
+    fn test_try_from_rounded(value: f64, mode: RoundingMode, expected: Result<u64, UnitError>) {
+        let result = Power::try_from_rounded(value, PowerUnit::MicroWatts, mode);
+        assert_eq!(result.map(|p| p.raw), expected);
+    }
+
+    #[test_case(250, "250.00 μW"; "microwatts")]
+    #[test_case(250_000, "250.00 mW"; "milliwatts")]
+    #[test_case(5_000_000, "5.00 W"; "watts")]
+    #[test_case(1_500_000_000, "1.50 kW"; "kilowatts")]
+    fn test_display(micro_watts: u64, expected: &str) {
+        let power = Power::from_micro_watts(micro_watts);
+        assert_eq!(format!("{power}"), expected);
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let power = Power::from_micro_watts(5_000_000);
+        assert_eq!(format!("{power:.0}"), "5 W");
+        assert_eq!(format!("{power:.6}"), "5.000000 W");
+    }
+
+    #[test_case("250mW", Ok(250_000); "milli prefix")]
+    #[test_case("1.5 kW", Ok(1_500_000_000); "kilo prefix with space")]
+    #[test_case("5", Ok(5_000_000); "watts without symbol")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Power>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_500_000, PowerUnit::Watts, 2, "1.50 W"; "watts")]
+    #[test_case(1_250_000_000, PowerUnit::KiloWatts, 2, "1.25 kW"; "kilowatts")]
+    fn test_format_into(micro_watts: u64, unit: PowerUnit, precision: usize, expected: &str) {
+        let mut buf = [0u8; 32];
+        let power = Power::from_micro_watts(micro_watts);
+        assert_eq!(power.format_into(&mut buf, unit, precision), Ok(expected));
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test_case(2_500_000, "2.50 W"; "watts")]
+    #[test_case(1_050, "1.05 mW"; "milliwatts")]
+    fn test_udisplay(micro_watts: u64, expected: &str) {
+        let power = Power::from_micro_watts(micro_watts);
+        let mut output = alloc::string::String::new();
+        ufmt::uwrite!(output, "{}", power).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test_case(1_500_000, Some(1_500); "whole microwatts")]
+    #[test_case(1_500_999, None; "fraction of a microwatt")]
+    #[test_case(999, None; "less than one microwatt")]
+    fn test_from_nano_watts(nano: u64, expected_micro: Option<u64>) {
+        let p = Power::from_nano_watts(nano);
+
+        assert_eq!(p.map(|p| p.raw), expected_micro);
+        assert_eq!(
+            p.map(|p| p.nano_watts()),
+            expected_micro.map(|micro| micro as f64 * 1_000.0)
+        );
+    }
+
+    #[test_case(2_000.0, Ok(2); "whole microunits")]
+    #[test_case(2_500.0, Err(UnitError::Inexact); "fractional microunits")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    fn test_try_from_nano_watts(nano: f64, expected_micro: Result<u64, UnitError>) {
+        let result = Power::try_from_nano_watts(nano);
+        assert_eq!(result.map(|p| p.raw), expected_micro);
+    }
+
+    #[test_case(Power::try_from_milli_watts(1.5), Ok(Power::from_micro_watts(1_500)); "valid value")]
+    #[test_case(Power::try_from_milli_watts(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Power::try_from_milli_watts(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Power::try_from_milli_watts(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Power::try_from_milli_watts(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(result: Result<Power, UnitError>, expected: Result<Power, UnitError>) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
 
-        write!(f, "{value:.2} {unit}")
+        assert_tokens(
+            &Power::from_micro_watts(250000),
+            &[
+                Token::Struct {
+                    name: "Power",
+                    len: 1,
+                },
+                Token::Str("micro_watts"),
+                Token::U64(250000),
+                Token::StructEnd,
+            ],
+        );
     }
 }
@@ -0,0 +1,90 @@
+use crate::display;
+use core::fmt;
+
+/// Represents a dimensionless ratio in whole parts per million (ppm), such as a tolerance or a
+/// temperature coefficient. This value may be negative.
+///
+/// **Reminder:** `10,000 ppm = 1%`
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let tcr = Ppm::new(-50); // -50 ppm/°C
+///
+/// assert_eq!(Ppm::new(10_000).percent(), 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ppm {
+    #[cfg_attr(feature = "serde", serde(rename = "ppm"))]
+    raw: i32,
+}
+
+impl Ppm {
+    /// Creates a new `Ppm` from a number of whole parts per million (ppm).
+    #[inline]
+    pub const fn new(ppm: i32) -> Self {
+        Self { raw: ppm }
+    }
+
+    /// Returns the value in whole parts per million (ppm).
+    #[inline]
+    pub const fn ppm(&self) -> i32 {
+        self.raw
+    }
+
+    /// Returns the value as a fractional percentage (%).
+    #[inline]
+    pub fn percent(&self) -> f64 {
+        self.raw as f64 / 10_000f64
+    }
+
+    /// Returns the value as a fraction, such as `0.01` for 10,000 ppm.
+    #[inline]
+    pub fn fraction(&self) -> f64 {
+        self.raw as f64 / 1_000_000f64
+    }
+
+    /// Returns whether the value is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `Ppm` value of zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::new(0)
+    }
+}
+
+impl fmt::Display for Ppm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(f, format_args!("{} ppm", self.raw))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(10_000, 1.0; "10000 ppm equals 1%")]
+    #[test_case(-50, -0.005; "negative")]
+    #[test_case(0, 0.0; "zero")]
+    fn test_percent(ppm: i32, expected: f64) {
+        assert_eq!(Ppm::new(ppm).percent(), expected);
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(Ppm::new(250_000).fraction(), 0.25);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", Ppm::new(-50)), "-50 ppm");
+        assert_eq!(format!("{:>8}", Ppm::new(100)), " 100 ppm");
+    }
+}
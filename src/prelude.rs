@@ -1,11 +1,72 @@
+#[cfg(feature = "current")]
+pub use crate::ac::AcCurrent;
+#[cfg(any(feature = "voltage", feature = "current"))]
+pub use crate::ac::AcMeasure;
+#[cfg(feature = "voltage")]
+pub use crate::ac::AcVoltage;
+#[cfg(feature = "power")]
+pub use crate::apparent_power::ApparentPower;
+pub use crate::calibration::{CalTable, LinearCal};
+pub use crate::capacitance::{
+    Capacitance, FromFloat as CapacitanceFromFloat, FromInteger as CapacitanceFromInteger,
+};
+pub use crate::capacity::Capacity;
+pub use crate::charge::{Charge, FromFloat as ChargeFromFloat, FromInteger as ChargeFromInteger};
+pub use crate::conductance::{
+    Conductance, FromFloat as ConductanceFromFloat, FromInteger as ConductanceFromInteger,
+};
+#[cfg(feature = "current")]
 pub use crate::current::{
-    Current, FromFloat as CurrentFromFloat, FromInteger as CurrentFromInteger,
+    Current, CurrentUnit, FromFloat as CurrentFromFloat, FromInteger as CurrentFromInteger,
+};
+#[cfg(feature = "current")]
+pub use crate::current_density::CurrentDensity;
+pub use crate::decibel::Decibel;
+#[cfg(all(feature = "voltage", feature = "resistance"))]
+pub use crate::divider::VoltageDivider;
+pub use crate::duty_cycle::DutyCycle;
+pub use crate::energy::{Energy, FromFloat as EnergyFromFloat, FromInteger as EnergyFromInteger};
+pub use crate::frequency::{
+    Frequency, FromFloat as FrequencyFromFloat, FromInteger as FrequencyFromInteger,
 };
-pub use crate::law::*;
-pub use crate::power::{FromFloat as PowerFromFloat, FromInteger as PowerFromInteger, Power};
+#[cfg(feature = "current")]
+pub use crate::i2t::I2t;
+#[cfg(feature = "resistance")]
+pub use crate::impedance::Impedance;
+pub use crate::inductance::{
+    FromFloat as InductanceFromFloat, FromInteger as InductanceFromInteger, Inductance,
+};
+pub use crate::law::{CheckedDiv, CheckedMul, RoundedDiv, RoundedMul};
+#[cfg(feature = "current")]
+pub use crate::nonzero::NonZeroCurrent;
+#[cfg(feature = "resistance")]
+pub use crate::nonzero::NonZeroResistance;
+pub use crate::percent::Percent;
+pub use crate::phase::{PhaseAngle, PowerFactor};
+#[cfg(feature = "power")]
+pub use crate::power::{
+    FromFloat as PowerFromFloat, FromInteger as PowerFromInteger, Power, PowerUnit,
+};
+pub use crate::ppm::Ppm;
+#[cfg(feature = "power")]
+pub use crate::reactive_power::ReactivePower;
+#[cfg(feature = "resistance")]
 pub use crate::resistance::{
     FromFloat as ResistanceFromFloat, FromInteger as ResistanceFromInteger, Resistance,
+    ResistanceUnit,
+};
+pub use crate::resistivity::Resistivity;
+pub use crate::rounding::RoundingMode;
+#[cfg(feature = "current")]
+pub use crate::signed_current::SignedCurrent;
+#[cfg(feature = "power")]
+pub use crate::signed_power::SignedPower;
+pub use crate::temperature::{
+    FromFloat as TemperatureFromFloat, FromInteger as TemperatureFromInteger, Temperature,
 };
+pub use crate::thermal::ThermalResistance;
+pub use crate::tolerance::Toleranced;
+#[cfg(feature = "voltage")]
 pub use crate::voltage::{
-    FromFloat as VoltageFromFloat, FromInteger as VoltageFromInteger, Voltage,
+    FromFloat as VoltageFromFloat, FromInteger as VoltageFromInteger, Voltage, VoltageUnit,
 };
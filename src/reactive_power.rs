@@ -0,0 +1,375 @@
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::try_scale_f64_to_i64;
+use crate::parse::{parse_si_signed, ParseError};
+use crate::UnitError;
+use core::{cmp, fmt, hash, iter, ops, str};
+
+/// Represents a reactive power value, stored as whole microvolt-amperes reactive (μVAR) as a
+/// signed 64-bit value. This value can be positive or negative.
+///
+/// **Reminder:** `1000 μVAR = 1 mVAR, 1000 mVAR = 1 VAR, 1000 VAR = 1 kVAR`
+///
+/// This is an immutable type. Any math operators return a new `ReactivePower` value.
+///
+/// Reactive power is exchanged back and forth between a source and the inductance or capacitance
+/// of an AC load, without doing any work. By convention, a positive value is drawn by an inductive
+/// (lagging) load and a negative value by a capacitive (leading) load, so the two cancel out when
+/// added together, as with power factor correction.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let motor = ReactivePower::from_micro_vars(750_000_000); // 750 VAR
+/// let capacitor = ReactivePower::from_micro_vars(-500_000_000); // -500 VAR
+///
+/// let net = motor + capacitor;
+/// assert!(net.is_inductive());
+/// assert_eq!(format!("{net}"), "250.00 VAR");
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReactivePower {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_vars"))]
+    raw: i64,
+}
+
+impl ReactivePower {
+    /// Creates a new `ReactivePower` from a number of whole microvolt-amperes reactive (μVAR).
+    #[inline]
+    pub const fn from_micro_vars(value: i64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `ReactivePower` from a number of fractional millivolt-amperes reactive
+    /// (mVAR).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_vars(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000f64).map(Self::from_micro_vars)
+    }
+
+    /// Creates a new `ReactivePower` from a number of fractional volt-amperes reactive (VAR).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_vars(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000_000f64).map(Self::from_micro_vars)
+    }
+
+    /// Creates a new `ReactivePower` from a number of fractional kilovolt-amperes reactive
+    /// (kVAR).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_vars(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000_000_000f64).map(Self::from_micro_vars)
+    }
+
+    /// Returns the reactive power value in whole microvolt-amperes reactive (μVAR).
+    #[inline]
+    pub const fn micro_vars(&self) -> i64 {
+        self.raw
+    }
+
+    /// Returns the reactive power value in fractional millivolt-amperes reactive (mVAR).
+    #[inline]
+    pub fn milli_vars(&self) -> f64 {
+        self.raw as f64 / 1_000_f64
+    }
+
+    /// Returns the reactive power value in fractional volt-amperes reactive (VAR).
+    #[inline]
+    pub fn vars(&self) -> f64 {
+        self.raw as f64 / 1_000_000_f64
+    }
+
+    /// Returns the reactive power value in fractional kilovolt-amperes reactive (kVAR).
+    #[inline]
+    pub fn kilo_vars(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_f64
+    }
+
+    /// Returns whether the reactive power value is zero volt-amperes reactive (0VAR).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns whether the reactive power is drawn by an inductive (lagging) load.
+    ///
+    /// This returns `true` if the reactive power value is greater than zero.
+    #[inline]
+    pub const fn is_inductive(&self) -> bool {
+        self.raw > 0
+    }
+
+    /// Returns whether the reactive power is drawn by a capacitive (leading) load.
+    ///
+    /// This returns `true` if the reactive power value is less than zero.
+    #[inline]
+    pub const fn is_capacitive(&self) -> bool {
+        self.raw < 0
+    }
+
+    /// Returns the absolute value of the reactive power value.
+    ///
+    /// Panics if the reactive power is the minimum value, as its magnitude cannot be represented.
+    #[inline]
+    pub const fn abs(&self) -> Self {
+        Self::from_micro_vars(self.raw.abs())
+    }
+
+    /// Inverts the reactive power value from positive to negative or negative to positive.
+    ///
+    /// This is equivalent to the unary `-` operator.
+    /// Panics if the reactive power is the minimum value, as its inverse cannot be represented.
+    #[inline]
+    pub const fn invert(&self) -> Self {
+        match self.raw.checked_neg() {
+            Some(raw) => Self::from_micro_vars(raw),
+            None => panic!("Overflow when negating reactive power value"),
+        }
+    }
+
+    /// Inverts the reactive power value, returning `None` if the result would overflow.
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.raw.checked_neg() {
+            Some(raw) => Some(Self::from_micro_vars(raw)),
+            None => None,
+        }
+    }
+
+    /// Returns a `ReactivePower` value of zero volt-amperes reactive (0VAR).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_micro_vars(0)
+    }
+
+    /// Adds two `ReactivePower` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_micro_vars)
+    }
+
+    /// Subtracts one `ReactivePower` value from another, returning `None` if the result would
+    /// overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_micro_vars)
+    }
+
+    /// Returns the unit symbol used when displaying the reactive power value, such as `"kVAR"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw.unsigned_abs() {
+            0..=999 => (1, "μVAR"),
+            1_000..=999_999 => (1_000, "mVAR"),
+            1_000_000..=999_999_999 => (1_000_000, "VAR"),
+            _ => (1_000_000_000, "kVAR"),
+        }
+    }
+}
+
+approx::impl_approx_eq!(ReactivePower, signed);
+compare::impl_const_compare!(ReactivePower, signed);
+
+impl PartialEq for ReactivePower {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for ReactivePower {}
+
+impl PartialOrd for ReactivePower {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ReactivePower {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for ReactivePower {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for ReactivePower {
+    type Output = Self;
+
+    /// Adds two `ReactivePower` values together, returning a new `ReactivePower` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("Overflow when adding reactive power values")
+    }
+}
+
+impl ops::Sub for ReactivePower {
+    type Output = Self;
+
+    /// Subtracts the `ReactivePower` value from another, returning a new `ReactivePower` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other)
+            .expect("Overflow when subtracting reactive power values")
+    }
+}
+
+impl iter::Sum for ReactivePower {
+    /// Sums the `ReactivePower` values, returning zero (0VAR) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a ReactivePower> for ReactivePower {
+    /// Sums the `ReactivePower` values, returning zero (0VAR) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl ops::Neg for ReactivePower {
+    type Output = Self;
+
+    /// Inverts the `ReactivePower` value from positive to negative or negative to positive.
+    #[inline]
+    fn neg(self) -> Self {
+        self.invert()
+    }
+}
+
+impl fmt::Display for ReactivePower {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let (scale, unit) = self.display_scale();
+        let value = (self.raw.unsigned_abs() as f64) / (scale as f64);
+
+        display::write_padded(f, format_args!("{sign}{value:.precision$} {unit}"))
+    }
+}
+
+impl str::FromStr for ReactivePower {
+    type Err = ParseError;
+
+    /// Parses a `ReactivePower` value from a string with an optional sign, SI prefix and unit
+    /// symbol, such as `-500VAR`, `1.5 kvar` or `250m`.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt-ampere reactive (μVAR).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_signed(s, &["VAR", "var"], 6).map(Self::from_micro_vars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(1_000, true, false; "inductive")]
+    #[test_case(-1_000, false, true; "capacitive")]
+    #[test_case(0, false, false; "zero")]
+    fn test_direction(micro_vars: i64, inductive: bool, capacitive: bool) {
+        let reactive = ReactivePower::from_micro_vars(micro_vars);
+
+        assert_eq!(reactive.is_inductive(), inductive);
+        assert_eq!(reactive.is_capacitive(), capacitive);
+        assert_eq!(reactive.is_zero(), micro_vars == 0);
+    }
+
+    #[test]
+    fn test_sign() {
+        let reactive = ReactivePower::from_micro_vars(-1);
+
+        assert_eq!(-reactive, ReactivePower::from_micro_vars(1));
+        assert_eq!(reactive.abs(), ReactivePower::from_micro_vars(1));
+        assert_eq!(ReactivePower::from_micro_vars(i64::MIN).checked_neg(), None);
+    }
+
+    #[test_case(1.5, Ok(1_500_000); "vars")]
+    #[test_case(-0.25, Ok(-250_000); "negative")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "NaN")]
+    fn test_try_from_vars(value: f64, expected: Result<i64, UnitError>) {
+        assert_eq!(
+            ReactivePower::try_from_vars(value).map(|q| q.micro_vars()),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_conversions() {
+        let reactive = ReactivePower::from_micro_vars(-2_500_000_000);
+
+        assert_eq!(reactive.milli_vars(), -2_500_000.0);
+        assert_eq!(reactive.vars(), -2_500.0);
+        assert_eq!(reactive.kilo_vars(), -2.5);
+        assert_eq!(ReactivePower::try_from_kilo_vars(-2.5), Ok(reactive));
+        assert_eq!(
+            ReactivePower::try_from_milli_vars(-2_500_000.0),
+            Ok(reactive)
+        );
+    }
+
+    #[test_case(1_000, -3_000, Some(-2_000); "mixed signs")]
+    #[test_case(i64::MIN, -1, None; "overflow")]
+    fn test_checked_add(a: i64, b: i64, expected: Option<i64>) {
+        let sum = ReactivePower::from_micro_vars(a).checked_add(ReactivePower::from_micro_vars(b));
+        assert_eq!(sum.map(|q| q.micro_vars()), expected);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = ReactivePower::from_micro_vars(3_000);
+        let b = ReactivePower::from_micro_vars(-1_000);
+
+        assert_eq!(a - b, ReactivePower::from_micro_vars(4_000));
+        assert_eq!(
+            [a, b].iter().sum::<ReactivePower>(),
+            ReactivePower::from_micro_vars(2_000)
+        );
+    }
+
+    #[test_case(-999, "-999.00 μVAR"; "microvars")]
+    #[test_case(1_500_000, "1.50 VAR"; "vars")]
+    #[test_case(-2_500_000_000, "-2.50 kVAR"; "kilovars")]
+    fn test_display(micro_vars: i64, expected: &str) {
+        let reactive = ReactivePower::from_micro_vars(micro_vars);
+        assert_eq!(format!("{}", reactive), expected);
+    }
+
+    #[test_case("-500VAR", Ok(-500_000_000); "negative")]
+    #[test_case("1.5 kvar", Ok(1_500_000_000); "lowercase kilovars")]
+    #[test_case("250m", Ok(250_000); "no unit")]
+    #[test_case("", Err(ParseError::Empty); "empty")]
+    fn test_from_str(s: &str, expected: Result<i64, ParseError>) {
+        assert_eq!(s.parse::<ReactivePower>().map(|q| q.micro_vars()), expected);
+    }
+}
@@ -1,10 +1,16 @@
-use crate::assert_positive_float;
-use core::{cmp, fmt, ops};
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::eseries::ESeries;
+use crate::math::{f64_to_u64, try_round_f64_to_u64, try_scale_f64_to_u64};
+use crate::parse::{parse_rkm, parse_si_unsigned, ParseError};
+use crate::{Conductance, Ppm, RoundingMode, Temperature, UnitError};
+use core::{cmp, fmt, hash, iter, ops, str};
 
 /// Represents a resistance value, stored as whole milliohms (mΩ) as a 64-bit value.
 /// This value can only be positive.
 ///
-/// **Reminder:** `1000 mΩ = 1 Ω, 1000 Ω = 1 kΩ, 1000 kΩ = 1 MΩ`
+/// **Reminder:** `1000 mΩ = 1 Ω, 1000 Ω = 1 kΩ, 1000 kΩ = 1 MΩ, 1000 MΩ = 1 GΩ, 1000 GΩ = 1 TΩ`
 ///
 /// This is an immutable type. Any math operators return a new `Resistance` value.
 ///
@@ -45,6 +51,7 @@ use core::{cmp, fmt, ops};
 /// The result is a new `Resistance` value, rounded down to the nearest whole milliohm (mΩ).
 ///
 /// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
 ///
 /// ```rust
 /// use ohms::prelude::*;
@@ -64,6 +71,9 @@ use core::{cmp, fmt, ops};
 ///
 /// If the result of the operation would be infinite or NaN, the operation will panic.
 ///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
 /// ```rust
 /// use ohms::prelude::*;
 ///
@@ -75,7 +85,7 @@ use core::{cmp, fmt, ops};
 /// ```
 ///
 /// # Converting to other denominations
-/// You can use the `milli_ohms`, `ohms`, `kilo_ohms` and `mega_ohms` methods to convert a `Resistance`
+/// You can use the `milli_ohms`, `ohms`, `kilo_ohms`, `mega_ohms`, `giga_ohms` and `tera_ohms` methods to convert a `Resistance`
 /// value to a numeric value in the specified denomination.
 ///
 /// ```rust
@@ -86,8 +96,10 @@ use core::{cmp, fmt, ops};
 /// println!("{:.3} kΩ is {:.1} Ω", r1.kilo_ohms(), r1.ohms());
 /// ```
 ///
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Resistance {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_ohms"))]
     raw: u64,
 }
 
@@ -101,6 +113,68 @@ impl Resistance {
         Self { raw: value }
     }
 
+    /// Creates a new `Resistance` from a number of fractional milliohms (mΩ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_ohms(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1f64).map(Self::from_milli_ohms)
+    }
+
+    /// Creates a new `Resistance` from a number of fractional ohms (Ω).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_ohms(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000f64).map(Self::from_milli_ohms)
+    }
+
+    /// Creates a new `Resistance` from a number of fractional kilohms (kΩ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_ohms(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000f64).map(Self::from_milli_ohms)
+    }
+
+    /// Creates a new `Resistance` from a number of fractional megaohms (MΩ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_mega_ohms(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000f64).map(Self::from_milli_ohms)
+    }
+
+    /// Creates a new `Resistance` from a number of fractional gigaohms (GΩ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_giga_ohms(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000_000f64).map(Self::from_milli_ohms)
+    }
+
+    /// Creates a new `Resistance` from a number of fractional teraohms (TΩ).
+    ///
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    #[inline]
+    pub fn try_from_tera_ohms(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000_000_000f64).map(Self::from_milli_ohms)
+    }
+
+    /// Creates a new `Resistance` from a number of fractional units, rounding to the nearest whole
+    /// milliohm (mΩ) in the given direction rather than truncating.
+    ///
+    /// Returns an error if the value is NaN, infinite, rounds to a negative number or is too large
+    /// to be represented.
+    #[inline]
+    pub fn try_from_rounded(
+        value: f64,
+        unit: ResistanceUnit,
+        mode: RoundingMode,
+    ) -> Result<Self, UnitError> {
+        try_round_f64_to_u64(value, unit.scale() as f64, mode).map(Self::from_milli_ohms)
+    }
+
     /// Returns the resistance value in whole milliohms (mΩ).
     #[inline]
     pub const fn milli_ohms(&self) -> u64 {
@@ -125,6 +199,60 @@ impl Resistance {
         self.raw as f64 / 1_000_000_000f64
     }
 
+    /// Returns the resistance value in fractional gigaohms (GΩ).
+    #[inline]
+    pub fn giga_ohms(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_000f64
+    }
+
+    /// Returns the resistance value in fractional teraohms (TΩ).
+    #[inline]
+    pub fn tera_ohms(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_000_000f64
+    }
+
+    /// Returns the resistance value as whole ohms (Ω) and the remaining milliohms (mΩ),
+    /// without any floating-point math.
+    #[inline]
+    pub const fn to_ohms_parts(&self) -> (u64, u32) {
+        (self.raw / 1_000, (self.raw % 1_000) as u32)
+    }
+
+    /// Returns the resistance value as whole kilohms (kΩ) and the remaining milliohms (mΩ),
+    /// without any floating-point math.
+    #[inline]
+    pub const fn to_kilo_ohms_parts(&self) -> (u64, u32) {
+        (self.raw / 1_000_000, (self.raw % 1_000_000) as u32)
+    }
+
+    /// Returns the resistance value as whole megaohms (MΩ) and the remaining milliohms (mΩ),
+    /// without any floating-point math.
+    #[inline]
+    pub const fn to_mega_ohms_parts(&self) -> (u64, u32) {
+        (self.raw / 1_000_000_000, (self.raw % 1_000_000_000) as u32)
+    }
+
+    /// Returns the resistance value as whole gigaohms (GΩ) and the remaining milliohms (mΩ),
+    /// without any floating-point math.
+    ///
+    /// The remainder can exceed `u32::MAX`, so it is returned as a `u64`.
+    #[inline]
+    pub const fn to_giga_ohms_parts(&self) -> (u64, u64) {
+        (self.raw / 1_000_000_000_000, self.raw % 1_000_000_000_000)
+    }
+
+    /// Returns the resistance value as whole teraohms (TΩ) and the remaining milliohms (mΩ),
+    /// without any floating-point math.
+    ///
+    /// The remainder can exceed `u32::MAX`, so it is returned as a `u64`.
+    #[inline]
+    pub const fn to_tera_ohms_parts(&self) -> (u64, u64) {
+        (
+            self.raw / 1_000_000_000_000_000,
+            self.raw % 1_000_000_000_000_000,
+        )
+    }
+
     /// Returns whether the resistance value is zero ohms (0Ω).
     #[inline]
     pub const fn is_zero(&self) -> bool {
@@ -136,8 +264,432 @@ impl Resistance {
     pub const fn zero() -> Self {
         Self::from_milli_ohms(0)
     }
+
+    /// Adds two `Resistance` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_milli_ohms)
+    }
+
+    /// Subtracts one `Resistance` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_milli_ohms)
+    }
+
+    /// Multiplies the `Resistance` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: u64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_milli_ohms)
+    }
+
+    /// Divides the `Resistance` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: u64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_milli_ohms)
+    }
+
+    /// Multiplies the `Resistance` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole milliohm (mΩ).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 * factor).map(Self::from_milli_ohms)
+    }
+
+    /// Divides the `Resistance` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN, negative or the result would overflow.
+    ///
+    /// The result is rounded down to the nearest whole milliohm (mΩ).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(self.raw as f64 / divisor).map(Self::from_milli_ohms)
+    }
+
+    /// Multiplies the `Resistance` value by a floating-point value, rounding the result to the nearest
+    /// whole milliohm (mΩ) in the given direction.
+    ///
+    /// Returns `None` if the factor is infinite, NaN, negative or the result would overflow.
+    #[inline]
+    pub fn checked_mul_f64_rounded(self, factor: f64, mode: RoundingMode) -> Option<Self> {
+        if factor.is_infinite() || factor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(mode.round_f64(self.raw as f64 * factor)).map(Self::from_milli_ohms)
+    }
+
+    /// Divides the `Resistance` value by a floating-point value, rounding the result to the nearest
+    /// whole milliohm (mΩ) in the given direction.
+    ///
+    /// Returns `None` if the divisor is zero, infinite, NaN, negative or the result would overflow.
+    #[inline]
+    pub fn checked_div_f64_rounded(self, divisor: f64, mode: RoundingMode) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() || divisor.is_sign_negative() {
+            return None;
+        }
+
+        f64_to_u64(mode.round_f64(self.raw as f64 / divisor)).map(Self::from_milli_ohms)
+    }
+
+    /// Converts the resistance to its equivalent conductance (`G = 1 / R`).
+    ///
+    /// Will be rounded down to the nearest whole nanosiemens (nS).
+    /// Panics if the resistance is zero, as an infinite conductance would result.
+    pub fn to_conductance(&self) -> Conductance {
+        if self.is_zero() {
+            panic!("Resistance cannot be zero, infinite conductance would result");
+        }
+
+        Conductance::from_nano_siemens(1_000_000_000_000u64 / self.raw)
+    }
+
+    /// Calculates the resistance after a change in temperature, given its temperature coefficient
+    /// of resistance (TCR) in parts per million per degree Celsius (ppm/°C).
+    ///
+    /// The change in temperature is relative to the temperature the resistance was specified at,
+    /// such as `temperature - 25.degrees_celsius()`, and follows `R = R₀ × (1 + α·ΔT)`.
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ), saturating at zero ohms (0Ω).
+    /// Panics if the resistance would overflow.
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let r = 100.ohms().at_temperature(Ppm::new(3_850), 100.degrees_celsius()); // Pt100 at 100°C
+    /// assert_eq!(r, 138.5.ohms());
+    /// ```
+    pub fn at_temperature(&self, tcr: Ppm, delta_t: Temperature) -> Resistance {
+        // ppm/°C × m°C is parts per billion
+        let factor =
+            1_000_000_000i128 + tcr.ppm() as i128 * delta_t.milli_degrees_celsius() as i128;
+
+        if factor <= 0 {
+            return Self::zero();
+        }
+
+        let milli_ohms = (self.raw as i128)
+            .checked_mul(factor)
+            .expect("Resistance would overflow")
+            / 1_000_000_000i128;
+
+        Self::from_milli_ohms(u64::try_from(milli_ohms).expect("Resistance would overflow"))
+    }
+
+    /// Calculates the combined resistance of resistors connected in series.
+    ///
+    /// Returns zero ohms (0Ω) if there are no resistors.
+    /// Panics if the combined resistance would overflow.
+    pub fn series(resistors: &[Resistance]) -> Resistance {
+        resistors
+            .iter()
+            .try_fold(0u64, |sum, resistor| sum.checked_add(resistor.raw))
+            .map(Self::from_milli_ohms)
+            .expect("Overflow when combining resistance values in series")
+    }
+
+    /// Calculates the combined resistance of resistors connected in parallel.
+    ///
+    /// If any of the resistors are zero ohms (0Ω), the combined resistance is also zero ohms,
+    /// as the short circuit dominates the network.
+    ///
+    /// Only integer math is used, with the conductances summed as 128-bit fixed-point values
+    /// relative to the smallest resistor.
+    ///
+    /// Will be rounded to the nearest whole milliohm (mΩ).
+    /// Returns `None` if there are no resistors.
+    pub fn parallel(resistors: &[Resistance]) -> Option<Resistance> {
+        let smallest = resistors.iter().map(|resistor| resistor.raw).min()?;
+
+        if smallest == 0 {
+            return Some(Self::zero());
+        }
+
+        // Each conductance is at most 2⁶⁴ in units of 1 / (smallest × 2⁶⁴)
+        let scale = (smallest as u128) << 64;
+
+        let conductance = resistors
+            .iter()
+            .try_fold(0u128, |sum, resistor| {
+                sum.checked_add(scale / resistor.raw as u128)
+            })
+            .expect("Overflow when combining resistance values in parallel");
+
+        let milli_ohms = RoundingMode::Nearest.div_u128(scale, conductance);
+
+        // The combined resistance is never larger than the smallest resistor
+        Some(Self::from_milli_ohms(milli_ohms as u64))
+    }
+
+    /// Calculates the combined resistance of two resistors connected in parallel.
+    ///
+    /// This is a shorthand for `r1.parallel_with(r2)`, and always returns `Some`.
+    #[inline]
+    pub fn parallel_two(r1: Resistance, r2: Resistance) -> Option<Resistance> {
+        Some(r1.parallel_with(r2))
+    }
+
+    /// Calculates the combined resistance of this resistor connected in parallel with another
+    /// (`R1 × R2 / (R1 + R2)`).
+    ///
+    /// Uses 128-bit intermediate values, so the result is exact before rounding and cannot
+    /// overflow. If either resistor is zero ohms (0Ω), the combined resistance is also zero ohms.
+    ///
+    /// Will be rounded to the nearest whole milliohm (mΩ).
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let r = 220.ohms().parallel_with(330.ohms());
+    /// assert_eq!(r, 132.ohms());
+    /// ```
+    pub fn parallel_with(&self, other: Resistance) -> Resistance {
+        let sum = self.raw as u128 + other.raw as u128;
+
+        if sum == 0 {
+            return Self::zero();
+        }
+
+        let milli_ohms = RoundingMode::Nearest.div_u128(self.raw as u128 * other.raw as u128, sum);
+
+        // The combined resistance is never larger than either resistor
+        Self::from_milli_ohms(milli_ohms as u64)
+    }
+
+    /// Rounds the resistance to a standard value in an E-series of preferred values.
+    ///
+    /// Nearest rounding compares ratios rather than differences, as the series are spaced
+    /// logarithmically, so 11Ω rounds to 12Ω in the E12 series.
+    /// Returns `None` if there is no standard value in the rounding direction.
+    ///
+    /// ```rust
+    /// use ohms::eseries::ESeries;
+    /// use ohms::prelude::*;
+    ///
+    /// let r = 5.kilo_ohms();
+    /// assert_eq!(r.nearest_standard(ESeries::E12, RoundingMode::Up), Some(5.6.kilo_ohms()));
+    /// assert_eq!(r.nearest_standard(ESeries::E12, RoundingMode::Down), Some(4.7.kilo_ohms()));
+    /// ```
+    pub fn nearest_standard(&self, series: ESeries, mode: RoundingMode) -> Option<Resistance> {
+        let milli_ohms = match series.bounds(self.raw) {
+            (lower, upper) if lower == upper => lower,
+            (lower, upper) => match mode {
+                RoundingMode::Up => upper,
+                RoundingMode::Down => lower,
+                RoundingMode::Nearest => match (lower, upper) {
+                    (Some(lower), Some(upper)) => {
+                        let value = self.raw as u128;
+
+                        if value * value < lower as u128 * upper as u128 {
+                            Some(lower)
+                        } else {
+                            Some(upper)
+                        }
+                    }
+                    (lower, upper) => lower.or(upper),
+                },
+            },
+        };
+
+        milli_ohms.map(Self::from_milli_ohms)
+    }
+
+    /// Finds the pair of resistors from the available values that, when combined in parallel,
+    /// best approximates the target resistance.
+    ///
+    /// Each available value may be used twice, as a component library will usually stock more
+    /// than one of each. If multiple pairs are equally close, the first one found is returned.
+    /// Returns `None` if there are no available values.
+    pub fn best_parallel_match(
+        target: Resistance,
+        available: &[Resistance],
+    ) -> Option<(Resistance, Resistance)> {
+        let mut best: Option<(Resistance, Resistance, u64)> = None;
+
+        for (index, &r1) in available.iter().enumerate() {
+            for &r2 in &available[index..] {
+                let error = r1.parallel_with(r2).raw.abs_diff(target.raw);
+
+                match best {
+                    Some((_, _, best_error)) if best_error <= error => {}
+                    _ => best = Some((r1, r2, error)),
+                }
+            }
+        }
+
+        best.map(|(r1, r2, _)| (r1, r2))
+    }
+
+    /// Parses a `Resistance` value from a string in compact EIA notation, as commonly found in
+    /// spreadsheets and bills of materials.
+    ///
+    /// Anything accepted by `FromStr` is parsed as such, such as `4.7k`, `470` and `2.2kΩ`.
+    /// Otherwise the value is parsed as RKM notation by `from_rkm`, with an optional `Ω` suffix,
+    /// such as `4k7` (4.7kΩ), `10R` (10Ω), `0R1` (0.1Ω) or `1M5Ω` (1.5MΩ).
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let r = Resistance::from_spreadsheet_ohm_notation("4k7").unwrap();
+    /// assert_eq!(r, 4.7.kilo_ohms());
+    /// ```
+    pub fn from_spreadsheet_ohm_notation(s: &str) -> Result<Resistance, ParseError> {
+        s.parse().or_else(|error| {
+            let s = s.trim();
+            let s = s.strip_suffix('Ω').unwrap_or(s).trim_end();
+
+            // Plain numbers are not RKM notation, so keep the reason they failed to parse
+            match Self::from_rkm(s) {
+                Err(_) if s.chars().all(|c| c.is_ascii_digit()) => Err(error),
+                result => result,
+            }
+        })
+    }
+
+    /// Parses a `Resistance` value from RKM notation (IEC 60062), where the multiplier letter takes
+    /// the place of the decimal point, such as `4k7` (4.7kΩ), `2M2` (2.2MΩ), `0R1` (0.1Ω) or
+    /// `R47` (0.47Ω).
+    ///
+    /// The accepted multipliers are `L` or `m` (mΩ), `R` (Ω), `k` or `K` (kΩ), `M` (MΩ), `G` (GΩ)
+    /// and `T` (TΩ). Unlike `from_spreadsheet_ohm_notation`, a multiplier is required and decimal
+    /// points or unit symbols are rejected.
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let r = Resistance::from_rkm("2M2").unwrap();
+    /// assert_eq!(r, 2.2.mega_ohms());
+    /// ```
+    pub fn from_rkm(s: &str) -> Result<Resistance, ParseError> {
+        let multiplier = |marker| match marker {
+            'L' | 'm' => Some(0),
+            'R' => Some(3),
+            'k' | 'K' => Some(6),
+            'M' => Some(9),
+            'G' => Some(12),
+            'T' => Some(15),
+            _ => None,
+        };
+
+        parse_rkm(s, multiplier).map(Self::from_milli_ohms)
+    }
+
+    /// Formats the resistance value into a byte buffer in RKM notation, such as `4k7`, `2M2` or
+    /// `0R1`, returning the written string.
+    ///
+    /// The largest multiplier that keeps a non-zero whole part is used, except that values below
+    /// one ohm (1Ω) are written with `R`. The value is never rounded. Returns an error if the
+    /// buffer is too small to hold the formatted value.
+    pub fn to_rkm<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+        let (scale, marker) = match self.raw {
+            0..=999_999 => (1_000, 'R'),
+            1_000_000..=999_999_999 => (1_000_000, 'k'),
+            1_000_000_000..=999_999_999_999 => (1_000_000_000, 'M'),
+            1_000_000_000_000..=999_999_999_999_999 => (1_000_000_000_000, 'G'),
+            _ => (1_000_000_000_000_000, 'T'),
+        };
+
+        display::format_rkm(buf, false, self.raw, scale, marker)
+    }
+
+    /// Returns the unit symbol used when displaying the resistance value, such as `"Ω"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw {
+            0..=999 => (1, "mΩ"),
+            1_000..=999_999 => (1_000, "Ω"),
+            1_000_000..=999_999_999 => (1_000_000, "kΩ"),
+            1_000_000_000..=999_999_999_999 => (1_000_000_000, "MΩ"),
+            1_000_000_000_000..=999_999_999_999_999 => (1_000_000_000_000, "GΩ"),
+            _ => (1_000_000_000_000_000, "TΩ"),
+        }
+    }
+
+    /// Formats the resistance value into a byte buffer in the given unit, rounded to `precision`
+    /// decimal places, returning the written string, such as `"4.70 kΩ"`.
+    ///
+    /// No floating-point math or heap allocation is used, so this is suitable for rendering values
+    /// to displays on targets without a floating-point unit. Returns an error if the buffer is too
+    /// small to hold the formatted value.
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        unit: ResistanceUnit,
+        precision: usize,
+    ) -> Result<&'a str, fmt::Error> {
+        display::format_into(buf, false, self.raw, unit.scale(), precision, unit.symbol())
+    }
+}
+
+/// The units a `Resistance` value can be formatted in, used by [`Resistance::format_into`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ResistanceUnit {
+    /// Milliohms (mΩ).
+    MilliOhms,
+    /// Ohms (Ω).
+    Ohms,
+    /// Kilohms (kΩ).
+    KiloOhms,
+    /// Megohms (MΩ).
+    MegaOhms,
+    /// Gigaohms (GΩ).
+    GigaOhms,
+    /// Teraohms (TΩ).
+    TeraOhms,
+}
+
+impl ResistanceUnit {
+    /// Returns the number of base units in one of this unit.
+    const fn scale(self) -> u64 {
+        match self {
+            ResistanceUnit::MilliOhms => 1,
+            ResistanceUnit::Ohms => 1_000,
+            ResistanceUnit::KiloOhms => 1_000_000,
+            ResistanceUnit::MegaOhms => 1_000_000_000,
+            ResistanceUnit::GigaOhms => 1_000_000_000_000,
+            ResistanceUnit::TeraOhms => 1_000_000_000_000_000,
+        }
+    }
+
+    /// Returns the symbol of this unit, such as `"kΩ"`.
+    #[inline]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            ResistanceUnit::MilliOhms => "mΩ",
+            ResistanceUnit::Ohms => "Ω",
+            ResistanceUnit::KiloOhms => "kΩ",
+            ResistanceUnit::MegaOhms => "MΩ",
+            ResistanceUnit::GigaOhms => "GΩ",
+            ResistanceUnit::TeraOhms => "TΩ",
+        }
+    }
 }
 
+approx::impl_approx_eq!(Resistance, unsigned);
+compare::impl_const_compare!(Resistance, unsigned);
+
 impl PartialEq for Resistance {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -150,7 +702,7 @@ impl Eq for Resistance {}
 impl PartialOrd for Resistance {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Some(self.cmp(other))
     }
 }
 
@@ -161,6 +713,13 @@ impl Ord for Resistance {
     }
 }
 
+impl hash::Hash for Resistance {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
 impl ops::Add for Resistance {
     type Output = Self;
 
@@ -187,6 +746,24 @@ impl ops::Sub for Resistance {
     }
 }
 
+impl iter::Sum for Resistance {
+    /// Sums the `Resistance` values, returning zero (0Ω) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a Resistance> for Resistance {
+    /// Sums the `Resistance` values, returning zero (0Ω) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 macro_rules! impl_mul_for_integer {
     ($i:ty) => {
         impl ops::Mul<$i> for Resistance {
@@ -325,51 +902,35 @@ pub trait FromInteger {
 
     /// Creates a new `Resistance` from a number of whole megaohms (MΩ).
     fn mega_ohms(self) -> Resistance;
-}
 
-macro_rules! impl_resistance_from_integer {
-    ($i:ty) => {
-        impl FromInteger for $i {
-            #[inline]
-            fn milli_ohms(self) -> Resistance {
-                Resistance::from_milli_ohms(self as u64)
-            }
-
-            #[inline]
-            fn ohms(self) -> Resistance {
-                let milliohms = (self as u64)
-                    .checked_mul(1_000)
-                    .expect("Overflow when converting ohms to milliohms");
-                Resistance::from_milli_ohms(milliohms)
-            }
+    /// Creates a new `Resistance` from a number of whole gigaohms (GΩ).
+    fn giga_ohms(self) -> Resistance;
 
-            #[inline]
-            fn kilo_ohms(self) -> Resistance {
-                let milliohms = (self as u64)
-                    .checked_mul(1_000_000)
-                    .expect("Overflow when converting kilohms to milliohms");
-                Resistance::from_milli_ohms(milliohms)
-            }
-
-            #[inline]
-            fn mega_ohms(self) -> Resistance {
-                let milliohms = (self as u64)
-                    .checked_mul(1_000_000_000)
-                    .expect("Overflow when converting megaohms to milliohms");
-                Resistance::from_milli_ohms(milliohms)
-            }
-        }
-    };
+    /// Creates a new `Resistance` from a number of whole teraohms (TΩ).
+    fn tera_ohms(self) -> Resistance;
 }
 
-impl_resistance_from_integer!(u8);
-impl_resistance_from_integer!(u16);
-impl_resistance_from_integer!(u32);
-impl_resistance_from_integer!(u64);
-impl_resistance_from_integer!(i8);
-impl_resistance_from_integer!(i16);
-impl_resistance_from_integer!(i32);
-impl_resistance_from_integer!(i64);
+crate::__define_unit_from_integer!(
+    Resistance,
+    FromInteger,
+    from_milli_ohms,
+    milli_ohms,
+    [
+        ohms = 1_000,
+        kilo_ohms = 1_000_000,
+        mega_ohms = 1_000_000_000,
+        giga_ohms = 1_000_000_000_000,
+        tera_ohms = 1_000_000_000_000_000
+    ],
+    u8,
+    u16,
+    u32,
+    u64,
+    i8,
+    i16,
+    i32,
+    i64
+);
 
 /// Extension trait for simple short-hands for creating `Resistance` values from floating-point values.
 pub trait FromFloat {
@@ -384,53 +945,687 @@ pub trait FromFloat {
 
     /// Creates a new `Resistance` from a fractional number of megaohms (MΩ).
     fn mega_ohms(self) -> Resistance;
+
+    /// Creates a new `Resistance` from a fractional number of gigaohms (GΩ).
+    fn giga_ohms(self) -> Resistance;
+
+    /// Creates a new `Resistance` from a fractional number of teraohms (TΩ).
+    fn tera_ohms(self) -> Resistance;
 }
 
-macro_rules! impl_resistance_from_float {
-    ($f:ty) => {
-        impl FromFloat for $f {
-            #[inline]
-            fn milli_ohms(self) -> Resistance {
-                assert_positive_float!(self);
-                Resistance::from_milli_ohms(self as u64)
-            }
+crate::__define_unit_from_float!(
+    Resistance,
+    FromFloat,
+    from_milli_ohms,
+    milli_ohms,
+    [
+        ohms = 1_000,
+        kilo_ohms = 1_000_000,
+        mega_ohms = 1_000_000_000,
+        giga_ohms = 1_000_000_000_000,
+        tera_ohms = 1_000_000_000_000_000
+    ],
+    f32,
+    f64
+);
 
-            #[inline]
-            fn ohms(self) -> Resistance {
-                assert_positive_float!(self);
-                let milliohms = (self as f64) * 1_000f64;
-                Resistance::from_milli_ohms(milliohms as u64)
-            }
+crate::__define_unit_display!(Resistance);
 
-            #[inline]
-            fn kilo_ohms(self) -> Resistance {
-                assert_positive_float!(self);
-                let milliohms = (self as f64) * 1_000_000f64;
-                Resistance::from_milli_ohms(milliohms as u64)
+impl str::FromStr for Resistance {
+    type Err = ParseError;
+
+    /// Parses a `Resistance` value from a string with an optional SI prefix and unit symbol, such as
+    /// `4.7k`, `330 Ω` or `2.2MΩ`.
+    ///
+    /// Will be rounded down to the nearest whole milliohm (mΩ).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_unsigned(s, &["Ω"], 3).map(Self::from_milli_ohms)
+    }
+}
+
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+impl Resistance {
+    /// Splits the resistance value into its whole and hundredths parts in the most appropriate unit,
+    /// for formatting without floating-point math.
+    fn scaled_parts(&self) -> (u64, u64, &'static str) {
+        let (scale, unit) = self.display_scale();
+
+        let whole = self.raw / scale;
+        let fraction = self.raw % scale * 100 / scale;
+
+        (whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Resistance {
+    fn format(&self, f: defmt::Formatter) {
+        let (whole, fraction, unit) = self.scaled_parts();
+
+        defmt::write!(f, "{=u64}.{=u64:02} {=str}", whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Resistance {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let (whole, fraction, unit) = self.scaled_parts();
+        let padding = if fraction < 10 { "0" } else { "" };
+
+        ufmt::uwrite!(f, "{}.{}{} {}", whole, padding, fraction, unit)
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(4_700_000, (4_700, 0), (4, 700_000); "kilohms")]
+    #[test_case(470_500, (470, 500), (0, 470_500); "ohms")]
+    #[test_case(u64::MAX, (18_446_744_073_709_551, 615), (18_446_744_073_709, 551_615); "maximum")]
+    fn test_to_parts(milli_ohms: u64, ohms: (u64, u32), kilo_ohms: (u64, u32)) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(resistance.to_ohms_parts(), ohms);
+        assert_eq!(resistance.to_kilo_ohms_parts(), kilo_ohms);
+    }
+
+    #[test_case(4_700_000_000_000, (4, 700_000_000_000), (0, 4_700_000_000_000); "gigaohms")]
+    #[test_case(u64::MAX, (18_446_744, 73_709_551_615), (18_446, 744_073_709_551_615); "maximum")]
+    fn test_to_large_parts(milli_ohms: u64, giga_ohms: (u64, u64), tera_ohms: (u64, u64)) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(resistance.to_giga_ohms_parts(), giga_ohms);
+        assert_eq!(resistance.to_tera_ohms_parts(), tera_ohms);
+    }
+
+    #[test]
+    fn test_giga_and_tera_ohms() {
+        assert_eq!(
+            4.giga_ohms(),
+            Resistance::from_milli_ohms(4_000_000_000_000)
+        );
+        assert_eq!(
+            1.5.tera_ohms(),
+            Resistance::from_milli_ohms(1_500_000_000_000_000)
+        );
+        assert_eq!(Resistance::try_from_giga_ohms(2.5), Ok(2_500.mega_ohms()));
+        assert_eq!(
+            Resistance::try_from_tera_ohms(20_000.0),
+            Err(UnitError::Overflow)
+        );
+        assert_eq!(2.tera_ohms().giga_ohms(), 2_000.0);
+        assert_eq!(500.giga_ohms().tera_ohms(), 0.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tera_ohms_overflow_panics() {
+        let _ = 20_000.tera_ohms();
+    }
+
+    const E6: [u64; 6] = [10_000, 15_000, 22_000, 33_000, 47_000, 68_000];
+
+    #[test_case("4k7", Ok(4_700_000); "4k7 equals 4.7kΩ")]
+    #[test_case("2k2", Ok(2_200_000); "2k2 equals 2.2kΩ")]
+    #[test_case("10R", Ok(10_000); "10R equals 10Ω")]
+    #[test_case("0R1", Ok(100); "0R1 equals 0.1Ω")]
+    #[test_case("R47", Ok(470); "R47 equals 0.47Ω")]
+    #[test_case("1M", Ok(1_000_000_000); "1M equals 1MΩ")]
+    #[test_case("1M5", Ok(1_500_000_000); "1M5 equals 1.5MΩ")]
+    #[test_case("1G", Ok(1_000_000_000_000); "1G equals 1GΩ")]
+    #[test_case("500m", Ok(500); "500m equals 500mΩ")]
+    #[test_case("470", Ok(470_000); "470 equals 470Ω")]
+    #[test_case("4.7k", Ok(4_700_000); "4.7k equals 4.7kΩ")]
+    #[test_case(" 2.2 kΩ ", Ok(2_200_000); "whitespace and ohm symbol are ignored")]
+    #[test_case("10Ω", Ok(10_000); "ohm symbol without multiplier")]
+    #[test_case("1R2345", Ok(1_234); "extra digits are rounded down")]
+    #[test_case("", Err(ParseError::Empty); "empty string")]
+    #[test_case("Ω", Err(ParseError::Empty); "ohm symbol only")]
+    #[test_case("4x7", Err(ParseError::InvalidUnit); "unknown multiplier")]
+    #[test_case("4.7k5", Err(ParseError::InvalidUnit); "decimal point and multiplier digits")]
+    #[test_case("1..2", Err(ParseError::InvalidUnit); "two decimal points")]
+    #[test_case("k", Err(ParseError::InvalidNumber); "multiplier without digits")]
+    #[test_case("-4k7", Err(ParseError::InvalidUnit); "negative value")]
+    #[test_case("99999999999G", Err(ParseError::Overflow); "overflow")]
+    #[test_case("99999999999999999999", Err(ParseError::Overflow); "plain number overflow")]
+    #[test_case("1T5", Ok(1_500_000_000_000_000); "1T5 equals 1.5TΩ")]
+    #[test_case("470L", Ok(470); "470L equals 470mΩ")]
+    fn test_from_spreadsheet_ohm_notation(s: &str, expected: Result<u64, ParseError>) {
+        let resistance = Resistance::from_spreadsheet_ohm_notation(s);
+
+        assert_eq!(resistance.map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test_case("4k7", Ok(4_700_000); "4k7 equals 4.7kΩ")]
+    #[test_case("2M2", Ok(2_200_000_000); "2M2 equals 2.2MΩ")]
+    #[test_case("0R1", Ok(100); "0R1 equals 0.1Ω")]
+    #[test_case("R47", Ok(470); "R47 equals 0.47Ω")]
+    #[test_case("10K", Ok(10_000_000); "uppercase kilo multiplier")]
+    #[test_case("470L", Ok(470); "470L equals 470mΩ")]
+    #[test_case("1T", Ok(1_000_000_000_000_000); "1T equals 1TΩ")]
+    #[test_case("470", Err(ParseError::InvalidUnit); "missing multiplier")]
+    #[test_case("4.7k", Err(ParseError::InvalidUnit); "decimal point")]
+    #[test_case("1..2", Err(ParseError::InvalidUnit); "two decimal points")]
+    #[test_case("4k7Ω", Err(ParseError::InvalidNumber); "ohm symbol")]
+    fn test_from_rkm(s: &str, expected: Result<u64, ParseError>) {
+        let resistance = Resistance::from_rkm(s);
+        assert_eq!(resistance.map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test_case(4_700_000, "4k7"; "kilohms")]
+    #[test_case(2_200_000_000, "2M2"; "megohms")]
+    #[test_case(100, "0R1"; "below one ohm")]
+    #[test_case(10_000, "10R"; "whole ohms")]
+    #[test_case(470_000, "470R"; "hundreds of ohms")]
+    #[test_case(4_700_100, "4k7001"; "value is not rounded")]
+    #[test_case(1_000_000_000_000, "1G"; "gigohms")]
+    #[test_case(0, "0R"; "zero")]
+    fn test_to_rkm(milli_ohms: u64, expected: &str) {
+        let mut buf = [0u8; 32];
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(resistance.to_rkm(&mut buf), Ok(expected));
+    }
+
+    #[test_case("4k7"; "kilohms")]
+    #[test_case("0R47"; "below one ohm")]
+    #[test_case("1M5"; "megohms")]
+    fn test_rkm_round_trip(s: &str) {
+        let mut buf = [0u8; 32];
+        let resistance = Resistance::from_rkm(s).unwrap();
+        assert_eq!(resistance.to_rkm(&mut buf), Ok(s));
+    }
+    #[test_case(5_000_000, RoundingMode::Nearest, Some(4_700_000); "nearest rounds down")]
+    #[test_case(5_200_000, RoundingMode::Nearest, Some(5_600_000); "nearest rounds up")]
+    #[test_case(11_000, RoundingMode::Nearest, Some(12_000); "nearest compares ratios")]
+    #[test_case(5_000_000, RoundingMode::Up, Some(5_600_000); "rounds up")]
+    #[test_case(5_000_000, RoundingMode::Down, Some(4_700_000); "rounds down")]
+    #[test_case(9_000_000, RoundingMode::Up, Some(10_000_000); "rounds up into next decade")]
+    #[test_case(4_700_000, RoundingMode::Up, Some(4_700_000); "standard value is unchanged")]
+    #[test_case(0, RoundingMode::Down, None; "nothing below smallest value")]
+    #[test_case(0, RoundingMode::Nearest, Some(100); "nearest below smallest value")]
+    #[test_case(u64::MAX, RoundingMode::Up, None; "nothing above largest value")]
+    fn test_nearest_standard(milli_ohms: u64, mode: RoundingMode, expected: Option<u64>) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        let result = resistance.nearest_standard(ESeries::E12, mode);
+
+        assert_eq!(result.map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test_case(1_000_000, 1_000_000; "1kΩ equals 1mS")]
+    #[test_case(1_000, 1_000_000_000; "1Ω equals 1S")]
+    #[test_case(3_000_000, 333_333; "rounds down to nearest nanosiemens")]
+    fn test_to_conductance(milli_ohms: u64, expected_nano_siemens: u64) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(
+            resistance.to_conductance().nano_siemens(),
+            expected_nano_siemens
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_conductance_zero_resistance_panics() {
+        let _ = Resistance::zero().to_conductance();
+    }
+
+    #[test_case(100_000, 3_850, 100_000, 138_500; "Pt100 at 100°C")]
+    #[test_case(100_000, 3_850, -25_000, 90_375; "Pt100 at 0°C from 25°C")]
+    #[test_case(1_000_000, -200, 50_000, 990_000; "negative coefficient")]
+    #[test_case(1_000_000, 100, 0, 1_000_000; "no change in temperature")]
+    #[test_case(1_000_000, 1_000_000, -2_000, 0; "saturates at zero")]
+    #[test_case(u64::MAX, 0, 1_000_000, u64::MAX; "zero coefficient at maximum")]
+    fn test_at_temperature(milli_ohms: u64, tcr: i32, delta_t: i64, expected_milli_ohms: u64) {
+        let r = Resistance::from_milli_ohms(milli_ohms).at_temperature(
+            Ppm::new(tcr),
+            Temperature::from_milli_degrees_celsius(delta_t),
+        );
+
+        assert_eq!(r.milli_ohms(), expected_milli_ohms);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_at_temperature_overflow_panics() {
+        Resistance::from_milli_ohms(u64::MAX).at_temperature(
+            Ppm::new(100),
+            Temperature::from_milli_degrees_celsius(10_000),
+        );
+    }
+
+    #[test_case([], 0; "no resistors equals 0Ω")]
+    #[test_case([4_700], 4_700; "single resistor equals itself")]
+    #[test_case([1_000, 2_000, 3_000], 6_000; "sums resistances")]
+    #[test_case([u64::MAX - 1, 1], u64::MAX; "sums to maximum")]
+    fn test_series<const N: usize>(milli_ohms: [u64; N], expected_milli_ohms: u64) {
+        let resistors = milli_ohms.map(Resistance::from_milli_ohms);
+
+        let resistance = Resistance::series(&resistors);
+
+        assert_eq!(resistance.milli_ohms(), expected_milli_ohms);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_series_overflow_panics() {
+        Resistance::series(&[Resistance::from_milli_ohms(u64::MAX), 1.milli_ohms()]);
+    }
+
+    #[test]
+    fn test_parallel_empty_is_none() {
+        assert_eq!(Resistance::parallel(&[]), None);
+    }
+
+    #[test_case([4_700], 4_700; "single resistor equals itself")]
+    #[test_case([10_000, 10_000, 10_000], 3_333; "three equal resistors equals one third")]
+    #[test_case([10_000, 10_000, 10_000, 10_000], 2_500; "four equal resistors equals one quarter")]
+    #[test_case([10_000, 0, 10_000], 0; "short circuit equals 0Ω")]
+    #[test_case([1_000_000, 2_000_000, 2_000_000], 500_000; "1kΩ, 2kΩ, 2kΩ equals 500Ω")]
+    fn test_parallel<const N: usize>(milli_ohms: [u64; N], expected_milli_ohms: u64) {
+        let resistors = milli_ohms.map(Resistance::from_milli_ohms);
+
+        let resistance = Resistance::parallel(&resistors).unwrap();
+
+        assert_eq!(resistance.milli_ohms(), expected_milli_ohms);
+    }
+
+    #[test]
+    fn test_parallel_near_maximum() {
+        let r = Resistance::from_milli_ohms(u64::MAX);
+        let resistance = Resistance::parallel(&[r, r]).unwrap();
+
+        let expected = u64::MAX / 2;
+        assert!(resistance.milli_ohms().abs_diff(expected) <= expected / 1_000_000_000_000);
+    }
+
+    #[test_case([1_000, 2_000, 4_000, 8_000, 8_000], 500; "mixed values equal 500mΩ")]
+    #[test_case([3, 3, 3, 3, 3, 3, 3, 3, 3], 0; "rounds down to nearest milliohm")]
+    #[test_case([3, 3, 3, 3, 3, 3], 1; "rounds half up to nearest milliohm")]
+    #[test_case([u64::MAX, 1], 1; "maximum and minimum")]
+    fn test_parallel_integer_math<const N: usize>(milli_ohms: [u64; N], expected_milli_ohms: u64) {
+        let resistors = milli_ohms.map(Resistance::from_milli_ohms);
+
+        let resistance = Resistance::parallel(&resistors).unwrap();
+
+        assert_eq!(resistance.milli_ohms(), expected_milli_ohms);
+    }
+
+    #[test_case(100_000, 100_000, 50_000; "100Ω, 100Ω equals 50Ω")]
+    #[test_case(220_000, 330_000, 132_000; "220Ω, 330Ω equals 132Ω")]
+    #[test_case(10_000, 20_000, 6_667; "rounds to nearest milliohm")]
+    #[test_case(0, 10_000, 0; "short circuit equals 0Ω")]
+    #[test_case(0, 0, 0; "both zero equals 0Ω")]
+    #[test_case(u64::MAX, u64::MAX, 1 << 63; "maximum does not overflow")]
+    fn test_parallel_with(r1_milli_ohms: u64, r2_milli_ohms: u64, expected_milli_ohms: u64) {
+        let r1 = Resistance::from_milli_ohms(r1_milli_ohms);
+        let r2 = Resistance::from_milli_ohms(r2_milli_ohms);
+
+        assert_eq!(r1.parallel_with(r2).milli_ohms(), expected_milli_ohms);
+        assert_eq!(r2.parallel_with(r1).milli_ohms(), expected_milli_ohms);
+    }
+
+    #[test_case(100_000, 100_000; "100Ω, 100Ω")]
+    #[test_case(220_000, 330_000; "220Ω, 330Ω")]
+    #[test_case(1_000_000, 4_700_000; "1kΩ, 4.7kΩ")]
+    #[test_case(10_000, 1_000_000_000; "10Ω, 1MΩ")]
+    fn test_parallel_two_equals_product_over_sum(r1_milli_ohms: u64, r2_milli_ohms: u64) {
+        let r1 = Resistance::from_milli_ohms(r1_milli_ohms);
+        let r2 = Resistance::from_milli_ohms(r2_milli_ohms);
+
+        let product = r1_milli_ohms as f64 * r2_milli_ohms as f64;
+        let sum = r1_milli_ohms as f64 + r2_milli_ohms as f64;
+        let expected = (product / sum + 0.5) as u64;
+
+        assert_eq!(
+            Resistance::parallel_two(r1, r2).unwrap().milli_ohms(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_best_parallel_match_empty_is_none() {
+        assert_eq!(Resistance::best_parallel_match(50.ohms(), &[]), None);
+    }
+
+    #[test_case(5_000, 10_000, 10_000; "5Ω equals 10Ω and 10Ω")]
+    #[test_case(6_000, 10_000, 15_000; "6Ω equals 10Ω and 15Ω")]
+    #[test_case(13_200, 22_000, 33_000; "13.2Ω equals 22Ω and 33Ω")]
+    #[test_case(100_000, 68_000, 68_000; "100Ω falls back to largest pair")]
+    #[test_case(1_000, 10_000, 10_000; "1Ω falls back to smallest pair")]
+    fn test_best_parallel_match(
+        target_milli_ohms: u64,
+        expected_r1_milli_ohms: u64,
+        expected_r2_milli_ohms: u64,
+    ) {
+        let available = E6.map(Resistance::from_milli_ohms);
+        let target = Resistance::from_milli_ohms(target_milli_ohms);
+
+        let (r1, r2) = Resistance::best_parallel_match(target, &available).unwrap();
+
+        assert_eq!(r1.milli_ohms(), expected_r1_milli_ohms);
+        assert_eq!(r2.milli_ohms(), expected_r2_milli_ohms);
+    }
+
+    #[test]
+    fn test_best_parallel_match_rounds_to_nearest() {
+        // 5mΩ || 5mΩ is 2.5mΩ and 5mΩ || 4mΩ is 2.22mΩ, so only the second rounds to the target
+        let available = [
+            Resistance::from_milli_ohms(5),
+            Resistance::from_milli_ohms(4),
+        ];
+        let target = Resistance::from_milli_ohms(2);
+
+        let (r1, r2) = Resistance::best_parallel_match(target, &available).unwrap();
+
+        assert_eq!((r1.milli_ohms(), r2.milli_ohms()), (5, 4));
+        assert_eq!(r1.parallel_with(r2), target);
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [
+            Resistance::from_milli_ohms(1),
+            Resistance::from_milli_ohms(2),
+            Resistance::from_milli_ohms(3),
+        ];
+
+        assert_eq!(values.iter().sum::<Resistance>().milli_ohms(), 6);
+        assert_eq!(values.into_iter().sum::<Resistance>().milli_ohms(), 6);
+        assert_eq!(
+            core::iter::empty::<Resistance>().sum::<Resistance>(),
+            Resistance::zero()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sum_overflow_panics() {
+        let _: Resistance = [
+            Resistance::from_milli_ohms(u64::MAX),
+            Resistance::from_milli_ohms(1),
+        ]
+        .iter()
+        .sum();
+    }
+
+    #[test_case(1, 2, Some(3); "adds values")]
+    #[test_case(u64::MAX, 1, None; "overflow is none")]
+    fn test_checked_add(a: u64, b: u64, expected: Option<u64>) {
+        let result = Resistance::from_milli_ohms(a).checked_add(Resistance::from_milli_ohms(b));
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(3, 2, Some(1); "subtracts values")]
+    #[test_case(2, 3, None; "underflow is none")]
+    fn test_checked_sub(a: u64, b: u64, expected: Option<u64>) {
+        let result = Resistance::from_milli_ohms(a).checked_sub(Resistance::from_milli_ohms(b));
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test]
+    fn test_default_is_zero() {
+        assert_eq!(Resistance::default(), Resistance::zero());
+    }
+
+    #[test]
+    fn test_hash_matches_raw_value() {
+        use core::hash::{Hash, Hasher};
+
+        #[derive(Default)]
+        struct RecordingHasher(u64);
+
+        impl Hasher for RecordingHasher {
+            fn finish(&self) -> u64 {
+                self.0
             }
 
-            #[inline]
-            fn mega_ohms(self) -> Resistance {
-                assert_positive_float!(self);
-                let milliohms = (self as f64) * 1_000_000_000f64;
-                Resistance::from_milli_ohms(milliohms as u64)
+            fn write(&mut self, bytes: &[u8]) {
+                for &byte in bytes {
+                    self.0 = self.0.rotate_left(8) ^ byte as u64;
+                }
             }
         }
-    };
-}
 
-impl_resistance_from_float!(f32);
-impl_resistance_from_float!(f64);
-
-impl fmt::Display for Resistance {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let (value, unit) = match self.raw {
-            0..=999 => (self.raw as f64, "mΩ"),
-            1_000..=999_999 => ((self.raw as f64) / 1_000f64, "Ω"),
-            1_000_000..=999_999_999 => ((self.raw as f64) / 1_000_000f64, "kΩ"),
-            _ => ((self.raw as f64) / 1_000_000_000f64, "MΩ"),
+        let hash = |value: &dyn Fn(&mut RecordingHasher)| {
+            let mut hasher = RecordingHasher::default();
+            value(&mut hasher);
+            hasher.finish()
         };
 
-        write!(f, "{value:.2} {unit}")
+        let r = Resistance::from_milli_ohms(4_700_000);
+        assert_eq!(hash(&|h| r.hash(h)), hash(&|h| 4_700_000u64.hash(h)));
+    }
+
+    #[test_case(1_000, 3_000, 2_000; "smaller minus larger")]
+    #[test_case(3_000, 1_000, 2_000; "larger minus smaller")]
+    #[test_case(0, u64::MAX, u64::MAX; "maximum")]
+    fn test_abs_diff(a: u64, b: u64, expected: u64) {
+        let a = Resistance::from_milli_ohms(a);
+        let b = Resistance::from_milli_ohms(b);
+
+        assert_eq!(a.abs_diff(b).milli_ohms(), expected);
+    }
+
+    #[test_case(1_000, 2_000, 1_000, 2_000; "ordered")]
+    #[test_case(2_000, 1_000, 1_000, 2_000; "reversed")]
+    #[test_case(1_000, 1_000, 1_000, 1_000; "equal")]
+    fn test_min_max(a: u64, b: u64, expected_min: u64, expected_max: u64) {
+        let a = Resistance::from_milli_ohms(a);
+        let b = Resistance::from_milli_ohms(b);
+
+        assert_eq!(a.min(b).milli_ohms(), expected_min);
+        assert_eq!(a.max(b).milli_ohms(), expected_max);
+    }
+
+    #[test_case(1_000, 1_005, 5, true; "within tolerance")]
+    #[test_case(1_005, 1_000, 5, true; "within tolerance in either order")]
+    #[test_case(1_000, 1_006, 5, false; "outside tolerance")]
+    #[test_case(0, u64::MAX, u64::MAX, true; "maximum tolerance")]
+    fn test_approx_eq(a: u64, b: u64, tolerance: u64, expected: bool) {
+        let a = Resistance::from_milli_ohms(a);
+        let b = Resistance::from_milli_ohms(b);
+
+        assert_eq!(
+            a.approx_eq(b, Resistance::from_milli_ohms(tolerance)),
+            expected
+        );
+    }
+
+    #[test_case(1_000_000, 1_010_000, 1.0, true; "within 1%")]
+    #[test_case(1_000_000, 1_020_000, 1.0, false; "outside 1%")]
+    #[test_case(1_000, 1_000, 0.0, true; "equal with no tolerance")]
+    #[test_case(1_000, 1_001, -1.0, false; "negative percentage")]
+    #[test_case(1_000, 1_001, f64::NAN, false; "NaN percentage")]
+    fn test_approx_eq_rel(a: u64, b: u64, percent: f64, expected: bool) {
+        let a = Resistance::from_milli_ohms(a);
+        let b = Resistance::from_milli_ohms(b);
+
+        assert_eq!(a.approx_eq_rel(b, percent), expected);
+    }
+
+    #[test_case(3, 2, Some(6); "multiplies values")]
+    #[test_case(u64::MAX, 2, None; "overflow is none")]
+    fn test_checked_mul_int(value: u64, factor: u64, expected: Option<u64>) {
+        let result = Resistance::from_milli_ohms(value).checked_mul_int(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(7, 2, Some(3); "divides and rounds down")]
+    #[test_case(1, 0, None; "division by zero is none")]
+    fn test_checked_div_int(value: u64, divisor: u64, expected: Option<u64>) {
+        let result = Resistance::from_milli_ohms(value).checked_div_int(divisor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 1.5, Some(1_500); "multiplies by fractional factor")]
+    #[test_case(1_000, -1.0, None; "negative factor is none")]
+    #[test_case(0, -2.0, None; "zero times negative factor is none")]
+    #[test_case(1_000, -0.0, None; "negative zero factor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    #[test_case(u64::MAX, 2.0, None; "overflow is none")]
+    fn test_checked_mul_f64(value: u64, factor: f64, expected: Option<u64>) {
+        let result = Resistance::from_milli_ohms(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, 4.0, Some(250); "divides by fractional divisor")]
+    #[test_case(1_000, 0.0, None; "division by zero is none")]
+    #[test_case(1_000, -4.0, None; "negative divisor is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    #[test_case(u64::MAX, 0.5, None; "overflow is none")]
+    fn test_checked_div_f64(value: u64, divisor: f64, expected: Option<u64>) {
+        let result = Resistance::from_milli_ohms(value).checked_div_f64(divisor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(5, 0.5, RoundingMode::Nearest, Some(3); "nearest rounds half up")]
+    #[test_case(5, 0.5, RoundingMode::Up, Some(3); "rounds up")]
+    #[test_case(5, 0.5, RoundingMode::Down, Some(2); "rounds down")]
+    #[test_case(u64::MAX, 2.0, RoundingMode::Up, None; "overflow is none")]
+    #[test_case(5, -0.1, RoundingMode::Nearest, None; "negative factor is none")]
+    #[test_case(5, -0.1, RoundingMode::Up, None; "negative factor rounded up is none")]
+    #[test_case(0, -2.0, RoundingMode::Nearest, None; "zero times negative factor is none")]
+    fn test_checked_mul_f64_rounded(
+        value: u64,
+        factor: f64,
+        mode: RoundingMode,
+        expected: Option<u64>,
+    ) {
+        let result = Resistance::from_milli_ohms(value).checked_mul_f64_rounded(factor, mode);
+        assert_eq!(result.map(|r| r.raw), expected);
+    }
+
+    #[test_case(5, 2.0, RoundingMode::Nearest, Some(3); "nearest rounds half up")]
+    #[test_case(7, 2.0, RoundingMode::Down, Some(3); "rounds down")]
+    #[test_case(5, 0.0, RoundingMode::Up, None; "division by zero is none")]
+    #[test_case(5, -20.0, RoundingMode::Up, None; "negative divisor is none")]
+    #[test_case(5, -20.0, RoundingMode::Nearest, None; "negative divisor rounded is none")]
+    fn test_checked_div_f64_rounded(
+        value: u64,
+        divisor: f64,
+        mode: RoundingMode,
+        expected: Option<u64>,
+    ) {
+        let result = Resistance::from_milli_ohms(value).checked_div_f64_rounded(divisor, mode);
+        assert_eq!(result.map(|r| r.raw), expected);
+    }
+
+    #[test_case(2.5, RoundingMode::Nearest, Ok(3); "nearest rounds half up")]
+    #[test_case(2.1, RoundingMode::Up, Ok(3); "rounds up")]
+    #[test_case(-0.4, RoundingMode::Nearest, Ok(0); "small negative rounds to zero")]
+    #[test_case(-0.6, RoundingMode::Nearest, Err(UnitError::Negative); "negative")]
+    fn test_try_from_rounded(value: f64, mode: RoundingMode, expected: Result<u64, UnitError>) {
+        let result = Resistance::try_from_rounded(value, ResistanceUnit::MilliOhms, mode);
+        assert_eq!(result.map(|r| r.raw), expected);
+    }
+
+    #[test_case(470, "470.00 mΩ"; "milliohms")]
+    #[test_case(220_000, "220.00 Ω"; "ohms")]
+    #[test_case(4_700_000, "4.70 kΩ"; "kilohms")]
+    #[test_case(1_500_000_000, "1.50 MΩ"; "megaohms")]
+    #[test_case(10_000_000_000_000, "10.00 GΩ"; "gigaohms")]
+    #[test_case(2_500_000_000_000_000, "2.50 TΩ"; "teraohms")]
+    fn test_display(milli_ohms: u64, expected: &str) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(format!("{resistance}"), expected);
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let resistance = Resistance::from_milli_ohms(4_700_000);
+        assert_eq!(format!("{resistance:.0}"), "5 kΩ");
+        assert_eq!(format!("{resistance:.6}"), "4.700000 kΩ");
+    }
+
+    #[test_case("4.7k", Ok(4_700_000); "kilo prefix")]
+    #[test_case("330 Ω", Ok(330_000); "ohms with symbol")]
+    #[test_case("2.2MΩ", Ok(2_200_000_000); "mega prefix with symbol")]
+    #[test_case("10 GΩ", Ok(10_000_000_000_000); "giga prefix with symbol")]
+    #[test_case("1.5T", Ok(1_500_000_000_000_000); "tera prefix")]
+    #[test_case("100m", Ok(100); "milli prefix")]
+    #[test_case("-1k", Err(ParseError::InvalidNumber); "negative value")]
+    fn test_from_str(s: &str, expected: Result<u64, ParseError>) {
+        let result = s.parse::<Resistance>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(4_700_000, ResistanceUnit::KiloOhms, 2, "4.70 kΩ"; "kilohms")]
+    #[test_case(4_700_000, ResistanceUnit::Ohms, 0, "4700 Ω"; "ohms")]
+    #[test_case(2_200_000_000, ResistanceUnit::MegaOhms, 1, "2.2 MΩ"; "megohms")]
+    #[test_case(4_700_000_000_000, ResistanceUnit::GigaOhms, 1, "4.7 GΩ"; "gigaohms")]
+    #[test_case(1_000_000_000_000, ResistanceUnit::TeraOhms, 3, "0.001 TΩ"; "teraohms")]
+    fn test_format_into(milli_ohms: u64, unit: ResistanceUnit, precision: usize, expected: &str) {
+        let mut buf = [0u8; 32];
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(
+            resistance.format_into(&mut buf, unit, precision),
+            Ok(expected)
+        );
+    }
+
+    #[test]
+    fn test_display_width() {
+        let resistance = Resistance::from_milli_ohms(4_700_000);
+        assert_eq!(format!("{resistance:>10.3}"), "  4.700 kΩ");
+        assert_eq!(format!("{resistance:<10.1}"), "4.7 kΩ    ");
+        assert_eq!(format!("{resistance:^10}"), " 4.70 kΩ  ");
+        assert_eq!(format!("{resistance:_>9}"), "__4.70 kΩ");
+    }
+
+    #[test_case(470, "mΩ"; "milliohms")]
+    #[test_case(470_000, "Ω"; "ohms")]
+    #[test_case(4_700_000, "kΩ"; "kilohms")]
+    #[test_case(4_700_000_000, "MΩ"; "megohms")]
+    #[test_case(4_700_000_000_000, "GΩ"; "gigaohms")]
+    #[test_case(u64::MAX, "TΩ"; "teraohms")]
+    fn test_display_unit(milli_ohms: u64, expected: &str) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        assert_eq!(resistance.display_unit(), expected);
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test_case(4_700_000, "4.70 kΩ"; "kilohms")]
+    fn test_udisplay(milli_ohms: u64, expected: &str) {
+        let resistance = Resistance::from_milli_ohms(milli_ohms);
+        let mut output = alloc::string::String::new();
+        ufmt::uwrite!(output, "{}", resistance).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test_case(Resistance::try_from_ohms(1.5), Ok(Resistance::from_milli_ohms(1_500)); "valid value")]
+    #[test_case(Resistance::try_from_ohms(-1.0), Err(UnitError::Negative); "negative")]
+    #[test_case(Resistance::try_from_ohms(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Resistance::try_from_ohms(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Resistance::try_from_ohms(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(
+        result: Result<Resistance, UnitError>,
+        expected: Result<Resistance, UnitError>,
+    ) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Resistance::from_milli_ohms(4700000),
+            &[
+                Token::Struct {
+                    name: "Resistance",
+                    len: 1,
+                },
+                Token::Str("milli_ohms"),
+                Token::U64(4700000),
+                Token::StructEnd,
+            ],
+        );
     }
 }
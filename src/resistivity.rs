@@ -0,0 +1,197 @@
+use crate::display;
+use crate::math::try_scale_f64_to_u64;
+use crate::UnitError;
+#[cfg(feature = "resistance")]
+use crate::{Resistance, RoundingMode};
+use core::fmt;
+
+/// Represents the resistivity of a material, stored as whole picoohm meters (pΩ·m) as a 64-bit
+/// value.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let copper = Resistivity::from_pico_ohm_meters(16_780); // 1.678 × 10⁻⁸ Ω·m
+///
+/// assert_eq!(copper.nano_ohm_meters(), 16.78);
+/// assert_eq!(copper.to_string(), "16.780 nΩ·m");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Resistivity {
+    #[cfg_attr(feature = "serde", serde(rename = "pico_ohm_meters"))]
+    raw: u64,
+}
+
+impl Resistivity {
+    /// Creates a new `Resistivity` from a number of whole picoohm meters (pΩ·m).
+    #[inline]
+    pub const fn from_pico_ohm_meters(value: u64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Resistivity` from a number of fractional ohm meters (Ω·m).
+    ///
+    /// Will be rounded down to the nearest whole picoohm meter (pΩ·m).
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    pub fn try_from_ohm_meters(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_u64(value, 1_000_000_000_000f64).map(Self::from_pico_ohm_meters)
+    }
+
+    /// Returns the resistivity in whole picoohm meters (pΩ·m).
+    #[inline]
+    pub const fn pico_ohm_meters(&self) -> u64 {
+        self.raw
+    }
+
+    /// Returns the resistivity in fractional nanoohm meters (nΩ·m).
+    #[inline]
+    pub fn nano_ohm_meters(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the resistivity in fractional ohm meters (Ω·m).
+    #[inline]
+    pub fn ohm_meters(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_000f64
+    }
+
+    /// Returns whether the resistivity is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `Resistivity` value of zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_pico_ohm_meters(0)
+    }
+}
+
+impl fmt::Display for Resistivity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(
+            f,
+            format_args!("{}.{:03} nΩ·m", self.raw / 1_000, self.raw % 1_000),
+        )
+    }
+}
+
+/// Calculates the resistance of a wire or conductor from its resistivity, its length in whole
+/// millimeters (mm) and its cross-sectional area in fractional square millimeters (mm²)
+/// (`R = ρ × L ÷ A`).
+///
+/// The area is rounded down to the nearest whole square micrometer (μm²), and the resistance is
+/// rounded down to the nearest whole milliohm (mΩ).
+/// Returns `None` if the area is NaN, infinite, negative or below one square micrometer, or the
+/// result would overflow.
+///
+/// ```rust
+/// use ohms::prelude::*;
+/// use ohms::wire_resistance;
+///
+/// let copper = Resistivity::from_pico_ohm_meters(16_780);
+///
+/// // 2m of 0.5mm² wire
+/// let resistance = wire_resistance(copper, 2_000, 0.5).unwrap();
+/// assert_eq!(resistance, 67.milli_ohms());
+/// ```
+#[cfg(feature = "resistance")]
+pub fn wire_resistance(
+    resistivity: Resistivity,
+    length_mm: u64,
+    cross_section_mm2: f64,
+) -> Option<Resistance> {
+    let square_micro_meters = try_scale_f64_to_u64(cross_section_mm2, 1_000_000f64).ok()?;
+    conductor_resistance(
+        resistivity,
+        length_mm,
+        square_micro_meters,
+        RoundingMode::Down,
+    )
+}
+
+/// Calculates the resistance of a conductor from its length in whole millimeters (mm) and its
+/// cross-sectional area in whole square micrometers (μm²), rounded to a whole milliohm (mΩ) in
+/// the given direction. Returns `None` if the area is zero or the result would overflow.
+#[cfg(feature = "resistance")]
+pub(crate) fn conductor_resistance(
+    resistivity: Resistivity,
+    length_mm: u64,
+    square_micro_meters: u64,
+    mode: RoundingMode,
+) -> Option<Resistance> {
+    if square_micro_meters == 0 {
+        return None;
+    }
+
+    // pΩ·m × mm ÷ μm² is in milliohms
+    let milli_ohms = mode.div_u128(
+        resistivity.raw as u128 * length_mm as u128,
+        square_micro_meters as u128,
+    );
+
+    u64::try_from(milli_ohms)
+        .ok()
+        .map(Resistance::from_milli_ohms)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(1.678e-8, Ok(16_780); "copper")]
+    #[test_case(1.1e-6, Ok(1_100_000); "nichrome")]
+    #[test_case(-1e-8, Err(UnitError::Negative); "negative")]
+    #[test_case(1e8, Err(UnitError::Overflow); "overflow")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    fn test_try_from_ohm_meters(value: f64, expected: Result<u64, UnitError>) {
+        let resistivity = Resistivity::try_from_ohm_meters(value).map(|r| r.pico_ohm_meters());
+        assert_eq!(resistivity, expected);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let resistivity = Resistivity::from_pico_ohm_meters(1_100_000);
+
+        assert_eq!(resistivity.nano_ohm_meters(), 1_100.0);
+        assert_eq!(resistivity.ohm_meters(), 1.1e-6);
+        assert!(Resistivity::zero().is_zero());
+        assert!(!resistivity.is_zero());
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test_case(16_780, 2_000, 0.5, Some(67); "copper wire")]
+    #[test_case(16_780, 1_000, 1.0, Some(16); "rounds down")]
+    #[test_case(1_100_000, 100, 0.1, Some(1_100); "nichrome heater")]
+    #[test_case(16_780, 0, 1.0, Some(0); "zero length")]
+    #[test_case(16_780, 1_000, 0.0000001, None; "area below a square micrometer")]
+    #[test_case(16_780, 1_000, 0.0, None; "zero area")]
+    #[test_case(16_780, 1_000, -1.0, None; "negative area")]
+    #[test_case(16_780, 1_000, f64::NAN, None; "nan area")]
+    #[test_case(u64::MAX, u64::MAX, 1.0, None; "overflow")]
+    fn test_wire_resistance(
+        pico_ohm_meters: u64,
+        length_mm: u64,
+        cross_section_mm2: f64,
+        expected: Option<u64>,
+    ) {
+        let resistance = wire_resistance(
+            Resistivity::from_pico_ohm_meters(pico_ohm_meters),
+            length_mm,
+            cross_section_mm2,
+        );
+        assert_eq!(resistance.map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test_case(16_780, "16.780 nΩ·m"; "copper")]
+    #[test_case(0, "0.000 nΩ·m"; "zero")]
+    fn test_display(pico_ohm_meters: u64, expected: &str) {
+        let resistivity = Resistivity::from_pico_ohm_meters(pico_ohm_meters);
+        assert_eq!(format!("{resistivity}"), expected);
+    }
+}
@@ -0,0 +1,94 @@
+/// The direction to round a value in when it cannot be represented exactly.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds to the closest value, rounding up when exactly between two values.
+    #[default]
+    Nearest,
+    /// Rounds up to the closest value that is greater than or equal.
+    Up,
+    /// Rounds down to the closest value that is less than or equal.
+    Down,
+}
+
+/// The largest magnitude at which an `f64` can still hold a fractional part (2^52).
+const MAX_FRACTIONAL_F64: f64 = 4_503_599_627_370_496f64;
+
+impl RoundingMode {
+    /// Rounds a floating-point value to a whole number in this direction.
+    ///
+    /// NaN, infinite and very large values are returned unchanged.
+    pub(crate) fn round_f64(self, value: f64) -> f64 {
+        if !(value < MAX_FRACTIONAL_F64 && value > -MAX_FRACTIONAL_F64) {
+            return value;
+        }
+
+        let truncated = value as i64 as f64;
+        let fraction = value - truncated;
+
+        match self {
+            RoundingMode::Nearest if fraction >= 0.5 => truncated + 1f64,
+            RoundingMode::Nearest if fraction < -0.5 => truncated - 1f64,
+            RoundingMode::Up if fraction > 0f64 => truncated + 1f64,
+            RoundingMode::Down if fraction < 0f64 => truncated - 1f64,
+            _ => truncated,
+        }
+    }
+
+    /// Divides one unsigned value by another, rounding the quotient in this direction.
+    ///
+    /// Panics if the divisor is zero.
+    #[cfg(any(feature = "voltage", feature = "resistance"))]
+    pub(crate) const fn div_u128(self, dividend: u128, divisor: u128) -> u128 {
+        let quotient = dividend / divisor;
+        let remainder = dividend % divisor;
+
+        match self {
+            RoundingMode::Nearest if remainder >= divisor - remainder => quotient + 1,
+            RoundingMode::Up if remainder > 0 => quotient + 1,
+            _ => quotient,
+        }
+    }
+}
+
+#[cfg(all(
+    test,
+    any(
+        feature = "voltage",
+        feature = "current",
+        feature = "resistance",
+        feature = "power"
+    )
+))]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2.4, 2.0, 3.0, 2.0; "positive below half")]
+    #[test_case(2.5, 3.0, 3.0, 2.0; "positive half")]
+    #[test_case(-2.4, -2.0, -2.0, -3.0; "negative below half")]
+    #[test_case(-2.5, -2.0, -2.0, -3.0; "negative half rounds up")]
+    #[test_case(-2.6, -3.0, -2.0, -3.0; "negative above half")]
+    #[test_case(7.0, 7.0, 7.0, 7.0; "whole number")]
+    fn test_round_f64(value: f64, nearest: f64, up: f64, down: f64) {
+        assert_eq!(RoundingMode::Nearest.round_f64(value), nearest);
+        assert_eq!(RoundingMode::Up.round_f64(value), up);
+        assert_eq!(RoundingMode::Down.round_f64(value), down);
+    }
+
+    #[test]
+    fn test_round_f64_non_finite() {
+        assert!(RoundingMode::Nearest.round_f64(f64::NAN).is_nan());
+        assert_eq!(RoundingMode::Up.round_f64(f64::INFINITY), f64::INFINITY);
+        assert_eq!(RoundingMode::Down.round_f64(1e300), 1e300);
+    }
+
+    #[cfg(any(feature = "voltage", feature = "resistance"))]
+    #[test_case(13, 4, 3, 4, 3; "below half")]
+    #[test_case(10, 4, 3, 3, 2; "half")]
+    #[test_case(12, 4, 3, 3, 3; "exact")]
+    fn test_div_u128(dividend: u128, divisor: u128, nearest: u128, up: u128, down: u128) {
+        assert_eq!(RoundingMode::Nearest.div_u128(dividend, divisor), nearest);
+        assert_eq!(RoundingMode::Up.div_u128(dividend, divisor), up);
+        assert_eq!(RoundingMode::Down.div_u128(dividend, divisor), down);
+    }
+}
@@ -0,0 +1,75 @@
+#[cfg(feature = "resistance")]
+use crate::Resistance;
+#[cfg(feature = "voltage")]
+use crate::Voltage;
+#[cfg(feature = "power")]
+use crate::{ApparentPower, Power, ReactivePower, SignedPower};
+use crate::{
+    Capacitance, Capacity, Charge, Conductance, Energy, Frequency, Inductance, Temperature,
+};
+#[cfg(feature = "current")]
+use crate::{Current, SignedCurrent};
+
+/// A unit with a single value that can be converted to and from its whole base units, such as
+/// microvolts for `Voltage`.
+///
+/// This is public so it can be used as a bound, but cannot be named outside of the crate, so it
+/// cannot be implemented for other types.
+pub trait Scalar: Copy {
+    /// The value of zero base units.
+    const ZERO: Self;
+
+    /// Returns the value in whole base units.
+    fn to_raw(self) -> i128;
+
+    /// Creates a value from whole base units, returning `None` if it cannot be represented.
+    fn from_raw(raw: i128) -> Option<Self>;
+}
+
+macro_rules! impl_scalar {
+    ($type:ident, $from:ident, $raw:ident, $int:ty) => {
+        impl Scalar for $type {
+            const ZERO: Self = $type::$from(0);
+
+            #[inline]
+            fn to_raw(self) -> i128 {
+                self.$raw() as i128
+            }
+
+            #[inline]
+            fn from_raw(raw: i128) -> Option<Self> {
+                <$int>::try_from(raw).ok().map($type::$from)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "power")]
+impl_scalar!(ApparentPower, from_micro_volt_amps, micro_volt_amps, u64);
+impl_scalar!(Capacitance, from_pico_farads, pico_farads, u64);
+impl_scalar!(Capacity, from_micro_amp_hours, micro_amp_hours, u64);
+impl_scalar!(Charge, from_micro_coulombs, micro_coulombs, u64);
+impl_scalar!(Conductance, from_nano_siemens, nano_siemens, u64);
+#[cfg(feature = "current")]
+impl_scalar!(Current, from_micro_amps, micro_amps, u64);
+impl_scalar!(Energy, from_nano_joules, nano_joules, u64);
+impl_scalar!(Frequency, from_milli_hertz, milli_hertz, u64);
+impl_scalar!(Inductance, from_nano_henries, nano_henries, u64);
+#[cfg(feature = "power")]
+impl_scalar!(Power, from_micro_watts, micro_watts, u64);
+#[cfg(feature = "power")]
+impl_scalar!(ReactivePower, from_micro_vars, micro_vars, i64);
+#[cfg(feature = "resistance")]
+impl_scalar!(Resistance, from_milli_ohms, milli_ohms, u64);
+#[cfg(feature = "current")]
+impl_scalar!(SignedCurrent, from_micro_amps, micro_amps, i64);
+#[cfg(feature = "power")]
+impl_scalar!(SignedPower, from_micro_watts, micro_watts, i64);
+impl_scalar!(
+    Temperature,
+    from_milli_degrees_celsius,
+    milli_degrees_celsius,
+    i64
+);
+#[cfg(feature = "voltage")]
+impl_scalar!(Voltage, from_micro_volts, micro_volts, i64);
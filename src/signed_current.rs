@@ -0,0 +1,703 @@
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::{f64_to_i64, try_scale_f64_to_i64};
+use crate::parse::{parse_si_signed, ParseError};
+use crate::{Current, CurrentUnit, UnitError};
+use core::{cmp, fmt, hash, iter, ops, str};
+
+/// Represents a bidirectional current value, stored as whole microamps (μA) as a signed 64-bit
+/// value. This value can be positive or negative.
+///
+/// **Reminder:** `1000 μA = 1 mA, 1000 mA = 1 A`
+///
+/// This is an immutable type. Any math operators return a new `SignedCurrent` value.
+///
+/// Use this instead of `Current` where the direction of the current matters, such as a battery
+/// charging (positive) or discharging (negative), or the current sensed through an H-bridge.
+///
+/// # Creating a SignedCurrent value
+/// You can create a `SignedCurrent` value using the `from_micro_amps` method, from a floating-point
+/// value with one of the `try_from` methods, or by converting an unsigned `Current`.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let discharge = SignedCurrent::from_micro_amps(-1_500_000); // -1.5A
+/// let charge = SignedCurrent::try_from(500.milli_amps()).unwrap(); // 500mA
+///
+/// assert_eq!((discharge + charge).magnitude(), 1.amps());
+/// ```
+///
+/// # Ohm's Law
+/// Multiplying a `SignedCurrent` by a `Resistance` returns a `Voltage` with the same polarity, and
+/// [`Voltage::signed_current`](crate::Voltage::signed_current) keeps the polarity of the voltage.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
+/// use ohms::prelude::*;
+///
+/// let shunt = 10.milli_ohms();
+/// let sense = SignedCurrent::from_micro_amps(-2_000_000) * shunt; // -20mV
+///
+/// assert_eq!(sense, (-20).milli_volts());
+/// assert_eq!(sense.signed_current(shunt).micro_amps(), -2_000_000);
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedCurrent {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_amps"))]
+    raw: i64,
+}
+
+impl SignedCurrent {
+    /// Creates a new `SignedCurrent` from a number of whole microamps (μA).
+    #[inline]
+    pub const fn from_micro_amps(value: i64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `SignedCurrent` from a number of fractional microamps (μA).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1f64).map(Self::from_micro_amps)
+    }
+
+    /// Creates a new `SignedCurrent` from a number of fractional milliamps (mA).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000f64).map(Self::from_micro_amps)
+    }
+
+    /// Creates a new `SignedCurrent` from a number of fractional amps (A).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_amps(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000_000f64).map(Self::from_micro_amps)
+    }
+
+    /// Returns the current value in whole microamps (μA).
+    #[inline]
+    pub const fn micro_amps(&self) -> i64 {
+        self.raw
+    }
+
+    /// Returns the current value in fractional milliamps (mA).
+    #[inline]
+    pub fn milli_amps(&self) -> f64 {
+        self.raw as f64 / 1_000_f64
+    }
+
+    /// Returns the current value in fractional amps (A).
+    #[inline]
+    pub fn amps(&self) -> f64 {
+        self.raw as f64 / 1_000_000_f64
+    }
+
+    /// Returns the current value as whole milliamps (mA) and the remaining microamps (μA),
+    /// without any floating-point math.
+    ///
+    /// The remainder is never negative, so a negative current rounds the whole part down. Use
+    /// `abs` first to format the sign separately.
+    #[inline]
+    pub const fn to_milli_amps_parts(&self) -> (i64, u32) {
+        (
+            self.raw.div_euclid(1_000),
+            self.raw.rem_euclid(1_000) as u32,
+        )
+    }
+
+    /// Returns the current value as whole amps (A) and the remaining microamps (μA),
+    /// without any floating-point math.
+    ///
+    /// The remainder is never negative, so a negative current rounds the whole part down. Use
+    /// `abs` first to format the sign separately.
+    #[inline]
+    pub const fn to_amps_parts(&self) -> (i64, u32) {
+        (
+            self.raw.div_euclid(1_000_000),
+            self.raw.rem_euclid(1_000_000) as u32,
+        )
+    }
+
+    /// Returns whether the current value is zero amps (0A).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns whether the current value is positive.
+    ///
+    /// This returns `true` if the current value is greater than or equal to zero amps (0A).
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        self.raw >= 0
+    }
+
+    /// Returns whether the current value is negative.
+    ///
+    /// This returns `true` if the current value is less than zero amps (0A).
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.raw < 0
+    }
+
+    /// Returns the absolute value of the current value.
+    #[inline]
+    pub const fn abs(&self) -> Self {
+        Self::from_micro_amps(self.raw.abs())
+    }
+
+    /// Returns the magnitude of the current value as an unsigned `Current`.
+    #[inline]
+    pub const fn magnitude(&self) -> Current {
+        Current::from_micro_amps(self.raw.unsigned_abs())
+    }
+
+    /// Inverts the current value from positive to negative or negative to positive.
+    ///
+    /// This is equivalent to the unary `-` operator.
+    /// Panics if the current is the minimum value, as its inverse cannot be represented.
+    #[inline]
+    pub const fn invert(&self) -> Self {
+        match self.raw.checked_neg() {
+            Some(raw) => Self::from_micro_amps(raw),
+            None => panic!("Overflow when negating current value"),
+        }
+    }
+
+    /// Inverts the current value, returning `None` if the result would overflow.
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.raw.checked_neg() {
+            Some(raw) => Some(Self::from_micro_amps(raw)),
+            None => None,
+        }
+    }
+
+    /// Returns a `SignedCurrent` value of zero amps (0A).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_micro_amps(0)
+    }
+
+    /// Adds two `SignedCurrent` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_micro_amps)
+    }
+
+    /// Subtracts one `SignedCurrent` value from another, returning `None` if the result would
+    /// overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_micro_amps)
+    }
+
+    /// Multiplies the `SignedCurrent` value by an integer value, returning `None` if the result
+    /// would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: i64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_micro_amps)
+    }
+
+    /// Divides the `SignedCurrent` value by an integer value, returning `None` if the divisor is
+    /// zero or the result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: i64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_micro_amps)
+    }
+
+    /// Multiplies the `SignedCurrent` value by a floating-point value, returning `None` if the
+    /// factor is infinite, NaN or the result would overflow.
+    ///
+    /// The result is rounded toward zero to the nearest whole microamp (μA).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(self.raw as f64 * factor).map(Self::from_micro_amps)
+    }
+
+    /// Divides the `SignedCurrent` value by a floating-point value, returning `None` if the
+    /// divisor is zero, infinite, NaN or the result would overflow.
+    ///
+    /// The result is rounded toward zero to the nearest whole microamp (μA).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(self.raw as f64 / divisor).map(Self::from_micro_amps)
+    }
+
+    /// Returns the unit symbol used when displaying the current value, such as `"mA"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw.unsigned_abs() {
+            0..=999 => (1, "μA"),
+            1_000..=999_999 => (1_000, "mA"),
+            _ => (1_000_000, "A"),
+        }
+    }
+
+    /// Formats the current value into a byte buffer in the given unit, rounded to `precision`
+    /// decimal places, returning the written string, such as `"-22.72 mA"`.
+    ///
+    /// No floating-point math or heap allocation is used, so this is suitable for rendering values
+    /// to displays on targets without a floating-point unit. Returns an error if the buffer is too
+    /// small to hold the formatted value.
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        unit: CurrentUnit,
+        precision: usize,
+    ) -> Result<&'a str, fmt::Error> {
+        display::format_into(
+            buf,
+            self.raw < 0,
+            self.raw.unsigned_abs(),
+            unit.scale(),
+            precision,
+            unit.symbol(),
+        )
+    }
+}
+
+approx::impl_approx_eq!(SignedCurrent, signed);
+compare::impl_const_compare!(SignedCurrent, signed);
+
+impl PartialEq for SignedCurrent {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for SignedCurrent {}
+
+impl PartialOrd for SignedCurrent {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SignedCurrent {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for SignedCurrent {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for SignedCurrent {
+    type Output = Self;
+
+    /// Adds two `SignedCurrent` values together, returning a new `SignedCurrent` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_micro_amps)
+            .expect("Overflow when adding current values")
+    }
+}
+
+impl ops::Sub for SignedCurrent {
+    type Output = Self;
+
+    /// Subtracts the `SignedCurrent` value from another, returning a new `SignedCurrent` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_micro_amps)
+            .expect("Overflow when subtracting current values")
+    }
+}
+
+impl iter::Sum for SignedCurrent {
+    /// Sums the `SignedCurrent` values, returning zero (0A) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a SignedCurrent> for SignedCurrent {
+    /// Sums the `SignedCurrent` values, returning zero (0A) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl ops::Neg for SignedCurrent {
+    type Output = Self;
+
+    /// Inverts the `SignedCurrent` value from positive to negative or negative to positive.
+    #[inline]
+    fn neg(self) -> Self {
+        self.invert()
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i: ty) => {
+        impl ops::Mul<$i> for SignedCurrent {
+            type Output = Self;
+
+            /// Multiplies the `SignedCurrent` value by an integer value, returning a new
+            /// `SignedCurrent` value.
+            #[inline]
+            fn mul(self, other: $i) -> Self {
+                self.raw
+                    .checked_mul(other as i64)
+                    .map(Self::from_micro_amps)
+                    .expect("Overflow when multiplying current value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for SignedCurrent {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for SignedCurrent {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply current value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply current value by NaN"),
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_micro_amps(result as i64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i: ty) => {
+        impl ops::Div<$i> for SignedCurrent {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide current value by zero");
+                }
+                self.raw
+                    .checked_div(divisor as i64)
+                    .map(Self::from_micro_amps)
+                    .expect("Overflow when dividing current value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for SignedCurrent {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for SignedCurrent {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide current value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide current value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide current value by NaN"),
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_micro_amps(result as i64)
+    }
+}
+
+impl TryFrom<Current> for SignedCurrent {
+    type Error = UnitError;
+
+    /// Converts an unsigned `Current` to a positive `SignedCurrent`.
+    ///
+    /// Returns an error if the current is too large to be represented.
+    fn try_from(current: Current) -> Result<Self, Self::Error> {
+        i64::try_from(current.micro_amps())
+            .map(Self::from_micro_amps)
+            .map_err(|_| UnitError::Overflow)
+    }
+}
+
+impl TryFrom<SignedCurrent> for Current {
+    type Error = UnitError;
+
+    /// Converts a `SignedCurrent` to an unsigned `Current`.
+    ///
+    /// Returns an error if the current is negative. Use `magnitude` to discard the direction
+    /// instead.
+    fn try_from(current: SignedCurrent) -> Result<Self, Self::Error> {
+        u64::try_from(current.raw)
+            .map(Current::from_micro_amps)
+            .map_err(|_| UnitError::Negative)
+    }
+}
+
+impl fmt::Display for SignedCurrent {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let (scale, unit) = self.display_scale();
+        let value = (self.raw.unsigned_abs() as f64) / (scale as f64);
+
+        display::write_padded(f, format_args!("{sign}{value:.precision$} {unit}"))
+    }
+}
+
+impl str::FromStr for SignedCurrent {
+    type Err = ParseError;
+
+    /// Parses a `SignedCurrent` value from a string with an optional sign, SI prefix and unit
+    /// symbol, such as `-22uA`, `1.5 mA` or `-330m`.
+    ///
+    /// Will be rounded toward zero to the nearest whole microamp (μA).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_signed(s, &["A"], 6).map(Self::from_micro_amps)
+    }
+}
+
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+impl SignedCurrent {
+    /// Splits the current value into its sign, whole and hundredths parts in the most appropriate
+    /// unit, for formatting without floating-point math.
+    fn scaled_parts(&self) -> (&'static str, u64, u64, &'static str) {
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let micro_amps = self.raw.unsigned_abs();
+        let (scale, unit) = self.display_scale();
+
+        let whole = micro_amps / scale;
+        let fraction = micro_amps % scale * 100 / scale;
+
+        (sign, whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for SignedCurrent {
+    fn format(&self, f: defmt::Formatter) {
+        let (sign, whole, fraction, unit) = self.scaled_parts();
+
+        defmt::write!(
+            f,
+            "{=str}{=u64}.{=u64:02} {=str}",
+            sign,
+            whole,
+            fraction,
+            unit
+        )
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for SignedCurrent {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let (sign, whole, fraction, unit) = self.scaled_parts();
+        let padding = if fraction < 10 { "0" } else { "" };
+
+        ufmt::uwrite!(f, "{}{}.{}{} {}", sign, whole, padding, fraction, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(1_500_000, (1_500, 0), (1, 500_000); "amps")]
+    #[test_case(-22_720, (-23, 280), (-1, 977_280); "negative milliamps")]
+    #[test_case(0, (0, 0), (0, 0); "zero")]
+    fn test_to_parts(micro_amps: i64, milli_amps: (i64, u32), amps: (i64, u32)) {
+        let current = SignedCurrent::from_micro_amps(micro_amps);
+
+        assert_eq!(current.to_milli_amps_parts(), milli_amps);
+        assert_eq!(current.to_amps_parts(), amps);
+    }
+
+    #[test_case(-1_500, 1_500; "negative")]
+    #[test_case(1_500, 1_500; "positive")]
+    #[test_case(i64::MIN, 9_223_372_036_854_775_808; "minimum")]
+    fn test_magnitude(micro_amps: i64, expected: u64) {
+        let current = SignedCurrent::from_micro_amps(micro_amps);
+        assert_eq!(current.magnitude().micro_amps(), expected);
+    }
+
+    #[test]
+    fn test_sign() {
+        let current = SignedCurrent::from_micro_amps(-1);
+
+        assert!(current.is_negative());
+        assert!(!current.is_positive());
+        assert_eq!(-current, SignedCurrent::from_micro_amps(1));
+        assert_eq!(current.abs(), SignedCurrent::from_micro_amps(1));
+        assert_eq!(SignedCurrent::from_micro_amps(i64::MIN).checked_neg(), None);
+    }
+
+    #[test_case(1_000, -3_000, Some(-2_000); "mixed signs")]
+    #[test_case(i64::MIN, -1, None; "overflow")]
+    fn test_checked_add(a: i64, b: i64, expected: Option<i64>) {
+        let sum = SignedCurrent::from_micro_amps(a).checked_add(SignedCurrent::from_micro_amps(b));
+        assert_eq!(sum.map(|i| i.micro_amps()), expected);
+    }
+
+    #[test]
+    fn test_scale() {
+        let current = SignedCurrent::from_micro_amps(-1_000);
+
+        assert_eq!((current * 3i64).micro_amps(), -3_000);
+        assert_eq!((current / 4i64).micro_amps(), -250);
+        assert_eq!((current * 1.5f64).micro_amps(), -1_500);
+        assert_eq!(current.checked_div_int(0), None);
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [
+            SignedCurrent::from_micro_amps(1_000),
+            SignedCurrent::from_micro_amps(-3_000),
+        ];
+
+        assert_eq!(values.iter().sum::<SignedCurrent>().micro_amps(), -2_000);
+    }
+
+    #[test_case(500_000, Ok(500_000); "in range")]
+    #[test_case(u64::MAX, Err(UnitError::Overflow); "too large")]
+    fn test_try_from_current(micro_amps: u64, expected: Result<i64, UnitError>) {
+        let current = SignedCurrent::try_from(Current::from_micro_amps(micro_amps));
+        assert_eq!(current.map(|i| i.micro_amps()), expected);
+    }
+
+    #[test_case(500_000, Ok(500_000); "positive")]
+    #[test_case(-1, Err(UnitError::Negative); "negative")]
+    fn test_try_into_current(micro_amps: i64, expected: Result<u64, UnitError>) {
+        let current = Current::try_from(SignedCurrent::from_micro_amps(micro_amps));
+        assert_eq!(current.map(|i| i.micro_amps()), expected);
+    }
+
+    #[test_case(-22_720, "-22.72 mA"; "negative milliamps")]
+    #[test_case(1_500_000, "1.50 A"; "amps")]
+    #[test_case(-5, "-5.00 μA"; "negative microamps")]
+    fn test_display(micro_amps: i64, expected: &str) {
+        let current = SignedCurrent::from_micro_amps(micro_amps);
+        assert_eq!(format!("{current}"), expected);
+    }
+
+    #[test_case("-1.5A", Ok(-1_500_000); "negative amps")]
+    #[test_case("22 mA", Ok(22_000); "milliamps")]
+    #[test_case("1.5 V", Err(ParseError::InvalidUnit); "wrong unit")]
+    fn test_from_str(s: &str, expected: Result<i64, ParseError>) {
+        let current: Result<SignedCurrent, _> = s.parse();
+        assert_eq!(current.map(|i| i.micro_amps()), expected);
+    }
+
+    #[test]
+    fn test_format_into() {
+        let mut buf = [0u8; 16];
+        let current = SignedCurrent::from_micro_amps(-22_720);
+
+        assert_eq!(
+            current.format_into(&mut buf, CurrentUnit::MilliAmps, 1),
+            Ok("-22.7 mA")
+        );
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn test_udisplay() {
+        let mut output = alloc::string::String::new();
+        ufmt::uwrite!(output, "{}", SignedCurrent::from_micro_amps(-22_720)).unwrap();
+        assert_eq!(output, "-22.72 mA");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &SignedCurrent::from_micro_amps(-1_500),
+            &[
+                Token::Struct {
+                    name: "SignedCurrent",
+                    len: 1,
+                },
+                Token::Str("micro_amps"),
+                Token::I64(-1_500),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
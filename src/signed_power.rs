@@ -0,0 +1,694 @@
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::{f64_to_i64, try_scale_f64_to_i64};
+use crate::parse::{parse_si_signed, ParseError};
+use crate::{Power, PowerUnit, UnitError};
+use core::{cmp, fmt, hash, iter, ops, str};
+
+/// Represents a bidirectional power value, stored as whole microwatts (μW) as a signed 64-bit
+/// value. This value can be positive or negative.
+///
+/// **Reminder:** `1000 μW = 1 mW, 1000 mW = 1 W, 1000 W = 1 kW`
+///
+/// This is an immutable type. Any math operators return a new `SignedPower` value.
+///
+/// Use this instead of `Power` where the direction of energy flow matters. By convention, a
+/// positive value is power drawn from a source (imported or consumed) and a negative value is power
+/// returned to it (exported or regenerated), such as a motor braking or a battery being charged.
+///
+/// # Creating a SignedPower value
+/// You can create a `SignedPower` value using the `from_micro_watts` method, from a floating-point
+/// value with one of the `try_from` methods, or by converting an unsigned `Power`.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let load = SignedPower::try_from(1.5.watts()).unwrap(); // 1.5W
+/// let solar = SignedPower::from_micro_watts(-2_000_000); // -2W
+///
+/// let net = load + solar;
+/// assert!(net.is_negative());
+/// assert_eq!(net.magnitude(), 500.milli_watts());
+/// ```
+///
+/// # Power from a signed current
+/// Multiplying a `Voltage` by a `SignedCurrent` returns a `SignedPower`, where the sign follows the
+/// polarity of the voltage and the direction of the current.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "power"))] {
+/// use ohms::prelude::*;
+///
+/// let charging = SignedCurrent::from_micro_amps(-500_000); // -500mA
+/// let power = 12.volts() * charging;
+///
+/// assert_eq!(power, SignedPower::from_micro_watts(-6_000_000));
+/// assert_eq!(format!("{power}"), "-6.00 W");
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignedPower {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_watts"))]
+    raw: i64,
+}
+
+impl SignedPower {
+    /// Creates a new `SignedPower` from a number of whole microwatts (μW).
+    #[inline]
+    pub const fn from_micro_watts(value: i64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `SignedPower` from a number of fractional microwatts (μW).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_watts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1f64).map(Self::from_micro_watts)
+    }
+
+    /// Creates a new `SignedPower` from a number of fractional milliwatts (mW).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_watts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000f64).map(Self::from_micro_watts)
+    }
+
+    /// Creates a new `SignedPower` from a number of fractional watts (W).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_watts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000_000f64).map(Self::from_micro_watts)
+    }
+
+    /// Creates a new `SignedPower` from a number of fractional kilowatts (kW).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_watts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000_000_000f64).map(Self::from_micro_watts)
+    }
+
+    /// Returns the power value in whole microwatts (μW).
+    #[inline]
+    pub const fn micro_watts(&self) -> i64 {
+        self.raw
+    }
+
+    /// Returns the power value in fractional milliwatts (mW).
+    #[inline]
+    pub fn milli_watts(&self) -> f64 {
+        self.raw as f64 / 1_000_f64
+    }
+
+    /// Returns the power value in fractional watts (W).
+    #[inline]
+    pub fn watts(&self) -> f64 {
+        self.raw as f64 / 1_000_000_f64
+    }
+
+    /// Returns the power value in fractional kilowatts (kW).
+    #[inline]
+    pub fn kilo_watts(&self) -> f64 {
+        self.raw as f64 / 1_000_000_000_f64
+    }
+
+    /// Returns the power value as whole milliwatts (mW) and the remaining microwatts (μW),
+    /// without any floating-point math.
+    ///
+    /// The remainder is never negative, so a negative power rounds the whole part down. Use
+    /// `abs` first to format the sign separately.
+    #[inline]
+    pub const fn to_milli_watts_parts(&self) -> (i64, u32) {
+        (
+            self.raw.div_euclid(1_000),
+            self.raw.rem_euclid(1_000) as u32,
+        )
+    }
+
+    /// Returns the power value as whole watts (W) and the remaining microwatts (μW),
+    /// without any floating-point math.
+    ///
+    /// The remainder is never negative, so a negative power rounds the whole part down. Use
+    /// `abs` first to format the sign separately.
+    #[inline]
+    pub const fn to_watts_parts(&self) -> (i64, u32) {
+        (
+            self.raw.div_euclid(1_000_000),
+            self.raw.rem_euclid(1_000_000) as u32,
+        )
+    }
+
+    /// Returns the power value as whole kilowatts (kW) and the remaining microwatts (μW),
+    /// without any floating-point math.
+    ///
+    /// The remainder is never negative, so a negative power rounds the whole part down. Use
+    /// `abs` first to format the sign separately.
+    #[inline]
+    pub const fn to_kilo_watts_parts(&self) -> (i64, u32) {
+        (
+            self.raw.div_euclid(1_000_000_000),
+            self.raw.rem_euclid(1_000_000_000) as u32,
+        )
+    }
+
+    /// Returns whether the power value is zero watts (0W).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns whether the power value is positive.
+    ///
+    /// This returns `true` if the power value is greater than or equal to zero watts (0W).
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        self.raw >= 0
+    }
+
+    /// Returns whether the power value is negative.
+    ///
+    /// This returns `true` if the power value is less than zero watts (0W).
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.raw < 0
+    }
+
+    /// Returns the absolute value of the power value.
+    #[inline]
+    pub const fn abs(&self) -> Self {
+        Self::from_micro_watts(self.raw.abs())
+    }
+
+    /// Returns the magnitude of the power value as an unsigned `Power`.
+    #[inline]
+    pub const fn magnitude(&self) -> Power {
+        Power::from_micro_watts(self.raw.unsigned_abs())
+    }
+
+    /// Inverts the power value from positive to negative or negative to positive.
+    ///
+    /// This is equivalent to the unary `-` operator.
+    /// Panics if the power is the minimum value, as its inverse cannot be represented.
+    #[inline]
+    pub const fn invert(&self) -> Self {
+        match self.raw.checked_neg() {
+            Some(raw) => Self::from_micro_watts(raw),
+            None => panic!("Overflow when negating power value"),
+        }
+    }
+
+    /// Inverts the power value, returning `None` if the result would overflow.
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.raw.checked_neg() {
+            Some(raw) => Some(Self::from_micro_watts(raw)),
+            None => None,
+        }
+    }
+
+    /// Returns a `SignedPower` value of zero watts (0W).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_micro_watts(0)
+    }
+
+    /// Adds two `SignedPower` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_micro_watts)
+    }
+
+    /// Subtracts one `SignedPower` value from another, returning `None` if the result would
+    /// overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_micro_watts)
+    }
+
+    /// Multiplies the `SignedPower` value by an integer value, returning `None` if the result
+    /// would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: i64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_micro_watts)
+    }
+
+    /// Divides the `SignedPower` value by an integer value, returning `None` if the divisor is
+    /// zero or the result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: i64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_micro_watts)
+    }
+
+    /// Multiplies the `SignedPower` value by a floating-point value, returning `None` if the
+    /// factor is infinite, NaN or the result would overflow.
+    ///
+    /// The result is rounded toward zero to the nearest whole microwatt (μW).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(self.raw as f64 * factor).map(Self::from_micro_watts)
+    }
+
+    /// Divides the `SignedPower` value by a floating-point value, returning `None` if the
+    /// divisor is zero, infinite, NaN or the result would overflow.
+    ///
+    /// The result is rounded toward zero to the nearest whole microwatt (μW).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(self.raw as f64 / divisor).map(Self::from_micro_watts)
+    }
+
+    /// Returns the unit symbol used when displaying the power value, such as `"mW"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw.unsigned_abs() {
+            0..=999 => (1, "μW"),
+            1_000..=999_999 => (1_000, "mW"),
+            1_000_000..=999_999_999 => (1_000_000, "W"),
+            _ => (1_000_000_000, "kW"),
+        }
+    }
+
+    /// Formats the power value into a byte buffer in the given unit, rounded to `precision`
+    /// decimal places, returning the written string, such as `"-1.50 W"`.
+    ///
+    /// No floating-point math or heap allocation is used, so this is suitable for rendering values
+    /// to displays on targets without a floating-point unit. Returns an error if the buffer is too
+    /// small to hold the formatted value.
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        unit: PowerUnit,
+        precision: usize,
+    ) -> Result<&'a str, fmt::Error> {
+        display::format_into(
+            buf,
+            self.raw < 0,
+            self.raw.unsigned_abs(),
+            unit.scale(),
+            precision,
+            unit.symbol(),
+        )
+    }
+}
+
+approx::impl_approx_eq!(SignedPower, signed);
+compare::impl_const_compare!(SignedPower, signed);
+
+impl PartialEq for SignedPower {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for SignedPower {}
+
+impl PartialOrd for SignedPower {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SignedPower {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for SignedPower {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for SignedPower {
+    type Output = Self;
+
+    /// Adds two `SignedPower` values together, returning a new `SignedPower` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_micro_watts)
+            .expect("Overflow when adding power values")
+    }
+}
+
+impl ops::Sub for SignedPower {
+    type Output = Self;
+
+    /// Subtracts the `SignedPower` value from another, returning a new `SignedPower` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_micro_watts)
+            .expect("Overflow when subtracting power values")
+    }
+}
+
+impl iter::Sum for SignedPower {
+    /// Sums the `SignedPower` values, returning zero (0W) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a SignedPower> for SignedPower {
+    /// Sums the `SignedPower` values, returning zero (0W) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl ops::Neg for SignedPower {
+    type Output = Self;
+
+    /// Inverts the `SignedPower` value from positive to negative or negative to positive.
+    #[inline]
+    fn neg(self) -> Self {
+        self.invert()
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i: ty) => {
+        impl ops::Mul<$i> for SignedPower {
+            type Output = Self;
+
+            /// Multiplies the `SignedPower` value by an integer value, returning a new
+            /// `SignedPower` value.
+            #[inline]
+            fn mul(self, other: $i) -> Self {
+                self.raw
+                    .checked_mul(other as i64)
+                    .map(Self::from_micro_watts)
+                    .expect("Overflow when multiplying power value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for SignedPower {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for SignedPower {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply power value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply power value by NaN"),
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_micro_watts(result as i64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i: ty) => {
+        impl ops::Div<$i> for SignedPower {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide power value by zero");
+                }
+                self.raw
+                    .checked_div(divisor as i64)
+                    .map(Self::from_micro_watts)
+                    .expect("Overflow when dividing power value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for SignedPower {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for SignedPower {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide power value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide power value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide power value by NaN"),
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_micro_watts(result as i64)
+    }
+}
+
+impl TryFrom<Power> for SignedPower {
+    type Error = UnitError;
+
+    /// Converts an unsigned `Power` to a positive `SignedPower`.
+    ///
+    /// Returns an error if the power is too large to be represented.
+    fn try_from(power: Power) -> Result<Self, Self::Error> {
+        i64::try_from(power.micro_watts())
+            .map(Self::from_micro_watts)
+            .map_err(|_| UnitError::Overflow)
+    }
+}
+
+impl TryFrom<SignedPower> for Power {
+    type Error = UnitError;
+
+    /// Converts a `SignedPower` to an unsigned `Power`.
+    ///
+    /// Returns an error if the power is negative. Use `magnitude` to discard the direction
+    /// instead.
+    fn try_from(power: SignedPower) -> Result<Self, Self::Error> {
+        u64::try_from(power.raw)
+            .map(Power::from_micro_watts)
+            .map_err(|_| UnitError::Negative)
+    }
+}
+
+impl fmt::Display for SignedPower {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let (scale, unit) = self.display_scale();
+        let value = (self.raw.unsigned_abs() as f64) / (scale as f64);
+
+        display::write_padded(f, format_args!("{sign}{value:.precision$} {unit}"))
+    }
+}
+
+impl str::FromStr for SignedPower {
+    type Err = ParseError;
+
+    /// Parses a `SignedPower` value from a string with an optional sign, SI prefix and unit
+    /// symbol, such as `-22uW`, `1.5 mW` or `-330m`.
+    ///
+    /// Will be rounded toward zero to the nearest whole microwatt (μW).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_signed(s, &["W"], 6).map(Self::from_micro_watts)
+    }
+}
+
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+impl SignedPower {
+    /// Splits the power value into its sign, whole and hundredths parts in the most appropriate
+    /// unit, for formatting without floating-point math.
+    fn scaled_parts(&self) -> (&'static str, u64, u64, &'static str) {
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let micro_watts = self.raw.unsigned_abs();
+        let (scale, unit) = self.display_scale();
+
+        let whole = micro_watts / scale;
+        let fraction = micro_watts % scale * 100 / scale;
+
+        (sign, whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for SignedPower {
+    fn format(&self, f: defmt::Formatter) {
+        let (sign, whole, fraction, unit) = self.scaled_parts();
+
+        defmt::write!(
+            f,
+            "{=str}{=u64}.{=u64:02} {=str}",
+            sign,
+            whole,
+            fraction,
+            unit
+        )
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for SignedPower {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let (sign, whole, fraction, unit) = self.scaled_parts();
+        let padding = if fraction < 10 { "0" } else { "" };
+
+        ufmt::uwrite!(f, "{}{}.{}{} {}", sign, whole, padding, fraction, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(-1_500_000, (-2, 500_000), (-1, 998_500_000); "negative watts")]
+    #[test_case(2_500_000_000, (2_500, 0), (2, 500_000_000); "kilowatts")]
+    fn test_to_parts(micro_watts: i64, watts: (i64, u32), kilo_watts: (i64, u32)) {
+        let power = SignedPower::from_micro_watts(micro_watts);
+
+        assert_eq!(power.to_watts_parts(), watts);
+        assert_eq!(power.to_kilo_watts_parts(), kilo_watts);
+    }
+
+    #[test]
+    fn test_sign() {
+        let power = SignedPower::from_micro_watts(-1);
+
+        assert!(power.is_negative());
+        assert_eq!(-power, SignedPower::from_micro_watts(1));
+        assert_eq!(power.magnitude(), Power::from_micro_watts(1));
+        assert_eq!(SignedPower::from_micro_watts(i64::MIN).checked_neg(), None);
+    }
+
+    #[test_case(1_000, -3_000, Some(-2_000); "mixed signs")]
+    #[test_case(i64::MIN, -1, None; "overflow")]
+    fn test_checked_add(a: i64, b: i64, expected: Option<i64>) {
+        let sum = SignedPower::from_micro_watts(a).checked_add(SignedPower::from_micro_watts(b));
+        assert_eq!(sum.map(|p| p.micro_watts()), expected);
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [
+            SignedPower::from_micro_watts(5_000_000),
+            SignedPower::from_micro_watts(-7_500_000),
+        ];
+
+        assert_eq!(values.iter().sum::<SignedPower>().micro_watts(), -2_500_000);
+    }
+
+    #[test_case(u64::MAX, Err(UnitError::Overflow); "too large")]
+    #[test_case(500_000, Ok(500_000); "in range")]
+    fn test_try_from_power(micro_watts: u64, expected: Result<i64, UnitError>) {
+        let power = SignedPower::try_from(Power::from_micro_watts(micro_watts));
+        assert_eq!(power.map(|p| p.micro_watts()), expected);
+    }
+
+    #[test]
+    fn test_try_into_power() {
+        let power = Power::try_from(SignedPower::from_micro_watts(-1));
+        assert_eq!(power, Err(UnitError::Negative));
+    }
+
+    #[test_case(-22_720, "-22.72 mW"; "negative milliwatts")]
+    #[test_case(1_500_000, "1.50 W"; "watts")]
+    #[test_case(-2_500_000_000, "-2.50 kW"; "negative kilowatts")]
+    fn test_display(micro_watts: i64, expected: &str) {
+        let power = SignedPower::from_micro_watts(micro_watts);
+        assert_eq!(format!("{power}"), expected);
+    }
+
+    #[test_case("-1.5kW", Ok(-1_500_000_000); "negative kilowatts")]
+    #[test_case("250 mW", Ok(250_000); "milliwatts")]
+    #[test_case("1.5 A", Err(ParseError::InvalidUnit); "wrong unit")]
+    fn test_from_str(s: &str, expected: Result<i64, ParseError>) {
+        let power: Result<SignedPower, _> = s.parse();
+        assert_eq!(power.map(|p| p.micro_watts()), expected);
+    }
+
+    #[test]
+    fn test_format_into() {
+        let mut buf = [0u8; 16];
+        let power = SignedPower::from_micro_watts(-1_500_000);
+
+        assert_eq!(
+            power.format_into(&mut buf, PowerUnit::Watts, 1),
+            Ok("-1.5 W")
+        );
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test]
+    fn test_udisplay() {
+        let mut output = alloc::string::String::new();
+        ufmt::uwrite!(output, "{}", SignedPower::from_micro_watts(-2_500_000_000)).unwrap();
+        assert_eq!(output, "-2.50 kW");
+    }
+}
@@ -0,0 +1,344 @@
+//! SMD resistor marking codes.
+//!
+//! Three marking schemes are supported:
+//!
+//! - 3-digit codes, with two significant digits and a power of ten, such as `473` (47kΩ).
+//! - 4-digit codes, with three significant digits and a power of ten, such as `4702` (47kΩ).
+//! - EIA-96 codes, with a two digit index into the E96 series and a multiplier letter, such as
+//!   `01C` (10kΩ).
+//!
+//! Values below ten ohms use `R` as the decimal point, such as `4R7` (4.7Ω) or `R47` (0.47Ω).
+//!
+//! ```rust
+//! use ohms::prelude::*;
+//! use ohms::smd_code;
+//!
+//! assert_eq!(smd_code::decode("473").unwrap(), 47.kilo_ohms());
+//! assert_eq!(smd_code::decode("01C").unwrap(), 10.kilo_ohms());
+//!
+//! let code = smd_code::encode_3_digit(4.7.kilo_ohms()).unwrap();
+//! assert_eq!(code.as_str(), "472");
+//! ```
+use crate::eseries::ESeries;
+use crate::Resistance;
+use core::fmt;
+
+/// The EIA-96 multiplier letters, in order of their power of ten from `-3` to `5`.
+const EIA96_MULTIPLIERS: [char; 9] = ['Z', 'Y', 'X', 'A', 'B', 'C', 'D', 'E', 'F'];
+
+/// Error returned when decoding or encoding an SMD resistor code fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SmdCodeError {
+    /// The code is not a valid 3-digit, 4-digit or EIA-96 code.
+    InvalidCode,
+    /// The resistance is not a value in the E96 series, so has no EIA-96 code.
+    NotInSeries,
+    /// The resistance is too large to be represented by the code.
+    OutOfRange,
+}
+
+impl fmt::Display for SmdCodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let message = match self {
+            SmdCodeError::InvalidCode => "invalid SMD resistor code",
+            SmdCodeError::NotInSeries => "resistance is not an E96 value",
+            SmdCodeError::OutOfRange => "resistance cannot be represented by SMD code",
+        };
+
+        f.write_str(message)
+    }
+}
+
+/// An encoded SMD resistor code of up to four characters.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SmdCode {
+    bytes: [u8; 4],
+    len: usize,
+}
+
+impl SmdCode {
+    /// Returns the code as a string slice, such as `"473"`.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        // Codes are only ever built from ASCII digits and letters
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+
+    fn push(&mut self, c: char) {
+        self.bytes[self.len] = c as u8;
+        self.len += 1;
+    }
+}
+
+impl fmt::Display for SmdCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// Decodes a 3-digit, 4-digit or EIA-96 SMD resistor code into a `Resistance`.
+///
+/// Codes containing `R` are always treated as having a decimal point, so `10R` is 10Ω rather than
+/// the EIA-96 code for 1.24Ω. Use `Y` for the EIA-96 ×0.01 multiplier instead.
+pub fn decode(code: &str) -> Result<Resistance, SmdCodeError> {
+    let code = code.trim();
+
+    if code.contains('R') {
+        return decode_decimal(code);
+    }
+
+    let bytes = code.as_bytes();
+
+    match bytes {
+        [b'0'] | [b'0', b'0'] => Ok(Resistance::zero()),
+        [d1, d2, multiplier] if d1.is_ascii_digit() && d2.is_ascii_digit() => match multiplier {
+            b'0'..=b'9' => decode_digits(&bytes[..2], multiplier - b'0'),
+            _ => decode_eia96(((d1 - b'0') * 10 + (d2 - b'0')) as usize, *multiplier),
+        },
+        [_, _, _, exponent] if bytes.iter().all(u8::is_ascii_digit) => {
+            decode_digits(&bytes[..3], exponent - b'0')
+        }
+        _ => Err(SmdCodeError::InvalidCode),
+    }
+}
+
+/// Decodes significant digits followed by a power of ten in ohms.
+fn decode_digits(digits: &[u8], exponent: u8) -> Result<Resistance, SmdCodeError> {
+    let significand = digits
+        .iter()
+        .fold(0u64, |value, digit| value * 10 + (digit - b'0') as u64);
+
+    significand
+        .checked_mul(10u64.pow(exponent as u32 + 3))
+        .map(Resistance::from_milli_ohms)
+        .ok_or(SmdCodeError::OutOfRange)
+}
+
+/// Decodes an index into the E96 series followed by a multiplier letter.
+fn decode_eia96(index: usize, multiplier: u8) -> Result<Resistance, SmdCodeError> {
+    let value = index
+        .checked_sub(1)
+        .and_then(|index| ESeries::E96.values().get(index))
+        .ok_or(SmdCodeError::InvalidCode)?;
+
+    let exponent = match multiplier {
+        b'Z' => 0,
+        b'Y' => 1,
+        b'X' | b'S' => 2,
+        b'A' => 3,
+        b'B' | b'H' => 4,
+        b'C' => 5,
+        b'D' => 6,
+        b'E' => 7,
+        b'F' => 8,
+        _ => return Err(SmdCodeError::InvalidCode),
+    };
+
+    Ok(Resistance::from_milli_ohms(
+        *value as u64 * 10u64.pow(exponent),
+    ))
+}
+
+/// Decodes a code using `R` as the decimal point, such as `4R7` or `10R0`.
+fn decode_decimal(code: &str) -> Result<Resistance, SmdCodeError> {
+    if !(3..=4).contains(&code.len()) {
+        return Err(SmdCodeError::InvalidCode);
+    }
+
+    Resistance::from_rkm(code).map_err(|_| SmdCodeError::InvalidCode)
+}
+
+/// Encodes a resistance into a 3-digit SMD code, such as `473` (47kΩ) or `4R7` (4.7Ω).
+///
+/// The resistance is rounded to the nearest value that can be represented.
+pub fn encode_3_digit(resistance: Resistance) -> Result<SmdCode, SmdCodeError> {
+    encode_digits::<2>(resistance)
+}
+
+/// Encodes a resistance into a 4-digit SMD code, such as `4702` (47kΩ) or `4R70` (4.7Ω).
+///
+/// The resistance is rounded to the nearest value that can be represented.
+pub fn encode_4_digit(resistance: Resistance) -> Result<SmdCode, SmdCodeError> {
+    encode_digits::<3>(resistance)
+}
+
+/// Encodes a resistance into an EIA-96 SMD code, such as `01C` (10kΩ).
+///
+/// Returns an error if the resistance is not exactly a value in the E96 series.
+pub fn encode_eia96(resistance: Resistance) -> Result<SmdCode, SmdCodeError> {
+    let milli_ohms = resistance.milli_ohms();
+
+    for (exponent, multiplier) in EIA96_MULTIPLIERS.iter().enumerate() {
+        let scale = 10u64.pow(exponent as u32);
+
+        if !milli_ohms.is_multiple_of(scale) {
+            continue;
+        }
+
+        let value = milli_ohms / scale;
+
+        if let Ok(index) = ESeries::E96
+            .values()
+            .binary_search(&(value.min(u16::MAX as u64) as u16))
+        {
+            let index = index + 1;
+            let mut code = SmdCode {
+                bytes: [0; 4],
+                len: 0,
+            };
+
+            code.push((b'0' + (index / 10) as u8) as char);
+            code.push((b'0' + (index % 10) as u8) as char);
+            code.push(*multiplier);
+
+            return Ok(code);
+        }
+    }
+
+    Err(SmdCodeError::NotInSeries)
+}
+
+/// Encodes a resistance into `DIGITS` significant digits followed by a power of ten, using `R`
+/// as the decimal point for values too small for a power of ten.
+fn encode_digits<const DIGITS: u32>(resistance: Resistance) -> Result<SmdCode, SmdCodeError> {
+    let milli_ohms = resistance.milli_ohms() as u128;
+    let limit = 10u128.pow(DIGITS);
+
+    let mut code = SmdCode {
+        bytes: [0; 4],
+        len: 0,
+    };
+
+    if milli_ohms == 0 {
+        for _ in 0..=DIGITS {
+            code.push('0');
+        }
+
+        return Ok(code);
+    }
+
+    // The smallest resolution puts `R` before all of the significant digits
+    let mut exponent = 3 - DIGITS;
+
+    let significand = loop {
+        let scale = 10u128.pow(exponent);
+        let significand = (milli_ohms + scale / 2) / scale;
+
+        if significand < limit {
+            break significand as u32;
+        }
+
+        exponent += 1;
+    };
+
+    // Whole ohm multipliers are written as a trailing power of ten
+    if exponent >= 3 {
+        let power = exponent - 3;
+
+        if power > 9 {
+            return Err(SmdCodeError::OutOfRange);
+        }
+
+        push_digits(&mut code, significand, DIGITS, None);
+        code.push(char::from_digit(power, 10).unwrap_or('0'));
+    } else {
+        let decimal_point = DIGITS - (3 - exponent);
+        push_digits(&mut code, significand, DIGITS, Some(decimal_point));
+    }
+
+    Ok(code)
+}
+
+/// Pushes the zero-padded significant digits, with `R` inserted before the digit at
+/// `decimal_point` if given.
+fn push_digits(code: &mut SmdCode, significand: u32, digits: u32, decimal_point: Option<u32>) {
+    for position in 0..digits {
+        if decimal_point == Some(position) {
+            code.push('R');
+        }
+
+        let digit = significand / 10u32.pow(digits - position - 1) % 10;
+        code.push(char::from_digit(digit, 10).unwrap_or('0'));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case("473", Ok(47_000_000); "3-digit kilohms")]
+    #[test_case("100", Ok(10_000); "3-digit ten ohms")]
+    #[test_case("4R7", Ok(4_700); "3-digit decimal point")]
+    #[test_case("R47", Ok(470); "3-digit leading decimal point")]
+    #[test_case("4702", Ok(47_000_000); "4-digit kilohms")]
+    #[test_case("10R0", Ok(10_000); "4-digit decimal point")]
+    #[test_case("R010", Ok(10); "4-digit milliohms")]
+    #[test_case("01C", Ok(10_000_000); "EIA-96 10k")]
+    #[test_case("68X", Ok(49_900); "EIA-96 49.9 ohms")]
+    #[test_case("96F", Ok(97_600_000_000); "EIA-96 maximum")]
+    #[test_case("01Z", Ok(100); "EIA-96 smallest multiplier")]
+    #[test_case("0", Ok(0); "zero ohm jumper")]
+    #[test_case("000", Ok(0); "3-digit zero")]
+    #[test_case(" 472 ", Ok(4_700_000); "whitespace is ignored")]
+    #[test_case("", Err(SmdCodeError::InvalidCode); "empty string")]
+    #[test_case("97A", Err(SmdCodeError::InvalidCode); "EIA-96 index too large")]
+    #[test_case("00A", Err(SmdCodeError::InvalidCode); "EIA-96 index zero")]
+    #[test_case("01Q", Err(SmdCodeError::InvalidCode); "unknown multiplier")]
+    #[test_case("47k", Err(SmdCodeError::InvalidCode); "lowercase letter")]
+    #[test_case("47", Err(SmdCodeError::InvalidCode); "too short")]
+    #[test_case("47021", Err(SmdCodeError::InvalidCode); "too long")]
+    #[test_case("4R7R", Err(SmdCodeError::InvalidCode); "multiple decimal points")]
+    fn test_decode(code: &str, expected: Result<u64, SmdCodeError>) {
+        assert_eq!(decode(code).map(|r| r.milli_ohms()), expected);
+    }
+
+    #[test_case(47_000_000, "473"; "kilohms")]
+    #[test_case(10_000, "100"; "ten ohms")]
+    #[test_case(4_700, "4R7"; "below ten ohms")]
+    #[test_case(470, "R47"; "below one ohm")]
+    #[test_case(4_749_999, "472"; "rounds down")]
+    #[test_case(4_750_000, "482"; "rounds up")]
+    #[test_case(9_960, "100"; "rounding carries into multiplier")]
+    #[test_case(0, "000"; "zero")]
+    fn test_encode_3_digit(milli_ohms: u64, expected: &str) {
+        let code = encode_3_digit(Resistance::from_milli_ohms(milli_ohms)).unwrap();
+        assert_eq!(code.as_str(), expected);
+    }
+
+    #[test_case(47_000_000, "4702"; "kilohms")]
+    #[test_case(47_500, "47R5"; "tens of ohms")]
+    #[test_case(4_750, "4R75"; "below ten ohms")]
+    #[test_case(10, "R010"; "milliohms")]
+    #[test_case(0, "0000"; "zero")]
+    fn test_encode_4_digit(milli_ohms: u64, expected: &str) {
+        let code = encode_4_digit(Resistance::from_milli_ohms(milli_ohms)).unwrap();
+        assert_eq!(code.as_str(), expected);
+    }
+
+    #[test]
+    fn test_encode_out_of_range() {
+        let resistance = Resistance::from_milli_ohms(u64::MAX);
+
+        assert_eq!(encode_3_digit(resistance), Err(SmdCodeError::OutOfRange));
+        assert_eq!(encode_4_digit(resistance), Err(SmdCodeError::OutOfRange));
+    }
+
+    #[test_case(10_000_000, Ok("01C"); "10k")]
+    #[test_case(49_900, Ok("68X"); "49.9 ohms")]
+    #[test_case(100, Ok("01Z"); "100 milliohms")]
+    #[test_case(97_600_000_000, Ok("96F"); "maximum")]
+    #[test_case(4_700_000, Err(SmdCodeError::NotInSeries); "not an E96 value")]
+    #[test_case(1_000_000_000_000, Err(SmdCodeError::NotInSeries); "too large")]
+    fn test_encode_eia96(milli_ohms: u64, expected: Result<&str, SmdCodeError>) {
+        let code = encode_eia96(Resistance::from_milli_ohms(milli_ohms));
+        assert_eq!(code.as_ref().map(SmdCode::as_str).map_err(|e| *e), expected);
+    }
+
+    #[test]
+    fn test_display() {
+        let code = encode_3_digit(Resistance::from_milli_ohms(47_000_000)).unwrap();
+        assert_eq!(format!("{code:>5}"), "  473");
+    }
+}
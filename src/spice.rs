@@ -0,0 +1,123 @@
+use crate::{Current, Resistance, Voltage};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt;
+
+/// Builds a SPICE netlist from circuit components.
+///
+/// Component values are written in their base SI units (Ω, V and A).
+///
+/// Element names are prefixed with their SPICE designator (`R`, `V` or `I`) if they do not
+/// already begin with it.
+///
+/// Requires the `alloc` feature.
+///
+/// ```rust
+/// use ohms::prelude::*;
+/// use ohms::SpiceNetlist;
+///
+/// let mut netlist = SpiceNetlist::new("LED current limiter");
+/// netlist.add_voltage_source("V1", "vcc", "0", 5.volts());
+/// netlist.add_resistor("R1", "vcc", "out", 220.ohms());
+///
+/// println!("{}", netlist.to_string());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SpiceNetlist {
+    title: String,
+    elements: Vec<String>,
+}
+
+impl SpiceNetlist {
+    /// Creates a new empty `SpiceNetlist` with the given title.
+    pub fn new(title: &str) -> Self {
+        Self {
+            title: String::from(title),
+            elements: Vec::new(),
+        }
+    }
+
+    /// Adds a resistor between two nodes.
+    pub fn add_resistor(&mut self, name: &str, node_a: &str, node_b: &str, r: Resistance) {
+        self.add_element('R', name, node_a, node_b, format!("{}", r.ohms()));
+    }
+
+    /// Adds a DC voltage source between the positive and negative nodes.
+    pub fn add_voltage_source(&mut self, name: &str, plus: &str, minus: &str, v: Voltage) {
+        self.add_element('V', name, plus, minus, format!("DC {}", v.volts()));
+    }
+
+    /// Adds a DC current source between the positive and negative nodes.
+    ///
+    /// Following SPICE convention, current flows from the positive node through the source to
+    /// the negative node.
+    pub fn add_current_source(&mut self, name: &str, plus: &str, minus: &str, i: Current) {
+        self.add_element('I', name, plus, minus, format!("DC {}", i.amps()));
+    }
+
+    fn add_element(
+        &mut self,
+        designator: char,
+        name: &str,
+        node_a: &str,
+        node_b: &str,
+        value: String,
+    ) {
+        let has_designator = name
+            .chars()
+            .next()
+            .is_some_and(|first| first.eq_ignore_ascii_case(&designator));
+
+        let element = if has_designator {
+            format!("{name} {node_a} {node_b} {value}")
+        } else {
+            format!("{designator}{name} {node_a} {node_b} {value}")
+        };
+
+        self.elements.push(element);
+    }
+}
+
+impl fmt::Display for SpiceNetlist {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "* {}", self.title)?;
+
+        for element in &self.elements {
+            writeln!(f, "{element}")?;
+        }
+
+        writeln!(f, ".end")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_empty_netlist() {
+        let netlist = SpiceNetlist::new("empty");
+
+        assert_eq!(netlist.to_string(), "* empty\n.end\n");
+    }
+
+    #[test]
+    fn test_netlist_elements() {
+        let mut netlist = SpiceNetlist::new("divider");
+        netlist.add_voltage_source("V1", "in", "0", 3.3.volts());
+        netlist.add_resistor("R1", "in", "out", 4.7.kilo_ohms());
+        netlist.add_resistor("2", "out", "0", 10.kilo_ohms());
+        netlist.add_current_source("load", "out", "0", 250.micro_amps());
+
+        assert_eq!(
+            netlist.to_string(),
+            "* divider\n\
+             V1 in 0 DC 3.3\n\
+             R1 in out 4700\n\
+             R2 out 0 10000\n\
+             Iload out 0 DC 0.00025\n\
+             .end\n"
+        );
+    }
+}
@@ -0,0 +1,659 @@
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::{f64_to_i64, try_round_f64_to_i64, try_scale_f64_to_i64};
+use crate::parse::{parse_si_signed, ParseError};
+use crate::{RoundingMode, UnitError};
+use core::{cmp, fmt, hash, ops, str};
+
+/// Represents a temperature value, stored as whole millidegrees Celsius (m°C) as a signed 64-bit
+/// value. This value can be positive or negative.
+///
+/// **Reminder:** `1000 m°C = 1 °C, 0 °C = 273.15 K`
+///
+/// This is an immutable type. Any math operators return a new `Temperature` value.
+///
+/// # Creating a Temperature value
+/// You can create a `Temperature` value using the `from_milli_degrees_celsius` method, or using one
+/// of the extension methods on integer and floating-point types.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let t1 = Temperature::from_milli_degrees_celsius(25_500); // 25.5°C
+///
+/// // More ergonomic:
+/// let t2 = 85.degrees_celsius(); // 85°C
+/// let t3 = (-40).degrees_celsius(); // -40°C
+/// let t4 = 300.kelvin(); // 26.85°C
+/// ```
+///
+/// # Comparing Temperature values
+/// You can compare two `Temperature` values using the `==`, `!=`, `<`, `>`, `<=` and `>=` operators.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let t1 = 25.degrees_celsius(); // 25°C
+/// let t2 = 125.degrees_celsius(); // 125°C
+///
+/// if t1 > t2 {
+///     println!("{} is greater than {}", t1, t2);
+/// } else {
+///     println!("{} is less than or equal to {}", t1, t2);
+/// }
+/// ```
+///
+/// # Combining Temperature values
+/// You can use the `+` and `-` operators to add and subtract `Temperature` values from each other,
+/// such as applying a temperature rise to an ambient temperature.
+/// The result is a new `Temperature` value, rounded down to the nearest whole millidegree (m°C).
+///
+/// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let ambient = 25.degrees_celsius(); // 25°C
+/// let rise = 42.5.degrees_celsius(); // 42.5°C
+///
+/// let junction = ambient + rise; // 67.5°C
+/// let diff = junction - 60.degrees_celsius(); // 7.5°C
+/// ```
+///
+/// # Scaling Temperature values
+/// You can use the `*` and `/` operators to scale `Temperature` values by an integer or floating-point value.
+/// The result is a new `Temperature` value, rounded down to the nearest whole millidegree (m°C).
+///
+/// If the result of operation would overflow or underflow, the operation will panic.
+///
+/// If the result of the operation would be infinite or NaN, the operation will panic.
+///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let t1 = 10.degrees_celsius(); // 10°C
+/// let t2 = t1 * 3; // 30°C
+///
+/// let t3 = 45.degrees_celsius(); // 45°C
+/// let t4 = t3 / 2.0; // 22.5°C
+/// ```
+///
+/// # Converting to other denominations
+/// You can use the `milli_degrees_celsius`, `degrees_celsius`, `kelvin`, and `degrees_fahrenheit`
+/// methods to convert a `Temperature` value to a numeric value in the specified denomination.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let t1 = 25.degrees_celsius(); // 25°C
+///
+/// println!("{:.1} °C is {:.2} K", t1.degrees_celsius(), t1.kelvin());
+/// ```
+///
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Temperature {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_degrees_celsius"))]
+    raw: i64,
+}
+
+/// The offset between the Celsius and Kelvin scales, in millidegrees.
+const ZERO_CELSIUS_IN_MILLI_KELVIN: i64 = 273_150;
+
+impl Temperature {
+    /// Creates a new `Temperature` from a number of whole millidegrees Celsius (m°C).
+    ///
+    /// It is recommended to use the `milli_degrees_celsius`, `degrees_celsius`, and `kelvin`
+    /// extension methods on integer and floating-point types instead.
+    #[inline]
+    pub const fn from_milli_degrees_celsius(value: i64) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `Temperature` from a number of fractional millidegrees Celsius (m°C).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_degrees_celsius(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1f64).map(Self::from_milli_degrees_celsius)
+    }
+
+    /// Creates a new `Temperature` from a number of fractional degrees Celsius (°C).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_degrees_celsius(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000f64).map(Self::from_milli_degrees_celsius)
+    }
+
+    /// Creates a new `Temperature` from a number of fractional kelvin (K).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_kelvin(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000f64).and_then(Self::try_from_milli_kelvin)
+    }
+
+    /// Creates a new `Temperature` from a number of fractional kelvin (K), rounding to the nearest
+    /// whole millidegree (m°C) in the given direction rather than truncating.
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_kelvin_rounded(value: f64, mode: RoundingMode) -> Result<Self, UnitError> {
+        try_round_f64_to_i64(value, 1_000f64, mode).and_then(Self::try_from_milli_kelvin)
+    }
+
+    fn try_from_milli_kelvin(milli_kelvin: i64) -> Result<Self, UnitError> {
+        milli_kelvin
+            .checked_sub(ZERO_CELSIUS_IN_MILLI_KELVIN)
+            .map(Self::from_milli_degrees_celsius)
+            .ok_or(UnitError::Overflow)
+    }
+
+    /// Returns the temperature value in whole millidegrees Celsius (m°C).
+    #[inline]
+    pub const fn milli_degrees_celsius(&self) -> i64 {
+        self.raw
+    }
+
+    /// Returns the temperature value in fractional degrees Celsius (°C).
+    #[inline]
+    pub fn degrees_celsius(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns the temperature value in fractional kelvin (K).
+    #[inline]
+    pub fn kelvin(&self) -> f64 {
+        (self.raw as i128 + ZERO_CELSIUS_IN_MILLI_KELVIN as i128) as f64 / 1_000f64
+    }
+
+    /// Returns the temperature value in fractional degrees Fahrenheit (°F).
+    #[inline]
+    pub fn degrees_fahrenheit(&self) -> f64 {
+        self.raw as f64 * 9f64 / 5_000f64 + 32f64
+    }
+
+    /// Returns whether the temperature value is zero degrees Celsius (0°C).
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns whether the temperature value is positive.
+    ///
+    /// This returns `true` if the temperature value is greater than or equal to zero degrees
+    /// Celsius (0°C).
+    #[inline]
+    pub const fn is_positive(&self) -> bool {
+        self.raw >= 0
+    }
+
+    /// Returns whether the temperature value is negative.
+    ///
+    /// This returns `true` if the temperature value is less than zero degrees Celsius (0°C).
+    #[inline]
+    pub const fn is_negative(&self) -> bool {
+        self.raw < 0
+    }
+
+    /// Returns the absolute value of the temperature value.
+    #[inline]
+    pub const fn abs(&self) -> Self {
+        Self::from_milli_degrees_celsius(self.raw.abs())
+    }
+
+    /// Returns a `Temperature` value of zero degrees Celsius (0°C).
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_milli_degrees_celsius(0)
+    }
+
+    /// Adds two `Temperature` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_milli_degrees_celsius)
+    }
+
+    /// Subtracts one `Temperature` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_milli_degrees_celsius)
+    }
+
+    /// Multiplies the `Temperature` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: i64) -> Option<Self> {
+        self.raw
+            .checked_mul(factor)
+            .map(Self::from_milli_degrees_celsius)
+    }
+
+    /// Divides the `Temperature` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: i64) -> Option<Self> {
+        self.raw
+            .checked_div(divisor)
+            .map(Self::from_milli_degrees_celsius)
+    }
+
+    /// Multiplies the `Temperature` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN or the result would overflow.
+    ///
+    /// The result is rounded toward zero to the nearest whole millidegree (m°C).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(self.raw as f64 * factor).map(Self::from_milli_degrees_celsius)
+    }
+
+    /// Divides the `Temperature` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN or the result would overflow.
+    ///
+    /// The result is rounded toward zero to the nearest whole millidegree (m°C).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(self.raw as f64 / divisor).map(Self::from_milli_degrees_celsius)
+    }
+}
+
+approx::impl_approx_eq!(Temperature, signed);
+compare::impl_const_compare!(Temperature, signed);
+
+impl PartialEq for Temperature {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw
+    }
+}
+
+impl Eq for Temperature {}
+
+impl PartialOrd for Temperature {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Temperature {
+    #[inline]
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        self.raw.cmp(&other.raw)
+    }
+}
+
+impl hash::Hash for Temperature {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
+impl ops::Add for Temperature {
+    type Output = Self;
+
+    /// Adds two `Temperature` values together, returning a new `Temperature` value.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_milli_degrees_celsius)
+            .expect("Overflow when adding temperature values")
+    }
+}
+
+impl ops::Sub for Temperature {
+    type Output = Self;
+
+    /// Subtracts the `Temperature` value from another, returning a new `Temperature` value.
+    #[inline]
+    fn sub(self, other: Self) -> Self {
+        self.raw
+            .checked_sub(other.raw)
+            .map(Self::from_milli_degrees_celsius)
+            .expect("Overflow when subtracting temperature values")
+    }
+}
+
+macro_rules! impl_mul_for_integer {
+    ($i: ty) => {
+        impl ops::Mul<$i> for Temperature {
+            type Output = Self;
+
+            /// Multiplies the `Temperature` value by an integer value, returning a new `Temperature` value.
+            #[inline]
+            fn mul(self, other: $i) -> Self {
+                self.raw
+                    .checked_mul(other as i64)
+                    .map(Self::from_milli_degrees_celsius)
+                    .expect("Overflow when multiplying temperature value")
+            }
+        }
+    };
+}
+
+impl_mul_for_integer!(u8);
+impl_mul_for_integer!(u16);
+impl_mul_for_integer!(u32);
+impl_mul_for_integer!(u64);
+impl_mul_for_integer!(i8);
+impl_mul_for_integer!(i16);
+impl_mul_for_integer!(i32);
+impl_mul_for_integer!(i64);
+
+impl ops::Mul<f32> for Temperature {
+    type Output = Self;
+
+    /// Multiplies the `Temperature` value by a floating-point value, returning a new `Temperature` value.
+    #[inline]
+    fn mul(self, scale_factor: f32) -> Self {
+        self * scale_factor as f64
+    }
+}
+
+impl ops::Mul<f64> for Temperature {
+    type Output = Self;
+
+    /// Multiplies the `Temperature` value by a floating-point value, returning a new `Temperature` value.
+    #[inline]
+    fn mul(self, scale_factor: f64) -> Self {
+        let result = match scale_factor {
+            _ if scale_factor.is_infinite() => {
+                panic!("Cannot multiply temperature value by infinity")
+            }
+            _ if scale_factor.is_nan() => panic!("Cannot multiply temperature value by NaN"),
+            _ => self.raw as f64 * scale_factor,
+        };
+
+        Self::from_milli_degrees_celsius(result as i64)
+    }
+}
+
+macro_rules! impl_div_for_integer {
+    ($i: ty) => {
+        impl ops::Div<$i> for Temperature {
+            type Output = Self;
+
+            /// Divides the `Temperature` value by an integer value, returning a new `Temperature` value.
+            #[inline]
+            fn div(self, divisor: $i) -> Self {
+                if divisor == 0 {
+                    panic!("Cannot divide temperature value by zero");
+                }
+                self.raw
+                    .checked_div(divisor as i64)
+                    .map(Self::from_milli_degrees_celsius)
+                    .expect("Overflow when dividing temperature value")
+            }
+        }
+    };
+}
+
+impl_div_for_integer!(u8);
+impl_div_for_integer!(u16);
+impl_div_for_integer!(u32);
+impl_div_for_integer!(u64);
+impl_div_for_integer!(i8);
+impl_div_for_integer!(i16);
+impl_div_for_integer!(i32);
+impl_div_for_integer!(i64);
+
+impl ops::Div<f32> for Temperature {
+    type Output = Self;
+
+    /// Divides the `Temperature` value by a floating-point value, returning a new `Temperature` value.
+    #[inline]
+    fn div(self, divisor: f32) -> Self {
+        self / divisor as f64
+    }
+}
+
+impl ops::Div<f64> for Temperature {
+    type Output = Self;
+
+    /// Divides the `Temperature` value by a floating-point value, returning a new `Temperature` value.
+    #[inline]
+    fn div(self, divisor: f64) -> Self {
+        let result = match divisor {
+            _ if divisor == 0f64 => panic!("Cannot divide temperature value by zero"),
+            _ if divisor.is_infinite() => {
+                panic!("Cannot divide temperature value by infinity")
+            }
+            _ if divisor.is_nan() => panic!("Cannot divide temperature value by NaN"),
+            _ => (self.raw as f64) / divisor,
+        };
+
+        Self::from_milli_degrees_celsius(result as i64)
+    }
+}
+
+/// Extension trait for simple short-hands for creating `Temperature` values from integer values.
+pub trait FromInteger {
+    /// Creates a new `Temperature` from a number of whole millidegrees Celsius (m°C).
+    fn milli_degrees_celsius(self) -> Temperature;
+
+    /// Creates a new `Temperature` from a number of whole degrees Celsius (°C).
+    fn degrees_celsius(self) -> Temperature;
+
+    /// Creates a new `Temperature` from a number of whole kelvin (K).
+    fn kelvin(self) -> Temperature;
+}
+
+macro_rules! impl_temperature_from_integer {
+    ($i: ty) => {
+        impl FromInteger for $i {
+            #[inline]
+            fn milli_degrees_celsius(self) -> Temperature {
+                Temperature::from_milli_degrees_celsius(self as i64)
+            }
+
+            #[inline]
+            fn degrees_celsius(self) -> Temperature {
+                let millidegrees = (self as i64)
+                    .checked_mul(1_000)
+                    .expect("Overflow when converting degrees to millidegrees");
+                Temperature::from_milli_degrees_celsius(millidegrees)
+            }
+
+            #[inline]
+            fn kelvin(self) -> Temperature {
+                let millidegrees = (self as i64)
+                    .checked_mul(1_000)
+                    .and_then(|milli_kelvin| milli_kelvin.checked_sub(ZERO_CELSIUS_IN_MILLI_KELVIN))
+                    .expect("Overflow when converting kelvin to millidegrees");
+                Temperature::from_milli_degrees_celsius(millidegrees)
+            }
+        }
+    };
+}
+
+impl_temperature_from_integer!(u8);
+impl_temperature_from_integer!(u16);
+impl_temperature_from_integer!(u32);
+impl_temperature_from_integer!(u64);
+impl_temperature_from_integer!(i8);
+impl_temperature_from_integer!(i16);
+impl_temperature_from_integer!(i32);
+impl_temperature_from_integer!(i64);
+
+/// Extension trait for simple short-hands for creating `Temperature` values from floating-point values.
+pub trait FromFloat {
+    /// Creates a new `Temperature` from a number of fractional millidegrees Celsius (m°C).
+    ///
+    /// The fractional part is rounded toward zero to the nearest whole millidegree (m°C).
+    fn milli_degrees_celsius(self) -> Temperature;
+
+    /// Creates a new `Temperature` from a number of fractional degrees Celsius (°C).
+    ///
+    /// The fractional part is rounded toward zero to the nearest whole millidegree (m°C).
+    fn degrees_celsius(self) -> Temperature;
+
+    /// Creates a new `Temperature` from a number of fractional kelvin (K).
+    ///
+    /// The fractional part is rounded toward zero to the nearest whole millidegree (m°C).
+    fn kelvin(self) -> Temperature;
+}
+
+macro_rules! impl_temperature_from_float {
+    ($f: ty) => {
+        impl FromFloat for $f {
+            #[inline]
+            fn milli_degrees_celsius(self) -> Temperature {
+                Temperature::from_milli_degrees_celsius(self as i64)
+            }
+
+            #[inline]
+            fn degrees_celsius(self) -> Temperature {
+                let millidegrees = (self as f64) * 1_000f64;
+                Temperature::from_milli_degrees_celsius(millidegrees as i64)
+            }
+
+            #[inline]
+            fn kelvin(self) -> Temperature {
+                let millidegrees = (self as f64) * 1_000f64 - ZERO_CELSIUS_IN_MILLI_KELVIN as f64;
+                Temperature::from_milli_degrees_celsius(millidegrees as i64)
+            }
+        }
+    };
+}
+
+impl_temperature_from_float!(f32);
+impl_temperature_from_float!(f64);
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
+        let value = self.degrees_celsius();
+
+        display::write_padded(f, format_args!("{value:.precision$} °C"))
+    }
+}
+
+impl str::FromStr for Temperature {
+    type Err = ParseError;
+
+    /// Parses a `Temperature` value from a string in degrees Celsius or kelvin, with an optional
+    /// SI prefix, such as `25 °C`, `-40.5°C`, `300K` or `21.5`.
+    ///
+    /// Values without a unit symbol are in degrees Celsius. Will be rounded toward zero to the
+    /// nearest whole millidegree (m°C).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        match s.strip_suffix('K') {
+            Some(kelvin) => parse_si_signed(kelvin, &[], 3)?
+                .checked_sub(ZERO_CELSIUS_IN_MILLI_KELVIN)
+                .map(Self::from_milli_degrees_celsius)
+                .ok_or(ParseError::Overflow),
+            None => parse_si_signed(s, &["°C"], 3).map(Self::from_milli_degrees_celsius),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(25.degrees_celsius(), 25_000; "25°C")]
+    #[test_case((-40).degrees_celsius(), -40_000; "negative degrees")]
+    #[test_case(0.kelvin(), -273_150; "absolute zero")]
+    #[test_case(300.kelvin(), 26_850; "300K")]
+    #[test_case(273.15.kelvin(), 0; "273.15K equals 0°C")]
+    #[test_case(36.6.degrees_celsius(), 36_600; "fractional degrees")]
+    fn test_extension_methods(temperature: Temperature, expected_milli_degrees: i64) {
+        assert_eq!(temperature.milli_degrees_celsius(), expected_milli_degrees);
+    }
+
+    #[test_case(0, 273.15, 32.0; "freezing point")]
+    #[test_case(100_000, 373.15, 212.0; "boiling point")]
+    #[test_case(-40_000, 233.15, -40.0; "Celsius equals Fahrenheit")]
+    #[test_case(i64::MAX, 9_223_372_036_855_048.0, 16_602_069_666_338_628.0; "maximum value")]
+    fn test_conversions(milli_degrees: i64, expected_kelvin: f64, expected_fahrenheit: f64) {
+        let temperature = Temperature::from_milli_degrees_celsius(milli_degrees);
+
+        assert!((temperature.kelvin() - expected_kelvin).abs() < 1e-9);
+        assert!((temperature.degrees_fahrenheit() - expected_fahrenheit).abs() < 1e-9);
+    }
+
+    #[test_case(i64::MAX, 1, None; "overflow is none")]
+    #[test_case(-25_000, 30_000, Some(5_000); "adds rise to negative temperature")]
+    fn test_checked_add(a: i64, b: i64, expected: Option<i64>) {
+        let result = Temperature::from_milli_degrees_celsius(a)
+            .checked_add(Temperature::from_milli_degrees_celsius(b));
+        assert_eq!(result.map(|t| t.raw), expected);
+    }
+
+    #[test_case(25_000, "25.00 °C"; "positive degrees")]
+    #[test_case(-40_500, "-40.50 °C"; "negative degrees")]
+    #[test_case(0, "0.00 °C"; "zero")]
+    fn test_display(milli_degrees: i64, expected: &str) {
+        let temperature = Temperature::from_milli_degrees_celsius(milli_degrees);
+        assert_eq!(format!("{temperature}"), expected);
+    }
+
+    #[test_case("25 °C", Ok(25_000); "degrees celsius with space")]
+    #[test_case("-40.5°C", Ok(-40_500); "negative degrees celsius")]
+    #[test_case("21.5", Ok(21_500); "degrees celsius without symbol")]
+    #[test_case("300K", Ok(26_850); "kelvin")]
+    #[test_case("0 K", Ok(-273_150); "absolute zero")]
+    #[test_case("500 m°C", Ok(500); "milli prefix")]
+    #[test_case("°C", Err(ParseError::Empty); "symbol only")]
+    fn test_from_str(s: &str, expected: Result<i64, ParseError>) {
+        let result = s.parse::<Temperature>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(Temperature::try_from_degrees_celsius(-1.5), Ok(Temperature::from_milli_degrees_celsius(-1_500)); "valid value")]
+    #[test_case(Temperature::try_from_degrees_celsius(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Temperature::try_from_degrees_celsius(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Temperature::try_from_degrees_celsius(1e30), Err(UnitError::Overflow); "too large")]
+    #[test_case(Temperature::try_from_kelvin(0.0), Ok(Temperature::from_milli_degrees_celsius(-273_150)); "absolute zero")]
+    #[test_case(Temperature::try_from_kelvin(273.1496), Ok(Temperature::from_milli_degrees_celsius(-1)); "kelvin truncates")]
+    #[test_case(Temperature::try_from_kelvin_rounded(273.1496, RoundingMode::Nearest), Ok(Temperature::from_milli_degrees_celsius(0)); "kelvin rounds to nearest")]
+    #[test_case(Temperature::try_from_kelvin_rounded(f64::NAN, RoundingMode::Up), Err(UnitError::NaN); "rounded NaN")]
+    fn test_try_from(
+        result: Result<Temperature, UnitError>,
+        expected: Result<Temperature, UnitError>,
+    ) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Temperature::from_milli_degrees_celsius(-40500),
+            &[
+                Token::Struct {
+                    name: "Temperature",
+                    len: 1,
+                },
+                Token::Str("milli_degrees_celsius"),
+                Token::I64(-40500),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,364 @@
+use crate::display;
+use crate::math::f64_to_u64;
+use crate::UnitError;
+#[cfg(feature = "power")]
+use crate::{Percent, Power, Temperature};
+use core::{fmt, iter, ops};
+
+/// Represents a thermal resistance, stored as whole millidegrees Celsius per watt (m°C/W) as a
+/// 32-bit value.
+///
+/// This is the temperature rise across a thermal path, such as from a junction to the ambient
+/// air, for each watt of heat flowing through it. Thermal resistances in series add together, so
+/// the junction-to-case, case-to-sink and sink-to-ambient values can be summed.
+///
+/// ```rust
+/// use ohms::prelude::*;
+///
+/// let junction_to_case = ThermalResistance::from_milli_degrees_celsius_per_watt(1_500);
+/// let case_to_ambient = ThermalResistance::from_milli_degrees_celsius_per_watt(60_000);
+///
+/// let total = junction_to_case + case_to_ambient;
+/// assert_eq!(total.degrees_celsius_per_watt(), 61.5);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThermalResistance {
+    #[cfg_attr(feature = "serde", serde(rename = "milli_degrees_celsius_per_watt"))]
+    raw: u32,
+}
+
+impl ThermalResistance {
+    /// Creates a new `ThermalResistance` from a number of whole millidegrees Celsius per watt
+    /// (m°C/W).
+    #[inline]
+    pub const fn from_milli_degrees_celsius_per_watt(value: u32) -> Self {
+        Self { raw: value }
+    }
+
+    /// Creates a new `ThermalResistance` from a number of fractional degrees Celsius per watt
+    /// (°C/W).
+    ///
+    /// Will be rounded down to the nearest whole millidegree Celsius per watt (m°C/W).
+    /// Returns an error if the value is NaN, infinite, negative or too large to be represented.
+    pub fn try_from_degrees_celsius_per_watt(value: f64) -> Result<Self, UnitError> {
+        if value.is_nan() {
+            return Err(UnitError::NaN);
+        }
+        if value.is_infinite() {
+            return Err(UnitError::Infinite);
+        }
+        if value < 0f64 {
+            return Err(UnitError::Negative);
+        }
+
+        f64_to_u64(value * 1_000f64)
+            .and_then(|raw| u32::try_from(raw).ok())
+            .map(Self::from_milli_degrees_celsius_per_watt)
+            .ok_or(UnitError::Overflow)
+    }
+
+    /// Returns the thermal resistance in whole millidegrees Celsius per watt (m°C/W).
+    #[inline]
+    pub const fn milli_degrees_celsius_per_watt(&self) -> u32 {
+        self.raw
+    }
+
+    /// Returns the thermal resistance in fractional degrees Celsius per watt (°C/W).
+    #[inline]
+    pub fn degrees_celsius_per_watt(&self) -> f64 {
+        self.raw as f64 / 1_000f64
+    }
+
+    /// Returns whether the thermal resistance is zero.
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.raw == 0
+    }
+
+    /// Returns a `ThermalResistance` value of zero.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self::from_milli_degrees_celsius_per_watt(0)
+    }
+
+    /// Adds two `ThermalResistance` values in series, returning `None` if the result would
+    /// overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw
+            .checked_add(other.raw)
+            .map(Self::from_milli_degrees_celsius_per_watt)
+    }
+
+    /// Calculates the temperature rise across the thermal path for the given heat flow
+    /// (`ΔT = P × Rth`).
+    ///
+    /// Will be rounded down to the nearest whole millidegree Celsius (m°C).
+    /// Returns `None` if the result would overflow.
+    #[cfg(feature = "power")]
+    pub fn temperature_rise(&self, dissipation: Power) -> Option<Temperature> {
+        let milli_celsius = dissipation.micro_watts() as u128 * self.raw as u128 / 1_000_000;
+
+        i64::try_from(milli_celsius)
+            .ok()
+            .map(Temperature::from_milli_degrees_celsius)
+    }
+}
+
+impl ops::Add for ThermalResistance {
+    type Output = Self;
+
+    /// Adds two `ThermalResistance` values in series, returning a new value.
+    /// Panics if the result would overflow.
+    #[inline]
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("Overflow when adding thermal resistance values")
+    }
+}
+
+impl iter::Sum for ThermalResistance {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a ThermalResistance> for ThermalResistance {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl fmt::Display for ThermalResistance {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        display::write_padded(
+            f,
+            format_args!("{}.{:03} °C/W", self.raw / 1_000, self.raw % 1_000),
+        )
+    }
+}
+
+/// Estimates the junction temperature of a device from the ambient temperature, the power it
+/// dissipates and the thermal resistance from its junction to the ambient air
+/// (`Tj = Ta + P × Rth`).
+///
+/// Will be rounded down to the nearest whole millidegree Celsius (m°C).
+/// Returns `None` if the result would overflow.
+///
+/// ```rust
+/// use ohms::junction_temperature;
+/// use ohms::prelude::*;
+///
+/// let rth = ThermalResistance::from_milli_degrees_celsius_per_watt(62_500); // TO-220, no heatsink
+/// let tj = junction_temperature(40.degrees_celsius(), 1.2.watts(), rth).unwrap();
+///
+/// assert_eq!(tj, 115.degrees_celsius());
+/// ```
+#[cfg(feature = "power")]
+pub fn junction_temperature(
+    ambient: Temperature,
+    dissipation: Power,
+    rth: ThermalResistance,
+) -> Option<Temperature> {
+    rth.temperature_rise(dissipation)
+        .and_then(|rise| ambient.checked_add(rise))
+}
+
+/// Describes how the allowed power dissipation of a component, such as a resistor, is derated
+/// with temperature.
+///
+/// The full rated power is allowed up to the start temperature, above which it falls linearly by
+/// the slope, a percentage of the rated power for each degree Celsius, until it reaches zero.
+///
+/// ```rust
+/// use ohms::prelude::*;
+/// use ohms::PowerDerating;
+///
+/// // 250mW up to 70°C, derated to zero at 155°C
+/// let derating = PowerDerating::new(250.milli_watts(), 70.degrees_celsius(), Percent::from_milli_percent(1_176));
+///
+/// assert_eq!(derating.allowed_power(100.degrees_celsius()), 161_800.micro_watts());
+/// assert!(!derating.is_within(200.milli_watts(), 100.degrees_celsius()));
+/// ```
+#[cfg(feature = "power")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PowerDerating {
+    rated: Power,
+    start: Temperature,
+    slope: Percent,
+}
+
+#[cfg(feature = "power")]
+impl PowerDerating {
+    /// Creates a new `PowerDerating` from the rated power, the temperature above which it is
+    /// derated and the slope as a percentage of the rated power per degree Celsius (%/°C).
+    #[inline]
+    pub const fn new(rated: Power, start: Temperature, slope: Percent) -> Self {
+        Self {
+            rated,
+            start,
+            slope,
+        }
+    }
+
+    /// Returns the rated power, which is allowed up to the start temperature.
+    #[inline]
+    pub const fn rated(&self) -> Power {
+        self.rated
+    }
+
+    /// Returns the temperature above which the rated power is derated.
+    #[inline]
+    pub const fn start(&self) -> Temperature {
+        self.start
+    }
+
+    /// Returns the slope as a percentage of the rated power per degree Celsius (%/°C).
+    #[inline]
+    pub const fn slope(&self) -> Percent {
+        self.slope
+    }
+
+    /// Calculates the allowed power dissipation at the given operating temperature.
+    ///
+    /// Will be rounded down to the nearest whole microwatt (μW), and never falls below zero.
+    pub fn allowed_power(&self, temperature: Temperature) -> Power {
+        let above = temperature.milli_degrees_celsius() as i128
+            - self.start.milli_degrees_celsius() as i128;
+        if above <= 0 {
+            return self.rated;
+        }
+
+        // Slope is in m%/°C and the rise in m°C, so full power is 100_000 × 1_000
+        let full = 100_000_000u128;
+        let lost = (above as u128).saturating_mul(self.slope.milli_percent() as u128);
+        if lost >= full {
+            return Power::zero();
+        }
+
+        let micro_watts = self.rated.micro_watts() as u128 * (full - lost) / full;
+        Power::from_micro_watts(micro_watts as u64)
+    }
+
+    /// Returns whether the given power dissipation is allowed at the operating temperature.
+    #[inline]
+    pub fn is_within(&self, power: Power, temperature: Temperature) -> bool {
+        power <= self.allowed_power(temperature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(62.5, Ok(62_500); "fraction")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(5e6, Err(UnitError::Overflow); "overflow")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    fn test_try_from_degrees_celsius_per_watt(value: f64, expected: Result<u32, UnitError>) {
+        let rth = ThermalResistance::try_from_degrees_celsius_per_watt(value)
+            .map(|r| r.milli_degrees_celsius_per_watt());
+        assert_eq!(rth, expected);
+    }
+
+    #[test]
+    fn test_series() {
+        let values = [
+            ThermalResistance::from_milli_degrees_celsius_per_watt(1_500),
+            ThermalResistance::from_milli_degrees_celsius_per_watt(500),
+            ThermalResistance::from_milli_degrees_celsius_per_watt(20_000),
+        ];
+
+        let total: ThermalResistance = values.iter().sum();
+        assert_eq!(total.degrees_celsius_per_watt(), 22.0);
+        assert_eq!(
+            ThermalResistance::from_milli_degrees_celsius_per_watt(u32::MAX)
+                .checked_add(ThermalResistance::from_milli_degrees_celsius_per_watt(1)),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_overflow_panics() {
+        let _ = ThermalResistance::from_milli_degrees_celsius_per_watt(u32::MAX)
+            + ThermalResistance::from_milli_degrees_celsius_per_watt(1);
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(40_000, 1_200_000, 62_500, Some(115_000); "TO-220 at 1.2W")]
+    #[test_case(-20_000, 500_000, 40_000, Some(0); "below freezing ambient")]
+    #[test_case(25_000, 0, 62_500, Some(25_000); "no dissipation")]
+    #[test_case(25_000, 1, 999, Some(25_000); "rounds down")]
+    #[test_case(i64::MAX, 1_000_000, 1_000, None; "overflow")]
+    fn test_junction_temperature(ambient: i64, micro_watts: u64, rth: u32, expected: Option<i64>) {
+        let tj = junction_temperature(
+            Temperature::from_milli_degrees_celsius(ambient),
+            Power::from_micro_watts(micro_watts),
+            ThermalResistance::from_milli_degrees_celsius_per_watt(rth),
+        );
+
+        assert_eq!(tj.map(|t| t.milli_degrees_celsius()), expected);
+    }
+
+    #[cfg(feature = "power")]
+    fn derating_250mw() -> PowerDerating {
+        // Derated from 70°C to zero at 155°C
+        PowerDerating::new(
+            Power::from_micro_watts(250_000),
+            Temperature::from_milli_degrees_celsius(70_000),
+            Percent::from_milli_percent(1_176),
+        )
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(-40_000, 250_000; "cold")]
+    #[test_case(70_000, 250_000; "at start")]
+    #[test_case(100_000, 161_800; "derated")]
+    #[test_case(70_001, 249_997; "just above start")]
+    #[test_case(155_000, 100; "near zero")]
+    #[test_case(156_000, 0; "beyond zero")]
+    #[test_case(i64::MAX, 0; "extreme")]
+    fn test_allowed_power(milli_celsius: i64, expected: u64) {
+        let allowed =
+            derating_250mw().allowed_power(Temperature::from_milli_degrees_celsius(milli_celsius));
+        assert_eq!(allowed.micro_watts(), expected);
+    }
+
+    #[cfg(feature = "power")]
+    #[test_case(200_000, 25_000, true; "within at room temperature")]
+    #[test_case(250_000, 70_000, true; "rated at start")]
+    #[test_case(200_000, 100_000, false; "violates when hot")]
+    #[test_case(1, 160_000, false; "anything beyond zero")]
+    fn test_is_within(micro_watts: u64, milli_celsius: i64, expected: bool) {
+        let within = derating_250mw().is_within(
+            Power::from_micro_watts(micro_watts),
+            Temperature::from_milli_degrees_celsius(milli_celsius),
+        );
+        assert_eq!(within, expected);
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    fn test_no_slope() {
+        let derating = PowerDerating::new(
+            Power::from_micro_watts(250_000),
+            Temperature::from_milli_degrees_celsius(70_000),
+            Percent::zero(),
+        );
+        let allowed = derating.allowed_power(Temperature::from_milli_degrees_celsius(150_000));
+        assert_eq!(allowed.micro_watts(), 250_000);
+    }
+
+    #[test_case(62_500, "62.500 °C/W"; "fraction")]
+    #[test_case(0, "0.000 °C/W"; "zero")]
+    fn test_display(rth: u32, expected: &str) {
+        let rth = ThermalResistance::from_milli_degrees_celsius_per_watt(rth);
+        assert_eq!(format!("{rth}"), expected);
+    }
+}
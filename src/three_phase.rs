@@ -0,0 +1,224 @@
+//! Calculations for balanced three-phase AC systems.
+//!
+//! All voltages and currents are RMS values. In a balanced system the line-to-line voltage is
+//! `√3` times the line-to-neutral (phase) voltage, and the total power is
+//! `P = √3 × V(line-to-line) × I(line) × PF`. The `√3` factors are applied with integer math.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "voltage", feature = "current", feature = "power"))] {
+//! use ohms::prelude::*;
+//! use ohms::three_phase;
+//!
+//! let phase = three_phase::line_to_neutral(400.volts());
+//! assert_eq!(phase, 230_940_108.micro_volts());
+//!
+//! let pf = PowerFactor::try_from_ratio(0.85).unwrap();
+//! let power = three_phase::real_power(400.volts(), 10.amps(), pf).unwrap();
+//! assert_eq!(power, Power::from_micro_watts(5_888_972_745)); // 5.89kW
+//! # }
+//! ```
+use crate::Voltage;
+#[cfg(all(feature = "current", feature = "power"))]
+use crate::{ApparentPower, Current, Power, PowerFactor};
+
+/// The square root of three, scaled by 10¹⁸.
+const SQRT_3_E18: u128 = 1_732_050_807_568_877_294;
+
+/// The scale of `SQRT_3_E18`, so that 10¹⁸ is a factor of one.
+const ONE_E18: u128 = 1_000_000_000_000_000_000;
+
+/// Converts a line-to-line voltage to the line-to-neutral (phase) voltage of a balanced system
+/// (`V(LN) = V(LL) ÷ √3`).
+///
+/// The sign of the voltage is kept. Will be rounded to the nearest whole microvolt (μV).
+pub fn line_to_neutral(line_to_line: Voltage) -> Voltage {
+    let magnitude = scale(line_to_line, ONE_E18, SQRT_3_E18);
+
+    // Dividing by √3 always makes the magnitude smaller, so it can be represented
+    signed(line_to_line, magnitude).expect("Line-to-neutral voltage is within range")
+}
+
+/// Converts a line-to-neutral (phase) voltage to the line-to-line voltage of a balanced system
+/// (`V(LL) = V(LN) × √3`), returning `None` if the result would overflow.
+///
+/// The sign of the voltage is kept. Will be rounded to the nearest whole microvolt (μV).
+pub fn checked_line_to_line(line_to_neutral: Voltage) -> Option<Voltage> {
+    let magnitude = scale(line_to_neutral, SQRT_3_E18, ONE_E18);
+
+    signed(line_to_neutral, magnitude)
+}
+
+/// Converts a line-to-neutral (phase) voltage to the line-to-line voltage of a balanced system
+/// (`V(LL) = V(LN) × √3`).
+///
+/// The sign of the voltage is kept. Will be rounded to the nearest whole microvolt (μV).
+/// Panics if the result would overflow.
+pub fn line_to_line(line_to_neutral: Voltage) -> Voltage {
+    checked_line_to_line(line_to_neutral).expect("Overflow when converting to line-to-line voltage")
+}
+
+/// Calculates the total apparent power of a balanced three-phase load from its line-to-line
+/// voltage and line current (`S = √3 × V(LL) × I`).
+///
+/// Will be rounded down to the nearest whole microvolt-ampere (μVA).
+/// Returns `None` if the result would overflow.
+#[cfg(all(feature = "current", feature = "power"))]
+pub fn apparent_power(line_to_line: Voltage, line_current: Current) -> Option<ApparentPower> {
+    let volt_amps = line_to_line.micro_volts().unsigned_abs() as u128
+        * line_current.micro_amps() as u128
+        / 1_000_000;
+
+    // Larger products would overflow after multiplying by √3 anyway
+    if volt_amps > u64::MAX as u128 {
+        return None;
+    }
+
+    u64::try_from(volt_amps * SQRT_3_E18 / ONE_E18)
+        .ok()
+        .map(ApparentPower::from_micro_volt_amps)
+}
+
+/// Calculates the total real power delivered to a balanced three-phase load from its line-to-line
+/// voltage, line current and power factor (`P = √3 × V(LL) × I × PF`).
+///
+/// Will be rounded down to the nearest whole microwatt (μW).
+/// Returns `None` if the result would overflow.
+#[cfg(all(feature = "current", feature = "power"))]
+pub fn real_power(
+    line_to_line: Voltage,
+    line_current: Current,
+    power_factor: PowerFactor,
+) -> Option<Power> {
+    let apparent = apparent_power(line_to_line, line_current)?;
+
+    Some(crate::real_power(apparent, power_factor))
+}
+
+/// Calculates the line current drawn by a balanced three-phase load from its total real power,
+/// line-to-line voltage and power factor (`I = P ÷ (√3 × V(LL) × PF)`).
+///
+/// Will be rounded down to the nearest whole microamp (μA).
+/// Returns `None` if the voltage or power factor is zero, or the result would overflow.
+#[cfg(all(feature = "current", feature = "power"))]
+pub fn line_current(
+    power: Power,
+    line_to_line: Voltage,
+    power_factor: PowerFactor,
+) -> Option<Current> {
+    let apparent = crate::apparent_power(power, power_factor)?;
+
+    let voltage = line_to_line.micro_volts().unsigned_abs() as u128;
+    if voltage == 0 {
+        return None;
+    }
+
+    // The current before dividing by √3, which must be less than twice the maximum to fit after
+    let micro_amps = apparent.micro_volt_amps() as u128 * 1_000_000 / voltage;
+    if micro_amps > 2 * u64::MAX as u128 {
+        return None;
+    }
+
+    u64::try_from(micro_amps * ONE_E18 / SQRT_3_E18)
+        .ok()
+        .map(Current::from_micro_amps)
+}
+
+/// Scales the magnitude of a voltage by `numerator ÷ denominator`, rounded to the nearest whole
+/// microvolt (μV).
+fn scale(voltage: Voltage, numerator: u128, denominator: u128) -> u128 {
+    let magnitude = voltage.micro_volts().unsigned_abs() as u128;
+
+    (magnitude * numerator + denominator / 2) / denominator
+}
+
+/// Applies the sign of `voltage` to a magnitude, returning `None` if it cannot be represented.
+fn signed(voltage: Voltage, magnitude: u128) -> Option<Voltage> {
+    let magnitude = i64::try_from(magnitude).ok()?;
+
+    if voltage.is_negative() {
+        Some(Voltage::from_micro_volts(-magnitude))
+    } else {
+        Some(Voltage::from_micro_volts(magnitude))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(400_000_000, 230_940_108; "400V")]
+    #[test_case(208_000_000, 120_088_856; "208V")]
+    #[test_case(-400_000_000, -230_940_108; "negative")]
+    #[test_case(0, 0; "zero")]
+    #[test_case(i64::MIN, -5_325_116_328_314_171_699; "minimum")]
+    fn test_line_to_neutral(line_to_line: i64, expected: i64) {
+        let voltage = line_to_neutral(Voltage::from_micro_volts(line_to_line));
+        assert_eq!(voltage.micro_volts(), expected);
+    }
+
+    #[test_case(230_000_000, Some(398_371_686); "230V")]
+    #[test_case(120_000_000, Some(207_846_097); "120V")]
+    #[test_case(-1, Some(-2); "rounds to nearest")]
+    #[test_case(i64::MAX, None; "overflow")]
+    fn test_checked_line_to_line(line_to_neutral: i64, expected: Option<i64>) {
+        let voltage = checked_line_to_line(Voltage::from_micro_volts(line_to_neutral));
+        assert_eq!(voltage.map(|v| v.micro_volts()), expected);
+    }
+
+    #[test]
+    fn test_line_to_line_round_trip() {
+        let phase = Voltage::from_micro_volts(230_000_000);
+        assert_eq!(line_to_neutral(line_to_line(phase)), phase);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_line_to_line_overflow_panics() {
+        line_to_line(Voltage::from_micro_volts(i64::MAX));
+    }
+
+    #[cfg(all(feature = "current", feature = "power"))]
+    #[test_case(400_000_000, 10_000_000, Some(6_928_203_230); "400V at 10A")]
+    #[test_case(-400_000_000, 10_000_000, Some(6_928_203_230); "negative voltage")]
+    #[test_case(0, 10_000_000, Some(0); "zero voltage")]
+    #[test_case(i64::MAX, u64::MAX, None; "overflow")]
+    fn test_apparent_power(line_to_line: i64, line_current: u64, expected: Option<u64>) {
+        let apparent = apparent_power(
+            Voltage::from_micro_volts(line_to_line),
+            Current::from_micro_amps(line_current),
+        );
+
+        assert_eq!(apparent.map(|s| s.micro_volt_amps()), expected);
+    }
+
+    #[cfg(all(feature = "current", feature = "power"))]
+    #[test_case(400_000_000, 10_000_000, 850_000, Some(5_888_972_745); "lagging load")]
+    #[test_case(400_000_000, 10_000_000, 1_000_000, Some(6_928_203_230); "unity")]
+    #[test_case(i64::MAX, u64::MAX, 1_000_000, None; "overflow")]
+    fn test_real_power(line_to_line: i64, line_current: u64, pf: u32, expected: Option<u64>) {
+        let power = real_power(
+            Voltage::from_micro_volts(line_to_line),
+            Current::from_micro_amps(line_current),
+            PowerFactor::from_millionths(pf).unwrap(),
+        );
+
+        assert_eq!(power.map(|p| p.micro_watts()), expected);
+    }
+
+    #[cfg(all(feature = "current", feature = "power"))]
+    #[test_case(6_928_203_230, 400_000_000, 1_000_000, Some(9_999_999); "unity")]
+    #[test_case(5_888_972_745, 400_000_000, 850_000, Some(9_999_999); "lagging load")]
+    #[test_case(1_000_000, 0, 1_000_000, None; "zero voltage")]
+    #[test_case(1_000_000, 400_000_000, 0, None; "zero power factor")]
+    #[test_case(u64::MAX, 1, 1_000_000, None; "overflow")]
+    fn test_line_current(power: u64, line_to_line: i64, pf: u32, expected: Option<u64>) {
+        let current = line_current(
+            Power::from_micro_watts(power),
+            Voltage::from_micro_volts(line_to_line),
+            PowerFactor::from_millionths(pf).unwrap(),
+        );
+
+        assert_eq!(current.map(|i| i.micro_amps()), expected);
+    }
+}
@@ -0,0 +1,607 @@
+use core::ops;
+
+#[cfg(feature = "current")]
+use crate::Current;
+#[cfg(feature = "power")]
+use crate::Power;
+#[cfg(feature = "resistance")]
+use crate::Resistance;
+#[cfg(feature = "voltage")]
+use crate::Voltage;
+use crate::{Capacitance, Charge, Conductance, Energy, Frequency, Inductance};
+
+/// Represents a nominal value with worst-case minimum and maximum bounds, such as a resistor
+/// with a 1% tolerance.
+///
+/// Arithmetic and Ohm's Law operations between toleranced values propagate the bounds, so the
+/// result covers every combination of the inputs within their tolerances.
+///
+/// ```rust
+/// # #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))] {
+/// use ohms::prelude::*;
+///
+/// let r = Toleranced::from_percent(1.kilo_ohms(), 5); // 950Ω to 1.05kΩ
+/// let v = Toleranced::from_percent(5.volts(), 1); // 4.95V to 5.05V
+///
+/// let i = v / r;
+/// assert_eq!(i.min(), 4_714.micro_amps()); // 4.95V / 1.05kΩ
+/// assert_eq!(i.max(), 5_315.micro_amps()); // 5.05V / 950Ω
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        try_from = "TolerancedData<T>",
+        bound(deserialize = "T: serde::Deserialize<'de> + Copy + Ord")
+    )
+)]
+pub struct Toleranced<T> {
+    nominal: T,
+    min: T,
+    max: T,
+}
+
+/// The serialized form of a `Toleranced` value, which is checked with `Toleranced::from_bounds`
+/// when deserialized.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "Toleranced")]
+struct TolerancedData<T> {
+    nominal: T,
+    min: T,
+    max: T,
+}
+
+#[cfg(feature = "serde")]
+impl<T: Copy + Ord> TryFrom<TolerancedData<T>> for Toleranced<T> {
+    type Error = &'static str;
+
+    fn try_from(data: TolerancedData<T>) -> Result<Self, Self::Error> {
+        Self::from_bounds(data.nominal, data.min, data.max)
+            .ok_or("nominal value is not between the minimum and maximum")
+    }
+}
+
+impl<T: Copy + Ord> Toleranced<T> {
+    /// Creates a new `Toleranced` value with no tolerance, so the bounds equal the nominal value.
+    #[inline]
+    pub const fn exact(nominal: T) -> Self {
+        Self {
+            nominal,
+            min: nominal,
+            max: nominal,
+        }
+    }
+
+    /// Creates a new `Toleranced` value from its nominal value and worst-case bounds.
+    ///
+    /// Returns `None` if the nominal value is not between the minimum and maximum.
+    pub fn from_bounds(nominal: T, min: T, max: T) -> Option<Self> {
+        if min <= nominal && nominal <= max {
+            Some(Self { nominal, min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the nominal value.
+    #[inline]
+    pub const fn nominal(&self) -> T {
+        self.nominal
+    }
+
+    /// Returns the worst-case minimum value.
+    #[inline]
+    pub const fn min(&self) -> T {
+        self.min
+    }
+
+    /// Returns the worst-case maximum value.
+    #[inline]
+    pub const fn max(&self) -> T {
+        self.max
+    }
+
+    /// Returns `true` if the value is within the bounds, inclusive.
+    #[inline]
+    pub fn contains(&self, value: T) -> bool {
+        self.min <= value && value <= self.max
+    }
+
+    /// Creates a new `Toleranced` value from the nominal result of an operation and its results at
+    /// each corner of the input bounds.
+    #[cfg(any(
+        all(feature = "voltage", feature = "resistance"),
+        all(feature = "voltage", feature = "current", feature = "power")
+    ))]
+    fn from_corners<const N: usize>(nominal: T, corners: [T; N]) -> Self {
+        let mut min = nominal;
+        let mut max = nominal;
+
+        for corner in corners {
+            min = min.min(corner);
+            max = max.max(corner);
+        }
+
+        Self { nominal, min, max }
+    }
+}
+
+impl<T: Copy + ops::Add<Output = T>> ops::Add for Toleranced<T> {
+    type Output = Self;
+
+    /// Adds two toleranced values, adding their minimum and maximum bounds.
+    ///
+    /// Panics if the result would overflow.
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            nominal: self.nominal + other.nominal,
+            min: self.min + other.min,
+            max: self.max + other.max,
+        }
+    }
+}
+
+impl<T: Copy + ops::Sub<Output = T>> ops::Sub for Toleranced<T> {
+    type Output = Self;
+
+    /// Subtracts two toleranced values, so the minimum is the smallest possible difference and the
+    /// maximum is the largest.
+    ///
+    /// Panics if the result would overflow.
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            nominal: self.nominal - other.nominal,
+            min: self.min - other.max,
+            max: self.max - other.min,
+        }
+    }
+}
+
+impl<T: sealed::Bounded> Toleranced<T> {
+    /// Creates a new `Toleranced` value from its nominal value and a symmetric tolerance in parts
+    /// per million (ppm), such as `10_000` for 1%.
+    ///
+    /// For unsigned units, the minimum is clamped to zero if the tolerance is more than 100%.
+    /// Panics if either bound would overflow.
+    pub fn from_ppm(nominal: T, tolerance_ppm: u32) -> Self {
+        let (min, max) = nominal.bounds(tolerance_ppm);
+        Self { nominal, min, max }
+    }
+
+    /// Creates a new `Toleranced` value from its nominal value and a symmetric tolerance in whole
+    /// percent.
+    ///
+    /// For unsigned units, the minimum is clamped to zero if the tolerance is more than 100%.
+    /// Panics if either bound would overflow.
+    #[inline]
+    pub fn from_percent(nominal: T, tolerance_percent: u32) -> Self {
+        Self::from_ppm(nominal, tolerance_percent.saturating_mul(10_000))
+    }
+}
+
+mod sealed {
+    /// A unit that can be given a symmetric tolerance.
+    pub trait Bounded: Copy + Ord {
+        /// Returns the minimum and maximum of the value with the tolerance applied.
+        fn bounds(self, tolerance_ppm: u32) -> (Self, Self);
+    }
+}
+
+macro_rules! impl_unsigned_toleranced {
+    ($type:ident, $from:ident, $raw:ident) => {
+        impl sealed::Bounded for $type {
+            fn bounds(self, tolerance_ppm: u32) -> (Self, Self) {
+                let raw = self.$raw();
+                let delta = (raw as u128 * tolerance_ppm as u128 / 1_000_000u128) as u64;
+
+                let max = raw
+                    .checked_add(delta)
+                    .expect(concat!(stringify!($type), " would overflow"));
+
+                ($type::$from(raw.saturating_sub(delta)), $type::$from(max))
+            }
+        }
+    };
+}
+
+impl_unsigned_toleranced!(Capacitance, from_pico_farads, pico_farads);
+impl_unsigned_toleranced!(Charge, from_micro_coulombs, micro_coulombs);
+impl_unsigned_toleranced!(Conductance, from_nano_siemens, nano_siemens);
+#[cfg(feature = "current")]
+impl_unsigned_toleranced!(Current, from_micro_amps, micro_amps);
+impl_unsigned_toleranced!(Energy, from_nano_joules, nano_joules);
+impl_unsigned_toleranced!(Frequency, from_milli_hertz, milli_hertz);
+impl_unsigned_toleranced!(Inductance, from_nano_henries, nano_henries);
+#[cfg(feature = "power")]
+impl_unsigned_toleranced!(Power, from_micro_watts, micro_watts);
+#[cfg(feature = "resistance")]
+impl_unsigned_toleranced!(Resistance, from_milli_ohms, milli_ohms);
+
+#[cfg(feature = "voltage")]
+impl sealed::Bounded for Voltage {
+    fn bounds(self, tolerance_ppm: u32) -> (Self, Self) {
+        let micro_volts = self.micro_volts();
+        let delta = micro_volts.unsigned_abs() as u128 * tolerance_ppm as u128 / 1_000_000u128;
+        let delta = i64::try_from(delta).expect("Voltage would overflow");
+
+        let min = micro_volts
+            .checked_sub(delta)
+            .expect("Voltage would overflow");
+        let max = micro_volts
+            .checked_add(delta)
+            .expect("Voltage would overflow");
+
+        (
+            Voltage::from_micro_volts(min),
+            Voltage::from_micro_volts(max),
+        )
+    }
+}
+
+#[cfg(all(feature = "voltage", feature = "resistance"))]
+impl Toleranced<Voltage> {
+    /// Calculates the unloaded output voltage of a divider from toleranced input voltage and
+    /// resistors, as for [`VoltageDivider::vout`](crate::VoltageDivider::vout).
+    ///
+    /// Will be rounded towards zero to the nearest whole microvolt (μV).
+    /// Panics if both resistors can be zero.
+    pub fn divider_vout(
+        self,
+        r_top: Toleranced<Resistance>,
+        r_bottom: Toleranced<Resistance>,
+    ) -> Toleranced<Voltage> {
+        let vout = |vin, r_top, r_bottom| crate::VoltageDivider::new(r_top, r_bottom).vout(vin);
+
+        // The output increases with the input and bottom resistor, and decreases with the top
+        Toleranced::from_corners(
+            vout(self.nominal, r_top.nominal, r_bottom.nominal),
+            [
+                vout(self.min, r_top.max, r_bottom.min),
+                vout(self.min, r_top.min, r_bottom.max),
+                vout(self.max, r_top.max, r_bottom.min),
+                vout(self.max, r_top.min, r_bottom.max),
+            ],
+        )
+    }
+}
+
+/// Converts the bounds of a toleranced value to the magnitudes used by the Ohm's Law operations.
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    any(feature = "resistance", feature = "power")
+))]
+trait Magnitude: Sized {
+    fn magnitude(value: Toleranced<Self>) -> Toleranced<Self>;
+}
+
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    any(feature = "resistance", feature = "power")
+))]
+macro_rules! impl_unsigned_magnitude {
+    ($type:ident) => {
+        impl Magnitude for $type {
+            #[inline]
+            fn magnitude(value: Toleranced<Self>) -> Toleranced<Self> {
+                value
+            }
+        }
+    };
+}
+
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    any(feature = "resistance", feature = "power")
+))]
+impl_unsigned_magnitude!(Current);
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl_unsigned_magnitude!(Power);
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl_unsigned_magnitude!(Resistance);
+
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    any(feature = "resistance", feature = "power")
+))]
+impl Magnitude for Voltage {
+    /// Ohm's Law operations use the magnitude of a voltage, so a range spanning zero has a
+    /// minimum magnitude of zero.
+    fn magnitude(value: Toleranced<Self>) -> Toleranced<Self> {
+        let abs = |voltage: Voltage| {
+            let micro_volts = voltage.micro_volts().unsigned_abs();
+            Voltage::from_micro_volts(i64::try_from(micro_volts).expect("Voltage would overflow"))
+        };
+
+        let (min, max) = (abs(value.min), abs(value.max));
+        let spans_zero = value.min.micro_volts() <= 0 && value.max.micro_volts() >= 0;
+
+        Toleranced {
+            nominal: abs(value.nominal),
+            min: if spans_zero {
+                Voltage::zero()
+            } else {
+                min.min(max)
+            },
+            max: min.max(max),
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "voltage",
+    feature = "current",
+    any(feature = "resistance", feature = "power")
+))]
+macro_rules! impl_toleranced_op {
+    ($trait:ident, $method:ident, $lhs:ident, $rhs:ident, $output:ident) => {
+        impl ops::$trait<Toleranced<$rhs>> for Toleranced<$lhs> {
+            type Output = Toleranced<$output>;
+
+            /// Applies the operation to each combination of bounds, so the result covers the
+            /// worst case in both directions.
+            ///
+            /// Panics if the operation would panic for any of the bounds.
+            fn $method(self, rhs: Toleranced<$rhs>) -> Self::Output {
+                let lhs = Magnitude::magnitude(self);
+                let rhs = Magnitude::magnitude(rhs);
+
+                Toleranced::from_corners(
+                    ops::$trait::$method(lhs.nominal, rhs.nominal),
+                    [
+                        ops::$trait::$method(lhs.min, rhs.min),
+                        ops::$trait::$method(lhs.min, rhs.max),
+                        ops::$trait::$method(lhs.max, rhs.min),
+                        ops::$trait::$method(lhs.max, rhs.max),
+                    ],
+                )
+            }
+        }
+    };
+}
+
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl_toleranced_op!(Div, div, Voltage, Resistance, Current);
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl_toleranced_op!(Mul, mul, Current, Resistance, Voltage);
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl_toleranced_op!(Mul, mul, Resistance, Current, Voltage);
+#[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+impl_toleranced_op!(Div, div, Voltage, Current, Resistance);
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl_toleranced_op!(Mul, mul, Voltage, Current, Power);
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl_toleranced_op!(Mul, mul, Current, Voltage, Power);
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl_toleranced_op!(Div, div, Power, Voltage, Current);
+#[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+impl_toleranced_op!(Div, div, Power, Current, Voltage);
+
+#[cfg(all(test, any(feature = "voltage", feature = "resistance")))]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[cfg(feature = "resistance")]
+    #[test_case(1_000_000, 10_000, 990_000, 1_010_000; "1kΩ at 1%")]
+    #[test_case(1_000_000, 50_000, 950_000, 1_050_000; "1kΩ at 5%")]
+    #[test_case(1_000, 2_000_000, 0, 3_000; "clamps minimum to zero")]
+    #[test_case(0, 10_000, 0, 0; "zero has no tolerance")]
+    fn test_from_ppm(milli_ohms: u64, ppm: u32, expected_min: u64, expected_max: u64) {
+        let r = Toleranced::from_ppm(Resistance::from_milli_ohms(milli_ohms), ppm);
+
+        assert_eq!(r.nominal().milli_ohms(), milli_ohms);
+        assert_eq!(r.min().milli_ohms(), expected_min);
+        assert_eq!(r.max().milli_ohms(), expected_max);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    #[should_panic]
+    fn test_from_ppm_overflow_panics() {
+        Toleranced::from_ppm(Resistance::from_milli_ohms(u64::MAX), 1_000_000);
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test_case(5_000_000, 1, 4_950_000, 5_050_000; "positive voltage")]
+    #[test_case(-5_000_000, 1, -5_050_000, -4_950_000; "negative voltage")]
+    fn test_voltage_from_percent(micro_volts: i64, percent: u32, min: i64, max: i64) {
+        let v = Toleranced::from_percent(Voltage::from_micro_volts(micro_volts), percent);
+
+        assert_eq!(v.min().micro_volts(), min);
+        assert_eq!(v.max().micro_volts(), max);
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    fn test_from_bounds() {
+        let r = |milli_ohms| Resistance::from_milli_ohms(milli_ohms);
+
+        assert!(Toleranced::from_bounds(r(1_000), r(900), r(1_200)).is_some());
+        assert!(Toleranced::from_bounds(r(1_000), r(1_100), r(1_200)).is_none());
+        assert!(Toleranced::from_bounds(r(1_000), r(900), r(950)).is_none());
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    fn test_contains() {
+        let r = Toleranced::from_percent(Resistance::from_milli_ohms(1_000_000), 1);
+
+        assert!(r.contains(Resistance::from_milli_ohms(990_000)));
+        assert!(r.contains(Resistance::from_milli_ohms(1_010_000)));
+        assert!(!r.contains(Resistance::from_milli_ohms(1_010_001)));
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    fn test_add_and_sub() {
+        let a = Toleranced::from_percent(Resistance::from_milli_ohms(1_000_000), 1);
+        let b = Toleranced::from_percent(Resistance::from_milli_ohms(500_000), 2);
+
+        let sum = a + b;
+        assert_eq!(sum.nominal().milli_ohms(), 1_500_000);
+        assert_eq!(sum.min().milli_ohms(), 1_480_000);
+        assert_eq!(sum.max().milli_ohms(), 1_520_000);
+
+        let difference = a - b;
+        assert_eq!(difference.nominal().milli_ohms(), 500_000);
+        assert_eq!(difference.min().milli_ohms(), 480_000);
+        assert_eq!(difference.max().milli_ohms(), 520_000);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test]
+    fn test_current_equals_voltage_over_resistance() {
+        let v = Toleranced::from_percent(Voltage::from_micro_volts(5_000_000), 1);
+        let r = Toleranced::from_percent(Resistance::from_milli_ohms(1_000_000), 5);
+
+        let i = v / r;
+        assert_eq!(i.nominal().micro_amps(), 5_000);
+        assert_eq!(i.min().micro_amps(), 4_714);
+        assert_eq!(i.max().micro_amps(), 5_315);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test]
+    fn test_current_for_voltage_spanning_zero() {
+        let v = Toleranced::from_bounds(
+            Voltage::from_micro_volts(100_000),
+            Voltage::from_micro_volts(-200_000),
+            Voltage::from_micro_volts(300_000),
+        )
+        .unwrap();
+        let r = Toleranced::exact(Resistance::from_milli_ohms(1_000_000));
+
+        let i = v / r;
+        assert_eq!(i.nominal().micro_amps(), 100);
+        assert_eq!(i.min().micro_amps(), 0);
+        assert_eq!(i.max().micro_amps(), 300);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "resistance"))]
+    #[test]
+    fn test_voltage_equals_current_times_resistance() {
+        let i = Toleranced::from_percent(Current::from_micro_amps(10_000), 10);
+        let r = Toleranced::from_percent(Resistance::from_milli_ohms(1_000_000), 1);
+
+        let v = i * r;
+        assert_eq!(v.nominal().micro_volts(), 10_000_000);
+        assert_eq!(v.min().micro_volts(), 8_910_000);
+        assert_eq!(v.max().micro_volts(), 11_110_000);
+        assert_eq!(r * i, v);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "current", feature = "power"))]
+    #[test]
+    fn test_power_equals_voltage_times_current() {
+        let v = Toleranced::from_percent(Voltage::from_micro_volts(12_000_000), 5);
+        let i = Toleranced::exact(Current::from_micro_amps(1_000_000));
+
+        let p = v * i;
+        assert_eq!(p.min().micro_watts(), 11_400_000);
+        assert_eq!(p.max().micro_watts(), 12_600_000);
+        assert_eq!(i * v, p);
+    }
+
+    #[cfg(all(feature = "voltage", feature = "resistance"))]
+    #[test]
+    fn test_divider_vout() {
+        let vin = Toleranced::exact(Voltage::from_micro_volts(10_000_000));
+        let r_top = Toleranced::from_percent(Resistance::from_milli_ohms(10_000_000), 1);
+        let r_bottom = Toleranced::from_percent(Resistance::from_milli_ohms(10_000_000), 1);
+
+        let vout = vin.divider_vout(r_top, r_bottom);
+        assert_eq!(vout.nominal().micro_volts(), 5_000_000);
+        assert_eq!(vout.min().micro_volts(), 4_950_000);
+        assert_eq!(vout.max().micro_volts(), 5_050_000);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Toleranced::from_percent(Frequency::from_milli_hertz(1_000), 10),
+            &[
+                Token::Struct {
+                    name: "Toleranced",
+                    len: 3,
+                },
+                Token::Str("nominal"),
+                Token::Struct {
+                    name: "Frequency",
+                    len: 1,
+                },
+                Token::Str("milli_hertz"),
+                Token::U64(1000),
+                Token::StructEnd,
+                Token::Str("min"),
+                Token::Struct {
+                    name: "Frequency",
+                    len: 1,
+                },
+                Token::Str("milli_hertz"),
+                Token::U64(900),
+                Token::StructEnd,
+                Token::Str("max"),
+                Token::Struct {
+                    name: "Frequency",
+                    len: 1,
+                },
+                Token::Str("milli_hertz"),
+                Token::U64(1100),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_deserialize_nominal_out_of_bounds() {
+        use serde_test::{assert_de_tokens_error, Token};
+
+        assert_de_tokens_error::<Toleranced<Frequency>>(
+            &[
+                Token::Struct {
+                    name: "Toleranced",
+                    len: 3,
+                },
+                Token::Str("nominal"),
+                Token::Struct {
+                    name: "Frequency",
+                    len: 1,
+                },
+                Token::Str("milli_hertz"),
+                Token::U64(1000),
+                Token::StructEnd,
+                Token::Str("min"),
+                Token::Struct {
+                    name: "Frequency",
+                    len: 1,
+                },
+                Token::Str("milli_hertz"),
+                Token::U64(1100),
+                Token::StructEnd,
+                Token::Str("max"),
+                Token::Struct {
+                    name: "Frequency",
+                    len: 1,
+                },
+                Token::Str("milli_hertz"),
+                Token::U64(900),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+            "nominal value is not between the minimum and maximum",
+        );
+    }
+}
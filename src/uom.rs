@@ -0,0 +1,282 @@
+//! Conversions between unit types and `uom` quantities, behind the `uom` feature.
+//!
+//! Each unit type converts into the matching `uom::si::f64` quantity with `From`, and back with
+//! `TryFrom`, rounding to the nearest whole base unit of this crate. Values round trip exactly as
+//! long as they fit in the 53-bit precision of an `f64`, which covers ±9GV for a `Voltage`.
+
+use crate::{Capacitance, Charge, Conductance, Energy, Frequency, Inductance, Temperature};
+#[cfg(feature = "current")]
+use crate::{Current, CurrentUnit};
+#[cfg(feature = "power")]
+use crate::{Power, PowerUnit};
+#[cfg(feature = "resistance")]
+use crate::{Resistance, ResistanceUnit};
+use crate::{RoundingMode, UnitError};
+#[cfg(feature = "voltage")]
+use crate::{Voltage, VoltageUnit};
+use ::uom::si;
+
+macro_rules! impl_uom_conversions {
+    ($type:ident, $quantity:ident, $unit:path, $getter:ident, rounded $base:expr) => {
+        impl From<$type> for si::f64::$quantity {
+            #[inline]
+            fn from(value: $type) -> Self {
+                Self::new::<$unit>(value.$getter() as f64)
+            }
+        }
+
+        impl TryFrom<si::f64::$quantity> for $type {
+            type Error = UnitError;
+
+            #[inline]
+            fn try_from(value: si::f64::$quantity) -> Result<Self, Self::Error> {
+                $type::try_from_rounded(value.get::<$unit>(), $base, RoundingMode::Nearest)
+            }
+        }
+    };
+    ($type:ident, $quantity:ident, $unit:path, $getter:ident, $try_from:ident) => {
+        impl From<$type> for si::f64::$quantity {
+            #[inline]
+            fn from(value: $type) -> Self {
+                Self::new::<$unit>(value.$getter() as f64)
+            }
+        }
+
+        impl TryFrom<si::f64::$quantity> for $type {
+            type Error = UnitError;
+
+            #[inline]
+            fn try_from(value: si::f64::$quantity) -> Result<Self, Self::Error> {
+                $type::$try_from(RoundingMode::Nearest.round_f64(value.get::<$unit>()))
+            }
+        }
+    };
+}
+
+impl_uom_conversions!(
+    Capacitance,
+    Capacitance,
+    si::capacitance::picofarad,
+    pico_farads,
+    try_from_pico_farads
+);
+impl_uom_conversions!(
+    Charge,
+    ElectricCharge,
+    si::electric_charge::microcoulomb,
+    micro_coulombs,
+    try_from_micro_coulombs
+);
+impl_uom_conversions!(
+    Conductance,
+    ElectricalConductance,
+    si::electrical_conductance::nanosiemens,
+    nano_siemens,
+    try_from_nano_siemens
+);
+#[cfg(feature = "current")]
+impl_uom_conversions!(
+    Current,
+    ElectricCurrent,
+    si::electric_current::microampere,
+    micro_amps,
+    rounded CurrentUnit::MicroAmps
+);
+impl_uom_conversions!(
+    Energy,
+    Energy,
+    si::energy::nanojoule,
+    nano_joules,
+    try_from_nano_joules
+);
+impl_uom_conversions!(
+    Frequency,
+    Frequency,
+    si::frequency::millihertz,
+    milli_hertz,
+    try_from_milli_hertz
+);
+impl_uom_conversions!(
+    Inductance,
+    Inductance,
+    si::inductance::nanohenry,
+    nano_henries,
+    try_from_nano_henries
+);
+#[cfg(feature = "power")]
+impl_uom_conversions!(
+    Power,
+    Power,
+    si::power::microwatt,
+    micro_watts,
+    rounded PowerUnit::MicroWatts
+);
+#[cfg(feature = "resistance")]
+impl_uom_conversions!(
+    Resistance,
+    ElectricalResistance,
+    si::electrical_resistance::milliohm,
+    milli_ohms,
+    rounded ResistanceUnit::MilliOhms
+);
+#[cfg(feature = "voltage")]
+impl_uom_conversions!(
+    Voltage,
+    ElectricPotential,
+    si::electric_potential::microvolt,
+    micro_volts,
+    rounded VoltageUnit::MicroVolts
+);
+
+impl From<Temperature> for si::f64::ThermodynamicTemperature {
+    #[inline]
+    fn from(value: Temperature) -> Self {
+        Self::new::<si::thermodynamic_temperature::degree_celsius>(value.degrees_celsius())
+    }
+}
+
+impl TryFrom<si::f64::ThermodynamicTemperature> for Temperature {
+    type Error = UnitError;
+
+    #[inline]
+    fn try_from(value: si::f64::ThermodynamicTemperature) -> Result<Self, Self::Error> {
+        let celsius = value.get::<si::thermodynamic_temperature::degree_celsius>();
+        Temperature::try_from_milli_degrees_celsius(
+            RoundingMode::Nearest.round_f64(celsius * 1_000f64),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[cfg(feature = "voltage")]
+    #[test_case(3_300_000; "positive")]
+    #[test_case(-12_345_678; "negative")]
+    #[test_case(1; "one microvolt")]
+    #[test_case(9_007_199_254_740_992; "largest exact")]
+    fn test_voltage_round_trip(micro_volts: i64) {
+        let quantity = si::f64::ElectricPotential::from(Voltage::from_micro_volts(micro_volts));
+        let voltage = Voltage::try_from(quantity).map(|v| v.micro_volts());
+        assert_eq!(voltage, Ok(micro_volts));
+    }
+
+    #[cfg(feature = "voltage")]
+    #[test]
+    fn test_voltage_units() {
+        let quantity = si::f64::ElectricPotential::from(Voltage::from_micro_volts(1_500_000));
+        assert_eq!(quantity.get::<si::electric_potential::volt>(), 1.5);
+
+        let quantity = si::f64::ElectricPotential::new::<si::electric_potential::millivolt>(2.5);
+        let voltage = Voltage::try_from(quantity).map(|v| v.micro_volts());
+        assert_eq!(voltage, Ok(2_500));
+    }
+
+    #[cfg(feature = "current")]
+    #[test_case(1.5, Ok(1_500_000); "fraction")]
+    #[test_case(0.0000004, Ok(0); "rounds down")]
+    #[test_case(0.0000006, Ok(1); "rounds up")]
+    #[test_case(-1e-13, Ok(0); "tiny negative rounds to zero")]
+    #[test_case(-1.0, Err(UnitError::Negative); "negative")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "nan")]
+    #[test_case(f64::INFINITY, Err(UnitError::Infinite); "infinite")]
+    #[test_case(1e14, Err(UnitError::Overflow); "overflow")]
+    fn test_current_try_from(amps: f64, expected: Result<u64, UnitError>) {
+        let quantity = si::f64::ElectricCurrent::new::<si::electric_current::ampere>(amps);
+        let current = Current::try_from(quantity).map(|c| c.micro_amps());
+        assert_eq!(current, expected);
+    }
+
+    #[cfg(feature = "current")]
+    #[test]
+    fn test_current_just_below_half() {
+        let quantity =
+            si::f64::ElectricCurrent::new::<si::electric_current::microampere>(0.49999999999999994);
+        assert_eq!(Current::try_from(quantity), Ok(Current::zero()));
+    }
+
+    #[cfg(feature = "resistance")]
+    #[test]
+    fn test_resistance_round_trip() {
+        let resistance = Resistance::from_milli_ohms(4_700_000);
+        let quantity = si::f64::ElectricalResistance::from(resistance);
+
+        assert_eq!(quantity.get::<si::electrical_resistance::kiloohm>(), 4.7);
+        assert_eq!(Resistance::try_from(quantity), Ok(resistance));
+    }
+
+    #[cfg(feature = "power")]
+    #[test]
+    fn test_power_round_trip() {
+        let power = Power::from_micro_watts(250_000);
+        let quantity = si::f64::Power::from(power);
+
+        assert_eq!(quantity.get::<si::power::milliwatt>(), 250.0);
+        assert_eq!(Power::try_from(quantity), Ok(power));
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let energy = Energy::from_nano_joules(123_456_789);
+        assert_eq!(Energy::try_from(si::f64::Energy::from(energy)), Ok(energy));
+
+        let capacitance = Capacitance::from_pico_farads(100_000);
+        assert_eq!(
+            Capacitance::try_from(si::f64::Capacitance::from(capacitance)),
+            Ok(capacitance)
+        );
+
+        let inductance = Inductance::from_nano_henries(4_700);
+        assert_eq!(
+            Inductance::try_from(si::f64::Inductance::from(inductance)),
+            Ok(inductance)
+        );
+
+        let frequency = Frequency::from_milli_hertz(50_000);
+        assert_eq!(
+            Frequency::try_from(si::f64::Frequency::from(frequency)),
+            Ok(frequency)
+        );
+
+        let charge = Charge::from_micro_coulombs(3_600_000);
+        assert_eq!(
+            Charge::try_from(si::f64::ElectricCharge::from(charge)),
+            Ok(charge)
+        );
+
+        let conductance = Conductance::from_nano_siemens(1_000);
+        assert_eq!(
+            Conductance::try_from(si::f64::ElectricalConductance::from(conductance)),
+            Ok(conductance)
+        );
+    }
+
+    #[test_case(0.49999999999999994, Ok(0); "just below half rounds down")]
+    #[test_case(-1e-13, Ok(0); "tiny negative rounds to zero")]
+    #[test_case(-2.5, Err(UnitError::Negative); "negative")]
+    fn test_capacitance_try_from(pico_farads: f64, expected: Result<u64, UnitError>) {
+        let quantity = si::f64::Capacitance::new::<si::capacitance::picofarad>(pico_farads);
+        let capacitance = Capacitance::try_from(quantity).map(|c| c.pico_farads());
+        assert_eq!(capacitance, expected);
+    }
+
+    #[test_case(25_000; "room temperature")]
+    #[test_case(-40_000; "cold")]
+    #[test_case(-273_150; "absolute zero")]
+    #[test_case(1; "one millidegree")]
+    fn test_temperature_round_trip(milli_celsius: i64) {
+        let temperature = Temperature::from_milli_degrees_celsius(milli_celsius);
+        let quantity = si::f64::ThermodynamicTemperature::from(temperature);
+        assert_eq!(Temperature::try_from(quantity), Ok(temperature));
+    }
+
+    #[test]
+    fn test_temperature_kelvin() {
+        let quantity =
+            si::f64::ThermodynamicTemperature::new::<si::thermodynamic_temperature::kelvin>(300.0);
+        let temperature = Temperature::try_from(quantity).map(|t| t.milli_degrees_celsius());
+        assert_eq!(temperature, Ok(26_850));
+    }
+}
@@ -1,4 +1,13 @@
-use core::{cmp, fmt, ops};
+use crate::approx;
+use crate::compare;
+use crate::display;
+use crate::math::{f64_to_i64, try_round_f64_to_i64, try_scale_f64_to_i64};
+use crate::math::{isqrt, mean_square};
+use crate::parse::{parse_rkm, parse_si_signed, ParseError};
+#[cfg(feature = "libm")]
+use crate::Decibel;
+use crate::{RoundingMode, UnitError};
+use core::{cmp, fmt, hash, iter, ops, str};
 
 /// Represents a voltage value, stored as whole microvolts (μV) as a signed 64-bit value.
 /// This value can be positive or negative.
@@ -43,6 +52,7 @@ use core::{cmp, fmt, ops};
 /// The result is a new `Voltage` value, rounded down to the nearest whole microvolt (μV).
 ///
 /// If the result of the operation would overflow or underflow, the operation will panic.
+/// Use the `checked_add` and `checked_sub` methods to handle this without panicking.
 ///
 /// ```rust
 /// use ohms::prelude::*;
@@ -62,6 +72,9 @@ use core::{cmp, fmt, ops};
 ///
 /// If the result of the operation would be infinite or NaN, the operation will panic.
 ///
+/// Use the `checked_mul_int`, `checked_div_int`, `checked_mul_f64` and `checked_div_f64` methods
+/// to handle these cases without panicking.
+///
 /// ```rust
 /// use ohms::prelude::*;
 ///
@@ -84,8 +97,10 @@ use core::{cmp, fmt, ops};
 /// println!("{:.2} V is {:.1} mV", v1.volts(), v1.milli_volts());
 /// ```
 ///
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Voltage {
+    #[cfg_attr(feature = "serde", serde(rename = "micro_volts"))]
     raw: i64,
 }
 
@@ -99,6 +114,83 @@ impl Voltage {
         Self { raw: value }
     }
 
+    /// Creates a new `Voltage` from a number of whole nanovolts (nV).
+    ///
+    /// Returns `None` if the value is not a whole number of microvolts (μV).
+    #[inline]
+    pub const fn from_nano_volts(value: i64) -> Option<Self> {
+        if value % 1_000 == 0 {
+            Some(Self::from_micro_volts(value / 1_000))
+        } else {
+            None
+        }
+    }
+
+    /// Creates a new `Voltage` from a number of nanovolts (nV).
+    ///
+    /// Returns an error if the value is NaN, infinite, not a whole number of microvolts (μV)
+    /// or too large to be represented.
+    #[inline]
+    pub fn try_from_nano_volts(value: f64) -> Result<Self, UnitError> {
+        let micro_volts = try_scale_f64_to_i64(value / 1_000f64, 1f64)?;
+        if value % 1_000f64 != 0f64 {
+            return Err(UnitError::Inexact);
+        }
+
+        Ok(Self::from_micro_volts(micro_volts))
+    }
+
+    /// Creates a new `Voltage` from a number of fractional microvolts (μV).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_micro_volts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1f64).map(Self::from_micro_volts)
+    }
+
+    /// Creates a new `Voltage` from a number of fractional millivolts (mV).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_milli_volts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000f64).map(Self::from_micro_volts)
+    }
+
+    /// Creates a new `Voltage` from a number of fractional volts (V).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_volts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000_000f64).map(Self::from_micro_volts)
+    }
+
+    /// Creates a new `Voltage` from a number of fractional kilovolts (kV).
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_kilo_volts(value: f64) -> Result<Self, UnitError> {
+        try_scale_f64_to_i64(value, 1_000_000_000f64).map(Self::from_micro_volts)
+    }
+
+    /// Creates a new `Voltage` from a number of fractional units, rounding to the nearest whole
+    /// microvolt (μV) in the given direction rather than truncating.
+    ///
+    /// Returns an error if the value is NaN, infinite or too large to be represented.
+    #[inline]
+    pub fn try_from_rounded(
+        value: f64,
+        unit: VoltageUnit,
+        mode: RoundingMode,
+    ) -> Result<Self, UnitError> {
+        try_round_f64_to_i64(value, unit.scale() as f64, mode).map(Self::from_micro_volts)
+    }
+
+    /// Returns the voltage value in nanovolts (nV).
+    #[inline]
+    pub fn nano_volts(&self) -> f64 {
+        self.raw as f64 * 1_000_f64
+    }
+
     /// Returns the voltage value in whole microvolts (μV).
     #[inline]
     pub fn micro_volts(&self) -> i64 {
@@ -123,6 +215,45 @@ impl Voltage {
         self.raw as f64 / 1_000_000_000_f64
     }
 
+    /// Returns the voltage value as whole millivolts (mV) and the remaining microvolts (μV),
+    /// without any floating-point math.
+    ///
+    /// The remainder is never negative, so a negative voltage rounds the whole part down. Use
+    /// `abs` first to format the sign separately.
+    #[inline]
+    pub const fn to_milli_volts_parts(&self) -> (i64, u32) {
+        (
+            self.raw.div_euclid(1_000),
+            self.raw.rem_euclid(1_000) as u32,
+        )
+    }
+
+    /// Returns the voltage value as whole volts (V) and the remaining microvolts (μV),
+    /// without any floating-point math.
+    ///
+    /// The remainder is never negative, so a negative voltage rounds the whole part down. Use
+    /// `abs` first to format the sign separately.
+    #[inline]
+    pub const fn to_volts_parts(&self) -> (i64, u32) {
+        (
+            self.raw.div_euclid(1_000_000),
+            self.raw.rem_euclid(1_000_000) as u32,
+        )
+    }
+
+    /// Returns the voltage value as whole kilovolts (kV) and the remaining microvolts (μV),
+    /// without any floating-point math.
+    ///
+    /// The remainder is never negative, so a negative voltage rounds the whole part down. Use
+    /// `abs` first to format the sign separately.
+    #[inline]
+    pub const fn to_kilo_volts_parts(&self) -> (i64, u32) {
+        (
+            self.raw.div_euclid(1_000_000_000),
+            self.raw.rem_euclid(1_000_000_000) as u32,
+        )
+    }
+
     /// Returns whether the voltage value is zero volts (0V).
     #[inline]
     pub const fn is_zero(&self) -> bool {
@@ -152,9 +283,24 @@ impl Voltage {
     }
 
     /// Inverts the voltage value from positive to negative or negative to positive.
+    ///
+    /// This is equivalent to the unary `-` operator.
+    /// Panics if the voltage is the minimum value, as its inverse cannot be represented.
     #[inline]
     pub const fn invert(&self) -> Self {
-        Self::from_micro_volts(self.raw * -1)
+        match self.raw.checked_neg() {
+            Some(raw) => Self::from_micro_volts(raw),
+            None => panic!("Overflow when negating voltage value"),
+        }
+    }
+
+    /// Inverts the voltage value, returning `None` if the result would overflow.
+    #[inline]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.raw.checked_neg() {
+            Some(raw) => Some(Self::from_micro_volts(raw)),
+            None => None,
+        }
     }
 
     /// Returns a `Voltage` value of zero volts (0V).
@@ -162,8 +308,449 @@ impl Voltage {
     pub const fn zero() -> Self {
         Self::from_micro_volts(0)
     }
+
+    /// Adds two `Voltage` values together, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_micro_volts)
+    }
+
+    /// Subtracts one `Voltage` value from another, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_micro_volts)
+    }
+
+    /// Multiplies the `Voltage` value by an integer value, returning `None` if the result would overflow.
+    #[inline]
+    pub fn checked_mul_int(self, factor: i64) -> Option<Self> {
+        self.raw.checked_mul(factor).map(Self::from_micro_volts)
+    }
+
+    /// Divides the `Voltage` value by an integer value, returning `None` if the divisor is zero or the
+    /// result would overflow.
+    #[inline]
+    pub fn checked_div_int(self, divisor: i64) -> Option<Self> {
+        self.raw.checked_div(divisor).map(Self::from_micro_volts)
+    }
+
+    /// Multiplies the `Voltage` value by a floating-point value, returning `None` if the factor is
+    /// infinite, NaN or the result would overflow.
+    ///
+    /// The result is rounded toward zero to the nearest whole microvolt (μV).
+    #[inline]
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Self> {
+        if factor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(self.raw as f64 * factor).map(Self::from_micro_volts)
+    }
+
+    /// Divides the `Voltage` value by a floating-point value, returning `None` if the divisor is zero,
+    /// infinite, NaN or the result would overflow.
+    ///
+    /// The result is rounded toward zero to the nearest whole microvolt (μV).
+    #[inline]
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(self.raw as f64 / divisor).map(Self::from_micro_volts)
+    }
+
+    /// Multiplies the `Voltage` value by a floating-point value, rounding the result to the nearest
+    /// whole microvolt (μV) in the given direction.
+    ///
+    /// Returns `None` if the factor is infinite, NaN or the result would overflow.
+    #[inline]
+    pub fn checked_mul_f64_rounded(self, factor: f64, mode: RoundingMode) -> Option<Self> {
+        if factor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(mode.round_f64(self.raw as f64 * factor)).map(Self::from_micro_volts)
+    }
+
+    /// Divides the `Voltage` value by a floating-point value, rounding the result to the nearest
+    /// whole microvolt (μV) in the given direction.
+    ///
+    /// Returns `None` if the divisor is zero, infinite, NaN or the result would overflow.
+    #[inline]
+    pub fn checked_div_f64_rounded(self, divisor: f64, mode: RoundingMode) -> Option<Self> {
+        if divisor == 0f64 || divisor.is_infinite() {
+            return None;
+        }
+
+        f64_to_i64(mode.round_f64(self.raw as f64 / divisor)).map(Self::from_micro_volts)
+    }
+
+    /// Calculates the true RMS (root mean square) voltage of a set of samples, such as a buffer of
+    /// mains or audio readings.
+    ///
+    /// The squares are calculated from the magnitude of each sample, so the result is always
+    /// positive. Uses only integer math, and cannot overflow for any number of samples.
+    ///
+    /// Will be rounded down to the nearest whole microvolt (μV).
+    /// Returns `None` if there are no samples.
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let samples = [-1.volts(), 1.volts(), -1.volts(), 1.volts()];
+    /// assert_eq!(Voltage::rms(&samples), Some(1.volts()));
+    /// ```
+    pub fn rms(samples: &[Voltage]) -> Option<Voltage> {
+        let mean_square = mean_square(samples.iter().map(|sample| sample.raw.unsigned_abs()))?;
+
+        // The RMS of samples at the minimum voltage is one microvolt above the maximum
+        Some(Self::from_micro_volts(
+            cmp::min(isqrt(mean_square), i64::MAX as u128) as i64,
+        ))
+    }
+
+    /// Calculates the total harmonic distortion (THD) of a signal given the magnitude of its
+    /// fundamental frequency and the magnitudes of its harmonics.
+    ///
+    /// The result is a fraction, where `0.01` is 1% THD.
+    /// Panics if the fundamental voltage is zero.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn total_harmonic_distortion(fundamental: Voltage, harmonics: &[Voltage]) -> f64 {
+        if fundamental.is_zero() {
+            panic!("Fundamental voltage cannot be zero");
+        }
+
+        let sum_of_squares: f64 = harmonics
+            .iter()
+            .map(|harmonic| {
+                let micro_volts = harmonic.raw as f64;
+                micro_volts * micro_volts
+            })
+            .sum();
+
+        libm::sqrt(sum_of_squares) / fundamental.raw.unsigned_abs() as f64
+    }
+
+    /// Converts the magnitude of the voltage to a level in decibel-volts (dBV), relative to one
+    /// volt.
+    ///
+    /// Will be rounded to the nearest whole millidecibel (mdB).
+    /// Returns `None` if the voltage is zero, as its level would be negative infinity.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn to_dbv(&self) -> Option<Decibel> {
+        self.to_dbuv()
+            .and_then(|level| level.checked_sub(Decibel::from_milli_decibels(120_000)))
+    }
+
+    /// Converts the magnitude of the voltage to a level in decibel-microvolts (dBμV), relative to
+    /// one microvolt.
+    ///
+    /// Will be rounded to the nearest whole millidecibel (mdB).
+    /// Returns `None` if the voltage is zero, as its level would be negative infinity.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn to_dbuv(&self) -> Option<Decibel> {
+        if self.is_zero() {
+            return None;
+        }
+
+        Decibel::from_decibels_rounded(20f64 * libm::log10(self.raw.unsigned_abs() as f64))
+    }
+
+    /// Creates a new positive `Voltage` from a level in decibel-volts (dBV), relative to one volt.
+    ///
+    /// Will be rounded to the nearest whole microvolt (μV).
+    /// Returns `None` if the voltage would be too large to be represented.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn from_dbv(level: Decibel) -> Option<Self> {
+        Self::from_micro_volts_level(level.decibels() + 120f64)
+    }
+
+    /// Creates a new positive `Voltage` from a level in decibel-microvolts (dBμV), relative to one
+    /// microvolt.
+    ///
+    /// Will be rounded to the nearest whole microvolt (μV).
+    /// Returns `None` if the voltage would be too large to be represented.
+    ///
+    /// Requires the `libm` feature.
+    #[cfg(feature = "libm")]
+    pub fn from_dbuv(level: Decibel) -> Option<Self> {
+        Self::from_micro_volts_level(level.decibels())
+    }
+
+    #[cfg(feature = "libm")]
+    fn from_micro_volts_level(decibels: f64) -> Option<Self> {
+        let micro_volts = libm::pow(10f64, decibels / 20f64);
+
+        f64_to_i64(libm::round(micro_volts)).map(Self::from_micro_volts)
+    }
+
+    /// Converts a single-ended ADC reading to the voltage it represents, given the resolution of the
+    /// ADC in bits and its reference voltage (`V = raw × Vref ÷ 2ⁿ`).
+    ///
+    /// Uses only integer math with a 128-bit intermediate value, so it cannot overflow. The result
+    /// is rounded toward zero to the nearest whole microvolt (μV).
+    ///
+    /// Panics if the resolution is zero or greater than 32 bits, or if the reading does not fit
+    /// within the resolution.
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let voltage = Voltage::from_adc(2_048, 12, 3.3.volts());
+    /// assert_eq!(voltage, 1.65.volts());
+    /// ```
+    pub const fn from_adc(raw: u32, resolution_bits: u8, vref: Voltage) -> Voltage {
+        if resolution_bits == 0 || resolution_bits > 32 {
+            panic!("ADC resolution must be between 1 and 32 bits");
+        }
+        if resolution_bits < 32 && raw >> resolution_bits != 0 {
+            panic!("ADC reading exceeds its resolution");
+        }
+
+        let full_scale = 1i128 << resolution_bits;
+        let micro_volts = raw as i128 * vref.raw as i128 / full_scale;
+
+        Voltage::from_micro_volts(micro_volts as i64)
+    }
+
+    /// Converts a differential ADC reading in two's complement to the signed voltage it represents,
+    /// given the resolution of the ADC in bits and its reference voltage
+    /// (`V = raw × Vref ÷ 2ⁿ⁻¹`).
+    ///
+    /// The reading spans `-Vref` to just below `+Vref`, as with bipolar and differential ADCs.
+    /// Uses only integer math with a 128-bit intermediate value, so it cannot overflow. The result
+    /// is rounded toward zero to the nearest whole microvolt (μV).
+    ///
+    /// Panics if the resolution is less than 2 or greater than 32 bits, or if the reading does not
+    /// fit within the resolution.
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let voltage = Voltage::from_adc_differential(-8_192, 16, 2.048.volts());
+    /// assert_eq!(voltage, (-512).milli_volts());
+    /// ```
+    pub const fn from_adc_differential(raw: i32, resolution_bits: u8, vref: Voltage) -> Voltage {
+        if resolution_bits < 2 || resolution_bits > 32 {
+            panic!("ADC resolution must be between 2 and 32 bits");
+        }
+
+        let half_scale = 1i128 << (resolution_bits - 1);
+        if (raw as i128) < -half_scale || raw as i128 >= half_scale {
+            panic!("ADC reading exceeds its resolution");
+        }
+
+        let micro_volts = raw as i128 * vref.raw as i128 / half_scale;
+
+        Voltage::from_micro_volts(micro_volts as i64)
+    }
+
+    /// Converts the voltage to the code a DAC would need to output it, given the resolution of the
+    /// DAC in bits and its reference voltage (`code = V × 2ⁿ ÷ Vref`).
+    ///
+    /// The code is rounded in the direction of `mode`, and clamped to the range of the DAC, so a
+    /// negative voltage results in zero and a voltage at or above the reference results in the
+    /// maximum code. Use `checked_to_dac_code` to detect an out of range voltage instead.
+    ///
+    /// Panics if the resolution is zero or greater than 32 bits, or if the reference voltage is not
+    /// positive.
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let code = 1.65.volts().to_dac_code(12, 3.3.volts(), RoundingMode::Nearest);
+    /// assert_eq!(code, 2_048);
+    ///
+    /// let clamped = 5.volts().to_dac_code(12, 3.3.volts(), RoundingMode::Nearest);
+    /// assert_eq!(clamped, 4_095);
+    /// ```
+    pub const fn to_dac_code(&self, resolution_bits: u8, vref: Voltage, mode: RoundingMode) -> u32 {
+        let max_code = Self::max_dac_code(resolution_bits, vref);
+
+        if self.raw <= 0 {
+            return 0;
+        }
+
+        match self.dac_code(resolution_bits, vref, mode) {
+            code if code > max_code as u128 => max_code,
+            code => code as u32,
+        }
+    }
+
+    /// Converts the voltage to the code a DAC would need to output it, given the resolution of the
+    /// DAC in bits and its reference voltage (`code = V × 2ⁿ ÷ Vref`).
+    ///
+    /// The code is rounded in the direction of `mode`. Returns `None` if the voltage is negative
+    /// or the rounded code is beyond the range of the DAC.
+    ///
+    /// Panics if the resolution is zero or greater than 32 bits, or if the reference voltage is not
+    /// positive.
+    pub const fn checked_to_dac_code(
+        &self,
+        resolution_bits: u8,
+        vref: Voltage,
+        mode: RoundingMode,
+    ) -> Option<u32> {
+        let max_code = Self::max_dac_code(resolution_bits, vref);
+
+        if self.raw < 0 {
+            return None;
+        }
+
+        match self.dac_code(resolution_bits, vref, mode) {
+            code if code > max_code as u128 => None,
+            code => Some(code as u32),
+        }
+    }
+
+    /// Returns the largest code a DAC with the given resolution can output, after checking that the
+    /// resolution and reference voltage are valid.
+    const fn max_dac_code(resolution_bits: u8, vref: Voltage) -> u32 {
+        if resolution_bits == 0 || resolution_bits > 32 {
+            panic!("DAC resolution must be between 1 and 32 bits");
+        }
+        if vref.raw <= 0 {
+            panic!("DAC reference voltage must be positive");
+        }
+
+        u32::MAX >> (32 - resolution_bits)
+    }
+
+    /// Calculates the unclamped DAC code for a non-negative voltage.
+    const fn dac_code(&self, resolution_bits: u8, vref: Voltage, mode: RoundingMode) -> u128 {
+        let scaled = (self.raw.unsigned_abs() as u128) << resolution_bits;
+
+        mode.div_u128(scaled, vref.raw as u128)
+    }
+
+    /// Returns the unit symbol used when displaying the voltage value, such as `"mV"`.
+    ///
+    /// This is the same unit chosen by the `Display` implementation, so it can be used to align
+    /// values in columns.
+    #[inline]
+    pub const fn display_unit(&self) -> &'static str {
+        self.display_scale().1
+    }
+
+    /// Returns the number of base units per displayed unit, along with its symbol.
+    const fn display_scale(&self) -> (u64, &'static str) {
+        match self.raw.unsigned_abs() {
+            0..=999 => (1, "μV"),
+            1_000..=999_999 => (1_000, "mV"),
+            1_000_000..=999_999_999 => (1_000_000, "V"),
+            _ => (1_000_000_000, "kV"),
+        }
+    }
+
+    /// Formats the voltage value into a byte buffer in the given unit, rounded to `precision`
+    /// decimal places, returning the written string, such as `"3.30 V"`.
+    ///
+    /// No floating-point math or heap allocation is used, so this is suitable for rendering values
+    /// to displays on targets without a floating-point unit. Returns an error if the buffer is too
+    /// small to hold the formatted value.
+    pub fn format_into<'a>(
+        &self,
+        buf: &'a mut [u8],
+        unit: VoltageUnit,
+        precision: usize,
+    ) -> Result<&'a str, fmt::Error> {
+        display::format_into(
+            buf,
+            self.raw < 0,
+            self.raw.unsigned_abs(),
+            unit.scale(),
+            precision,
+            unit.symbol(),
+        )
+    }
+
+    /// Parses a `Voltage` value from RKM notation, where `V` takes the place of the decimal point,
+    /// such as `3V3` (3.3V), `1V8` (1.8V), `12V` or `0V65` (650mV). A leading `-` is accepted for
+    /// negative voltages.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt (μV).
+    ///
+    /// ```rust
+    /// use ohms::prelude::*;
+    ///
+    /// let v = Voltage::from_rkm("3V3").unwrap();
+    /// assert_eq!(v, 3.3.volts());
+    /// ```
+    pub fn from_rkm(s: &str) -> Result<Voltage, ParseError> {
+        let multiplier = |marker| match marker {
+            'V' | 'v' => Some(6),
+            _ => None,
+        };
+
+        let s = s.trim();
+        let micro_volts = match s.strip_prefix('-') {
+            Some(magnitude) => 0i64.checked_sub_unsigned(parse_rkm(magnitude, multiplier)?),
+            None => i64::try_from(parse_rkm(s, multiplier)?).ok(),
+        };
+
+        micro_volts
+            .map(Self::from_micro_volts)
+            .ok_or(ParseError::Overflow)
+    }
+
+    /// Formats the voltage value into a byte buffer in RKM notation, such as `3V3` or `12V`,
+    /// returning the written string.
+    ///
+    /// The value is never rounded. Returns an error if the buffer is too small to hold the
+    /// formatted value.
+    pub fn to_rkm<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+        display::format_rkm(buf, self.raw < 0, self.raw.unsigned_abs(), 1_000_000, 'V')
+    }
+}
+
+/// The units a `Voltage` value can be formatted in, used by [`Voltage::format_into`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoltageUnit {
+    /// Microvolts (μV).
+    MicroVolts,
+    /// Millivolts (mV).
+    MilliVolts,
+    /// Volts (V).
+    Volts,
+    /// Kilovolts (kV).
+    KiloVolts,
+}
+
+impl VoltageUnit {
+    /// Returns the number of base units in one of this unit.
+    const fn scale(self) -> u64 {
+        match self {
+            VoltageUnit::MicroVolts => 1,
+            VoltageUnit::MilliVolts => 1_000,
+            VoltageUnit::Volts => 1_000_000,
+            VoltageUnit::KiloVolts => 1_000_000_000,
+        }
+    }
+
+    /// Returns the symbol of this unit, such as `"V"`.
+    #[inline]
+    pub const fn symbol(self) -> &'static str {
+        match self {
+            VoltageUnit::MicroVolts => "μV",
+            VoltageUnit::MilliVolts => "mV",
+            VoltageUnit::Volts => "V",
+            VoltageUnit::KiloVolts => "kV",
+        }
+    }
 }
 
+approx::impl_approx_eq!(Voltage, signed);
+compare::impl_const_compare!(Voltage, signed);
+
 impl PartialEq for Voltage {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
@@ -176,7 +763,7 @@ impl Eq for Voltage {}
 impl PartialOrd for Voltage {
     #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Some(self.cmp(other))
     }
 }
 
@@ -187,6 +774,13 @@ impl Ord for Voltage {
     }
 }
 
+impl hash::Hash for Voltage {
+    #[inline]
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+    }
+}
+
 impl ops::Add for Voltage {
     type Output = Self;
 
@@ -213,6 +807,34 @@ impl ops::Sub for Voltage {
     }
 }
 
+impl iter::Sum for Voltage {
+    /// Sums the `Voltage` values, returning zero (0V) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |total, value| total + value)
+    }
+}
+
+impl<'a> iter::Sum<&'a Voltage> for Voltage {
+    /// Sums the `Voltage` values, returning zero (0V) if there are none.
+    ///
+    /// Panics if the total would overflow.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl ops::Neg for Voltage {
+    type Output = Self;
+
+    /// Inverts the `Voltage` value from positive to negative or negative to positive.
+    #[inline]
+    fn neg(self) -> Self {
+        self.invert()
+    }
+}
+
 macro_rules! impl_mul_for_integer {
     ($i: ty) => {
         impl ops::Mul<$i> for Voltage {
@@ -441,20 +1063,583 @@ impl_voltage_from_float!(f64);
 
 impl fmt::Display for Voltage {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let precision = f.precision().unwrap_or(2);
         let sign = if self.raw < 0 { "-" } else { "" };
-        let microvolts = self.micro_volts().abs() as f64;
-        let kilovolts = microvolts / 1_000_000_000f64;
-        let volts = microvolts / 1_000_000f64;
-        let millivolts = microvolts / 1_000f64;
-
-        if kilovolts >= 1f64 {
-            write!(f, "{sign}{kilovolts:.2} kV")
-        } else if volts >= 1f64 {
-            write!(f, "{sign}{volts:.2} V")
-        } else if millivolts > 0f64 {
-            write!(f, "{sign}{millivolts:.2} mV")
-        } else {
-            write!(f, "{sign}{microvolts:.2} μV")
+        let (scale, unit) = self.display_scale();
+        let value = (self.raw.unsigned_abs() as f64) / (scale as f64);
+
+        display::write_padded(f, format_args!("{sign}{value:.precision$} {unit}"))
+    }
+}
+
+impl str::FromStr for Voltage {
+    type Err = ParseError;
+
+    /// Parses a `Voltage` value from a string with an optional SI prefix and unit symbol, such as
+    /// `3.3 V`, `-5mV` or `12`.
+    ///
+    /// Will be rounded toward zero to the nearest whole microvolt (μV).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_si_signed(s, &["V"], 6).map(Self::from_micro_volts)
+    }
+}
+
+#[cfg(any(feature = "defmt", feature = "ufmt"))]
+impl Voltage {
+    /// Splits the voltage value into its sign, whole and hundredths parts in the most appropriate
+    /// unit, for formatting without floating-point math.
+    fn scaled_parts(&self) -> (&'static str, u64, u64, &'static str) {
+        let sign = if self.raw < 0 { "-" } else { "" };
+        let micro_volts = self.raw.unsigned_abs();
+        let (scale, unit) = self.display_scale();
+
+        let whole = micro_volts / scale;
+        let fraction = micro_volts % scale * 100 / scale;
+
+        (sign, whole, fraction, unit)
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for Voltage {
+    fn format(&self, f: defmt::Formatter) {
+        let (sign, whole, fraction, unit) = self.scaled_parts();
+
+        defmt::write!(
+            f,
+            "{=str}{=u64}.{=u64:02} {=str}",
+            sign,
+            whole,
+            fraction,
+            unit
+        )
+    }
+}
+
+#[cfg(feature = "ufmt")]
+impl ufmt::uDisplay for Voltage {
+    fn fmt<W>(&self, f: &mut ufmt::Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: ufmt::uWrite + ?Sized,
+    {
+        let (sign, whole, fraction, unit) = self.scaled_parts();
+        let padding = if fraction < 10 { "0" } else { "" };
+
+        ufmt::uwrite!(f, "{}{}.{}{} {}", sign, whole, padding, fraction, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+    use test_case::test_case;
+
+    #[test_case(3_300_000, (3_300, 0), (3, 300_000); "positive volts")]
+    #[test_case(-1_500_000, (-1_500, 0), (-2, 500_000); "negative volts round down")]
+    #[test_case(-250, (-1, 750), (-1, 999_750); "negative microvolts round down")]
+    #[test_case(0, (0, 0), (0, 0); "zero")]
+    fn test_to_parts(micro_volts: i64, milli_volts: (i64, u32), volts: (i64, u32)) {
+        let voltage = Voltage::from_micro_volts(micro_volts);
+        assert_eq!(voltage.to_milli_volts_parts(), milli_volts);
+        assert_eq!(voltage.to_volts_parts(), volts);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test_case(1_000_000, [], 0.0; "no harmonics equals 0%")]
+    #[test_case(1_000_000, [10_000], 0.01; "1V fundamental, 10mV harmonic equals 1%")]
+    #[test_case(1_000_000, [30_000, 40_000], 0.05; "1V fundamental, 30mV and 40mV harmonics equals 5%")]
+    #[test_case(-2_000_000, [-30_000, 40_000], 0.025; "negative values use magnitude")]
+    fn test_total_harmonic_distortion<const N: usize>(
+        fundamental: i64,
+        harmonics: [i64; N],
+        expected: f64,
+    ) {
+        let voltages = harmonics.map(Voltage::from_micro_volts);
+
+        let thd =
+            Voltage::total_harmonic_distortion(Voltage::from_micro_volts(fundamental), &voltages);
+
+        assert!((thd - expected).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test]
+    #[should_panic]
+    fn test_total_harmonic_distortion_zero_fundamental_panics() {
+        Voltage::total_harmonic_distortion(Voltage::zero(), &[Voltage::from_micro_volts(1)]);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test_case(1_000_000, Some(0), Some(120_000); "1V")]
+    #[test_case(-1_000_000, Some(0), Some(120_000); "negative uses magnitude")]
+    #[test_case(100_000, Some(-20_000), Some(100_000); "100mV")]
+    #[test_case(1, Some(-120_000), Some(0); "1uV")]
+    #[test_case(0, None, None; "zero")]
+    fn test_to_dbv_and_dbuv(micro_volts: i64, dbv: Option<i32>, dbuv: Option<i32>) {
+        let voltage = Voltage::from_micro_volts(micro_volts);
+
+        assert_eq!(voltage.to_dbv().map(|db| db.milli_decibels()), dbv);
+        assert_eq!(voltage.to_dbuv().map(|db| db.milli_decibels()), dbuv);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test_case(0, Some(1_000_000); "0dBV")]
+    #[test_case(-6_021, Some(499_977); "-6dBV")]
+    #[test_case(-120_000, Some(1); "1uV")]
+    #[test_case(260_000, None; "overflow")]
+    fn test_from_dbv(milli_decibels: i32, expected: Option<i64>) {
+        let voltage = Voltage::from_dbv(Decibel::from_milli_decibels(milli_decibels));
+        assert_eq!(voltage.map(|v| v.micro_volts()), expected);
+    }
+
+    #[cfg(feature = "libm")]
+    #[test_case(120_000, Some(1_000_000); "1V")]
+    #[test_case(0, Some(1); "1uV")]
+    #[test_case(-20_000, Some(0); "rounds to zero")]
+    fn test_from_dbuv(milli_decibels: i32, expected: Option<i64>) {
+        let voltage = Voltage::from_dbuv(Decibel::from_milli_decibels(milli_decibels));
+        assert_eq!(voltage.map(|v| v.micro_volts()), expected);
+    }
+
+    #[test]
+    fn test_sum() {
+        let values = [
+            Voltage::from_micro_volts(1),
+            Voltage::from_micro_volts(2),
+            Voltage::from_micro_volts(-4),
+        ];
+
+        assert_eq!(values.iter().sum::<Voltage>().micro_volts(), -1);
+        assert_eq!(values.into_iter().sum::<Voltage>().micro_volts(), -1);
+        assert_eq!(
+            core::iter::empty::<Voltage>().sum::<Voltage>(),
+            Voltage::zero()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sum_overflow_panics() {
+        let _: Voltage = [
+            Voltage::from_micro_volts(i64::MAX),
+            Voltage::from_micro_volts(1),
+        ]
+        .iter()
+        .sum();
+    }
+
+    #[test_case(1, 2, Some(3); "adds values")]
+    #[test_case(-1, -2, Some(-3); "adds negative values")]
+    #[test_case(i64::MAX, 1, None; "overflow is none")]
+    #[test_case(i64::MIN, -1, None; "underflow is none")]
+    fn test_checked_add(a: i64, b: i64, expected: Option<i64>) {
+        let result = Voltage::from_micro_volts(a).checked_add(Voltage::from_micro_volts(b));
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(3, 2, Some(1); "subtracts values")]
+    #[test_case(2, 3, Some(-1); "subtracts to negative value")]
+    #[test_case(i64::MIN, 1, None; "underflow is none")]
+    #[test_case(i64::MAX, -1, None; "overflow is none")]
+    fn test_checked_sub(a: i64, b: i64, expected: Option<i64>) {
+        let result = Voltage::from_micro_volts(a).checked_sub(Voltage::from_micro_volts(b));
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(-1_000, 1_000, 2_000, true; "within tolerance across zero")]
+    #[test_case(-1_000, 1_001, 2_000, false; "outside tolerance across zero")]
+    #[test_case(i64::MIN, i64::MAX, i64::MIN, false; "negative tolerance uses magnitude")]
+    fn test_approx_eq(a: i64, b: i64, tolerance: i64, expected: bool) {
+        let a = Voltage::from_micro_volts(a);
+        let b = Voltage::from_micro_volts(b);
+
+        assert_eq!(
+            a.approx_eq(b, Voltage::from_micro_volts(tolerance)),
+            expected
+        );
+    }
+
+    #[test_case(-1_000_000, -1_010_000, 1.0, true; "negative values within 1%")]
+    #[test_case(-1_000_000, 1_000_000, 100.0, false; "opposite signs outside 100%")]
+    #[test_case(-1_000_000, 1_000_000, 200.0, true; "opposite signs within 200%")]
+    fn test_approx_eq_rel(a: i64, b: i64, percent: f64, expected: bool) {
+        let a = Voltage::from_micro_volts(a);
+        let b = Voltage::from_micro_volts(b);
+
+        assert_eq!(a.approx_eq_rel(b, percent), expected);
+    }
+
+    #[test]
+    fn test_const_min_max_clamp() {
+        const RAIL_MIN: Voltage = Voltage::from_micro_volts(-5_000_000);
+        const RAIL_MAX: Voltage = Voltage::from_micro_volts(5_000_000);
+        const CLAMPED: Voltage = Voltage::from_micro_volts(12_000_000).clamp(RAIL_MIN, RAIL_MAX);
+
+        assert_eq!(CLAMPED, RAIL_MAX);
+        assert_eq!(RAIL_MIN.min(RAIL_MAX), RAIL_MIN);
+        assert_eq!(RAIL_MIN.max(RAIL_MAX), RAIL_MAX);
+    }
+
+    #[test_case(1_000, 3_000, 2_000; "smaller minus larger")]
+    #[test_case(3_000, 1_000, 2_000; "larger minus smaller")]
+    #[test_case(-1_000, 1_000, 2_000; "across zero")]
+    #[test_case(i64::MIN, i64::MAX, i64::MAX; "saturates at maximum")]
+    fn test_abs_diff(a: i64, b: i64, expected: i64) {
+        let a = Voltage::from_micro_volts(a);
+        let b = Voltage::from_micro_volts(b);
+
+        assert_eq!(a.abs_diff(b).micro_volts(), expected);
+    }
+
+    #[test_case(-6_000_000, -5_000_000; "below minimum")]
+    #[test_case(-5_000_000, -5_000_000; "at minimum")]
+    #[test_case(1_000_000, 1_000_000; "within range")]
+    #[test_case(5_000_001, 5_000_000; "above maximum")]
+    fn test_clamp(micro_volts: i64, expected: i64) {
+        let v = Voltage::from_micro_volts(micro_volts).clamp(
+            Voltage::from_micro_volts(-5_000_000),
+            Voltage::from_micro_volts(5_000_000),
+        );
+
+        assert_eq!(v.micro_volts(), expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_clamp_inverted_range_panics() {
+        Voltage::zero().clamp(Voltage::from_micro_volts(1), Voltage::from_micro_volts(-1));
+    }
+
+    #[test_case(3_300_000, -3_300_000; "positive to negative")]
+    #[test_case(-5_000_000, 5_000_000; "negative to positive")]
+    #[test_case(0, 0; "zero")]
+    #[test_case(i64::MAX, -i64::MAX; "maximum")]
+    fn test_neg(micro_volts: i64, expected: i64) {
+        let v = Voltage::from_micro_volts(micro_volts);
+
+        assert_eq!((-v).micro_volts(), expected);
+        assert_eq!(v.invert(), -v);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_neg_minimum_panics() {
+        let _ = -Voltage::from_micro_volts(i64::MIN);
+    }
+
+    #[test_case(1, Some(-1); "negates value")]
+    #[test_case(i64::MIN, None; "minimum is none")]
+    fn test_checked_neg(micro_volts: i64, expected: Option<i64>) {
+        let result = Voltage::from_micro_volts(micro_volts).checked_neg();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(3, -2, Some(-6); "multiplies by negative factor")]
+    #[test_case(i64::MAX, 2, None; "overflow is none")]
+    #[test_case(i64::MIN, -1, None; "negating minimum is none")]
+    fn test_checked_mul_int(value: i64, factor: i64, expected: Option<i64>) {
+        let result = Voltage::from_micro_volts(value).checked_mul_int(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(-7, 2, Some(-3); "divides toward zero")]
+    #[test_case(1, 0, None; "division by zero is none")]
+    #[test_case(i64::MIN, -1, None; "overflow is none")]
+    fn test_checked_div_int(value: i64, divisor: i64, expected: Option<i64>) {
+        let result = Voltage::from_micro_volts(value).checked_div_int(divisor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, -1.5, Some(-1_500); "multiplies by negative factor")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    #[test_case(i64::MAX, 2.0, None; "overflow is none")]
+    #[test_case(i64::MIN, 2.0, None; "underflow is none")]
+    fn test_checked_mul_f64(value: i64, factor: f64, expected: Option<i64>) {
+        let result = Voltage::from_micro_volts(value).checked_mul_f64(factor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(1_000, -4.0, Some(-250); "divides by negative divisor")]
+    #[test_case(1_000, 0.0, None; "division by zero is none")]
+    #[test_case(1_000, f64::INFINITY, None; "infinity is none")]
+    #[test_case(1_000, f64::NAN, None; "NaN is none")]
+    #[test_case(i64::MAX, 0.5, None; "overflow is none")]
+    fn test_checked_div_f64(value: i64, divisor: f64, expected: Option<i64>) {
+        let result = Voltage::from_micro_volts(value).checked_div_f64(divisor);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(-5, 0.5, RoundingMode::Nearest, Some(-2); "nearest rounds half up")]
+    #[test_case(-5, 0.5, RoundingMode::Up, Some(-2); "rounds up")]
+    #[test_case(-5, 0.5, RoundingMode::Down, Some(-3); "rounds down")]
+    #[test_case(i64::MAX, 2.0, RoundingMode::Up, None; "overflow is none")]
+    fn test_checked_mul_f64_rounded(
+        value: i64,
+        factor: f64,
+        mode: RoundingMode,
+        expected: Option<i64>,
+    ) {
+        let result = Voltage::from_micro_volts(value).checked_mul_f64_rounded(factor, mode);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(5, 2.0, RoundingMode::Nearest, Some(3); "nearest rounds half up")]
+    #[test_case(7, 2.0, RoundingMode::Down, Some(3); "rounds down")]
+    #[test_case(5, 0.0, RoundingMode::Up, None; "division by zero is none")]
+    fn test_checked_div_f64_rounded(
+        value: i64,
+        divisor: f64,
+        mode: RoundingMode,
+        expected: Option<i64>,
+    ) {
+        let result = Voltage::from_micro_volts(value).checked_div_f64_rounded(divisor, mode);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(-2.5, RoundingMode::Nearest, Ok(-2); "nearest rounds half up")]
+    #[test_case(-2.4, RoundingMode::Down, Ok(-3); "rounds down")]
+    #[test_case(f64::NAN, RoundingMode::Up, Err(UnitError::NaN); "NaN")]
+    fn test_try_from_rounded(value: f64, mode: RoundingMode, expected: Result<i64, UnitError>) {
+        let result = Voltage::try_from_rounded(value, VoltageUnit::MicroVolts, mode);
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case(3_300_000, "3.30 V"; "positive volts")]
+    #[test_case(-3_300_000, "-3.30 V"; "negative volts")]
+    #[test_case(-250, "-250.00 μV"; "negative microvolts")]
+    #[test_case(0, "0.00 μV"; "zero")]
+    #[test_case(12_500, "12.50 mV"; "millivolts")]
+    #[test_case(1_200_000_000, "1.20 kV"; "kilovolts")]
+    fn test_display(micro_volts: i64, expected: &str) {
+        let voltage = Voltage::from_micro_volts(micro_volts);
+        assert_eq!(format!("{voltage}"), expected);
+    }
+
+    #[test]
+    fn test_display_precision() {
+        let voltage = Voltage::from_micro_volts(-3_300_000);
+        assert_eq!(format!("{voltage:.0}"), "-3 V");
+        assert_eq!(format!("{voltage:.4}"), "-3.3000 V");
+        assert_eq!(format!("{voltage:.6}"), "-3.300000 V");
+    }
+
+    #[test_case("3.3 V", Ok(3_300_000); "volts with space")]
+    #[test_case("-5mV", Ok(-5_000); "negative millivolts")]
+    #[test_case("12", Ok(12_000_000); "volts without symbol")]
+    #[test_case("1.2kV", Ok(1_200_000_000); "kilo prefix")]
+    #[test_case("5x", Err(ParseError::InvalidUnit); "unknown prefix")]
+    fn test_from_str(s: &str, expected: Result<i64, ParseError>) {
+        let result = s.parse::<Voltage>();
+        assert_eq!(result.map(|v| v.raw), expected);
+    }
+
+    #[test_case("3V3", Ok(3_300_000); "3V3 equals 3.3V")]
+    #[test_case("1V8", Ok(1_800_000); "1V8 equals 1.8V")]
+    #[test_case("12V", Ok(12_000_000); "trailing marker")]
+    #[test_case("0V65", Ok(650_000); "below one volt")]
+    #[test_case("-5V", Ok(-5_000_000); "negative")]
+    #[test_case("3.3V", Err(ParseError::InvalidUnit); "decimal point")]
+    #[test_case("33", Err(ParseError::InvalidUnit); "missing marker")]
+    #[test_case("99999999999999V", Err(ParseError::Overflow); "overflow")]
+    fn test_from_rkm(s: &str, expected: Result<i64, ParseError>) {
+        let voltage = Voltage::from_rkm(s);
+        assert_eq!(voltage.map(|v| v.micro_volts()), expected);
+    }
+
+    #[test_case(3_300_000, "3V3"; "3.3V")]
+    #[test_case(12_000_000, "12V"; "whole volts")]
+    #[test_case(650_000, "0V65"; "below one volt")]
+    #[test_case(-1_800_000, "-1V8"; "negative")]
+    fn test_to_rkm(micro_volts: i64, expected: &str) {
+        let mut buf = [0u8; 32];
+        let voltage = Voltage::from_micro_volts(micro_volts);
+        assert_eq!(voltage.to_rkm(&mut buf), Ok(expected));
+    }
+
+    #[test_case(3_300_000, VoltageUnit::Volts, 2, "3.30 V"; "volts")]
+    #[test_case(-3_300_000, VoltageUnit::MilliVolts, 0, "-3300 mV"; "negative millivolts")]
+    #[test_case(12_345, VoltageUnit::Volts, 3, "0.012 V"; "small value in volts")]
+    fn test_format_into(micro_volts: i64, unit: VoltageUnit, precision: usize, expected: &str) {
+        let mut buf = [0u8; 32];
+        let voltage = Voltage::from_micro_volts(micro_volts);
+        assert_eq!(voltage.format_into(&mut buf, unit, precision), Ok(expected));
+    }
+
+    #[test]
+    fn test_display_width() {
+        let voltage = Voltage::from_micro_volts(-3_300_000);
+        assert_eq!(format!("{voltage:>10.3}"), "  -3.300 V");
+        assert_eq!(format!("{voltage:<8}"), "-3.30 V ");
+    }
+
+    #[test_case(-250, "μV"; "negative microvolts")]
+    #[test_case(12_500, "mV"; "millivolts")]
+    #[test_case(-3_300_000, "V"; "negative volts")]
+    #[test_case(1_200_000_000, "kV"; "kilovolts")]
+    fn test_display_unit(micro_volts: i64, expected: &str) {
+        let voltage = Voltage::from_micro_volts(micro_volts);
+        assert_eq!(voltage.display_unit(), expected);
+    }
+
+    #[cfg(feature = "ufmt")]
+    #[test_case(3_300_000, "3.30 V"; "positive volts")]
+    #[test_case(-3_300_000, "-3.30 V"; "negative volts")]
+    #[test_case(12_050, "12.05 mV"; "millivolts")]
+    fn test_udisplay(micro_volts: i64, expected: &str) {
+        let voltage = Voltage::from_micro_volts(micro_volts);
+        let mut output = alloc::string::String::new();
+        ufmt::uwrite!(output, "{}", voltage).unwrap();
+        assert_eq!(output, expected);
+    }
+
+    #[test_case(1_500_000, Some(1_500); "whole microvolts")]
+    #[test_case(-1_500_000, Some(-1_500); "negative")]
+    #[test_case(1_500_999, None; "fraction of a microvolt")]
+    #[test_case(999, None; "less than one microvolt")]
+    fn test_from_nano_volts(nano: i64, expected_micro: Option<i64>) {
+        let v = Voltage::from_nano_volts(nano);
+
+        assert_eq!(v.map(|v| v.raw), expected_micro);
+        assert_eq!(
+            v.map(|v| v.nano_volts()),
+            expected_micro.map(|micro| micro as f64 * 1_000.0)
+        );
+    }
+
+    #[test_case(-2_000.0, Ok(-2); "whole microvolts")]
+    #[test_case(-2_500.0, Err(UnitError::Inexact); "fractional microvolts")]
+    #[test_case(f64::NAN, Err(UnitError::NaN); "NaN")]
+    fn test_try_from_nano_volts(nano: f64, expected_micro: Result<i64, UnitError>) {
+        let result = Voltage::try_from_nano_volts(nano);
+        assert_eq!(result.map(|v| v.raw), expected_micro);
+    }
+
+    #[test]
+    fn test_rms_empty_is_none() {
+        assert_eq!(Voltage::rms(&[]), None);
+    }
+
+    #[test_case([1_000_000], 1_000_000; "single 1V sample equals 1V")]
+    #[test_case([-2_000, 2_000, -2_000], 2_000; "square wave equals its amplitude")]
+    #[test_case([0, 0, 0], 0; "all zero equals 0V")]
+    #[test_case([-3_000, 4_000], 3_535; "mixed polarity rounds down")]
+    #[test_case([i64::MAX, i64::MAX], i64::MAX; "maximum samples do not overflow")]
+    #[test_case([i64::MIN, i64::MIN], i64::MAX; "minimum samples are clamped")]
+    fn test_rms<const N: usize>(samples: [i64; N], expected_micro_volts: i64) {
+        let voltages = samples.map(Voltage::from_micro_volts);
+
+        let rms = Voltage::rms(&voltages).unwrap();
+
+        assert_eq!(rms.micro_volts(), expected_micro_volts);
+    }
+
+    #[test_case(2_048, 12, 3_300_000, 1_650_000; "half scale")]
+    #[test_case(4_095, 12, 3_300_000, 3_299_194; "full scale rounds down")]
+    #[test_case(0, 12, 3_300_000, 0; "zero")]
+    #[test_case(u32::MAX, 32, 5_000_000, 4_999_999; "32 bits")]
+    #[test_case(1, 1, -2_000_000, -1_000_000; "negative reference")]
+    fn test_from_adc(raw: u32, bits: u8, vref: i64, expected: i64) {
+        let voltage = Voltage::from_adc(raw, bits, Voltage::from_micro_volts(vref));
+        assert_eq!(voltage.raw, expected);
+    }
+
+    #[test_case(4_096, 12; "reading too large")]
+    #[test_case(0, 0; "zero bits")]
+    #[test_case(0, 33; "too many bits")]
+    #[should_panic]
+    fn test_from_adc_invalid_panics(raw: u32, bits: u8) {
+        Voltage::from_adc(raw, bits, Voltage::from_micro_volts(3_300_000));
+    }
+
+    #[test_case(-32_768, 16, -2_048_000; "negative full scale")]
+    #[test_case(32_767, 16, 2_047_937; "positive full scale rounds down")]
+    #[test_case(-1, 16, -62; "negative rounds toward zero")]
+    #[test_case(i32::MIN, 32, -2_048_000; "32 bits")]
+    fn test_from_adc_differential(raw: i32, bits: u8, expected: i64) {
+        let vref = Voltage::from_micro_volts(2_048_000);
+        assert_eq!(
+            Voltage::from_adc_differential(raw, bits, vref).raw,
+            expected
+        );
+    }
+
+    #[test_case(2_048, 12; "reading too large")]
+    #[test_case(-2_049, 12; "reading too small")]
+    #[test_case(0, 1; "one bit")]
+    #[should_panic]
+    fn test_from_adc_differential_invalid_panics(raw: i32, bits: u8) {
+        Voltage::from_adc_differential(raw, bits, Voltage::from_micro_volts(2_048_000));
+    }
+    #[test_case(1_650_000, 12, RoundingMode::Nearest, 2_048; "half scale")]
+    #[test_case(1_000_000, 12, RoundingMode::Nearest, 1_241; "rounds to nearest")]
+    #[test_case(1_000_000, 12, RoundingMode::Up, 1_242; "rounds up")]
+    #[test_case(1_000_000, 12, RoundingMode::Down, 1_241; "rounds down")]
+    #[test_case(3_300_000, 12, RoundingMode::Nearest, 4_095; "reference clamps")]
+    #[test_case(-1_000_000, 12, RoundingMode::Up, 0; "negative clamps")]
+    #[test_case(3_300_000, 32, RoundingMode::Down, u32::MAX; "32 bits")]
+    fn test_to_dac_code(micro_volts: i64, bits: u8, mode: RoundingMode, expected: u32) {
+        let vref = Voltage::from_micro_volts(3_300_000);
+        let voltage = Voltage::from_micro_volts(micro_volts);
+
+        assert_eq!(voltage.to_dac_code(bits, vref, mode), expected);
+    }
+
+    #[test_case(1_650_000, RoundingMode::Nearest, Some(2_048); "in range")]
+    #[test_case(3_299_999, RoundingMode::Down, Some(4_095); "below reference")]
+    #[test_case(3_299_999, RoundingMode::Up, None; "rounds beyond range")]
+    #[test_case(-1, RoundingMode::Nearest, None; "negative")]
+    fn test_checked_to_dac_code(micro_volts: i64, mode: RoundingMode, expected: Option<u32>) {
+        let vref = Voltage::from_micro_volts(3_300_000);
+        let voltage = Voltage::from_micro_volts(micro_volts);
+
+        assert_eq!(voltage.checked_to_dac_code(12, vref, mode), expected);
+    }
+
+    #[test]
+    fn test_dac_code_round_trips_adc() {
+        let vref = Voltage::from_micro_volts(2_500_000);
+
+        for code in [0, 1, 1_000, 65_535] {
+            let voltage = Voltage::from_adc(code, 16, vref);
+            assert_eq!(voltage.to_dac_code(16, vref, RoundingMode::Up), code);
         }
     }
+
+    #[test_case(0, 3_300_000; "zero bits")]
+    #[test_case(12, 0; "zero reference")]
+    #[test_case(12, -3_300_000; "negative reference")]
+    #[should_panic]
+    fn test_to_dac_code_invalid_panics(bits: u8, vref: i64) {
+        let voltage = Voltage::from_micro_volts(-1_000_000);
+        voltage.to_dac_code(bits, Voltage::from_micro_volts(vref), RoundingMode::Nearest);
+    }
+
+    #[test_case(Voltage::try_from_milli_volts(-1.5), Ok(Voltage::from_micro_volts(-1_500)); "valid value")]
+    #[test_case(Voltage::try_from_milli_volts(f64::NAN), Err(UnitError::NaN); "NaN")]
+    #[test_case(Voltage::try_from_milli_volts(f64::INFINITY), Err(UnitError::Infinite); "infinity")]
+    #[test_case(Voltage::try_from_milli_volts(1e30), Err(UnitError::Overflow); "too large")]
+    fn test_try_from(result: Result<Voltage, UnitError>, expected: Result<Voltage, UnitError>) {
+        assert_eq!(result, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        use serde_test::{assert_tokens, Token};
+
+        assert_tokens(
+            &Voltage::from_micro_volts(-3300000),
+            &[
+                Token::Struct {
+                    name: "Voltage",
+                    len: 1,
+                },
+                Token::Str("micro_volts"),
+                Token::I64(-3300000),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -0,0 +1,174 @@
+//! Sizing the series resistor of a zener diode or shunt voltage reference.
+//!
+//! The resistor must pass the largest load current plus the minimum zener current at the lowest
+//! supply voltage, so it is sized as `R = (Vs(min) - Vz) ÷ (I(load, max) + Iz(min))`. Its worst-case
+//! dissipation is at the highest supply voltage, and the zener dissipates the most when the load
+//! draws its smallest current, so the supply and load current are given as `Toleranced` ranges.
+//!
+//! ```rust
+//! use ohms::prelude::*;
+//! use ohms::zener;
+//!
+//! let supply = Toleranced::from_percent(12.volts(), 10); // 10.8V to 13.2V
+//! let load = Toleranced::from_bounds(5.milli_amps(), 0.milli_amps(), 10.milli_amps()).unwrap();
+//!
+//! let bias = zener::bias_resistor(supply, 5_100.milli_volts(), load, 1.milli_amps()).unwrap();
+//! assert_eq!(bias.resistance(), 518_181.milli_ohms()); // 518.18Ω
+//! assert_eq!(bias.resistor_power(), 126_616.micro_watts()); // 126.6mW
+//! assert_eq!(bias.zener_power(), 79_722.micro_watts()); // 79.7mW
+//! ```
+use crate::{Current, Power, Resistance, Toleranced, Voltage};
+
+/// The series resistor for a zener diode and its worst-case power dissipation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ZenerBias {
+    resistance: Resistance,
+    resistor_power: Power,
+    zener_power: Power,
+}
+
+impl ZenerBias {
+    /// Returns the largest series resistance that still passes the minimum zener current.
+    ///
+    /// A standard value at or below this resistance should be chosen.
+    #[inline]
+    pub const fn resistance(&self) -> Resistance {
+        self.resistance
+    }
+
+    /// Returns the worst-case power dissipated in the series resistor, at the highest supply
+    /// voltage.
+    #[inline]
+    pub const fn resistor_power(&self) -> Power {
+        self.resistor_power
+    }
+
+    /// Returns the worst-case power dissipated in the zener, at the highest supply voltage and the
+    /// smallest load current.
+    #[inline]
+    pub const fn zener_power(&self) -> Power {
+        self.zener_power
+    }
+}
+
+/// Calculates the series resistor for a zener diode from the supply voltage range, the zener
+/// voltage, the load current range and the minimum zener current needed to stay in regulation.
+///
+/// The resistance is rounded down to the nearest whole milliohm (mΩ), and the powers are rounded
+/// up to the nearest whole microwatt (μW).
+/// Returns `None` if the zener voltage is not positive, the lowest supply voltage is not above it,
+/// no current is needed or the result would overflow.
+pub fn bias_resistor(
+    supply: Toleranced<Voltage>,
+    zener: Voltage,
+    load: Toleranced<Current>,
+    min_zener_current: Current,
+) -> Option<ZenerBias> {
+    let zener_micro_volts = zener.micro_volts();
+    if zener_micro_volts <= 0 {
+        return None;
+    }
+
+    let headroom_min = supply.min().micro_volts().checked_sub(zener_micro_volts)?;
+    let headroom_max = supply.max().micro_volts().checked_sub(zener_micro_volts)?;
+    if headroom_min <= 0 {
+        return None;
+    }
+
+    let (headroom_min, headroom_max) = (headroom_min as u128, headroom_max as u128);
+    let zener_micro_volts = zener_micro_volts as u128;
+
+    let total_micro_amps = load.max().micro_amps() as u128 + min_zener_current.micro_amps() as u128;
+    if total_micro_amps == 0 {
+        return None;
+    }
+
+    let milli_ohms = headroom_min * 1_000 / total_micro_amps;
+    if milli_ohms == 0 {
+        return None;
+    }
+    let resistance = Resistance::from_milli_ohms(u64::try_from(milli_ohms).ok()?);
+
+    // (μV)² ÷ mΩ is in nanowatts
+    let resistor_power = headroom_max
+        .checked_mul(headroom_max)?
+        .div_ceil(milli_ohms * 1_000);
+
+    // Zener current scaled by the resistance (μA × mΩ), which cannot go below zero
+    let zener_current =
+        (headroom_max * 1_000).saturating_sub(load.min().micro_amps() as u128 * milli_ohms);
+    let zener_power = zener_micro_volts
+        .checked_mul(zener_current)?
+        .div_ceil(milli_ohms * 1_000_000);
+
+    Some(ZenerBias {
+        resistance,
+        resistor_power: Power::from_micro_watts(u64::try_from(resistor_power).ok()?),
+        zener_power: Power::from_micro_watts(u64::try_from(zener_power).ok()?),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn supply(min: i64, max: i64) -> Toleranced<Voltage> {
+        let nominal = Voltage::from_micro_volts(min);
+        Toleranced::from_bounds(nominal, nominal, Voltage::from_micro_volts(max)).unwrap()
+    }
+
+    fn load(min: u64, max: u64) -> Toleranced<Current> {
+        let nominal = Current::from_micro_amps(min);
+        Toleranced::from_bounds(nominal, nominal, Current::from_micro_amps(max)).unwrap()
+    }
+
+    #[test_case((10_800_000, 13_200_000), 5_100_000, (0, 10_000), 1_000, Some((518_181, 126_616, 79_722)); "12V supply")]
+    #[test_case((5_000_000, 5_000_000), 3_300_000, (1_000, 1_000), 1_000, Some((850_000, 3_400, 3_300)); "fixed supply and load")]
+    #[test_case((9_000_000, 9_000_000), 5_000_000, (20_000, 20_000), 0, Some((200_000, 80_000, 0)); "no minimum zener current")]
+    fn test_bias_resistor(
+        (supply_min, supply_max): (i64, i64),
+        zener: i64,
+        (load_min, load_max): (u64, u64),
+        min_zener_current: u64,
+        expected: Option<(u64, u64, u64)>,
+    ) {
+        let bias = bias_resistor(
+            supply(supply_min, supply_max),
+            Voltage::from_micro_volts(zener),
+            load(load_min, load_max),
+            Current::from_micro_amps(min_zener_current),
+        );
+
+        let bias = bias.map(|b| {
+            (
+                b.resistance().milli_ohms(),
+                b.resistor_power().micro_watts(),
+                b.zener_power().micro_watts(),
+            )
+        });
+        assert_eq!(bias, expected);
+    }
+
+    #[test_case((5_000_000, 6_000_000), 5_000_000, (0, 1_000), 1_000; "supply at zener voltage")]
+    #[test_case((3_000_000, 6_000_000), 5_000_000, (0, 1_000), 1_000; "supply below zener voltage")]
+    #[test_case((12_000_000, 12_000_000), 0, (0, 1_000), 1_000; "zero zener voltage")]
+    #[test_case((12_000_000, 12_000_000), -5_000_000, (0, 1_000), 1_000; "negative zener voltage")]
+    #[test_case((12_000_000, 12_000_000), 5_000_000, (0, 0), 0; "no current")]
+    #[test_case((5_000_001, 5_000_001), 5_000_000, (0, 2_000), 0; "resistance rounds to zero")]
+    #[test_case((2_000_000, i64::MAX), 1_000_000, (0, 1), 0; "overflow")]
+    fn test_bias_resistor_none(
+        (supply_min, supply_max): (i64, i64),
+        zener: i64,
+        (load_min, load_max): (u64, u64),
+        min_zener_current: u64,
+    ) {
+        let bias = bias_resistor(
+            supply(supply_min, supply_max),
+            Voltage::from_micro_volts(zener),
+            load(load_min, load_max),
+            Current::from_micro_amps(min_zener_current),
+        );
+        assert_eq!(bias, None);
+    }
+}